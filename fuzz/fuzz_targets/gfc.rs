@@ -0,0 +1,23 @@
+//This file is licensed under EUPL v1.2
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use miniproj_ops::spherical_harmonic_geoid::SphericalHarmonicGeoid;
+
+#[derive(Arbitrary, Debug)]
+struct Input<'a> {
+    file: &'a [u8],
+    positions: Vec<(f64, f64)>,
+}
+
+// Malformed gravity field models, like headers claiming an enormous degree, have to result in an
+// error rather than a panic or an abort.
+fuzz_target!(|input: Input| {
+    if let Ok(model) = SphericalHarmonicGeoid::from_gfc_reader(input.file, None) {
+        for (lon, lat) in input.positions.into_iter().take(4) {
+            let _ = model.undulation_rad(lon, lat);
+        }
+    }
+});
@@ -0,0 +1,22 @@
+//This file is licensed under EUPL v1.2
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use miniproj_ops::grids::gtx::GtxGrid;
+
+#[derive(Arbitrary, Debug)]
+struct Input<'a> {
+    file: &'a [u8],
+    positions: Vec<(f64, f64)>,
+}
+
+// Malformed grid files have to result in an error rather than a panic or an abort.
+fuzz_target!(|input: Input| {
+    if let Ok(grid) = GtxGrid::from_bytes(input.file) {
+        for (lon, lat) in input.positions {
+            let _ = grid.value(lon, lat);
+        }
+    }
+});
@@ -0,0 +1,13 @@
+//This file is licensed under EUPL v1.2
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miniproj_epsg_registry::MemoryDb;
+
+// Malformed SQL has to result in an error, not a panic.
+fuzz_target!(|sql: &str| {
+    if let Ok(db) = MemoryDb::from_sql(sql) {
+        let _ = format!("{db:?}");
+    }
+});
@@ -0,0 +1,23 @@
+//This file is licensed under EUPL v1.2
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use miniproj_ops::grids::nadcon5::Nadcon5Grid;
+
+#[derive(Arbitrary, Debug)]
+struct Input<'a> {
+    file: &'a [u8],
+    positions: Vec<(f64, f64)>,
+}
+
+// Malformed grid files, like headers promising more rows than the file holds, have to result in
+// an error rather than a panic or an abort.
+fuzz_target!(|input: Input| {
+    if let Ok(grid) = Nadcon5Grid::from_bytes(input.file) {
+        for (lon, lat) in input.positions {
+            let _ = grid.value(lon, lat);
+        }
+    }
+});
@@ -0,0 +1,23 @@
+//This file is licensed under EUPL v1.2
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use miniproj_ops::grids::ntv2::NTv2Grid;
+
+#[derive(Arbitrary, Debug)]
+struct Input<'a> {
+    file: &'a [u8],
+    positions: Vec<(f64, f64)>,
+}
+
+// Malformed grid files, like headers promising more nodes than the file holds or subgrids that are
+// their own parents, have to result in an error rather than a panic, an abort or a hang.
+fuzz_target!(|input: Input| {
+    if let Ok(grid) = NTv2Grid::from_bytes(input.file) {
+        for (lon, lat) in input.positions {
+            let _ = grid.shift(lon, lat);
+        }
+    }
+});
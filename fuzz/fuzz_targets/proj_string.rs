@@ -0,0 +1,20 @@
+//This file is licensed under EUPL v1.2
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miniproj::{parse_proj_pipeline, parse_proj_string, PipelineCoordinate};
+use miniproj_ops::coordinate::Geographic3DCoordinate;
+
+// Malformed PROJ strings have to result in an error, not a panic, and whatever parses has to be
+// written back out or applied.
+fuzz_target!(|proj: &str| {
+    if let Ok(definition) = parse_proj_string(proj) {
+        let _ = definition.to_proj_string();
+        let _ = definition.to_wkt2();
+    }
+    if let Ok(pipeline) = parse_proj_pipeline(proj) {
+        let from = Geographic3DCoordinate::from_deg(10.0, 54.0, 0.0);
+        let _ = pipeline.try_apply(PipelineCoordinate::from(from));
+    }
+});
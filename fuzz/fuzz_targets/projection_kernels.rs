@@ -0,0 +1,35 @@
+//This file is licensed under EUPL v1.2
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use miniproj_epsg_registry::IMPL_CONV;
+use miniproj_ops::{custom_projection, Ellipsoid};
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    method: usize,
+    a: f64,
+    b: f64,
+    params: Vec<f64>,
+    coordinates: Vec<(f64, f64)>,
+}
+
+// Projections with arbitrary parameters, ellipsoids and coordinates, including NaN, infinities
+// and positions far outside the area of use, have to return without panicking or hanging in the
+// iterations of the inverse.
+fuzz_target!(|input: Input| {
+    let method = IMPL_CONV[input.method % IMPL_CONV.len()].0;
+    let ellipsoid = Ellipsoid::from_a_b(input.a, input.b);
+    let mut params = input.params.iter().copied();
+    let Some(projection) = custom_projection(method, |_| params.next(), &ellipsoid) else {
+        return;
+    };
+    for (x, y) in input.coordinates {
+        let (lon, lat) = projection.projected_to_rad(x, y);
+        let _ = projection.rad_to_projected(lon, lat);
+        let _ = projection.rad_to_projected(x, y);
+        let _ = projection.jacobian(x, y);
+    }
+});
@@ -0,0 +1,15 @@
+//This file is licensed under EUPL v1.2
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miniproj::parse_wkt;
+
+// Malformed or deeply nested WKT has to result in an error, not a panic or a stack overflow, and
+// whatever parses has to be written back out.
+fuzz_target!(|wkt: &str| {
+    if let Ok(definition) = parse_wkt(wkt) {
+        let _ = definition.to_wkt2();
+        let _ = definition.to_proj_string();
+    }
+});
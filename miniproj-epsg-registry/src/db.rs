@@ -1,14 +1,23 @@
 //This file is licensed under EUPL v1.2 as part of the Digital Earth Viewer
 
-use std::{collections::HashMap, error::Error, num::TryFromIntError};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    num::TryFromIntError,
+};
 
 use crate::{
     helpers::*,
     sql::{Field, MemoryDb},
-    ImplementedProjection,
+    ImplementedGeocentricConversion, ImplementedGeocentricTransformation, ImplementedProjection,
+    ImplementedTransformation, ImplementedVerticalTransformation,
 };
+use miniproj_ops::axis_order::AxisDirection;
 use miniproj_ops::ellipsoid::Ellipsoid;
-use miniproj_ops::PseudoSerialize;
+use miniproj_ops::identity_projection::IdentityProjection;
+use miniproj_ops::longitude_rotation::PrimeMeridianShift;
+use miniproj_ops::unit::UnitKind;
+use miniproj_ops::{custom_projection, Projection, PseudoSerialize};
 
 /// Generates rust source code mapping EPSG codes to `Ellipsoid`s.
 pub fn gen_ellipsoid_constructors(db: &MemoryDb) -> Result<String, Box<dyn Error>> {
@@ -117,14 +126,454 @@ pub fn get_ellipsoids(db: &MemoryDb) -> Result<HashMap<u32, Ellipsoid>, Box<dyn
     Ok(ellipsoids)
 }
 
+/// Constructs a `HashMap` mapping EPSG unit of measure codes to their `(factor_b, factor_c)` pair.
+///
+/// A value in the unit is converted to the base unit of its kind (metre, radian, unity or second)
+/// by multiplying with `factor_b / factor_c`. Units without factors, like the sexagesimal
+/// encodings, are not contained in the map.
+pub fn get_units(db: &MemoryDb) -> Result<HashMap<u32, (f64, f64)>, Box<dyn Error>> {
+    db.get_table("epsg_unitofmeasure")
+        .ok_or("No UOM table")?
+        .get_rows(&["uom_code", "factor_b", "factor_c"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(uom_code)), Some(Field::Double(factor_b)), Some(Field::Double(factor_c))] => {
+                Some(u32::try_from(uom_code).map(|c| (c, (factor_b, factor_c))).map_err(Into::into))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Coordinate system from the `epsg_coordinatesystem` table, together with its axes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoordinateSystem {
+    pub code: u32,
+    pub name: String,
+    /// Type of the coordinate system, e.g. `"Cartesian"` or `"ellipsoidal"`
+    pub cs_type: String,
+    pub dimension: u32,
+    /// Axes in the order of the coordinate tuple
+    pub axes: Vec<CoordinateAxis>,
+}
+
+/// Coordinate axis from the `epsg_coordinateaxis` table, with its name resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoordinateAxis {
+    pub code: u32,
+    pub name: String,
+    /// Direction of the axis, e.g. `"east"` or `"north"`
+    pub orientation: String,
+    pub abbreviation: String,
+    pub uom_code: Option<u32>,
+    /// One-based position of the axis in the coordinate tuple
+    pub order: u32,
+}
+
+/// Constructs a `HashMap` mapping EPSG coordinate system codes to their axes, sorted by axis order.
+pub fn get_coordinate_axes(
+    db: &MemoryDb,
+) -> Result<HashMap<u32, Vec<CoordinateAxis>>, Box<dyn Error>> {
+    let axis_names = db
+        .get_table("epsg_coordinateaxisname")
+        .ok_or("No Axis Name Table")?
+        .get_rows(&["coord_axis_name_code", "coord_axis_name"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::StringLike(name))] => Some((code, name)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let mut axes: HashMap<u32, Vec<CoordinateAxis>> = HashMap::new();
+    for row in db
+        .get_table("epsg_coordinateaxis")
+        .ok_or("No Axis Table")?
+        .get_rows(&[
+            "coord_axis_code",
+            "coord_sys_code",
+            "coord_axis_name_code",
+            "coord_axis_orientation",
+            "coord_axis_abbreviation",
+            "uom_code",
+            "coord_axis_order",
+        ])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::IntLike(cs_code)), Some(Field::IntLike(name_code)), Some(Field::StringLike(orientation)), Some(Field::StringLike(abbreviation)), uom_code, Some(Field::IntLike(order))] =
+            row
+        else {
+            continue;
+        };
+        let uom_code = match uom_code {
+            Some(Field::IntLike(c)) => Some(u32::try_from(c)?),
+            _ => None,
+        };
+        axes.entry(u32::try_from(cs_code)?)
+            .or_default()
+            .push(CoordinateAxis {
+                code: u32::try_from(code)?,
+                name: axis_names
+                    .get(&name_code)
+                    .ok_or("Unknown Axis Name")?
+                    .to_string(),
+                orientation: orientation.to_string(),
+                abbreviation: abbreviation.to_string(),
+                uom_code,
+                order: u32::try_from(order)?,
+            });
+    }
+    for cs_axes in axes.values_mut() {
+        cs_axes.sort_by_key(|a| a.order);
+    }
+    Ok(axes)
+}
+
+/// Constructs a `HashMap` mapping EPSG codes to `CoordinateSystem`s.
+pub fn get_coordinate_systems(
+    db: &MemoryDb,
+) -> Result<HashMap<u32, CoordinateSystem>, Box<dyn Error>> {
+    let mut axes = get_coordinate_axes(db)?;
+    let mut systems = HashMap::new();
+    for row in db
+        .get_table("epsg_coordinatesystem")
+        .ok_or("No Coordinate System Table")?
+        .get_rows(&[
+            "coord_sys_code",
+            "coord_sys_name",
+            "coord_sys_type",
+            "dimension",
+        ])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::StringLike(name)), Some(Field::StringLike(cs_type)), Some(Field::IntLike(dimension))] =
+            row
+        else {
+            continue;
+        };
+        let code = u32::try_from(code)?;
+        systems.insert(
+            code,
+            CoordinateSystem {
+                code,
+                name: name.to_string(),
+                cs_type: cs_type.to_string(),
+                dimension: u32::try_from(dimension)?,
+                axes: axes.remove(&code).unwrap_or_default(),
+            },
+        );
+    }
+    Ok(systems)
+}
+
+/// Generates rust source code mapping EPSG unit of measure codes to `Unit`s.
+pub fn gen_unit_constructors(db: &MemoryDb) -> Result<String, Box<dyn Error>> {
+    let mut phf_map = phf_codegen::Map::new();
+    for row in db
+        .get_table("epsg_unitofmeasure")
+        .ok_or("No UOM table")?
+        .get_rows(&[
+            "uom_code",
+            "unit_of_meas_name",
+            "unit_of_meas_type",
+            "target_uom_code",
+            "factor_b",
+            "factor_c",
+        ])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::StringLike(name)), kind, Some(Field::IntLike(base_code)), factor_b, factor_c] =
+            row
+        else {
+            continue;
+        };
+        let kind = match kind {
+            Some(Field::StringLike(kind)) => UnitKind::from_epsg_type(kind),
+            _ => UnitKind::Other,
+        };
+        let to_base_factor = match (factor_b, factor_c) {
+            (Some(Field::Double(b)), Some(Field::Double(c))) => format!("Some({:?}f64)", b / c),
+            _ => "None".to_owned(),
+        };
+        phf_map.entry(
+            u32::try_from(code)?,
+            &format!("Unit{{ name: {name:?}, kind: UnitKind::{kind:?}, base_code: {base_code}, to_base_factor: {to_base_factor} }}"),
+        );
+    }
+    Ok(format!(
+        "#[allow(clippy::approx_constant)]\nstatic UNITS: phf::Map<u32, Unit> = {};",
+        phf_map.build()
+    ))
+}
+
+/// Generates rust source code mapping EPSG datum ensemble codes to `DatumEnsemble`s, and the codes of the
+/// coordinate reference systems based on a datum ensemble to the ensemble code.
+pub fn gen_datum_ensemble_constructors(db: &MemoryDb) -> Result<String, Box<dyn Error>> {
+    let mut members: HashMap<i64, Vec<(i64, i64)>> = HashMap::new();
+    db.get_table("epsg_datumensemblemember")
+        .ok_or("No Datum Ensemble Member Table")?
+        .get_rows(&["datum_ensemble_code", "datum_code", "datum_sequence"])?
+        .for_each(|row| {
+            if let [Some(Field::IntLike(ensemble)), Some(Field::IntLike(datum)), Some(Field::IntLike(sequence))] = row {
+                members.entry(ensemble).or_default().push((sequence, datum));
+            }
+        });
+    let mut phf_map = phf_codegen::Map::new();
+    let mut ensembles = HashSet::new();
+    for row in db
+        .get_table("epsg_datumensemble")
+        .ok_or("No Datum Ensemble Table")?
+        .get_rows(&["datum_ensemble_code", "ensemble_accuracy"])?
+    {
+        let [Some(Field::IntLike(code)), accuracy] = row else {
+            continue;
+        };
+        let accuracy = match accuracy {
+            Some(Field::Double(a)) => format!("Some({a:?}f64)"),
+            _ => "None".to_owned(),
+        };
+        let mut ensemble_members = members.remove(&code).unwrap_or_default();
+        ensemble_members.sort_unstable();
+        let ensemble_members = ensemble_members
+            .iter()
+            .map(|(_, datum)| datum.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        phf_map.entry(
+            u32::try_from(code)?,
+            &format!("DatumEnsemble{{ accuracy: {accuracy}, members: &[{ensemble_members}] }}"),
+        );
+        ensembles.insert(code);
+    }
+    let mut crs_map = phf_codegen::Map::new();
+    for row in db
+        .get_table("epsg_coordinatereferencesystem")
+        .ok_or("No CRS table")?
+        .get_rows(&["coord_ref_sys_code", "datum_code"])?
+    {
+        if let [Some(Field::IntLike(code)), Some(Field::IntLike(datum))] = row {
+            if ensembles.contains(&datum) {
+                crs_map.entry(u32::try_from(code)?, &datum.to_string());
+            }
+        }
+    }
+    Ok(format!(
+        "static DATUM_ENSEMBLES: phf::Map<u32, DatumEnsemble> = {};\nstatic CRS_DATUM_ENSEMBLES: phf::Map<u32, u32> = {};",
+        phf_map.build(),
+        crs_map.build()
+    ))
+}
+
+/// Generates rust source code mapping the EPSG codes of operation methods and their parameters to
+/// their names, for resolving methods and parameters of definitions without codes, like WKT.
+/// Also maps geographic and projected CRSs to the names of their datums and conversions, and
+/// ellipsoids and prime meridians to their names, for writing definitions like WKT.
+pub fn gen_name_constructors(db: &MemoryDb) -> Result<String, Box<dyn Error>> {
+    let mut method_map = phf_codegen::Map::new();
+    for row in db
+        .get_table("epsg_coordoperationmethod")
+        .ok_or("No Method table")?
+        .get_rows(&["coord_op_method_code", "coord_op_method_name"])?
+    {
+        if let [Some(Field::IntLike(code)), Some(Field::StringLike(name))] = row {
+            method_map.entry(u32::try_from(code)?, &format!("{name:?}"));
+        }
+    }
+    let mut parameter_map = phf_codegen::Map::new();
+    for row in db
+        .get_table("epsg_coordoperationparam")
+        .ok_or("No Parameter table")?
+        .get_rows(&["parameter_code", "parameter_name"])?
+    {
+        if let [Some(Field::IntLike(code)), Some(Field::StringLike(name))] = row {
+            parameter_map.entry(u32::try_from(code)?, &format!("{name:?}"));
+        }
+    }
+    let name_table =
+        |table: &str, columns: &[&str; 2]| -> Result<HashMap<i64, String>, Box<dyn Error>> {
+            Ok(db
+                .get_table(table)
+                .ok_or("Missing table")?
+                .get_rows(columns)?
+                .filter_map(|row| match row {
+                    [Some(Field::IntLike(code)), Some(Field::StringLike(name))] => {
+                        Some((code, name.to_owned()))
+                    }
+                    _ => None,
+                })
+                .collect())
+        };
+    let datum_names = name_table("epsg_datum", &["datum_code", "datum_name"])?;
+    let operation_names = name_table("epsg_coordoperation", &["coord_op_code", "coord_op_name"])?;
+    let mut datum_map = phf_codegen::Map::new();
+    let mut conversion_map = phf_codegen::Map::new();
+    for row in db
+        .get_table("epsg_coordinatereferencesystem")
+        .ok_or("No CRS table")?
+        .get_rows(&[
+            "coord_ref_sys_code",
+            "datum_code",
+            "projection_conv_code",
+            "coord_ref_sys_kind",
+        ])?
+    {
+        match row {
+            [Some(Field::IntLike(code)), Some(Field::IntLike(datum)), _, Some(Field::StringLike("geographic 2D"))] => {
+                if let Some(name) = datum_names.get(&datum) {
+                    datum_map.entry(u32::try_from(code)?, &format!("{name:?}"));
+                }
+            }
+            [Some(Field::IntLike(code)), _, Some(Field::IntLike(conversion)), Some(Field::StringLike("projected"))] => {
+                if let Some(name) = operation_names.get(&conversion) {
+                    conversion_map.entry(u32::try_from(code)?, &format!("{name:?}"));
+                }
+            }
+            _ => {}
+        }
+    }
+    let mut ellipsoid_map = phf_codegen::Map::new();
+    for (code, name) in name_table("epsg_ellipsoid", &["ellipsoid_code", "ellipsoid_name"])? {
+        ellipsoid_map.entry(u32::try_from(code)?, &format!("{name:?}"));
+    }
+    let mut prime_meridian_map = phf_codegen::Map::new();
+    for (code, name) in name_table(
+        "epsg_primemeridian",
+        &["prime_meridian_code", "prime_meridian_name"],
+    )? {
+        prime_meridian_map.entry(u32::try_from(code)?, &format!("{name:?}"));
+    }
+    Ok(format!(
+        "static METHOD_NAMES: phf::Map<u32, &str> = {};\nstatic PARAMETER_NAMES: phf::Map<u32, &str> = {};\n/// Datum names of geographic CRSs\nstatic DATUM_NAMES: phf::Map<u32, &str> = {};\n/// Conversion names of projected CRSs\nstatic CONVERSION_NAMES: phf::Map<u32, &str> = {};\nstatic ELLIPSOID_NAMES: phf::Map<u32, &str> = {};\nstatic PRIME_MERIDIAN_NAMES: phf::Map<u32, &str> = {};",
+        method_map.build(),
+        parameter_map.build(),
+        datum_map.build(),
+        conversion_map.build(),
+        ellipsoid_map.build(),
+        prime_meridian_map.build()
+    ))
+}
+
+/// Generates rust source code defining the version of the EPSG dataset as `REGISTRY_VERSION`.
+pub fn gen_registry_version(db: &MemoryDb) -> Result<String, Box<dyn Error>> {
+    let (_, version) = db
+        .get_table("epsg_versionhistory")
+        .ok_or("No Version History Table")?
+        .get_rows(&["version_history_code", "version_number"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::StringLike(version))] => Some((code, version)),
+            _ => None,
+        })
+        .max_by_key(|(code, _)| *code)
+        .ok_or("Empty Version History")?;
+    Ok(format!("static REGISTRY_VERSION: &str = {version:?};"))
+}
+
 /// Generates rust source code mapping EPSG codes to prime meridian angles in radians relative to the Greenwich meridian.
-pub fn gen_prime_meridians_source(_c: &MemoryDb) -> Result<String, Box<dyn Error>> {
-    todo!()
+pub fn gen_prime_meridians_source(db: &MemoryDb) -> Result<String, Box<dyn Error>> {
+    let mut phf_map = phf_codegen::Map::new();
+    for (code, lon) in get_prime_meridians(db)? {
+        phf_map.entry(code, &format!("{lon:?}"));
+    }
+    Ok(format!(
+        "#[allow(clippy::approx_constant)]\nstatic PRIME_MERIDIANS: phf::Map<u32, f64> = {};",
+        phf_map.build()
+    ))
 }
 
 /// Constructs a `HashMap` mapping EPSG codes to prime meridian angles in radians relative to the Greenwich meridian.
-pub fn get_prime_meridians(_c: &MemoryDb) -> Result<HashMap<u32, f64>, Box<dyn Error>> {
-    todo!()
+pub fn get_prime_meridians(db: &MemoryDb) -> Result<HashMap<u32, f64>, Box<dyn Error>> {
+    let units = get_units(db)?;
+    db.get_table("epsg_primemeridian")
+        .ok_or("No Prime Meridian table")?
+        .get_rows(&["prime_meridian_code", "greenwich_longitude", "uom_code"])?
+        .map(|row| {
+            let [Some(Field::IntLike(code)), Some(Field::Double(lon)), Some(Field::IntLike(uom_code))] = row else {
+                return Err::<_, Box<dyn Error>>(format!("Malformed prime meridian {row:?}").into());
+            };
+            let lon = to_base_unit(lon, u32::try_from(uom_code)?, &units)
+                .ok_or_else(|| format!("Unsupported unit EPSG:{uom_code} in {row:?}"))?;
+            Ok((u32::try_from(code)?, lon))
+        })
+        .collect()
+}
+
+type ParamValues = HashMap<u32, Vec<(u32, f64)>>;
+
+type UsageAreas<'a> = HashMap<i64, Vec<(&'a str, [f64; 4])>>;
+
+/// Constructs a `HashMap` mapping the codes of the objects of `table`, e.g. `"epsg_coordoperation"`, to the
+/// names and bounding boxes of their areas of use, in `[east, north, west, south]` order and degrees.
+fn get_usage_areas<'a>(db: &'a MemoryDb, table: &str) -> Result<UsageAreas<'a>, Box<dyn Error>> {
+    let degrees = |field: Option<Field>| match field {
+        Some(Field::Double(v)) => Some(v),
+        Some(Field::IntLike(v)) => Some(v as f64),
+        _ => None,
+    };
+    let extents = db
+        .get_table("epsg_extent")
+        .ok_or("No Extent Table")?
+        .get_rows(&[
+            "extent_code",
+            "extent_name",
+            "bbox_south_bound_lat",
+            "bbox_west_bound_lon",
+            "bbox_north_bound_lat",
+            "bbox_east_bound_lon",
+        ])?
+        .filter_map(
+            |[code, name, south, west, north, east]| match (code, name) {
+                (Some(Field::IntLike(code)), Some(Field::StringLike(name))) => Some((
+                    code,
+                    (
+                        name,
+                        [
+                            degrees(east)?,
+                            degrees(north)?,
+                            degrees(west)?,
+                            degrees(south)?,
+                        ],
+                    ),
+                )),
+                _ => None,
+            },
+        )
+        .collect::<HashMap<_, _>>();
+    let mut areas: UsageAreas = HashMap::new();
+    for row in db
+        .get_table("epsg_usage")
+        .ok_or("No Usage Table")?
+        .get_rows(&["object_table_name", "object_code", "extent_code"])?
+    {
+        if let [Some(Field::StringLike(object_table)), Some(Field::IntLike(code)), Some(Field::IntLike(extent))] =
+            row
+        {
+            if let (true, Some(area)) = (object_table == table, extents.get(&extent)) {
+                areas.entry(code).or_default().push(*area);
+            }
+        }
+    }
+    Ok(areas)
+}
+
+/// Constructs a `HashMap` mapping EPSG coordinate operation codes to their `(parameter code, value)`-tuples,
+/// with the values converted to base units. Parameters in units that can not be converted are left out,
+/// parameters without unit are passed through.
+fn get_param_values(db: &MemoryDb) -> Result<ParamValues, Box<dyn Error>> {
+    let units = get_units(db)?;
+    let mut paramvalues: ParamValues = HashMap::new();
+    db.get_table("epsg_coordoperationparamvalue")
+            .ok_or("No Param Value table")?
+            .get_rows(&["coord_op_code", "parameter_code", "parameter_value", "uom_code"])?
+            .try_for_each::<_, Result<_, Box<dyn Error>>>(|row| {
+                let [Some(Field::IntLike(coord_op_code)), Some(Field::IntLike(parameter_code)), Some(Field::Double(v)), uom_code] = row else {
+                    return Ok(());
+                };
+                let v = match uom_code {
+                    Some(Field::IntLike(uom_code)) => to_base_unit(v, u32::try_from(uom_code)?, &units),
+                    // parameters without unit are codes, like the EPSG code for the horizontal CRS
+                    None => Some(v),
+                    _ => None,
+                };
+                if let Some(v) = v {
+                    paramvalues.entry(u32::try_from(coord_op_code)?).or_default().push((u32::try_from(parameter_code)?, v));
+                }
+                Ok(())
+            })?;
+    Ok(paramvalues)
 }
 
 #[derive(Debug)]
@@ -139,17 +588,6 @@ pub fn gen_parameter_constructors(
     supporteds: &[ImplementedProjection],
     ellipsoids: &HashMap<u32, Ellipsoid>,
 ) -> Result<String, Box<dyn Error>> {
-    let units = db.get_table("epsg_unitofmeasure")
-        .ok_or("No UOM table")?
-        .get_rows(&["uom_code", "factor_b", "factor_c"])?
-        .filter_map(|row| {
-            match row {
-                [Some(Field::IntLike(uom_code)), Some(Field::Double(factor_b)), Some(Field::Double(factor_c))] =>
-                    Some((u32::try_from(uom_code).ok()?, (factor_b, factor_c))),
-                _ => None
-            }
-        }).collect::<HashMap<u32, _>>();
-
     let crs_table = db.get_table("epsg_coordinatereferencesystem")
         .ok_or("No CRS table")?
         .get_rows(&["coord_ref_sys_code", "base_crs_code", "projection_conv_code", "datum_code", "coord_ref_sys_kind"])?
@@ -166,28 +604,92 @@ pub fn gen_parameter_constructors(
         })
         .collect::<HashMap<u32, _>>();
     assert!(!crs_table.is_empty());
-    let names_table = db.get_table("epsg_coordinatereferencesystem")
+    // kinds of the CRSs without a projection, like geocentric or vertical CRSs
+    let other_kinds = db
+        .get_table("epsg_coordinatereferencesystem")
+        .ok_or("No CRS table")?
+        .get_rows(&["coord_ref_sys_code", "coord_ref_sys_kind"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::StringLike(kind))] => {
+                Some((u32::try_from(code).ok()?, kind))
+            }
+            _ => None,
+        })
+        .filter(|(code, _)| !crs_table.contains_key(code))
+        .collect::<Vec<_>>();
+    let names_table = db
+        .get_table("epsg_coordinatereferencesystem")
         .ok_or("No CRS table")?
         .get_rows(&["coord_ref_sys_code", "coord_ref_sys_name"])?
-        .filter_map(|row| {
-            match row {
-                [Some(Field::IntLike(code)), Some(Field::StringLike(name))] => {
-                    Some((u32::try_from(code).ok()?, name))
-                },
-                _ => None
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::StringLike(name))] => {
+                Some((u32::try_from(code).ok()?, name))
+            }
+            _ => None,
+        })
+        .collect::<HashMap<u32, _>>();
+    // coordinate system of each CRS, for the unit and the directions of its axes
+    let coordinate_systems = get_coordinate_systems(db)?;
+    let cs_table = db
+        .get_table("epsg_coordinatereferencesystem")
+        .ok_or("No CRS table")?
+        .get_rows(&["coord_ref_sys_code", "coord_sys_code"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::IntLike(cs))] => {
+                Some((u32::try_from(code).ok()?, u32::try_from(cs).ok()?))
             }
+            _ => None,
         })
         .collect::<HashMap<u32, _>>();
-    let extents_table = db.get_table("epsg_extent")
+    let units_table = cs_table
+        .iter()
+        .filter_map(|(code, cs)| Some((*code, coordinate_systems.get(cs)?.axes.first()?.uom_code?)))
+        .collect::<HashMap<u32, _>>();
+    let axes_table = cs_table
+        .iter()
+        .filter_map(
+            |(code, cs)| match coordinate_systems.get(cs)?.axes.as_slice() {
+                [first, second, ..] => Some((
+                    *code,
+                    [&first.orientation, &second.orientation]
+                        .map(|o| AxisDirection::from_epsg_orientation(o)),
+                )),
+                _ => None,
+            },
+        )
+        .collect::<HashMap<u32, _>>();
+    // bounds of whole degrees are stored as integers
+    let degrees = |field: Option<Field>| match field {
+        Some(Field::Double(v)) => Some(v),
+        Some(Field::IntLike(v)) => Some(v as f64),
+        _ => None,
+    };
+    let extents_table = db
+        .get_table("epsg_extent")
         .ok_or("No Extent Table")?
-        .get_rows(&["extent_code", "extent_name", "bbox_south_bound_lat", "bbox_west_bound_lon", "bbox_north_bound_lat", "bbox_east_bound_lon"])?
-        .filter_map(|row| {
-            match row {
-                [Some(Field::IntLike(code)), Some(Field::StringLike(name)), Some(Field::Double(lat_s)), Some(Field::Double(lon_w)), Some(Field::Double(lat_n)), Some(Field::Double(lon_e))] => {
-                    Some((u32::try_from(code).ok()?, (name, [lon_e, lat_n, lon_w, lat_s]))) //TODO make a real type
-                },
-                _ => None
+        .get_rows(&[
+            "extent_code",
+            "extent_name",
+            "bbox_south_bound_lat",
+            "bbox_west_bound_lon",
+            "bbox_north_bound_lat",
+            "bbox_east_bound_lon",
+        ])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::StringLike(name)), south, west, north, east] =>
+            {
+                let [east, north, west, south] = [
+                    degrees(east)?,
+                    degrees(north)?,
+                    degrees(west)?,
+                    degrees(south)?,
+                ];
+                Some((
+                    u32::try_from(code).ok()?,
+                    (name, [east, north, west, south]),
+                ))
             }
+            _ => None,
         })
         .collect::<HashMap<u32, _>>();
     let mut usages_table: HashMap<u32, Vec<_>> = HashMap::new();
@@ -195,15 +697,19 @@ pub fn gen_parameter_constructors(
         .ok_or("No Usage Table")?
         .get_rows(&["object_code", "extent_code"])?
         .for_each(|row| {
-            match row {
-                [Some(Field::IntLike(object_code)), Some(Field::IntLike(extent_code))] => {
-                    let Ok(object_code) = u32::try_from(object_code) else {return};
-                    let Ok(extent_code) = u32::try_from(extent_code) else {return};
-                    if let Some((name, area)) = extents_table.get(&extent_code) {
-                        usages_table.entry(object_code).or_default().push((name, area))
-                    }
-                },
-                _ => {}
+            if let [Some(Field::IntLike(object_code)), Some(Field::IntLike(extent_code))] = row {
+                let Ok(object_code) = u32::try_from(object_code) else {
+                    return;
+                };
+                let Ok(extent_code) = u32::try_from(extent_code) else {
+                    return;
+                };
+                if let Some((name, area)) = extents_table.get(&extent_code) {
+                    usages_table
+                        .entry(object_code)
+                        .or_default()
+                        .push((*name, *area))
+                }
             }
         });
 
@@ -229,26 +735,7 @@ pub fn gen_parameter_constructors(
         })
         .collect::<Result<HashMap<u32, u32>, TryFromIntError>>()?;
 
-    let mut paramvalues: HashMap<u32, Vec<_>> = HashMap::new();
-    db.get_table("epsg_coordoperationparamvalue")
-            .ok_or("No Param Value table")?
-            .get_rows(&["coord_op_code", "parameter_code", "parameter_value", "uom_code"])?
-            .try_for_each::<_, Result<_, Box<dyn Error>>>(|row| {
-
-                match row {
-                    [Some(Field::IntLike(coord_op_code)), Some(Field::IntLike(parameter_code)), Some(Field::Double(v)), Some(Field::IntLike(9110))] => {
-                        paramvalues.entry(u32::try_from(coord_op_code)?).or_default().push((u32::try_from(parameter_code)?, epsg_9110_to_rad(v)));
-                    },
-                    [Some(Field::IntLike(coord_op_code)), Some(Field::IntLike(parameter_code)), Some(Field::Double(v)), Some(Field::IntLike(uom_code))] => {
-                        if let Some((factor_b, factor_c)) = units.get(&u32::try_from(uom_code)?) {
-                            paramvalues.entry(u32::try_from(coord_op_code)?).or_default().push((u32::try_from(parameter_code)?, v * factor_b / factor_c));
-                        }
-                    },
-                    //e => return Err(format!("Missing param values in {e:?}").into()),
-                    _ => {}
-                };
-                Ok(())
-            })?;
+    let paramvalues = get_param_values(db)?;
 
     assert!(!op_table.is_empty());
     let datum_table = db
@@ -258,14 +745,13 @@ pub fn gen_parameter_constructors(
         .filter_map(|row| {
             let [Some(Field::IntLike(code)), Some(Field::IntLike(ellipsoid_code)), Some(Field::IntLike(prime_meridian_code))] = row else {return None};
             match(u32::try_from(code), u32::try_from(ellipsoid_code), u32::try_from(prime_meridian_code)) {
-                (Ok(code), Ok(ellipsoid_code), Ok(8901)) => { // since correction for other meridians is currently missing.
+                (Ok(code), Ok(ellipsoid_code), Ok(prime_meridian_code)) => {
                     if ellipsoids.contains_key(&ellipsoid_code) {
-                        Some(Ok((code, (ellipsoid_code, 8901))))
+                        Some(Ok((code, (ellipsoid_code, prime_meridian_code))))
                     } else {
                         None
                     }
                 },
-                (Ok(_), Ok(_), Ok(_)) => None,
                 (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => Some(Err(e))
             }
         }).collect::<Result<HashMap<u32, _>, TryFromIntError>>()?;
@@ -293,75 +779,180 @@ pub fn gen_parameter_constructors(
             .or_insert(vec![d]);
     }
 
+    let prime_meridians = get_prime_meridians(db)?;
+    // resolves a datum, or the first member of a datum ensemble, to its ellipsoid and prime meridian
+    let resolve_datum = |datum: &u32| {
+        std::iter::once(datum)
+            .chain(
+                datum_ensemble_member_table
+                    .get(datum)
+                    .iter()
+                    .flat_map(|v| v.iter()),
+            )
+            .filter_map(|d| datum_table.get(d))
+            .filter_map(|(e, pm)| ellipsoids.get(e).map(|ell| (ell, e, *pm)))
+            .next()
+    };
+    // longitudes of CRSs on other prime meridians than Greenwich are rotated by the projection
+    let shift_meridian = |constructor: String, prime_meridian: u32| -> Result<String, String> {
+        if prime_meridian == 8901 {
+            return Ok(constructor);
+        }
+        let lon = prime_meridians
+            .get(&prime_meridian)
+            .ok_or_else(|| format!("Unknown prime meridian EPSG:{prime_meridian}"))?;
+        Ok(format!(
+            "PrimeMeridianShift{{\n    projection: {constructor},\n    prime_meridian: {lon}f64,\n}}"
+        ))
+    };
+    // the same rotation for the projections built to sample the projected bounds
+    let shift_projection =
+        |projection: Box<dyn Projection>, prime_meridian: u32| match prime_meridians
+            .get(&prime_meridian)
+        {
+            Some(&lon) if prime_meridian != 8901 => Box::new(PrimeMeridianShift {
+                projection,
+                prime_meridian: lon,
+            }) as Box<dyn Projection>,
+            _ => projection,
+        };
+
     let mut constructors_map = phf_codegen::Map::new();
     let mut ellipsoids_map = phf_codegen::Map::new();
+    let mut meridians_map = phf_codegen::Map::new();
     let mut names_map = phf_codegen::Map::new();
     let mut areas_map = phf_codegen::Map::new();
+    let mut methods_map = phf_codegen::Map::new();
+    let mut areas_of_use_map = phf_codegen::Map::new();
+    let mut bounds_map = phf_codegen::Map::new();
+    let mut parameters_map = phf_codegen::Map::new();
+    let mut bases_map = phf_codegen::Map::new();
+    let mut units_map = phf_codegen::Map::new();
+    let mut axes_map = phf_codegen::Map::new();
+    // reasons why CRSs of the registry have no projection
+    let mut unsupported_map = phf_codegen::Map::new();
+    for (code, kind) in &other_kinds {
+        unsupported_map.entry(
+            *code,
+            &format!("MiniprojError::UnsupportedCrsKind {{ code: {code}, kind: {kind:?} }}"),
+        );
+    }
 
     for (code, crs) in &crs_table {
-        let name = names_table.get(code).unwrap_or(&"Unknown Coordinate Reference System");
+        let name = names_table
+            .get(code)
+            .unwrap_or(&"Unknown Coordinate Reference System");
         let areas = usages_table.get(code);
         match crs {
-            CrsEntry::Geographic2D { datum: _ } => {
-                constructors_map.entry(code, "&IdentityProjection as &dyn Projection");
+            CrsEntry::Geographic2D { datum } => {
+                let prime_meridian = resolve_datum(datum).map_or(8901, |(_, _, pm)| pm);
+                let Ok(constructor) = shift_meridian("IdentityProjection".into(), prime_meridian)
+                else {
+                    unsupported_map.entry(*code, &format!("MiniprojError::NonGreenwichMeridian {{ code: {code}, prime_meridian: {prime_meridian} }}"));
+                    continue;
+                };
+                meridians_map.entry(code, &format!("{prime_meridian}"));
+                constructors_map.entry(code, &format!("&{constructor} as &dyn Projection"));
+                if let Some((_, ellipsoid_code, _)) = resolve_datum(datum) {
+                    ellipsoids_map.entry(code, &format!("{ellipsoid_code}"));
+                }
+                if let Some(unit) = units_table.get(code) {
+                    units_map.entry(code, &format!("{unit}"));
+                }
+                if let Some([first, second]) = axes_table.get(code) {
+                    axes_map.entry(
+                        code,
+                        &format!("[AxisDirection::{first:?}, AxisDirection::{second:?}]"),
+                    );
+                }
                 names_map.entry(code, &format!("{name:?}"));
                 if let Some(areas) = areas {
-                    let mut areas_string = String::new();
-                    areas_string.push_str("&[");
-                    for (_, [e, n, w, s]) in areas { // TODO make a real type
-
-                        areas_string.push_str(&format!("[{e:?}, {n:?}, {w:?}, {s:?}],"));
+                    areas_map.entry(
+                        code,
+                        &format_areas(&areas.iter().map(|(_, a)| *a).collect::<Vec<_>>()),
+                    );
+                    areas_of_use_map.entry(code, &format_areas_of_use(areas));
+                    let projection = shift_projection(Box::new(IdentityProjection), prime_meridian);
+                    if let Some(bounds) = projected_bounds(&projection, areas) {
+                        bounds_map.entry(code, &format_bounds(&bounds));
                     }
-                    areas_string.push_str("]");
-                    areas_map.entry(code, &areas_string);
                 }
             }
             CrsEntry::Projected { conversion, base } => {
                 let Some(CrsEntry::Geographic2D { datum }) = crs_table.get(base) else {
                     //println!("cargo:warning=Skipping EPSG:{code} because base CRS EPSG:{base} does not resolve.");
+                    unsupported_map.entry(
+                        *code,
+                        &format!(
+                            "MiniprojError::BaseCrsUnresolved {{ code: {code}, base: {base} }}"
+                        ),
+                    );
                     continue;
                 };
-                let Some((ellipsoid, ellipsoid_code)) = std::iter::once(datum)
-                    .chain(
-                        datum_ensemble_member_table
-                            .get(datum)
-                            .iter()
-                            .flat_map(|v| v.iter()),
-                    )
-                    .filter_map(|d| datum_table.get(d))
-                    .filter_map(|(e, _)| ellipsoids.get(e).map(|ell| (ell, e))) //this is the spot to handle meridians as well
-                    .next()
-                else {
+                let Some((ellipsoid, ellipsoid_code, prime_meridian)) = resolve_datum(datum) else {
                     //println!("cargo:warning=Skipping EPSG:{code} because datum EPSG:{datum} does not resolve.");
+                    unsupported_map.entry(
+                        *code,
+                        &format!(
+                            "MiniprojError::DatumUnresolved {{ code: {code}, datum: {datum} }}"
+                        ),
+                    );
                     continue;
                 };
-                let Some(param_values) = paramvalues.get(conversion) else {
-                    //println!("cargo:warning=Skipping EPSG:{code} because parameter values do not resolve.");
-                    continue;
-                };
+                // methods without parameters, like Pseudo Plate Carree, have no parameter values
+                let param_values = paramvalues
+                    .get(conversion)
+                    .map(Vec::as_slice)
+                    .unwrap_or(&[]);
                 let Some(op_code) = op_table.get(conversion) else {
                     //println!("cargo:warning=Skipping EPSG:{code} because operation EPSG:{conversion} does not resolve.");
+                    unsupported_map.entry(*code, &format!("MiniprojError::ConversionUnresolved {{ code: {code}, conversion: {conversion} }}"));
                     continue;
                 };
                 let Some((_, conv)) = supporteds.iter().find(|(v, _)| v == op_code) else {
                     //println!("cargo:warning=Skipping EPSG:{code} because operation method EPSG:{op_code} is not implemented.");
+                    unsupported_map.entry(*code, &format!("MiniprojError::MethodNotImplemented {{ code: {code}, method: {op_code} }}"));
                     continue;
                 };
-                constructors_map.entry(
-                    code,
-                    &format!("&{} as &dyn Projection", conv(param_values, *ellipsoid)),
-                );
+                let Ok(constructor) =
+                    shift_meridian(conv(param_values, *ellipsoid), prime_meridian)
+                else {
+                    unsupported_map.entry(*code, &format!("MiniprojError::NonGreenwichMeridian {{ code: {code}, prime_meridian: {prime_meridian} }}"));
+                    continue;
+                };
+                constructors_map.entry(code, &format!("&{constructor} as &dyn Projection"));
                 ellipsoids_map.entry(code, &format!("{ellipsoid_code}"));
+                meridians_map.entry(code, &format!("{prime_meridian}"));
+                methods_map.entry(code, &format!("{op_code}"));
+                bases_map.entry(code, &format!("{base}"));
+                if let Some(unit) = units_table.get(code) {
+                    units_map.entry(code, &format!("{unit}"));
+                }
+                if let Some([first, second]) = axes_table.get(code) {
+                    axes_map.entry(
+                        code,
+                        &format!("[AxisDirection::{first:?}, AxisDirection::{second:?}]"),
+                    );
+                }
+                let parameters = param_values
+                    .iter()
+                    .map(|(c, v)| format!("({c}, {v:?})"))
+                    .collect::<Vec<_>>();
+                parameters_map.entry(code, &format!("&[{}]", parameters.join(", ")));
                 names_map.entry(code, &format!("{name:?}"));
                 if let Some(areas) = areas {
-                    let mut areas_string = String::new();
-                    areas_string.push_str("&[");
-                    for (_, [e, n, w, s]) in areas { // TODO make a real type
-
-                        areas_string.push_str(&format!("[{e:?}, {n:?}, {w:?}, {s:?}],"));
+                    areas_map.entry(
+                        code,
+                        &format_areas(&areas.iter().map(|(_, a)| *a).collect::<Vec<_>>()),
+                    );
+                    areas_of_use_map.entry(code, &format_areas_of_use(areas));
+                    let getter = |c| param_values.iter().find(|(p, _)| *p == c).map(|(_, v)| *v);
+                    if let Some(bounds) = custom_projection(*op_code, getter, ellipsoid)
+                        .map(|projection| shift_projection(projection, prime_meridian))
+                        .and_then(|projection| projected_bounds(&projection, areas))
+                    {
+                        bounds_map.entry(code, &format_bounds(&bounds));
                     }
-                    areas_string.push_str("]");
-                    areas_map.entry(code, &areas_string);
                 }
             }
         }
@@ -371,12 +962,972 @@ pub fn gen_parameter_constructors(
         r"#[allow(clippy::approx_constant)]
 static PROJECTIONS: phf::Map<u32, &dyn Projection> = {};
 static ELLIPSOIDS: phf::Map<u32, u32> = {};
+static MERIDIANS: phf::Map<u32, u32> = {};
 static NAMES: phf::Map<u32, &str> = {};
+#[allow(clippy::approx_constant)]
 static AREAS: phf::Map<u32, &[[f64; 4]]> = {};
+static METHODS: phf::Map<u32, u32> = {};
+#[allow(clippy::approx_constant)]
+static AREAS_OF_USE: phf::Map<u32, &[AreaOfUse]> = {};
+/// Bounding boxes of the areas of use in projected coordinates, in [max x, max y, min x, min y] order.
+#[allow(clippy::approx_constant)]
+static PROJECTED_BOUNDS: phf::Map<u32, [f64; 4]> = {};
+#[allow(clippy::approx_constant)]
+static PARAMETERS: phf::Map<u32, &[(u32, f64)]> = {};
+static BASES: phf::Map<u32, u32> = {};
+static UNITS: phf::Map<u32, u32> = {};
+static AXES: phf::Map<u32, [AxisDirection; 2]> = {};
+static UNSUPPORTED: phf::Map<u32, MiniprojError> = {};
 ",
         constructors_map.build(),
         ellipsoids_map.build(),
+        meridians_map.build(),
         names_map.build(),
-        areas_map.build()
+        areas_map.build(),
+        methods_map.build(),
+        areas_of_use_map.build(),
+        bounds_map.build(),
+        parameters_map.build(),
+        bases_map.build(),
+        units_map.build(),
+        axes_map.build(),
+        unsupported_map.build()
+    ))
+}
+
+/// Constructs a `HashMap` mapping the codes of geographic 2D and 3D CRSs to the ellipsoids of their
+/// datums. Datum ensembles use the ellipsoid of their first member. CRSs whose datums do not resolve
+/// to a known ellipsoid on the Greenwich meridian are left out.
+fn get_crs_ellipsoids(
+    db: &MemoryDb,
+    ellipsoids: &HashMap<u32, Ellipsoid>,
+) -> Result<HashMap<i64, Ellipsoid>, Box<dyn Error>> {
+    let crs_ellipsoids =
+        get_crs_datum_ellipsoids(db, ellipsoids, &["geographic 2D", "geographic 3D"])?
+            .into_iter()
+            .map(|(code, (_, _, ellipsoid))| (code, ellipsoid))
+            .collect();
+    Ok(crs_ellipsoids)
+}
+
+/// CRS kind, datum code and ellipsoid of the datum per CRS code.
+type CrsDatumEllipsoids<'a> = HashMap<i64, (&'a str, i64, Ellipsoid)>;
+
+/// Constructs a `HashMap` mapping the codes of the CRSs of the given kinds to their kind, datum and the
+/// ellipsoid of the datum, like `get_crs_ellipsoids`.
+fn get_crs_datum_ellipsoids<'a>(
+    db: &'a MemoryDb,
+    ellipsoids: &HashMap<u32, Ellipsoid>,
+    kinds: &[&str],
+) -> Result<CrsDatumEllipsoids<'a>, Box<dyn Error>> {
+    let mut ensemble_members: HashMap<i64, Vec<(i64, i64)>> = HashMap::new();
+    db.get_table("epsg_datumensemblemember")
+        .ok_or("No Datum Ensemble Member Table")?
+        .get_rows(&["datum_ensemble_code", "datum_code", "datum_sequence"])?
+        .for_each(|row| {
+            if let [Some(Field::IntLike(ensemble)), Some(Field::IntLike(datum)), Some(Field::IntLike(sequence))] = row {
+                ensemble_members.entry(ensemble).or_default().push((sequence, datum));
+            }
+        });
+    let datum_ellipsoids = db
+        .get_table("epsg_datum")
+        .ok_or("No Datum table")?
+        .get_rows(&["datum_code", "ellipsoid_code", "prime_meridian_code"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::IntLike(ellipsoid_code)), Some(Field::IntLike(8901))] => {
+                Some((code, ellipsoid_code))
+            }
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    // ensembles have no ellipsoid of their own, use the one of the first member
+    let datum_ellipsoid = |datum: i64| {
+        let mut members = ensemble_members.get(&datum).cloned().unwrap_or_default();
+        members.sort_unstable();
+        std::iter::once(datum)
+            .chain(members.into_iter().map(|(_, d)| d))
+            .filter_map(|d| datum_ellipsoids.get(&d))
+            .filter_map(|e| ellipsoids.get(&u32::try_from(*e).ok()?))
+            .next()
+            .copied()
+    };
+    let crs_ellipsoids = db
+        .get_table("epsg_coordinatereferencesystem")
+        .ok_or("No CRS table")?
+        .get_rows(&["coord_ref_sys_code", "datum_code", "coord_ref_sys_kind"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::IntLike(datum)), Some(Field::StringLike(kind))]
+                if kinds.contains(&kind) =>
+            {
+                Some((code, (kind, datum, datum_ellipsoid(datum)?)))
+            }
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    Ok(crs_ellipsoids)
+}
+
+/// Generates rust source code for all transformations between geographic coordinate reference
+/// systems using one of the implemented transformation methods.
+///
+/// The operations are constructed with the ellipsoids of the source and target CRS. Transformations
+/// between CRSs whose datums do not resolve to a known ellipsoid on the Greenwich meridian are skipped,
+/// as are transformations lacking one of the parameters of their method.
+///
+/// Operations of methods that are reversible according to the registry are also constructed in the
+/// reverse direction, with the signs of the parameters flagged for sign reversal flipped. The others
+/// are applied in reverse as their `Inverse`, so that the routing graph links the CRSs both ways. Supported
+/// conversions between geographic 3D and 2D CRSs connect all such CRSs sharing a datum. Together with
+/// the accuracies of the operations, this is the graph used for routing between CRSs. Concatenated
+/// operations are listed as their steps, if all of them are constructed.
+pub fn gen_transformation_constructors(
+    db: &MemoryDb,
+    supporteds: &[ImplementedTransformation],
+    ellipsoids: &HashMap<u32, Ellipsoid>,
+) -> Result<String, Box<dyn Error>> {
+    let crs_ellipsoids = get_crs_ellipsoids(db, ellipsoids)?;
+    let paramvalues = get_param_values(db)?;
+    let reversibles = get_reversible_methods(db)?;
+    let sign_reversals = get_sign_reversals(db)?;
+    // parameters of each method
+    let mut method_params: HashMap<i64, Vec<u32>> = HashMap::new();
+    for row in db
+        .get_table("epsg_coordoperationparamusage")
+        .ok_or("No Param Usage table")?
+        .get_rows(&["coord_op_method_code", "parameter_code"])?
+    {
+        if let [Some(Field::IntLike(method)), Some(Field::IntLike(param))] = row {
+            method_params
+                .entry(method)
+                .or_default()
+                .push(u32::try_from(param)?);
+        }
+    }
+
+    let usage_areas = get_usage_areas(db, "epsg_coordoperation")?;
+    let mut operations_map = phf_codegen::Map::new();
+    let mut reverse_map = phf_codegen::Map::new();
+    let mut crs_map = phf_codegen::Map::new();
+    let mut areas_map = phf_codegen::Map::new();
+    // edges of the routing graph, as (target CRS, operation code, reversed, accuracy) per source CRS
+    let mut graph: RoutingGraph = HashMap::new();
+    // source and target CRS of the constructed operations
+    let mut implemented = HashMap::new();
+    for row in db
+        .get_table("epsg_coordoperation")
+        .ok_or("No Op table")?
+        .get_rows(&[
+            "coord_op_code",
+            "coord_op_type",
+            "source_crs_code",
+            "target_crs_code",
+            "coord_op_method_code",
+            "coord_op_accuracy",
+            "deprecated",
+        ])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::StringLike("transformation")), Some(Field::IntLike(source)), Some(Field::IntLike(target)), Some(Field::IntLike(method)), accuracy, deprecated] =
+            row
+        else {
+            continue;
+        };
+        let Some((_, constructor)) = supporteds.iter().find(|(m, _)| i64::from(*m) == method)
+        else {
+            continue;
+        };
+        let (Some(source_ellipsoid), Some(target_ellipsoid)) =
+            (crs_ellipsoids.get(&source), crs_ellipsoids.get(&target))
+        else {
+            continue;
+        };
+        let code = u32::try_from(code)?;
+        let param_values = paramvalues.get(&code).map(Vec::as_slice).unwrap_or(&[]);
+        // parameters may be missing from the dataset or given in units that can not be converted
+        if !method_params
+            .get(&method)
+            .is_none_or(|p| p.iter().all(|p| param_values.iter().any(|(c, _)| c == p)))
+        {
+            continue;
+        }
+        crs_map.entry(code, &format!("({source}, {target})"));
+        if let Some(areas) = usage_areas.get(&i64::from(code)) {
+            areas_map.entry(code, &format_areas_of_use(areas));
+        }
+        let forward = constructor(param_values, *source_ellipsoid, *target_ellipsoid);
+        if reversibles.contains(&method) {
+            let signs = sign_reversals.get(&method);
+            let reversed_values = param_values
+                .iter()
+                .map(|&(c, v)| {
+                    if signs.is_some_and(|s| s.contains(&c)) {
+                        (c, -v)
+                    } else {
+                        (c, v)
+                    }
+                })
+                .collect::<Vec<_>>();
+            reverse_map.entry(
+                code,
+                &format!(
+                    "&{} as &dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>",
+                    constructor(&reversed_values, *target_ellipsoid, *source_ellipsoid)
+                ),
+            );
+        } else {
+            // all implemented transformations are invertible, if only iteratively
+            reverse_map.entry(
+                code,
+                &format!("&miniproj_ops::inverse::Inverse({forward}) as &dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>"),
+            );
+        }
+        operations_map.entry(
+            code,
+            &format!(
+                "&{forward} as &dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>"
+            ),
+        );
+        if matches!(deprecated, Some(Field::IntLike(0))) {
+            add_graph_edge(&mut graph, (source, target), code, true, accuracy)?;
+        }
+        implemented.insert(code, (source, target));
+    }
+
+    // conversions from geographic 3D to 2D have no CRSs of their own, they connect all pairs of CRSs
+    // sharing a datum and do not add to the accuracy of a route
+    let crs_datums = get_crs_datum_ellipsoids(db, ellipsoids, &["geographic 2D", "geographic 3D"])?;
+    // conversions and concatenated operations, which are named like the constructed transformations
+    let mut named = HashSet::new();
+    for row in db
+        .get_table("epsg_coordoperation")
+        .ok_or("No Op table")?
+        .get_rows(&[
+            "coord_op_code",
+            "coord_op_type",
+            "source_crs_code",
+            "target_crs_code",
+            "coord_op_method_code",
+            "deprecated",
+        ])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::StringLike("conversion")), None, None, Some(Field::IntLike(method @ 9659)), Some(Field::IntLike(0))] =
+            row
+        else {
+            continue;
+        };
+        let Some((_, constructor)) = supporteds.iter().find(|(m, _)| i64::from(*m) == method)
+        else {
+            continue;
+        };
+        let code = u32::try_from(code)?;
+        named.insert(code);
+        // the conversion does not depend on the ellipsoid
+        let ellipsoid = Ellipsoid::from_a_b(1.0, 1.0);
+        let conversion = format!(
+            "&{} as &dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>",
+            constructor(&[], ellipsoid, ellipsoid)
+        );
+        operations_map.entry(code, &conversion);
+        reverse_map.entry(code, &conversion);
+        for (&source, &(source_kind, source_datum, _)) in &crs_datums {
+            for (&target, &(target_kind, target_datum, _)) in &crs_datums {
+                if source_kind == "geographic 3D"
+                    && target_kind == "geographic 2D"
+                    && source_datum == target_datum
+                {
+                    add_graph_edge(
+                        &mut graph,
+                        (source, target),
+                        code,
+                        true,
+                        Some(Field::IntLike(0)),
+                    )?;
+                }
+            }
+        }
+    }
+
+    let mut steps: HashMap<i64, Vec<(i64, u32)>> = HashMap::new();
+    for row in db
+        .get_table("epsg_coordoperationpath")
+        .ok_or("No Op Path table")?
+        .get_rows(&[
+            "concat_operation_code",
+            "single_operation_code",
+            "op_path_step",
+        ])?
+    {
+        if let [Some(Field::IntLike(concatenated)), Some(Field::IntLike(single)), Some(Field::IntLike(step))] =
+            row
+        {
+            steps
+                .entry(concatenated)
+                .or_default()
+                .push((step, u32::try_from(single)?));
+        }
+    }
+    let mut concatenated_map = phf_codegen::Map::new();
+    for row in db
+        .get_table("epsg_coordoperation")
+        .ok_or("No Op table")?
+        .get_rows(&[
+            "coord_op_code",
+            "coord_op_type",
+            "source_crs_code",
+            "target_crs_code",
+        ])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::StringLike("concatenated operation")), Some(Field::IntLike(source)), Some(Field::IntLike(target))] =
+            row
+        else {
+            continue;
+        };
+        let Some(mut concatenated_steps) = steps.remove(&code) else {
+            continue;
+        };
+        concatenated_steps.sort_unstable();
+        // the direction of each step follows from the CRS the previous step ended in
+        let mut crs = source;
+        let Some(path) = concatenated_steps
+            .iter()
+            .map(|(_, single)| {
+                let &(single_source, single_target) = implemented.get(single)?;
+                if single_source == crs {
+                    crs = single_target;
+                    Some(format!("({single}, false)"))
+                } else if single_target == crs {
+                    crs = single_source;
+                    Some(format!("({single}, true)"))
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        if crs != target {
+            continue;
+        }
+        let code = u32::try_from(code)?;
+        concatenated_map.entry(code, &format!("&[{}]", path.join(", ")));
+        crs_map.entry(code, &format!("({source}, {target})"));
+        if let Some(areas) = usage_areas.get(&i64::from(code)) {
+            areas_map.entry(code, &format_areas_of_use(areas));
+        }
+        named.insert(code);
+    }
+    let mut names_map = phf_codegen::Map::new();
+    for row in db
+        .get_table("epsg_coordoperation")
+        .ok_or("No Op table")?
+        .get_rows(&["coord_op_code", "coord_op_name"])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::StringLike(name))] = row else {
+            continue;
+        };
+        let code = u32::try_from(code)?;
+        if implemented.contains_key(&code) || named.contains(&code) {
+            names_map.entry(code, &format!("{name:?}"));
+        }
+    }
+    Ok(format!(
+        r"#[allow(clippy::approx_constant)]
+static TRANSFORMATIONS: phf::Map<u32, &dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>> = {};
+#[allow(clippy::approx_constant)]
+static REVERSE_TRANSFORMATIONS: phf::Map<u32, &dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>> = {};
+/// Concatenated operations whose steps are all implemented, as (operation code, reversed) per step.
+static CONCATENATED_TRANSFORMATIONS: phf::Map<u32, &[(u32, bool)]> = {};
+static TRANSFORMATION_CRS: phf::Map<u32, (u32, u32)> = {};
+/// Areas of use of the operations.
+#[allow(clippy::approx_constant)]
+static TRANSFORMATION_AREAS: phf::Map<u32, &[AreaOfUse]> = {};
+/// Implemented, non-deprecated transformations between geographic CRSs, as (target CRS, operation code,
+/// reversed, accuracy in metres) per source CRS. Unknown accuracies are infinite.
+static TRANSFORMATION_GRAPH: phf::Map<u32, &[(u32, u32, bool, f64)]> = {};
+/// Names of the implemented operations, including conversions and concatenated operations.
+static TRANSFORMATION_NAMES: phf::Map<u32, &str> = {};
+",
+        operations_map.build(),
+        reverse_map.build(),
+        concatenated_map.build(),
+        crs_map.build(),
+        areas_map.build(),
+        build_graph(graph).build(),
+        names_map.build()
+    ))
+}
+
+/// Edges of the graph of transformations between coordinate reference systems, as `(target CRS, operation code,
+/// reversed, accuracy)`-tuples per source CRS.
+type RoutingGraph = HashMap<u32, Vec<(u32, u32, bool, f64)>>;
+
+/// Returns the codes of all coordinate operation methods that are reversible by reversing the signs of parameters.
+fn get_reversible_methods(db: &MemoryDb) -> Result<HashSet<i64>, Box<dyn Error>> {
+    Ok(db
+        .get_table("epsg_coordoperationmethod")
+        .ok_or("No Method table")?
+        .get_rows(&["coord_op_method_code", "reverse_op"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(method)), Some(Field::IntLike(1))] => Some(method),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Returns the parameters whose sign has to be reversed for the reverse operation, per method.
+fn get_sign_reversals(db: &MemoryDb) -> Result<HashMap<i64, HashSet<u32>>, Box<dyn Error>> {
+    let mut sign_reversals: HashMap<i64, HashSet<u32>> = HashMap::new();
+    for row in db
+        .get_table("epsg_coordoperationparamusage")
+        .ok_or("No Param Usage table")?
+        .get_rows(&[
+            "coord_op_method_code",
+            "parameter_code",
+            "param_sign_reversal",
+        ])?
+    {
+        if let [Some(Field::IntLike(method)), Some(Field::IntLike(param)), Some(Field::StringLike("Yes"))] =
+            row
+        {
+            sign_reversals
+                .entry(method)
+                .or_default()
+                .insert(u32::try_from(param)?);
+        }
+    }
+    Ok(sign_reversals)
+}
+
+/// Adds the operation `code` between `crs` to the routing graph, in reverse as well if it is `reversible`.
+/// Operations of unknown accuracy get an infinite accuracy.
+fn add_graph_edge(
+    graph: &mut RoutingGraph,
+    crs: (i64, i64),
+    code: u32,
+    reversible: bool,
+    accuracy: Option<Field>,
+) -> Result<(), TryFromIntError> {
+    let accuracy = match accuracy {
+        Some(Field::Double(a)) => a,
+        Some(Field::IntLike(a)) => a as f64,
+        _ => f64::INFINITY,
+    };
+    let (source, target) = (u32::try_from(crs.0)?, u32::try_from(crs.1)?);
+    graph
+        .entry(source)
+        .or_default()
+        .push((target, code, false, accuracy));
+    if reversible {
+        graph
+            .entry(target)
+            .or_default()
+            .push((source, code, true, accuracy));
+    }
+    Ok(())
+}
+
+/// Number of samples per axis used for projecting the areas of use in `projected_bounds`.
+const BOUNDS_SAMPLES: usize = 33;
+
+/// Bounding box of `areas` in `[east, north, west, south]` order projected with `projection`, as
+/// `[max x, max y, min x, min y]`. The areas are sampled on a grid including their edges, so the box
+/// is exact for edges that map to straight lines and a close approximation otherwise.
+fn projected_bounds(projection: &dyn Projection, areas: &[(&str, [f64; 4])]) -> Option<[f64; 4]> {
+    let mut bounds = [
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        f64::INFINITY,
+    ];
+    for &(_, [east, north, west, south]) in areas {
+        // areas crossing the antimeridian have their western bound east of the eastern one
+        let east = if east < west { east + 360.0 } else { east };
+        for i in 0..BOUNDS_SAMPLES {
+            for j in 0..BOUNDS_SAMPLES {
+                let lon = west + (east - west) * i as f64 / (BOUNDS_SAMPLES - 1) as f64;
+                let lon = if lon > 180.0 { lon - 360.0 } else { lon };
+                let lat = south + (north - south) * j as f64 / (BOUNDS_SAMPLES - 1) as f64;
+                let (x, y) = projection.deg_to_projected(lon, lat);
+                if x.is_finite() && y.is_finite() {
+                    bounds = [
+                        bounds[0].max(x),
+                        bounds[1].max(y),
+                        bounds[2].min(x),
+                        bounds[3].min(y),
+                    ];
+                }
+            }
+        }
+    }
+    bounds.iter().all(|v| v.is_finite()).then_some(bounds)
+}
+
+/// Formats a bounding box as the source code of a `[f64; 4]`.
+fn format_bounds(bounds: &[f64; 4]) -> String {
+    format!("[{}]", bounds.map(|v| format!("{v:?}f64")).join(", "))
+}
+
+/// Formats bounding boxes as the source code of a slice of `[f64; 4]`s.
+fn format_areas(areas: &[[f64; 4]]) -> String {
+    let areas = areas.iter().map(format_bounds).collect::<Vec<_>>();
+    format!("&[{}]", areas.join(", "))
+}
+
+/// Formats named bounding boxes in `[east, north, west, south]` order as a slice of `AreaOfUse`s.
+fn format_areas_of_use(areas: &[(&str, [f64; 4])]) -> String {
+    let areas = areas
+        .iter()
+        .map(|(name, [e, n, w, s])| format!("AreaOfUse {{ name: {name:?}, west: {w:?}f64, south: {s:?}f64, east: {e:?}f64, north: {n:?}f64 }}"))
+        .collect::<Vec<_>>();
+    format!("&[{}]", areas.join(", "))
+}
+
+/// Builds the `phf` map of a routing graph, with the edges of each CRS in a fixed order.
+fn build_graph(graph: RoutingGraph) -> phf_codegen::Map<u32> {
+    let mut graph_map = phf_codegen::Map::new();
+    for (crs, mut edges) in graph {
+        edges.sort_by_key(|&(target, code, reversed, _)| (target, code, reversed));
+        let edges = edges
+            .iter()
+            .map(|(target, code, reversed, accuracy)| {
+                let accuracy = if accuracy.is_finite() {
+                    format!("{accuracy}f64")
+                } else {
+                    "f64::INFINITY".to_owned()
+                };
+                format!("({target}, {code}, {reversed}, {accuracy})")
+            })
+            .collect::<Vec<_>>();
+        graph_map.entry(crs, &format!("&[{}]", edges.join(", ")));
+    }
+    graph_map
+}
+
+/// Generates rust source code for all Helmert transformations between geocentric coordinate reference
+/// systems, using one of the implemented seven parameter or time-dependent fifteen parameter methods.
+///
+/// Molodensky-Badekas transformations, which additionally need the evaluation point, are generated into
+/// a separate map.
+///
+/// Transformations lacking a translation or, for the time-dependent ones, the reference epoch, for example
+/// because of parameters in units that can not be converted, are skipped.
+pub fn gen_helmert_constructors(
+    db: &MemoryDb,
+    supporteds: &[ImplementedGeocentricTransformation],
+    time_dependent_supporteds: &[ImplementedGeocentricTransformation],
+    molodensky_badekas_supporteds: &[ImplementedGeocentricTransformation],
+) -> Result<String, Box<dyn Error>> {
+    let paramvalues = get_param_values(db)?;
+    let reversibles = get_reversible_methods(db)?;
+
+    let mut operations_map = phf_codegen::Map::new();
+    let mut time_dependent_map = phf_codegen::Map::new();
+    let mut molodensky_badekas_map = phf_codegen::Map::new();
+    let mut crs_map = phf_codegen::Map::new();
+    let mut graph: RoutingGraph = HashMap::new();
+    for row in db
+        .get_table("epsg_coordoperation")
+        .ok_or("No Op table")?
+        .get_rows(&[
+            "coord_op_code",
+            "coord_op_type",
+            "source_crs_code",
+            "target_crs_code",
+            "coord_op_method_code",
+            "coord_op_accuracy",
+            "deprecated",
+        ])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::StringLike("transformation")), Some(Field::IntLike(source)), Some(Field::IntLike(target)), Some(Field::IntLike(method)), accuracy, deprecated] =
+            row
+        else {
+            continue;
+        };
+        let find = |supporteds: &[ImplementedGeocentricTransformation]| {
+            supporteds
+                .iter()
+                .find(|(m, _)| i64::from(*m) == method)
+                .map(|(_, c)| *c)
+        };
+        let (constructor, map, required) = match (
+            find(supporteds),
+            find(time_dependent_supporteds),
+            find(molodensky_badekas_supporteds),
+        ) {
+            (Some(constructor), _, _) => {
+                (constructor, &mut operations_map, &[8605, 8606, 8607][..])
+            }
+            (_, Some(constructor), _) => (
+                constructor,
+                &mut time_dependent_map,
+                &[8605, 8606, 8607, 1040, 1041, 1042, 1047][..],
+            ),
+            (_, _, Some(constructor)) => (
+                constructor,
+                &mut molodensky_badekas_map,
+                &[8605, 8606, 8607, 8617, 8618, 8667][..],
+            ),
+            _ => continue,
+        };
+        let code = u32::try_from(code)?;
+        let param_values = paramvalues.get(&code).map(Vec::as_slice).unwrap_or(&[]);
+        if !required
+            .iter()
+            .all(|r| param_values.iter().any(|(c, _)| c == r))
+        {
+            continue;
+        }
+        map.entry(code, &constructor(param_values));
+        crs_map.entry(code, &format!("({source}, {target})"));
+        if matches!(deprecated, Some(Field::IntLike(0))) {
+            add_graph_edge(
+                &mut graph,
+                (source, target),
+                code,
+                reversibles.contains(&method),
+                accuracy,
+            )?;
+        }
+    }
+    Ok(format!(
+        r"#[allow(clippy::approx_constant)]
+static GEOCENTRIC_TRANSFORMATIONS: phf::Map<u32, HelmertTransformation> = {};
+#[allow(clippy::approx_constant)]
+static TIME_DEPENDENT_TRANSFORMATIONS: phf::Map<u32, HelmertTimeDependentTransformation> = {};
+#[allow(clippy::approx_constant)]
+static MOLODENSKY_BADEKAS_TRANSFORMATIONS: phf::Map<u32, MolodenskyBadekasTransformation> = {};
+static GEOCENTRIC_TRANSFORMATION_CRS: phf::Map<u32, (u32, u32)> = {};
+/// Implemented, non-deprecated transformations between geocentric CRSs, as (target CRS, operation code,
+/// reversed, accuracy in metres) per source CRS. Unknown accuracies are infinite.
+static GEOCENTRIC_TRANSFORMATION_GRAPH: phf::Map<u32, &[(u32, u32, bool, f64)]> = {};
+",
+        operations_map.build(),
+        time_dependent_map.build(),
+        molodensky_badekas_map.build(),
+        crs_map.build(),
+        build_graph(graph).build()
     ))
 }
+
+/// Generates rust source code for the conversions between geographic and geocentric coordinate reference
+/// systems, using the first implemented conversion method.
+///
+/// As the conversion has no parameters of its own, it is generated per geographic 2D, geographic 3D and
+/// geocentric CRS from the ellipsoid of its datum, together with the datum code, so that the conversion
+/// between two CRSs sharing a datum can be looked up at runtime.
+pub fn gen_geocentric_conversion_constructors(
+    db: &MemoryDb,
+    supporteds: &[ImplementedGeocentricConversion],
+    ellipsoids: &HashMap<u32, Ellipsoid>,
+) -> Result<String, Box<dyn Error>> {
+    let crs_ellipsoids = get_crs_datum_ellipsoids(
+        db,
+        ellipsoids,
+        &["geographic 2D", "geographic 3D", "geocentric"],
+    )?;
+    let mut conversions_map = phf_codegen::Map::new();
+    if let Some((_, constructor)) = supporteds.first() {
+        for (code, (kind, datum, ellipsoid)) in crs_ellipsoids {
+            let geocentric = kind == "geocentric";
+            conversions_map.entry(
+                u32::try_from(code)?,
+                &format!("({datum}, {geocentric}, {})", constructor(&[], ellipsoid)),
+            );
+        }
+    }
+    Ok(format!(
+        r"#[allow(clippy::approx_constant)]
+/// Geographic and geocentric CRSs, as (datum code, geocentric, conversion between geographic 3D and
+/// geocentric coordinates on the ellipsoid of the datum).
+static GEOCENTRIC_CONVERSIONS: phf::Map<u32, (u32, bool, GeocentricConversion)> = {};
+",
+        conversions_map.build()
+    ))
+}
+
+/// Generates rust source code mapping EPSG codes of vertical CRSs to `VerticalCrs`s, and of compound CRSs
+/// to the codes of their horizontal and vertical CRS.
+///
+/// Only vertical CRSs with a single axis are considered, and only compound CRSs whose second component is
+/// one of them. Derived vertical CRSs, like depths derived from heights, use the datum of their base CRS.
+pub fn gen_compound_crs_constructors(db: &MemoryDb) -> Result<String, Box<dyn Error>> {
+    let coordinate_systems = get_coordinate_systems(db)?;
+    let rows = db
+        .get_table("epsg_coordinatereferencesystem")
+        .ok_or("No CRS table")?
+        .get_rows(&["coord_ref_sys_code", "coord_ref_sys_name", "coord_sys_code", "datum_code", "base_crs_code", "coord_ref_sys_kind"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::StringLike(name)), Some(Field::IntLike(cs)), datum, base, Some(Field::StringLike("vertical"))] => {
+                Some((code, name, cs, datum, base))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let datums = rows
+        .iter()
+        .filter_map(|(code, _, _, datum, _)| match datum {
+            Some(Field::IntLike(datum)) => Some((*code, *datum)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let mut vertical_map = phf_codegen::Map::new();
+    let mut verticals = HashSet::new();
+    for (code, name, cs, datum, base) in rows {
+        let datum = match (datum, base) {
+            (Some(Field::IntLike(datum)), _) => datum,
+            (_, Some(Field::IntLike(base))) => match datums.get(&base) {
+                Some(datum) => *datum,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let Some([axis]) = coordinate_systems
+            .get(&u32::try_from(cs)?)
+            .map(|cs| cs.axes.as_slice())
+        else {
+            continue;
+        };
+        let Some(unit) = axis.uom_code else {
+            continue;
+        };
+        let depth = axis.orientation == "down";
+        vertical_map.entry(
+            u32::try_from(code)?,
+            &format!(
+                "VerticalCrs{{ name: {name:?}, datum: {datum}, unit: {unit}, depth: {depth} }}"
+            ),
+        );
+        verticals.insert(code);
+    }
+    let mut compound_map = phf_codegen::Map::new();
+    for row in db
+        .get_table("epsg_coordinatereferencesystem")
+        .ok_or("No CRS table")?
+        .get_rows(&[
+            "coord_ref_sys_code",
+            "cmpd_horizcrs_code",
+            "cmpd_vertcrs_code",
+            "coord_ref_sys_kind",
+        ])?
+    {
+        if let [Some(Field::IntLike(code)), Some(Field::IntLike(horizontal)), Some(Field::IntLike(vertical)), Some(Field::StringLike("compound"))] =
+            row
+        {
+            if verticals.contains(&vertical) {
+                compound_map.entry(u32::try_from(code)?, &format!("({horizontal}, {vertical})"));
+            }
+        }
+    }
+    Ok(format!(
+        "static VERTICAL_CRS: phf::Map<u32, VerticalCrs> = {};\n/// Horizontal and vertical CRS per compound CRS.\nstatic COMPOUND_CRS: phf::Map<u32, (u32, u32)> = {};",
+        vertical_map.build(),
+        compound_map.build()
+    ))
+}
+
+/// Generates rust source code for all transformations between vertical coordinate reference
+/// systems using one of the implemented vertical transformation methods.
+///
+/// Only vertical CRSs with a single axis in metres are considered. Transformations between a height
+/// and a depth CRS are skipped, as the methods do not reverse the axis direction. Operations of
+/// reversible methods are also constructed in the reverse direction, and together with their
+/// accuracies make up the graph used for routing between vertical CRSs.
+pub fn gen_vertical_transformation_constructors(
+    db: &MemoryDb,
+    supporteds: &[ImplementedVerticalTransformation],
+    ellipsoids: &HashMap<u32, Ellipsoid>,
+) -> Result<String, Box<dyn Error>> {
+    let crs_ellipsoids = get_crs_ellipsoids(db, ellipsoids)?;
+    let coordinate_systems = get_coordinate_systems(db)?;
+    // maps vertical CRSs to the direction of their axis
+    let crs_directions = db
+        .get_table("epsg_coordinatereferencesystem")
+        .ok_or("No CRS table")?
+        .get_rows(&["coord_ref_sys_code", "coord_sys_code", "coord_ref_sys_kind"])?
+        .filter_map(|row| match row {
+            [Some(Field::IntLike(code)), Some(Field::IntLike(cs)), Some(Field::StringLike("vertical"))] => {
+                match coordinate_systems.get(&u32::try_from(cs).ok()?)?.axes.as_slice() {
+                    [axis] if axis.uom_code == Some(9001) => Some((code, axis.orientation.clone())),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let paramvalues = get_param_values(db)?;
+    let reversibles = get_reversible_methods(db)?;
+    let sign_reversals = get_sign_reversals(db)?;
+
+    let mut operations_map = phf_codegen::Map::new();
+    let mut reverse_map = phf_codegen::Map::new();
+    let mut crs_map = phf_codegen::Map::new();
+    let mut graph: RoutingGraph = HashMap::new();
+    for row in db
+        .get_table("epsg_coordoperation")
+        .ok_or("No Op table")?
+        .get_rows(&[
+            "coord_op_code",
+            "coord_op_type",
+            "source_crs_code",
+            "target_crs_code",
+            "coord_op_method_code",
+            "coord_op_accuracy",
+            "deprecated",
+        ])?
+    {
+        let [Some(Field::IntLike(code)), Some(Field::StringLike("transformation")), Some(Field::IntLike(source)), Some(Field::IntLike(target)), Some(Field::IntLike(method)), accuracy, deprecated] =
+            row
+        else {
+            continue;
+        };
+        let Some((_, constructor)) = supporteds.iter().find(|(m, _)| i64::from(*m) == method)
+        else {
+            continue;
+        };
+        let (Some(source_direction), Some(target_direction)) =
+            (crs_directions.get(&source), crs_directions.get(&target))
+        else {
+            continue;
+        };
+        if source_direction != target_direction {
+            continue;
+        }
+        let code = u32::try_from(code)?;
+        let param_values = paramvalues.get(&code).map(Vec::as_slice).unwrap_or(&[]);
+        // parameters varying with the horizontal position refer to a geographic CRS
+        let ellipsoid = match param_values.iter().find(|(c, _)| *c == 1037) {
+            Some((_, horizontal_crs)) => match crs_ellipsoids.get(&(*horizontal_crs as i64)) {
+                Some(ellipsoid) => *ellipsoid,
+                None => continue,
+            },
+            None => miniproj_ops::ellipsoids::WGS84,
+        };
+        operations_map.entry(
+            code,
+            &format!(
+                "&{} as &dyn CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>",
+                constructor(param_values, ellipsoid)
+            ),
+        );
+        crs_map.entry(code, &format!("({source}, {target})"));
+        let reversible = reversibles.contains(&method);
+        if reversible {
+            let signs = sign_reversals.get(&method);
+            let reversed_values = param_values
+                .iter()
+                .map(|&(c, v)| {
+                    if signs.is_some_and(|s| s.contains(&c)) {
+                        (c, -v)
+                    } else {
+                        (c, v)
+                    }
+                })
+                .collect::<Vec<_>>();
+            reverse_map.entry(
+                code,
+                &format!(
+                    "&{} as &dyn CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>",
+                    constructor(&reversed_values, ellipsoid)
+                ),
+            );
+        }
+        if matches!(deprecated, Some(Field::IntLike(0))) {
+            add_graph_edge(&mut graph, (source, target), code, reversible, accuracy)?;
+        }
+    }
+    Ok(format!(
+        r"#[allow(clippy::approx_constant)]
+static VERTICAL_TRANSFORMATIONS: phf::Map<u32, &dyn CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>> = {};
+#[allow(clippy::approx_constant)]
+static VERTICAL_REVERSE_TRANSFORMATIONS: phf::Map<u32, &dyn CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>> = {};
+static VERTICAL_TRANSFORMATION_CRS: phf::Map<u32, (u32, u32)> = {};
+/// Implemented, non-deprecated transformations between vertical CRSs, as (target CRS, operation code,
+/// reversed, accuracy in metres) per source CRS. Unknown accuracies are infinite.
+static VERTICAL_TRANSFORMATION_GRAPH: phf::Map<u32, &[(u32, u32, bool, f64)]> = {};
+",
+        operations_map.build(),
+        reverse_map.build(),
+        crs_map.build(),
+        build_graph(graph).build()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::{get_coordinate_systems, get_units},
+        helpers::to_base_unit,
+        sql::MemoryDb,
+    };
+
+    #[test]
+    fn historical_length_units() {
+        let units = get_units(&MemoryDb::new()).unwrap();
+        for (uom_code, metres) in [
+            (9097, 20.1168),            // chain
+            (9098, 0.201168),           // link
+            (9033, 20.11684023368047),  // US survey chain
+            (9034, 0.2011684023368047), // US survey link
+            (9038, 20.1166195164),      // Clarke's chain
+            (9039, 0.201166195164),     // Clarke's link
+            (9042, 20.116765121552632), // British chain (Sears 1922)
+            (9301, 20.116756),          // British chain (Sears 1922 truncated)
+            (9014, 1.8288),             // fathom
+            (9096, 0.9144),             // yard
+            (9037, 0.9143917962),       // Clarke's yard
+            (9040, 0.9143984146160287), // British yard (Sears 1922)
+            (9084, 0.9143985307444408), // Indian yard
+            (9085, 0.91439523),         // Indian yard (1937)
+            (9086, 0.9143988),          // Indian yard (1962)
+            (9087, 0.9143985),          // Indian yard (1975)
+        ] {
+            let converted = to_base_unit(1.0, uom_code, &units)
+                .unwrap_or_else(|| panic!("EPSG:{uom_code} is not convertible"));
+            eprintln!("EPSG:{uom_code}: {metres} - {converted}");
+            assert!((converted - metres).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn sexagesimal_units() {
+        let units = get_units(&MemoryDb::new()).unwrap();
+        let rad = to_base_unit(52.3030, 9110, &units).unwrap();
+        assert!((rad - (52.0 + 30.0 / 60.0 + 30.0 / 3600.0f64).to_radians()).abs() < 1e-12);
+        // Ferro, whose minutes are not exactly representable
+        let rad = to_base_unit(-17.4, 9110, &units).unwrap();
+        assert!((rad + (17.0 + 40.0 / 60.0f64).to_radians()).abs() < 1e-12);
+        assert_eq!(to_base_unit(1.0, 9107, &units), None);
+    }
+
+    #[test]
+    fn grads() {
+        let units = get_units(&MemoryDb::new()).unwrap();
+        let rad = to_base_unit(36.5964, 9105, &units).unwrap();
+        assert!((rad - 36.5964 * std::f64::consts::PI / 200.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn coordinate_systems() {
+        let systems = get_coordinate_systems(&MemoryDb::new()).unwrap();
+        let projected = &systems[&4400];
+        eprintln!("{projected:?}");
+        assert_eq!(projected.cs_type, "Cartesian");
+        assert_eq!(projected.dimension, 2);
+        let orientations = projected
+            .axes
+            .iter()
+            .map(|a| a.orientation.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(orientations, ["east", "north"]);
+        assert!(projected.axes.iter().all(|a| a.uom_code == Some(9001)));
+
+        let geographic = &systems[&6422];
+        assert_eq!(geographic.cs_type, "ellipsoidal");
+        let names = geographic
+            .axes
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Geodetic latitude", "Geodetic longitude"]);
+    }
+}
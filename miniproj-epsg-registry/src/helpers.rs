@@ -1,4 +1,6 @@
-/// This file is licensed under EUPL v1.2
+//This file is licensed under EUPL v1.2
+
+use std::collections::HashMap;
 
 /// Converts an EPSG:9110-encoded angle to radians.
 ///
@@ -12,7 +14,23 @@ pub fn epsg_9110_to_rad(val: f64) -> f64 {
     let sign = val.signum();
     let a = val.abs();
     let whole_deg = a.trunc();
-    let arcmins = (a.fract() * 100f64).trunc();
-    let arcsecs = (a.fract() * 100f64).fract() * 100f64;
+    // nudge the minutes before truncating, as e.g. 0.4 * 100 is 39.99999999999999
+    let arcmins = (a.fract() * 100f64 + 1e-9).trunc();
+    let arcsecs = (a.fract() * 100f64 - arcmins) * 100f64;
     sign * (whole_deg + arcmins / 60f64 + arcsecs / 3600f64).to_radians()
 }
+
+/// Converts a value given in the unit of measure `uom_code` to the base unit of its kind,
+/// i.e. metres for lengths (including historical units like chains, links, fathoms and the
+/// various national yards and feet), radians for angles and unity for scales.
+///
+/// `units` maps unit codes to their `(factor_b, factor_c)` pair, see `get_units`. Returns `None`
+/// if the unit is neither contained in `units` nor a supported sexagesimal encoding.
+pub fn to_base_unit(val: f64, uom_code: u32, units: &HashMap<u32, (f64, f64)>) -> Option<f64> {
+    match uom_code {
+        9110 => Some(epsg_9110_to_rad(val)),
+        _ => units
+            .get(&uom_code)
+            .map(|(factor_b, factor_c)| val * factor_b / factor_c),
+    }
+}
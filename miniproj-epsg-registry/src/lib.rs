@@ -41,6 +41,239 @@ pub static IMPL_CONV: &[ImplementedProjection] = &[
         &miniproj_ops::stereographic::direct_projection_oblique,
     ),
     (9822, &miniproj_ops::albers_equal_area::direct_projection),
+    (1026, &miniproj_ops::mercator::direct_projection_spherical),
+    (
+        9834,
+        &miniproj_ops::lambert_cylindrical_equal_area::direct_projection_spherical,
+    ),
+    (
+        9825,
+        &miniproj_ops::identity_projection::direct_projection_pseudo_plate_carree,
+    ),
+    (1044, &miniproj_ops::mercator::direct_projection_c),
+    (
+        9812,
+        &miniproj_ops::hotine_oblique_mercator::direct_projection_a,
+    ),
+    (
+        9815,
+        &miniproj_ops::hotine_oblique_mercator::direct_projection_b,
+    ),
+    (9806, &miniproj_ops::cassini_soldner::direct_projection),
+    (
+        9833,
+        &miniproj_ops::cassini_soldner::direct_projection_hyperbolic,
+    ),
+    (9819, &miniproj_ops::krovak::direct_projection),
+    (
+        9803,
+        &miniproj_ops::lambert_conic_conformal::direct_projection_2sp_belgium,
+    ),
+    (
+        1051,
+        &miniproj_ops::lambert_conic_conformal::direct_projection_2sp_michigan,
+    ),
+    (
+        9817,
+        &miniproj_ops::lambert_conic_conformal::direct_projection_near_conformal,
+    ),
+    (9829, &miniproj_ops::stereographic::direct_projection_b),
+    (
+        1028,
+        &miniproj_ops::equidistant_cylindrical::direct_projection,
+    ),
+    (
+        1029,
+        &miniproj_ops::equidistant_cylindrical::direct_projection_spherical,
+    ),
+    (9827, &miniproj_ops::bonne::direct_projection),
+    (
+        9828,
+        &miniproj_ops::bonne::direct_projection_south_orientated,
+    ),
+    (
+        9808,
+        &miniproj_ops::transverse_mercator::direct_projection_south_orientated,
+    ),
+    (
+        1027,
+        &miniproj_ops::lambert_azimuthal_equal_area::direct_projection_spherical,
+    ),
+    (
+        9832,
+        &miniproj_ops::azimuthal_equidistant::direct_projection_modified,
+    ),
+    (
+        9831,
+        &miniproj_ops::azimuthal_equidistant::direct_projection_guam,
+    ),
+    (1078, &miniproj_ops::equal_earth::direct_projection),
+    (1052, &miniproj_ops::colombia_urban::direct_projection),
+    (
+        9835,
+        &miniproj_ops::lambert_cylindrical_equal_area::direct_projection,
+    ),
+    (9838, &miniproj_ops::vertical_perspective::direct_projection),
+    (
+        1125,
+        &miniproj_ops::azimuthal_equidistant::direct_projection,
+    ),
+    (9816, &miniproj_ops::tunisia_mining_grid::direct_projection),
+];
+
+type ImplementedTransformation = (
+    u32,
+    &'static (dyn (Fn(&[(u32, f64)], Ellipsoid, Ellipsoid) -> String) + Send + Sync),
+);
+
+/// Implemented transformations.
+///
+/// Pairs operation method codes with functions that map a slice of (parameter code, value)-tuples and the
+/// ellipsoids of the source and target CRS to a `String` containing source code for constructing the
+/// `CoordOperation` with the given parameters. Only these methods are considered for routing between CRSs.
+pub static IMPL_TRANS: &[ImplementedTransformation] = &[
+    (
+        9605,
+        &miniproj_ops::abridged_molodensky::direct_transformation,
+    ),
+    (
+        9619,
+        &miniproj_ops::geographic_offsets::direct_transformation_2d,
+    ),
+    (
+        9660,
+        &miniproj_ops::geographic_offsets::direct_transformation_3d,
+    ),
+    (
+        9603,
+        &miniproj_ops::helmert::direct_transformation_position_vector,
+    ),
+    (
+        1035,
+        &miniproj_ops::helmert::direct_transformation_position_vector,
+    ),
+    (
+        9606,
+        &miniproj_ops::helmert::direct_transformation_position_vector,
+    ),
+    (
+        1037,
+        &miniproj_ops::helmert::direct_transformation_position_vector,
+    ),
+    (
+        9607,
+        &miniproj_ops::helmert::direct_transformation_coordinate_frame,
+    ),
+    (
+        1038,
+        &miniproj_ops::helmert::direct_transformation_coordinate_frame,
+    ),
+    (
+        1063,
+        &miniproj_ops::molodensky_badekas::direct_transformation_position_vector,
+    ),
+    (
+        1062,
+        &miniproj_ops::molodensky_badekas::direct_transformation_position_vector,
+    ),
+    (
+        9636,
+        &miniproj_ops::molodensky_badekas::direct_transformation_coordinate_frame,
+    ),
+    (
+        1039,
+        &miniproj_ops::molodensky_badekas::direct_transformation_coordinate_frame,
+    ),
+    (9659, &miniproj_ops::geographic_3d_to_2d::direct_conversion),
+];
+
+type ImplementedGeocentricTransformation = (
+    u32,
+    &'static (dyn (Fn(&[(u32, f64)]) -> String) + Send + Sync),
+);
+
+/// Implemented Helmert transformations between geocentric CRSs.
+///
+/// Pairs operation method codes with functions that map a slice of (parameter code, value)-tuples to a
+/// `String` containing source code for constructing the `HelmertTransformation` with the given parameters.
+pub static IMPL_GEOCENTRIC_TRANS: &[ImplementedGeocentricTransformation] = &[
+    (
+        1031,
+        &miniproj_ops::helmert::direct_geocentric_position_vector,
+    ),
+    (
+        1032,
+        &miniproj_ops::helmert::direct_geocentric_coordinate_frame,
+    ),
+    (
+        1033,
+        &miniproj_ops::helmert::direct_geocentric_position_vector,
+    ),
+];
+
+/// Implemented time-dependent Helmert transformations between geocentric CRSs.
+///
+/// Pairs operation method codes with functions that map a slice of (parameter code, value)-tuples to a
+/// `String` containing source code for constructing the `HelmertTimeDependentTransformation` with the given
+/// parameters.
+pub static IMPL_TIME_DEPENDENT_TRANS: &[ImplementedGeocentricTransformation] = &[
+    (
+        1053,
+        &miniproj_ops::helmert::direct_time_dependent_position_vector,
+    ),
+    (
+        1056,
+        &miniproj_ops::helmert::direct_time_dependent_coordinate_frame,
+    ),
+];
+
+/// Implemented Molodensky-Badekas transformations between geocentric CRSs.
+///
+/// Pairs operation method codes with functions that map a slice of (parameter code, value)-tuples to a
+/// `String` containing source code for constructing the `MolodenskyBadekasTransformation` with the given
+/// parameters.
+pub static IMPL_MOLODENSKY_BADEKAS_TRANS: &[ImplementedGeocentricTransformation] = &[
+    (
+        1061,
+        &miniproj_ops::molodensky_badekas::direct_geocentric_position_vector,
+    ),
+    (
+        1034,
+        &miniproj_ops::molodensky_badekas::direct_geocentric_coordinate_frame,
+    ),
+];
+
+type ImplementedGeocentricConversion = (
+    u32,
+    &'static (dyn (Fn(&[(u32, f64)], Ellipsoid) -> String) + Send + Sync),
+);
+
+/// Implemented conversions between geographic 3D and geocentric CRSs.
+///
+/// Pairs operation method codes with functions that map a slice of (parameter code, value)-tuples and
+/// the ellipsoid of the datum shared by both CRSs to a `String` containing source code for constructing
+/// the `GeocentricConversion`.
+pub static IMPL_GEOCENTRIC_CONV: &[ImplementedGeocentricConversion] = &[(
+    9602,
+    &miniproj_ops::geocentric_conversion::direct_conversion,
+)];
+
+type ImplementedVerticalTransformation = (
+    u32,
+    &'static (dyn (Fn(&[(u32, f64)], Ellipsoid) -> String) + Send + Sync),
+);
+
+/// Implemented transformations between vertical CRSs.
+///
+/// Pairs operation method codes with functions that map a slice of (parameter code, value)-tuples and
+/// the ellipsoid of the horizontal CRS the parameters refer to to a `String` containing source code for
+/// constructing the `CoordOperation` with the given parameters.
+pub static IMPL_VERT_TRANS: &[ImplementedVerticalTransformation] = &[
+    (
+        1046,
+        &miniproj_ops::vertical_offset::direct_transformation_slope,
+    ),
+    (9616, &miniproj_ops::vertical_offset::direct_transformation),
 ];
 
 #[cfg(test)]
@@ -52,4 +285,22 @@ mod tests {
         let memdb = MemoryDb::new();
         eprintln!("{memdb:#?}")
     }
+
+    #[test]
+    fn malformed_sql() {
+        for sql in [
+            "SELEC",
+            "INSERT INTO epsg_missing VALUES (1);",
+            "CREATE TABLE t (a INTEGER NOT NULL); INSERT INTO t VALUES ('x');",
+            "CREATE TABLE t (a INTEGER NOT NULL); INSERT INTO t VALUES (1, 2);",
+            "CREATE TABLE t (a INTEGER NOT NULL); INSERT INTO t (a) VALUES ();",
+            "CREATE TABLE t (a BLOB);",
+        ] {
+            assert!(MemoryDb::from_sql(sql).is_err(), "{sql}");
+        }
+        let db =
+            MemoryDb::from_sql("CREATE TABLE t (a INTEGER NOT NULL); INSERT INTO t VALUES (1);")
+                .unwrap();
+        assert_eq!(db.get_table("t").and_then(|t| t.rows()), Some(1));
+    }
 }
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, error::Error};
 
 use sqlparser::{
-    ast::{ColumnOption, DataType, Expr, SetExpr, UnaryOperator, Value, Ident},
+    ast::{ColumnOption, DataType, Expr, Ident, SetExpr, UnaryOperator, Value},
     dialect::GenericDialect,
     parser::Parser,
 };
@@ -36,7 +36,7 @@ impl Table {
         col: &str,
         val: i64,
         select: &[&str; N],
-    ) -> Option<[Option<Field>; N]> {
+    ) -> Option<[Option<Field<'_>>; N]> {
         let Column { data } = self.columns.get(col)?;
         let index = match data {
             ColumnData::IntLike(v) => v.iter().enumerate().find(|(_n, v)| **v == val)?.0,
@@ -77,7 +77,7 @@ impl Table {
     pub fn get_rows<const N: usize>(
         &self,
         select: &[&str; N],
-    ) -> Result<impl Iterator<Item = [Option<Field>; N]>, Box<dyn Error>> {
+    ) -> Result<impl Iterator<Item = [Option<Field<'_>>; N]>, Box<dyn Error>> {
         let Some(columns) = select
             .iter()
             .map(|n| self.columns.get(*n))
@@ -122,7 +122,7 @@ impl Table {
         col: &str,
         val: i64,
         select: &[&str; N],
-    ) -> Vec<[Option<Field>; N]> {
+    ) -> Vec<[Option<Field<'_>>; N]> {
         let Some(columns) = select
             .iter()
             .map(|n| self.columns.get(*n))
@@ -233,11 +233,18 @@ impl std::fmt::Debug for ColumnData {
 }
 
 impl MemoryDb {
-    #[allow(clippy::too_many_lines)]
+    /// Parse the bundled extract of the EPSG registry.
     #[must_use]
     pub fn new() -> Self {
+        Self::from_sql(DB).expect("Parser error.")
+    }
+
+    /// Parse a database from a script of `CREATE TABLE` and `INSERT INTO` statements.
+    /// Returns an error for invalid SQL or values that do not fit the table definitions.
+    #[allow(clippy::too_many_lines)]
+    pub fn from_sql(sql: &str) -> Result<Self, Box<dyn Error>> {
         let dialect = GenericDialect {};
-        let ast = Parser::parse_sql(&dialect, DB).expect("Parser error.");
+        let ast = Parser::parse_sql(&dialect, sql)?;
         let mut tables = HashMap::new();
         for stmt in &ast {
             match stmt {
@@ -248,34 +255,44 @@ impl MemoryDb {
                     columns,
                     ..
                 } => {
-                    let table: &mut Table = tables
-                        .get_mut(&table_name.0.iter().last().unwrap().value)
-                        .unwrap();
+                    let table: &mut Table = table_name
+                        .0
+                        .last()
+                        .and_then(|name| tables.get_mut(&name.value))
+                        .ok_or_else(|| format!("table {table_name} does not exist."))?;
                     let SetExpr::Values(ref values) = *source.body else {
-                        panic!("expected values!")
+                        return Err("expected values!".into());
                     };
                     for row in &values.rows {
                         let mapping = if columns.is_empty() {
                             if row.len() == table.columns.len() {
-                                row.iter().zip(table.column_order.iter()).collect::<Vec<_>>()
+                                row.iter()
+                                    .zip(table.column_order.iter())
+                                    .collect::<Vec<_>>()
                             } else {
-                                panic!("table {table_name:#?} could not be set.")
+                                return Err(format!("table {table_name} could not be set.").into());
                             }
                         } else {
-                            table.column_order.iter().map(|name| {
-                                if let Some((index, _)) = columns.iter().enumerate().find(|(_, Ident{value, ..})| {
-                                    value == name
-                                }) {
-                                    (&row[index], name)
-                                } else {
-                                    (&Expr::Value(Value::Null), name)
-                                }
-                            }).collect::<Vec<_>>()
+                            table
+                                .column_order
+                                .iter()
+                                .map(|name| {
+                                    if let Some(expr) = columns
+                                        .iter()
+                                        .position(|Ident { value, .. }| value == name)
+                                        .and_then(|index| row.get(index))
+                                    {
+                                        (expr, name)
+                                    } else {
+                                        (&Expr::Value(Value::Null), name)
+                                    }
+                                })
+                                .collect::<Vec<_>>()
                         };
-                        
+
                         for (expr, col_name) in mapping {
                             let Column { data } =
-                                table.columns.get_mut(col_name).expect("Missing column.");
+                                table.columns.get_mut(col_name).ok_or("Missing column.")?;
                             match (data, expr) {
                                 (ColumnData::MaybeStringLike(v), Expr::Value(Value::Null)) => {
                                     v.push(None);
@@ -287,10 +304,10 @@ impl MemoryDb {
                                     v.push(None);
                                 }
                                 (ColumnData::IntLike(v), Expr::Value(Value::Number(n, _))) => {
-                                    v.push(n.parse().expect("cannot parse i64"));
+                                    v.push(n.parse()?);
                                 }
                                 (ColumnData::MaybeIntLike(v), Expr::Value(Value::Number(n, _))) => {
-                                    v.push(Some(n.parse().expect("cannot parse i64")));
+                                    v.push(Some(n.parse()?));
                                 }
                                 (
                                     ColumnData::StringLike(v),
@@ -301,7 +318,7 @@ impl MemoryDb {
                                     Expr::Value(Value::SingleQuotedString(s)),
                                 ) => v.push(Some(s.clone())),
                                 (ColumnData::Double(v), Expr::Value(Value::Number(n, _))) => {
-                                    v.push(n.parse().expect("cannot parse f64"));
+                                    v.push(n.parse()?);
                                 }
                                 (
                                     ColumnData::Double(v),
@@ -311,12 +328,12 @@ impl MemoryDb {
                                     },
                                 ) => {
                                     let Expr::Value(Value::Number(n, _)) = expr.as_ref() else {
-                                        panic!("cannot negate non-numbers")
+                                        return Err("cannot negate non-numbers".into());
                                     };
-                                    v.push(-n.parse::<f64>().expect("cannot parse f64"));
+                                    v.push(-n.parse::<f64>()?);
                                 }
                                 (ColumnData::MaybeDouble(v), Expr::Value(Value::Number(n, _))) => {
-                                    v.push(Some(n.parse::<f64>().expect("cannot parse f64")));
+                                    v.push(Some(n.parse::<f64>()?));
                                 }
                                 (
                                     ColumnData::MaybeDouble(v),
@@ -326,12 +343,12 @@ impl MemoryDb {
                                     },
                                 ) => {
                                     let Expr::Value(Value::Number(n, _)) = expr.as_ref() else {
-                                        panic!("cannot negate non-numbers")
+                                        return Err("cannot negate non-numbers".into());
                                     };
-                                    v.push(Some(-n.parse::<f64>().expect("cannot parse f64")));
+                                    v.push(Some(-n.parse::<f64>()?));
                                 }
                                 (d, e) => {
-                                    panic!("cannot push {e:?} to {d:?}.")
+                                    return Err(format!("cannot push {e:?} to {d:?}.").into());
                                 }
                             }
                         }
@@ -339,13 +356,13 @@ impl MemoryDb {
                 }
                 sqlparser::ast::Statement::CreateTable { name, columns, .. } => {
                     tables.insert(
-                        name.0.last().unwrap().value.clone(),
+                        name.0.last().ok_or("Missing table name.")?.value.clone(),
                         Table {
                             column_order: columns.iter().map(|c| c.name.value.clone()).collect(),
                             columns: columns
                                 .iter()
                                 .map(|c| {
-                                    (
+                                    Ok((
                                         c.name.value.clone(),
                                         Column {
                                             data: if c
@@ -367,7 +384,11 @@ impl MemoryDb {
                                                     DataType::Varchar(_) | DataType::Date => {
                                                         ColumnData::StringLike(Vec::new())
                                                     }
-                                                    a => panic!("type {a:?} not supported!"),
+                                                    a => {
+                                                        return Err(format!(
+                                                            "type {a:?} not supported!"
+                                                        ))
+                                                    }
                                                 }
                                             } else {
                                                 match &c.data_type {
@@ -385,13 +406,17 @@ impl MemoryDb {
                                                     | DataType::Custom(_, _) => {
                                                         ColumnData::MaybeIntLike(Vec::new())
                                                     }
-                                                    a => panic!("type {a:?} not supported!"),
+                                                    a => {
+                                                        return Err(format!(
+                                                            "type {a:?} not supported!"
+                                                        ))
+                                                    }
                                                 }
                                             },
                                         },
-                                    )
+                                    ))
                                 })
-                                .collect(),
+                                .collect::<Result<_, String>>()?,
                         },
                     );
                 }
@@ -403,13 +428,13 @@ impl MemoryDb {
                     names,
                     ..
                 } => {
-                    for n in names {
-                        tables.remove(&n.0.last().unwrap().value);
+                    for n in names.iter().filter_map(|n| n.0.last()) {
+                        tables.remove(&n.value);
                     }
                 }
                 s => println!("cargo:warning=Unsupported SQL statement: {s:?}"),
             }
         }
-        Self { tables }
+        Ok(Self { tables })
     }
 }
@@ -0,0 +1,85 @@
+//This file is licensed under EUPL v1.2
+
+//! Throughput of the `Fast` projection variants against their full-precision projections.
+//! Run with `cargo bench -p miniproj-ops --bench fast`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use miniproj_ops::fast::Fast;
+use miniproj_ops::transverse_mercator::{TransverseMercatorParams, TransverseMercatorProjection};
+use miniproj_ops::{Ellipsoid, Projection};
+
+const ROUNDS: usize = 200;
+
+/// Longitudes and latitudes in radians within a few degrees of `(lon, lat)`.
+fn points(lon: f64, lat: f64) -> Vec<(f64, f64)> {
+    (0..100)
+        .flat_map(|i| {
+            (0..100).map(move |j| {
+                (
+                    (lon - 3.0 + 0.06 * i as f64).to_radians(),
+                    (lat - 3.0 + 0.06 * j as f64).to_radians(),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Nanoseconds per point of `f`, the best of several rounds.
+fn time(points: &[(f64, f64)], f: impl Fn(f64, f64) -> (f64, f64)) -> f64 {
+    let best = (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            for &(a, b) in points {
+                black_box(f(black_box(a), black_box(b)));
+            }
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::ZERO);
+    best.as_secs_f64() * 1e9 / points.len() as f64
+}
+
+fn bench<P: Projection + Copy>(name: &str, projection: P, lon: f64, lat: f64)
+where
+    Fast<P>: Projection,
+{
+    let fast = Fast(projection);
+    let geographic = points(lon, lat);
+    let projected = geographic
+        .iter()
+        .map(|&(lon, lat)| projection.rad_to_projected(lon, lat))
+        .collect::<Vec<_>>();
+    for (direction, points, full, quick) in [
+        (
+            "forward",
+            &geographic,
+            &(|a, b| projection.rad_to_projected(a, b)) as &dyn Fn(f64, f64) -> (f64, f64),
+            &(|a, b| fast.rad_to_projected(a, b)) as &dyn Fn(f64, f64) -> (f64, f64),
+        ),
+        (
+            "inverse",
+            &projected,
+            &|a, b| projection.projected_to_rad(a, b),
+            &|a, b| fast.projected_to_rad(a, b),
+        ),
+    ] {
+        let (full, quick) = (time(points, full), time(points, quick));
+        println!(
+            "{name:<24} {direction:<8} {full:>6.1} ns {quick:>6.1} ns  {:.2}x",
+            full / quick
+        );
+    }
+}
+
+fn main() {
+    let wgs84 = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+    println!(
+        "{:<24} {:<8} {:>9} {:>9}  speedup",
+        "projection", "", "full", "fast"
+    );
+    let params = TransverseMercatorParams::new(9f64.to_radians(), 0.0, 0.9996, 500000.0, 0.0);
+    let utm = TransverseMercatorProjection::new(&wgs84, &params);
+    bench("Transverse Mercator", utm, 9.0, 54.0);
+}
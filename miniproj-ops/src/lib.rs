@@ -2,6 +2,10 @@ mod ops;
 mod traits;
 pub use ops::ellipsoid::Ellipsoid;
 pub use ops::*;
+pub use traits::numerical_jacobian;
+pub use traits::CoordOperation;
 pub use traits::DbContstruct;
+pub use traits::Direction;
+pub use traits::InvertibleCoordOperation;
 pub use traits::Projection;
 pub use traits::PseudoSerialize;
@@ -0,0 +1,283 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::Geographic3DCoordinate,
+    ellipsoid::Ellipsoid,
+    inverse::iterative_geographic_inverse,
+    traits::{GetterContstruct, InvertibleCoordOperation},
+    CoordOperation, DbContstruct, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AbridgedMolodenskyParams {
+    /// X-axis translation
+    tx: f64,
+    /// Y-axis translation
+    ty: f64,
+    /// Z-axis translation
+    tz: f64,
+    /// semi-major axis length difference
+    da: f64,
+    /// flattening difference
+    df: f64,
+}
+
+impl_param_eq_hash!(AbridgedMolodenskyParams { tx, ty, tz, da, df });
+
+impl AbridgedMolodenskyParams {
+    pub const fn new(tx: f64, ty: f64, tz: f64, da: f64, df: f64) -> Self {
+        Self { tx, ty, tz, da, df }
+    }
+
+    /// Get X-axis translation, metres.
+    pub fn tx(&self) -> f64 {
+        self.tx
+    }
+
+    /// Get Y-axis translation, metres.
+    pub fn ty(&self) -> f64 {
+        self.ty
+    }
+
+    /// Get Z-axis translation, metres.
+    pub fn tz(&self) -> f64 {
+        self.tz
+    }
+
+    /// Get semi-major axis length difference of the target minus the source ellipsoid, metres.
+    pub fn da(&self) -> f64 {
+        self.da
+    }
+
+    /// Get flattening difference of the target minus the source ellipsoid.
+    pub fn df(&self) -> f64 {
+        self.df
+    }
+}
+
+/// Abridged Molodensky transformation (EPSG:9605).
+///
+/// Approximates a geocentric translation between two geographic 3D coordinate reference systems
+/// without converting to geocentric coordinates. The ellipsoid is the one of the source CRS.
+#[derive(Copy, Clone, Debug)]
+pub struct AbridgedMolodenskyTransformation {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_f: f64,
+    pub ellipsoid_e_squared: f64,
+    pub tx: f64,
+    pub ty: f64,
+    pub tz: f64,
+    pub da: f64,
+    pub df: f64,
+}
+
+impl_param_eq_hash!(AbridgedMolodenskyTransformation {
+    ellipsoid_a,
+    ellipsoid_f,
+    ellipsoid_e_squared,
+    tx,
+    ty,
+    tz,
+    da,
+    df,
+});
+
+impl AbridgedMolodenskyTransformation {
+    pub fn new(ell: &Ellipsoid, params: &AbridgedMolodenskyParams) -> Self {
+        Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_f: ell.f(),
+            ellipsoid_e_squared: ell.e_squared(),
+            tx: params.tx(),
+            ty: params.ty(),
+            tz: params.tz(),
+            da: params.da(),
+            df: params.df(),
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which uses the target ellipsoid and the
+    /// negated parameters.
+    pub fn reversed(&self) -> Self {
+        let ell = Ellipsoid::from_a_f_inv(
+            self.ellipsoid_a + self.da,
+            1.0 / (self.ellipsoid_f + self.df),
+        );
+        Self::new(
+            &ell,
+            &AbridgedMolodenskyParams::new(-self.tx, -self.ty, -self.tz, -self.da, -self.df),
+        )
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for AbridgedMolodenskyTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        let (sin_lat, cos_lat) = from.lat.sin_cos();
+        let (sin_lon, cos_lon) = from.lon.sin_cos();
+        let w_sq = 1.0 - self.ellipsoid_e_squared * sin_lat.powi(2);
+        let ny = self.ellipsoid_a / w_sq.sqrt();
+        let rho = self.ellipsoid_a * (1.0 - self.ellipsoid_e_squared) / w_sq.powf(1.5);
+        let ellipsoid_term = self.ellipsoid_a * self.df + self.ellipsoid_f * self.da;
+        Geographic3DCoordinate {
+            lon: from.lon + (-self.tx * sin_lon + self.ty * cos_lon) / (ny * cos_lat),
+            lat: from.lat
+                + (-self.tx * sin_lat * cos_lon - self.ty * sin_lat * sin_lon
+                    + self.tz * cos_lat
+                    + ellipsoid_term * (2.0 * from.lat).sin())
+                    / rho,
+            height: from.height
+                + self.tx * cos_lat * cos_lon
+                + self.ty * cos_lat * sin_lon
+                + self.tz * sin_lat
+                + ellipsoid_term * sin_lat.powi(2)
+                - self.da,
+        }
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for AbridgedMolodenskyTransformation
+{
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        iterative_geographic_inverse(self, to)
+    }
+}
+
+impl PseudoSerialize for AbridgedMolodenskyTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"AbridgedMolodenskyTransformation{{
+    ellipsoid_a: {}f64,
+    ellipsoid_f: {}f64,
+    ellipsoid_e_squared: {}f64,
+    tx: {}f64,
+    ty: {}f64,
+    tz: {}f64,
+    da: {}f64,
+    df: {}f64,
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_f,
+            self.ellipsoid_e_squared,
+            self.tx,
+            self.ty,
+            self.tz,
+            self.da,
+            self.df,
+        )
+    }
+}
+
+impl DbContstruct for AbridgedMolodenskyTransformation {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = AbridgedMolodenskyParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8605 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8606 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8607 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8654 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8655 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for AbridgedMolodenskyTransformation {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = AbridgedMolodenskyParams::new(
+            getter(8605)?,
+            getter(8606)?,
+            getter(8607)?,
+            getter(8654)?,
+            getter(8655)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_transformation(
+    params: &[(u32, f64)],
+    source: Ellipsoid,
+    _target: Ellipsoid,
+) -> String {
+    AbridgedMolodenskyTransformation::from_database_params(params, &source).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::abridged_molodensky::*;
+    use crate::coordinate::Geographic3DCoordinate;
+    use crate::ellipsoid::Ellipsoid;
+    use crate::traits::*;
+
+    #[test]
+    fn abridged_molodensky_consistency() {
+        // EPSG example, WGS 84 to ED50
+        let wgs84 = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let intl = Ellipsoid::from_a_f_inv(6378388.0, 297.0);
+        let params = AbridgedMolodenskyParams::new(
+            84.87,
+            96.49,
+            116.95,
+            intl.a() - wgs84.a(),
+            intl.f() - wgs84.f(),
+        );
+        let transformation = AbridgedMolodenskyTransformation::new(&wgs84, &params);
+
+        let from = Geographic3DCoordinate::from_deg(
+            2.0 + 7.0 / 60.0 + 46.38 / 3600.0,
+            53.0 + 48.0 / 60.0 + 33.82 / 3600.0,
+            73.0,
+        );
+        let (lon, lat, height) = transformation.apply(from).to_deg();
+        let lon_goal = 2.0 + 7.0 / 60.0 + 51.477 / 3600.0;
+        let lat_goal = 53.0 + 48.0 / 60.0 + 36.563 / 3600.0;
+        let height_goal = 28.091;
+
+        eprintln!("lon: {lon_goal} - {lon}");
+        eprintln!("lat: {lat_goal} - {lat}");
+        eprintln!("height: {height_goal} - {height}");
+
+        assert!((lon - lon_goal).abs() < 0.001 / 3600.0);
+        assert!((lat - lat_goal).abs() < 0.001 / 3600.0);
+        assert!((height - height_goal).abs() < 0.01);
+
+        // the abridged formulas approximate the geocentric translation to a few decimetres
+        let (x, y, z) = wgs84.rad_to_geocentric(from.lon, from.lat, from.height);
+        let (lon_g, lat_g, height_g) =
+            intl.geocentric_to_deg(x + params.tx(), y + params.ty(), z + params.tz());
+        eprintln!("geocentric: {lon_g}, {lat_g}, {height_g}");
+        assert!((lon - lon_g).abs() < 1e-5);
+        assert!((lat - lat_g).abs() < 1e-5);
+        assert!((height - height_g).abs() < 0.5);
+
+        let back = transformation.reversed().apply(transformation.apply(from));
+        eprintln!("{from:?} - {back:?}");
+        assert!((back.lon - from.lon).abs() < 1e-7);
+        assert!((back.lat - from.lat).abs() < 1e-7);
+        assert!((back.height - from.height).abs() < 0.5);
+    }
+}
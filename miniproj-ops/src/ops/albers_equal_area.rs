@@ -1,8 +1,13 @@
 //This file is licensed under EUPL v1.2 as part of the Digital Earth Viewer
 
-use crate::{ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, PseudoSerialize};
+use crate::{
+    ellipsoid::{authalic_q, Ellipsoid},
+    traits::GetterContstruct,
+    DbContstruct, PseudoSerialize,
+};
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlbersEqualAreaParams {
     /// longitude of false origin
     lon_orig: f64,
@@ -18,6 +23,15 @@ pub struct AlbersEqualAreaParams {
     false_n: f64,
 }
 
+impl_param_eq_hash!(AlbersEqualAreaParams {
+    lon_orig,
+    lat_orig,
+    lat_sp1,
+    lat_sp2,
+    false_e,
+    false_n,
+});
+
 impl AlbersEqualAreaParams {
     pub const fn new(
         lon_orig: f64,
@@ -68,7 +82,9 @@ impl AlbersEqualAreaParams {
     }
 }
 
-/// Lambert Azimuthal Equal Area coordinate operation (EPSG:9820)
+/// Albers Equal Area coordinate operation (EPSG:9822)
+///
+/// On a sphere (`e == 0`) the formulas reduce to the spherical ones.
 #[allow(non_snake_case)]
 #[derive(Copy, Clone, Debug)]
 pub struct AlbersEqualAreaProjection {
@@ -86,29 +102,34 @@ pub struct AlbersEqualAreaProjection {
     pub beta_fac_sin6: f64,
 }
 
+impl_param_eq_hash!(AlbersEqualAreaProjection {
+    false_e,
+    false_n,
+    lon_orig,
+    ellipsoid_e,
+    ellipsoid_e_sq,
+    ellipsoid_a,
+    C,
+    n,
+    rho_O,
+    beta_fac_sin2,
+    beta_fac_sin4,
+    beta_fac_sin6,
+});
+
 impl AlbersEqualAreaProjection {
     #[allow(non_snake_case)]
     pub fn new(ell: &Ellipsoid, params: &AlbersEqualAreaParams) -> Self {
-        dbg!(ell.e());
-        dbg!(ell.e_squared());
-        let alpha_O = Self::alpha(ell.e_squared(), params.lat_orig(), ell.e());
-        dbg!(alpha_O);
-        let alpha_1 = Self::alpha(ell.e_squared(), params.lat_sp1(), ell.e());
-        dbg!(alpha_1);
-        let alpha_2 = Self::alpha(ell.e_squared(), params.lat_sp2(), ell.e());
-        dbg!(alpha_2);
+        let alpha_O = authalic_q(ell.e(), ell.e_squared(), params.lat_orig().sin());
+        let alpha_1 = authalic_q(ell.e(), ell.e_squared(), params.lat_sp1().sin());
+        let alpha_2 = authalic_q(ell.e(), ell.e_squared(), params.lat_sp2().sin());
         let m1 = params.lat_sp1().cos()
             / (1f64 - ell.e_squared() * params.lat_sp1().sin().powi(2)).sqrt();
-        dbg!(m1);
         let m2 = params.lat_sp2().cos()
             / (1f64 - ell.e_squared() * params.lat_sp2().sin().powi(2)).sqrt();
-        dbg!(m2);
         let n = (m1.powi(2) - m2.powi(2)) / (alpha_2 - alpha_1);
-        dbg!(n);
         let C = m1.powi(2) + n * alpha_1;
-        dbg!(C);
         let rho_O = (ell.a() * (C - n * alpha_O).sqrt()) / n;
-        dbg!(rho_O);
 
         let beta_fac_sin2 = ell.e_squared() / 3f64
             + 31f64 * ell.e_squared().powi(2) / 180f64
@@ -132,13 +153,6 @@ impl AlbersEqualAreaProjection {
             beta_fac_sin6,
         }
     }
-
-    //#[inline]
-    fn alpha(e_sq: f64, phi: f64, e: f64) -> f64 {
-        (1f64 - e_sq)
-            * ((phi.sin() / (1f64 - e_sq * phi.sin().powi(2)))
-                - (1f64 / (2f64 * e)) * ((1f64 - e * phi.sin()) / (1f64 + e * phi.sin())).ln())
-    }
 }
 
 impl crate::traits::Projection for AlbersEqualAreaProjection {
@@ -146,12 +160,9 @@ impl crate::traits::Projection for AlbersEqualAreaProjection {
     /// longitude & latitude in radians
     #[allow(non_snake_case)]
     fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
-        let alpha = Self::alpha(self.ellipsoid_e_sq, latitude, self.ellipsoid_e);
-        dbg!(alpha);
+        let alpha = authalic_q(self.ellipsoid_e, self.ellipsoid_e_sq, latitude.sin());
         let theta = self.n * (longitude - self.lon_orig);
-        dbg!(theta);
         let rho = (self.ellipsoid_a * (self.C - self.n * alpha).sqrt()) / self.n;
-        dbg!(rho);
         (
             self.false_e + (rho * theta.sin()),
             self.false_n + self.rho_O - (rho * theta.cos()),
@@ -166,19 +177,13 @@ impl crate::traits::Projection for AlbersEqualAreaProjection {
     fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
         let theta_: f64 = ((easting - self.false_e) * self.n.signum())
             .atan2((self.rho_O - (northing - self.false_n)) * self.n.signum());
-        dbg!(theta_);
         let rho_ = ((easting - self.false_e).powi(2)
             + (self.rho_O - (northing - self.false_n)).powi(2))
         .sqrt();
-        dbg!(rho_);
         let alpha_ = (self.C - (rho_.powi(2) * self.n.powi(2) / self.ellipsoid_a.powi(2))) / self.n;
-        dbg!(alpha_);
-        let beta_ = (alpha_
-            / (1f64
-                - ((1f64 - self.ellipsoid_e_sq) / (2f64 * self.ellipsoid_e))
-                    * ((1f64 - self.ellipsoid_e) / (1f64 + self.ellipsoid_e)).ln()))
-        .asin();
-        dbg!(beta_);
+        let beta_ = (alpha_ / authalic_q(self.ellipsoid_e, self.ellipsoid_e_sq, 1f64))
+            .clamp(-1f64, 1f64)
+            .asin();
         let lat = beta_
             + (2f64 * beta_).sin() * self.beta_fac_sin2
             + (4f64 * beta_).sin() * self.beta_fac_sin4
@@ -343,4 +348,35 @@ mod tests {
 
         assert!((northing - northing_goal).abs() < 0.001);
     }
+
+    #[test]
+    fn albers_equal_area_spherical() {
+        // Snyder's spherical example on the unit sphere
+        let ell = Ellipsoid::from_a_b(1.0, 1.0);
+        let params = AlbersEqualAreaParams::new(
+            -96f64.to_radians(),
+            23f64.to_radians(),
+            29.5f64.to_radians(),
+            45.5f64.to_radians(),
+            0.0,
+            0.0,
+        );
+
+        let projection = AlbersEqualAreaProjection::new(&ell, &params);
+        let easting_goal = 0.2952720;
+        let northing_goal = 0.2416774;
+        let (easting, northing) = projection.deg_to_projected(-75.0, 35.0);
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 1e-7);
+        assert!((northing - northing_goal).abs() < 1e-7);
+
+        let (lon, lat) = projection.projected_to_deg(easting, northing);
+        eprintln!("lon: -75 - {lon}");
+        eprintln!("lat: 35 - {lat}");
+
+        assert!((lon + 75.0).abs() < 1e-9);
+        assert!((lat - 35.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,166 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{Projection, PseudoSerialize};
+
+/// Direction of a coordinate system axis as defined in the EPSG registry
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AxisDirection {
+    East,
+    West,
+    North,
+    South,
+    Up,
+    Down,
+    /// Directions relative to a meridian, like `"South along 90°E"` in polar CRSs, and other
+    /// directions
+    Other,
+}
+
+impl AxisDirection {
+    /// Get the direction for the `coord_axis_orientation` column of the EPSG registry.
+    pub fn from_epsg_orientation(orientation: &str) -> Self {
+        match orientation {
+            "east" => Self::East,
+            "west" => Self::West,
+            "north" => Self::North,
+            "south" => Self::South,
+            "up" => Self::Up,
+            "down" => Self::Down,
+            _ => Self::Other,
+        }
+    }
+
+    /// Whether the axis points north or south, like latitude or northing.
+    pub fn is_meridional(&self) -> bool {
+        matches!(self, Self::North | Self::South)
+    }
+}
+
+/// Projection taking and returning coordinates in the axis order of the authority.
+///
+/// All `Projection`s take and return the coordinate along the east or west pointing axis first,
+/// like `(longitude, latitude)` or `(easting, northing)`, whatever the axis order of the CRS in the
+/// EPSG registry. `AuthorityAxisOrder<P>` swaps the coordinates for CRSs whose first axis points
+/// north or south, like `(latitude, longitude)` for WGS 84 (EPSG:4326). Geographic coordinates
+/// passed to and returned from the `*_rad` and `*_deg` methods stay `(longitude, latitude)`.
+/// The signs are left as they are, since projections already return westings or southings for
+/// CRSs with such axes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AuthorityAxisOrder<P> {
+    pub projection: P,
+    /// directions of the first two axes of the CRS, in authority order
+    pub axes: [AxisDirection; 2],
+}
+
+impl<P> AuthorityAxisOrder<P> {
+    pub const fn new(projection: P, axes: [AxisDirection; 2]) -> Self {
+        Self { projection, axes }
+    }
+
+    /// Whether the authority axis order differs from the order used by `Projection`s.
+    pub fn swaps(&self) -> bool {
+        self.axes[0].is_meridional() && !self.axes[1].is_meridional()
+    }
+
+    /// Converts coordinates in authority axis order to the order used by `Projection`s.
+    pub fn from_authority_order(&self, a: f64, b: f64) -> (f64, f64) {
+        if self.swaps() {
+            (b, a)
+        } else {
+            (a, b)
+        }
+    }
+
+    /// Converts coordinates in the order used by `Projection`s to authority axis order.
+    pub fn to_authority_order(&self, x: f64, y: f64) -> (f64, f64) {
+        // swapping is its own inverse
+        self.from_authority_order(x, y)
+    }
+}
+
+impl<P: Projection> Projection for AuthorityAxisOrder<P> {
+    fn projected_to_rad(&self, a: f64, b: f64) -> (f64, f64) {
+        let (x, y) = self.from_authority_order(a, b);
+        self.projection.projected_to_rad(x, y)
+    }
+
+    fn rad_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (x, y) = self.projection.rad_to_projected(lon, lat);
+        self.to_authority_order(x, y)
+    }
+
+    fn projected_to_deg(&self, a: f64, b: f64) -> (f64, f64) {
+        let (x, y) = self.from_authority_order(a, b);
+        self.projection.projected_to_deg(x, y)
+    }
+
+    fn deg_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (x, y) = self.projection.deg_to_projected(lon, lat);
+        self.to_authority_order(x, y)
+    }
+
+    fn jacobian(&self, lon: f64, lat: f64) -> [[f64; 2]; 2] {
+        let [dx, dy] = self.projection.jacobian(lon, lat);
+        if self.swaps() {
+            [dy, dx]
+        } else {
+            [dx, dy]
+        }
+    }
+}
+
+impl<P: PseudoSerialize> PseudoSerialize for AuthorityAxisOrder<P> {
+    fn to_constructed(&self) -> String {
+        format!(
+            "AuthorityAxisOrder{{ projection: {}, axes: [AxisDirection::{:?}, AxisDirection::{:?}] }}",
+            self.projection.to_constructed(),
+            self.axes[0],
+            self.axes[1],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::axis_order::*;
+    use crate::identity_projection::IdentityProjection;
+    use crate::traits::*;
+    use crate::transverse_mercator::*;
+    use crate::Ellipsoid;
+
+    #[test]
+    fn authority_axis_order() {
+        assert_eq!(
+            AxisDirection::from_epsg_orientation("north"),
+            AxisDirection::North
+        );
+        assert_eq!(
+            AxisDirection::from_epsg_orientation("South along 90°E"),
+            AxisDirection::Other
+        );
+
+        // WGS 84 has latitude first
+        let axes = [AxisDirection::North, AxisDirection::East];
+        let wgs84 = AuthorityAxisOrder::new(IdentityProjection, axes);
+        assert!(wgs84.swaps());
+        assert_eq!(wgs84.deg_to_projected(10.0, 54.0), (54.0, 10.0));
+        assert_eq!(wgs84.projected_to_deg(54.0, 10.0), (10.0, 54.0));
+        let (lon, lat) = wgs84.projected_to_rad(54.0, 10.0);
+        assert_eq!((lon, lat), (10.0f64.to_radians(), 54.0f64.to_radians()));
+
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params = TransverseMercatorParams::new(9f64.to_radians(), 0.0, 0.9996, 500000.0, 0.0);
+        let utm = TransverseMercatorProjection::new(&ell, &params);
+        let (easting, northing) = utm.deg_to_projected(10.0, 54.0);
+        // SWEREF99 TM style northing, easting
+        let swapped = AuthorityAxisOrder::new(utm, axes);
+        assert_eq!(swapped.deg_to_projected(10.0, 54.0), (northing, easting));
+        let [dx, dy] = utm.jacobian(0.17, 0.94);
+        assert_eq!(swapped.jacobian(0.17, 0.94), [dy, dx]);
+        let unchanged = AuthorityAxisOrder::new(utm, [AxisDirection::East, AxisDirection::North]);
+        assert!(!unchanged.swaps());
+        let (lon, lat) = utm.projected_to_deg(easting, northing);
+        assert_eq!(unchanged.projected_to_deg(easting, northing), (lon, lat));
+    }
+}
@@ -0,0 +1,766 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    ellipsoid::{Ellipsoid, MeridianArc},
+    traits::GetterContstruct,
+    DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AzimuthalEquidistantParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// latitude of natural origin
+    lat_orig: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(AzimuthalEquidistantParams {
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n
+});
+
+impl AzimuthalEquidistantParams {
+    pub const fn new(lon_orig: f64, lat_orig: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            lat_orig,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of natural origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Modified Azimuthal Equidistant coordinate operation (EPSG:9832).
+///
+/// Intended for distances below 800 km from the origin, as used for the Micronesian islands.
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct ModifiedAzimuthalEquidistantProjection {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_e: f64,
+    pub ellipsoid_e_squared: f64,
+    pub lon_orig: f64,
+    pub sin_lat_orig: f64,
+    pub cos_lat_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+
+    pub nu_O: f64,
+}
+
+impl_param_eq_hash!(ModifiedAzimuthalEquidistantProjection {
+    ellipsoid_a,
+    ellipsoid_e,
+    ellipsoid_e_squared,
+    lon_orig,
+    sin_lat_orig,
+    cos_lat_orig,
+    false_e,
+    false_n,
+    nu_O,
+});
+
+impl ModifiedAzimuthalEquidistantProjection {
+    pub fn new(ell: &Ellipsoid, params: &AzimuthalEquidistantParams) -> Self {
+        let sin_lat_orig = params.lat_orig().sin();
+        Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_e: ell.e(),
+            ellipsoid_e_squared: ell.e_squared(),
+            lon_orig: params.lon_orig(),
+            sin_lat_orig,
+            cos_lat_orig: params.lat_orig().cos(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+
+            nu_O: ell.a() / (1.0 - ell.e_squared() * sin_lat_orig.powi(2)).sqrt(),
+        }
+    }
+}
+
+impl Projection for ModifiedAzimuthalEquidistantProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    #[allow(non_snake_case)]
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let e_sq = self.ellipsoid_e_squared;
+        let d_lon = longitude - self.lon_orig;
+        let nu = self.ellipsoid_a / (1.0 - e_sq * latitude.sin().powi(2)).sqrt();
+        let psi = ((1.0 - e_sq) * latitude.tan()
+            + e_sq * self.nu_O * self.sin_lat_orig / (nu * latitude.cos()))
+        .atan();
+        let alpha = f64::atan2(
+            d_lon.sin(),
+            self.cos_lat_orig * psi.tan() - self.sin_lat_orig * d_lon.cos(),
+        );
+        let G = self.ellipsoid_e * self.sin_lat_orig / (1.0 - e_sq).sqrt();
+        let H = self.ellipsoid_e * self.cos_lat_orig * alpha.cos() / (1.0 - e_sq).sqrt();
+        let s = if alpha.sin() == 0.0 {
+            (self.cos_lat_orig * psi.sin() - self.sin_lat_orig * psi.cos()).asin()
+                * alpha.cos().signum()
+        } else {
+            (d_lon.sin() * psi.cos() / alpha.sin()).asin()
+        };
+        let c = self.nu_O
+            * s
+            * ((1.0 - s.powi(2) * H.powi(2) * (1.0 - H.powi(2)) / 6.0)
+                + (s.powi(3) / 8.0) * G * H * (1.0 - 2.0 * H.powi(2))
+                + (s.powi(4) / 120.0)
+                    * (H.powi(2) * (4.0 - 7.0 * H.powi(2))
+                        - 3.0 * G.powi(2) * (1.0 - 7.0 * H.powi(2)))
+                - (s.powi(5) / 48.0) * G * H);
+        (
+            self.false_e + c * alpha.sin(),
+            self.false_n + c * alpha.cos(),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    #[allow(non_snake_case)]
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let e_sq = self.ellipsoid_e_squared;
+        let x = easting - self.false_e;
+        let y = northing - self.false_n;
+        let c_ = x.hypot(y);
+        let alpha_ = f64::atan2(x, y);
+        let A = -e_sq * self.cos_lat_orig.powi(2) * alpha_.cos().powi(2) / (1.0 - e_sq);
+        let B = 3.0 * e_sq * (1.0 - A) * self.sin_lat_orig * self.cos_lat_orig * alpha_.cos()
+            / (1.0 - e_sq);
+        let D = c_ / self.nu_O;
+        let J = D - A * (1.0 + A) * D.powi(3) / 6.0 - B * (1.0 + 3.0 * A) * D.powi(4) / 24.0;
+        let K = 1.0 - A * J.powi(2) / 2.0 - B * J.powi(3) / 6.0;
+        let psi_ =
+            (self.sin_lat_orig * J.cos() + self.cos_lat_orig * J.sin() * alpha_.cos()).asin();
+        (
+            self.lon_orig + (alpha_.sin() * J.sin() / psi_.cos()).asin(),
+            ((1.0 - e_sq * K * self.sin_lat_orig / psi_.sin()) * psi_.tan() / (1.0 - e_sq)).atan(),
+        )
+    }
+}
+
+impl PseudoSerialize for ModifiedAzimuthalEquidistantProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"ModifiedAzimuthalEquidistantProjection{{
+    ellipsoid_a: {}f64,
+    ellipsoid_e: {}f64,
+    ellipsoid_e_squared: {}f64,
+    lon_orig: {}f64,
+    sin_lat_orig: {}f64,
+    cos_lat_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    nu_O: {}f64,
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_e,
+            self.ellipsoid_e_squared,
+            self.lon_orig,
+            self.sin_lat_orig,
+            self.cos_lat_orig,
+            self.false_e,
+            self.false_n,
+            self.nu_O,
+        )
+    }
+}
+
+impl DbContstruct for ModifiedAzimuthalEquidistantProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = AzimuthalEquidistantParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for ModifiedAzimuthalEquidistantProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = AzimuthalEquidistantParams::new(
+            getter(8802)?,
+            getter(8801)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection_modified(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    ModifiedAzimuthalEquidistantProjection::from_database_params(params, &ell).to_constructed()
+}
+
+/// Guam Projection coordinate operation (EPSG:9831).
+///
+/// A simplified azimuthal equidistant projection. As specified by EPSG, the inverse uses exactly
+/// three iterations, which is sufficient over the small area of application.
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct GuamProjection {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_e_squared: f64,
+    pub lon_orig: f64,
+    pub lat_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+
+    pub meridian_arc: MeridianArc,
+    pub M_orig: f64,
+}
+
+impl_param_eq_hash!(GuamProjection {
+    ellipsoid_a,
+    ellipsoid_e_squared,
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n,
+    M_orig;
+    meridian_arc,
+});
+
+impl GuamProjection {
+    const ITERATIONS: usize = 3;
+
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &AzimuthalEquidistantParams) -> Self {
+        let meridian_arc = MeridianArc::new(ell);
+        Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_e_squared: ell.e_squared(),
+            lon_orig: params.lon_orig(),
+            lat_orig: params.lat_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+
+            meridian_arc,
+            M_orig: meridian_arc.distance(params.lat_orig()),
+        }
+    }
+}
+
+impl Projection for GuamProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let w = (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).sqrt();
+        let x = self.ellipsoid_a * (longitude - self.lon_orig) * latitude.cos() / w;
+        (
+            self.false_e + x,
+            self.false_n + self.meridian_arc.distance(latitude) - self.M_orig
+                + x.powi(2) * latitude.tan() * w / (2.0 * self.ellipsoid_a),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    #[allow(non_snake_case)]
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let x = easting - self.false_e;
+        let mut latitude = self.lat_orig;
+        for _ in 0..Self::ITERATIONS {
+            let M = self.M_orig + (northing - self.false_n)
+                - x.powi(2)
+                    * latitude.tan()
+                    * (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).sqrt()
+                    / (2.0 * self.ellipsoid_a);
+            latitude = self.meridian_arc.footpoint_latitude(M);
+        }
+        (
+            self.lon_orig
+                + x * (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).sqrt()
+                    / (self.ellipsoid_a * latitude.cos()),
+            latitude,
+        )
+    }
+}
+
+impl PseudoSerialize for GuamProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"GuamProjection{{
+    ellipsoid_a: {}f64,
+    ellipsoid_e_squared: {}f64,
+    lon_orig: {}f64,
+    lat_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    meridian_arc: {},
+    M_orig: {}f64,
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_e_squared,
+            self.lon_orig,
+            self.lat_orig,
+            self.false_e,
+            self.false_n,
+            self.meridian_arc.to_constructed(),
+            self.M_orig,
+        )
+    }
+}
+
+impl DbContstruct for GuamProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = AzimuthalEquidistantParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for GuamProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = AzimuthalEquidistantParams::new(
+            getter(8802)?,
+            getter(8801)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection_guam(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    GuamProjection::from_database_params(params, &ell).to_constructed()
+}
+
+/// Azimuthal Equidistant coordinate operation (EPSG:1125).
+///
+/// Distances and azimuths from the origin are preserved. They are computed rigorously on the
+/// ellipsoid using Vincenty's formulae, which converge for all but nearly antipodal positions;
+/// those and projected positions beyond the antipode result in `NaN`.
+#[derive(Copy, Clone, Debug)]
+pub struct AzimuthalEquidistantProjection {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_f: f64,
+    pub lon_orig: f64,
+    pub lat_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+}
+
+impl_param_eq_hash!(AzimuthalEquidistantProjection {
+    ellipsoid_a,
+    ellipsoid_f,
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n,
+});
+
+impl AzimuthalEquidistantProjection {
+    const MAX_ITERATIONS: usize = 200;
+    const TOLERANCE: f64 = 1e-12;
+
+    pub fn new(ell: &Ellipsoid, params: &AzimuthalEquidistantParams) -> Self {
+        Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_f: ell.f(),
+            lon_orig: params.lon_orig(),
+            lat_orig: params.lat_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+        }
+    }
+
+    /// Coefficients `A` and `B` of Vincenty's series for the distance along the geodesic.
+    #[allow(non_snake_case)]
+    fn series_coefficients(&self, cos_sq_alpha: f64) -> (f64, f64) {
+        let b = self.ellipsoid_a * (1.0 - self.ellipsoid_f);
+        let u_sq = cos_sq_alpha * (self.ellipsoid_a.powi(2) - b.powi(2)) / b.powi(2);
+        let A = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let B = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+        (A, B)
+    }
+
+    #[allow(non_snake_case)]
+    fn delta_sigma(B: f64, sigma: f64, cos_2_sigma_m: f64) -> f64 {
+        B * sigma.sin()
+            * (cos_2_sigma_m
+                + B / 4.0
+                    * (sigma.cos() * (-1.0 + 2.0 * cos_2_sigma_m.powi(2))
+                        - B / 6.0
+                            * cos_2_sigma_m
+                            * (-3.0 + 4.0 * sigma.sin().powi(2))
+                            * (-3.0 + 4.0 * cos_2_sigma_m.powi(2))))
+    }
+
+    /// Difference between the longitude on the auxiliary sphere and on the ellipsoid.
+    #[allow(non_snake_case)]
+    fn lon_correction(&self, sin_alpha: f64, sigma: f64, cos_2_sigma_m: f64) -> f64 {
+        let f = self.ellipsoid_f;
+        let cos_sq_alpha = 1.0 - sin_alpha.powi(2);
+        let C = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        (1.0 - C)
+            * f
+            * sin_alpha
+            * (sigma
+                + C * sigma.sin()
+                    * (cos_2_sigma_m + C * sigma.cos() * (-1.0 + 2.0 * cos_2_sigma_m.powi(2))))
+    }
+
+    /// Reduced latitude of `latitude`, as `(sin, cos)`.
+    fn reduced_lat(&self, latitude: f64) -> (f64, f64) {
+        f64::atan2((1.0 - self.ellipsoid_f) * latitude.sin(), latitude.cos()).sin_cos()
+    }
+
+    /// Solve the inverse geodesic problem from the origin, returning distance and azimuth.
+    #[allow(non_snake_case)]
+    fn distance_azimuth(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let (sin_u_1, cos_u_1) = self.reduced_lat(self.lat_orig);
+        let (sin_u_2, cos_u_2) = self.reduced_lat(latitude);
+        let L = longitude - self.lon_orig;
+        let mut lambda = L;
+        for _ in 0..Self::MAX_ITERATIONS {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma =
+                (cos_u_2 * sin_lambda).hypot(cos_u_1 * sin_u_2 - sin_u_1 * cos_u_2 * cos_lambda);
+            if sin_sigma == 0.0 {
+                return (0.0, 0.0);
+            }
+            let cos_sigma = sin_u_1 * sin_u_2 + cos_u_1 * cos_u_2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u_1 * cos_u_2 * sin_lambda / sin_sigma;
+            let cos_sq_alpha = 1.0 - sin_alpha.powi(2);
+            let cos_2_sigma_m = if cos_sq_alpha == 0.0 {
+                0.0
+            } else {
+                cos_sigma - 2.0 * sin_u_1 * sin_u_2 / cos_sq_alpha
+            };
+            let lambda_prev = lambda;
+            lambda = L + self.lon_correction(sin_alpha, sigma, cos_2_sigma_m);
+            if (lambda - lambda_prev).abs() < Self::TOLERANCE {
+                let (A, B) = self.series_coefficients(cos_sq_alpha);
+                let b = self.ellipsoid_a * (1.0 - self.ellipsoid_f);
+                let s = b * A * (sigma - Self::delta_sigma(B, sigma, cos_2_sigma_m));
+                let (sin_lambda, cos_lambda) = lambda.sin_cos();
+                let azimuth = f64::atan2(
+                    cos_u_2 * sin_lambda,
+                    cos_u_1 * sin_u_2 - sin_u_1 * cos_u_2 * cos_lambda,
+                );
+                return (s, azimuth);
+            }
+        }
+        (f64::NAN, f64::NAN)
+    }
+
+    /// Solve the direct geodesic problem from the origin, returning longitude and latitude.
+    #[allow(non_snake_case)]
+    fn destination(&self, azimuth: f64, distance: f64) -> (f64, f64) {
+        let (sin_u_1, cos_u_1) = self.reduced_lat(self.lat_orig);
+        let (sin_alpha_1, cos_alpha_1) = azimuth.sin_cos();
+        let sigma_1 = f64::atan2(sin_u_1, cos_u_1 * cos_alpha_1);
+        let sin_alpha = cos_u_1 * sin_alpha_1;
+        let (A, B) = self.series_coefficients(1.0 - sin_alpha.powi(2));
+        let b = self.ellipsoid_a * (1.0 - self.ellipsoid_f);
+        let mut sigma = distance / (b * A);
+        let mut cos_2_sigma_m = (2.0 * sigma_1 + sigma).cos();
+        for _ in 0..Self::MAX_ITERATIONS {
+            cos_2_sigma_m = (2.0 * sigma_1 + sigma).cos();
+            let sigma_prev = sigma;
+            sigma = distance / (b * A) + Self::delta_sigma(B, sigma, cos_2_sigma_m);
+            if (sigma - sigma_prev).abs() < Self::TOLERANCE {
+                break;
+            }
+        }
+        let (sin_sigma, cos_sigma) = sigma.sin_cos();
+        let latitude = f64::atan2(
+            sin_u_1 * cos_sigma + cos_u_1 * sin_sigma * cos_alpha_1,
+            (1.0 - self.ellipsoid_f)
+                * sin_alpha.hypot(sin_u_1 * sin_sigma - cos_u_1 * cos_sigma * cos_alpha_1),
+        );
+        let lambda = f64::atan2(
+            sin_sigma * sin_alpha_1,
+            cos_u_1 * cos_sigma - sin_u_1 * sin_sigma * cos_alpha_1,
+        );
+        (
+            self.lon_orig + lambda - self.lon_correction(sin_alpha, sigma, cos_2_sigma_m),
+            latitude,
+        )
+    }
+}
+
+impl Projection for AzimuthalEquidistantProjection {
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let (distance, azimuth) = self.distance_azimuth(longitude, latitude);
+        (
+            self.false_e + distance * azimuth.sin(),
+            self.false_n + distance * azimuth.cos(),
+        )
+    }
+
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let x = easting - self.false_e;
+        let y = northing - self.false_n;
+        let distance = x.hypot(y);
+        // beyond the antipode
+        if distance > std::f64::consts::PI * self.ellipsoid_a {
+            return (f64::NAN, f64::NAN);
+        }
+        self.destination(x.atan2(y), distance)
+    }
+}
+
+impl PseudoSerialize for AzimuthalEquidistantProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"AzimuthalEquidistantProjection{{
+    ellipsoid_a: {}f64,
+    ellipsoid_f: {}f64,
+    lon_orig: {}f64,
+    lat_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_f,
+            self.lon_orig,
+            self.lat_orig,
+            self.false_e,
+            self.false_n,
+        )
+    }
+}
+
+impl DbContstruct for AzimuthalEquidistantProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = AzimuthalEquidistantParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for AzimuthalEquidistantProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = AzimuthalEquidistantParams::new(
+            getter(8802)?,
+            getter(8801)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    AzimuthalEquidistantProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::azimuthal_equidistant::*;
+    use crate::ellipsoid::Ellipsoid;
+    use crate::traits::*;
+
+    #[test]
+    fn modified_azimuthal_equidistant_consistency() {
+        // Guam 1963 / Yap Islands
+        let ell = Ellipsoid::from_a_f_inv(6378206.4, 294.9786982);
+        let params = AzimuthalEquidistantParams::new(
+            (138.0 + 10.0 / 60.0 + 7.48 / 3600.0f64).to_radians(),
+            (9.0 + 32.0 / 60.0 + 48.15 / 3600.0f64).to_radians(),
+            40_000.0,
+            60_000.0,
+        );
+
+        let projection = ModifiedAzimuthalEquidistantProjection::new(&ell, &params);
+        let easting_goal = 42665.90;
+        let northing_goal = 65509.82;
+        let lon = 138.0 + 11.0 / 60.0 + 34.908 / 3600.0;
+        let lat = 9.0 + 35.0 / 60.0 + 47.493 / 3600.0;
+        let (easting, northing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+    }
+    #[test]
+    fn guam_consistency() {
+        // Guam 1963 / Guam SPCS
+        let ell = Ellipsoid::from_a_f_inv(6378206.4, 294.9786982);
+        let params = AzimuthalEquidistantParams::new(
+            (144.0 + 44.0 / 60.0 + 55.50254 / 3600.0f64).to_radians(),
+            (13.0 + 28.0 / 60.0 + 20.87887 / 3600.0f64).to_radians(),
+            50_000.0,
+            50_000.0,
+        );
+
+        let projection = GuamProjection::new(&ell, &params);
+        let easting_goal = 37712.48;
+        let northing_goal = 35242.00;
+        let lon = 144.0 + 38.0 / 60.0 + 7.19265 / 3600.0;
+        let lat = 13.0 + 20.0 / 60.0 + 20.53846 / 3600.0;
+        let (easting, northing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+    }
+
+    #[test]
+    fn azimuthal_equidistant_consistency() {
+        // Vincenty's example from Flinders Peak to Buninyong
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257222101);
+        let params = AzimuthalEquidistantParams::new(
+            (144.0 + 25.0 / 60.0 + 29.5244 / 3600.0f64).to_radians(),
+            (-(37.0 + 57.0 / 60.0 + 3.7203 / 3600.0f64)).to_radians(),
+            0.0,
+            0.0,
+        );
+
+        let projection = AzimuthalEquidistantProjection::new(&ell, &params);
+        let distance = 54972.271;
+        let azimuth = (306.0 + 52.0 / 60.0 + 5.37 / 3600.0f64).to_radians();
+        let easting_goal = distance * azimuth.sin();
+        let northing_goal = distance * azimuth.cos();
+        let lon = 143.0 + 55.0 / 60.0 + 35.3839 / 3600.0;
+        let lat = -(37.0 + 39.0 / 60.0 + 10.1561 / 3600.0);
+        let (easting, northing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+
+        for (lon, lat) in [(144.0, -38.0), (-30.0, 10.0), (100.0, 60.0), (144.0, 89.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn azimuthal_equidistant_polar() {
+        // WGS 84 / Equi7 Antarctica
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params =
+            AzimuthalEquidistantParams::new(0.0, (-90f64).to_radians(), 3714266.977, 3402016.506);
+
+        let projection = AzimuthalEquidistantProjection::new(&ell, &params);
+        // meridian distance from the pole to 80°S
+        let (easting, northing) = projection.deg_to_projected(90.0, -80.0);
+        eprintln!("{easting}, {northing}");
+        assert!((easting - 3714266.977 - 1116825.857).abs() < 0.01);
+        assert!((northing - 3402016.506).abs() < 1e-6);
+
+        let (lon, lat) = projection.projected_to_deg(easting, northing);
+        eprintln!("{lon}, {lat}");
+        assert!((lon - 90.0).abs() < 1e-9);
+        assert!((lat + 80.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,368 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    ellipsoid::{Ellipsoid, MeridianArc},
+    traits::GetterContstruct,
+    DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BonneParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// latitude of natural origin
+    lat_orig: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+    /// whether the axes point west and south (EPSG:9828) instead of east and north (EPSG:9827)
+    south_orientated: bool,
+}
+
+impl PartialEq for BonneParams {
+    fn eq(&self, other: &Self) -> bool {
+        self.lon_orig.to_bits() == other.lon_orig.to_bits()
+            && self.lat_orig.to_bits() == other.lat_orig.to_bits()
+            && self.false_e.to_bits() == other.false_e.to_bits()
+            && self.false_n.to_bits() == other.false_n.to_bits()
+            && self.south_orientated == other.south_orientated
+    }
+}
+
+impl Eq for BonneParams {}
+
+impl std::hash::Hash for BonneParams {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.lon_orig.to_bits().hash(state);
+        self.lat_orig.to_bits().hash(state);
+        self.false_e.to_bits().hash(state);
+        self.false_n.to_bits().hash(state);
+        self.south_orientated.hash(state);
+    }
+}
+
+impl BonneParams {
+    pub const fn new(
+        lon_orig: f64,
+        lat_orig: f64,
+        false_e: f64,
+        false_n: f64,
+        south_orientated: bool,
+    ) -> Self {
+        Self {
+            lon_orig,
+            lat_orig,
+            false_e,
+            false_n,
+            south_orientated,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of natural origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+
+    /// Get whether the projected axes are south orientated.
+    pub fn south_orientated(&self) -> bool {
+        self.south_orientated
+    }
+
+    /// Read the parameters shared by both variants from a getter.
+    fn with_db_getter<G>(mut getter: G, south_orientated: bool) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        Some(Self::new(
+            getter(8802)?,
+            getter(8801)?,
+            getter(8806)?,
+            getter(8807)?,
+            south_orientated,
+        ))
+    }
+}
+
+/// Bonne (EPSG:9827) and Bonne (South Orientated) (EPSG:9828) coordinate operation.
+///
+/// The south orientated variant returns `(westing, southing)`.
+/// The latitude of natural origin must not be zero.
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct BonneProjection {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_e_squared: f64,
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+    /// `1.0` for the regular, `-1.0` for the south orientated variant
+    pub orientation: f64,
+
+    pub meridian_arc: MeridianArc,
+    pub M_orig: f64,
+    /// `a mO / sin(latO)`, the radius of the parallel of origin
+    pub rho_orig: f64,
+}
+
+impl_param_eq_hash!(BonneProjection {
+    ellipsoid_a,
+    ellipsoid_e_squared,
+    lon_orig,
+    false_e,
+    false_n,
+    orientation,
+    M_orig,
+    rho_orig;
+    meridian_arc,
+});
+
+impl BonneProjection {
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &BonneParams) -> Self {
+        let lat_orig = params.lat_orig();
+        let meridian_arc = MeridianArc::new(ell);
+        let mut projection = Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_e_squared: ell.e_squared(),
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+            orientation: if params.south_orientated() { -1.0 } else { 1.0 },
+
+            meridian_arc,
+            M_orig: meridian_arc.distance(lat_orig),
+            rho_orig: 0.0,
+        };
+        projection.rho_orig = projection.parallel_radius(lat_orig) / lat_orig.sin();
+        projection
+    }
+
+    /// Radius of the parallel at `latitude`, `a m`.
+    fn parallel_radius(&self, latitude: f64) -> f64 {
+        self.ellipsoid_a * latitude.cos()
+            / (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).sqrt()
+    }
+
+    /// Construct the south orientated variant (EPSG:9828) with a getter that provides the
+    /// parameter values.
+    pub fn south_orientated_with_db_getter<G>(getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        Some(Self::new(
+            ellipsoid,
+            &BonneParams::with_db_getter(getter, true)?,
+        ))
+    }
+}
+
+impl Projection for BonneProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    #[allow(non_snake_case)]
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let rho = self.rho_orig + self.M_orig - self.meridian_arc.distance(latitude);
+        let T = self.parallel_radius(latitude) * (longitude - self.lon_orig) / rho;
+        (
+            self.false_e + self.orientation * rho * T.sin(),
+            self.false_n + self.orientation * (self.rho_orig - rho * T.cos()),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let x = self.orientation * (easting - self.false_e);
+        let y = self.orientation * (northing - self.false_n);
+        let sign = self.rho_orig.signum();
+        let rho = sign * x.hypot(self.rho_orig - y);
+        let latitude = self
+            .meridian_arc
+            .footpoint_latitude(self.rho_orig + self.M_orig - rho);
+        let parallel_radius = self.parallel_radius(latitude);
+        // at the poles the longitude is indeterminate
+        let longitude = if parallel_radius.abs() < 1e-9 {
+            self.lon_orig
+        } else {
+            self.lon_orig + rho * f64::atan2(sign * x, sign * (self.rho_orig - y)) / parallel_radius
+        };
+        (longitude, latitude)
+    }
+}
+
+impl PseudoSerialize for BonneProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"BonneProjection{{
+    ellipsoid_a: {}f64,
+    ellipsoid_e_squared: {}f64,
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    orientation: {}f64,
+    meridian_arc: {},
+    M_orig: {}f64,
+    rho_orig: {}f64,
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_e_squared,
+            self.lon_orig,
+            self.false_e,
+            self.false_n,
+            self.orientation,
+            self.meridian_arc.to_constructed(),
+            self.M_orig,
+            self.rho_orig,
+        )
+    }
+}
+
+impl DbContstruct for BonneProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = BonneParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+            false,
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for BonneProjection {
+    fn with_db_getter<G>(getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        Some(Self::new(
+            ellipsoid,
+            &BonneParams::with_db_getter(getter, false)?,
+        ))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    BonneProjection::from_database_params(params, &ell).to_constructed()
+}
+
+pub fn direct_projection_south_orientated(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    BonneProjection::south_orientated_with_db_getter(
+        |code| {
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == code { Some(*v) } else { None })
+        },
+        &ell,
+    )
+    .unwrap()
+    .to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::bonne::*;
+    use crate::ellipsoid::Ellipsoid;
+    use crate::equidistant_cylindrical::*;
+    use crate::traits::*;
+
+    #[test]
+    fn bonne_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let lat_orig = 39.4f64;
+        let params = BonneParams::new(
+            (-8.0f64).to_radians(),
+            lat_orig.to_radians(),
+            0.0,
+            0.0,
+            false,
+        );
+        let projection = BonneProjection::new(&ell, &params);
+
+        // along the central meridian the northing is the meridian distance from the origin
+        let meridian = EquidistantCylindricalProjection::new(
+            &ell,
+            &EquidistantCylindricalParams::new(0.0, 0.0, 0.0, 0.0),
+        );
+        let (_, m_orig) = meridian.deg_to_projected(0.0, lat_orig);
+        for lat in [-30.0, 10.0, 39.4, 50.0, 85.0] {
+            let (easting, northing) = projection.deg_to_projected(-8.0, lat);
+            let (_, m) = meridian.deg_to_projected(0.0, lat);
+            eprintln!("{lat}: {easting}, {northing} - {}", m - m_orig);
+            assert!(easting.abs() < 1e-6);
+            assert!((northing - (m - m_orig)).abs() < 0.001);
+        }
+
+        for (lon, lat) in [
+            (-9.0, 38.7),
+            (-6.2, 42.1),
+            (20.0, 10.0),
+            (-60.0, 70.0),
+            (40.0, -20.0),
+        ] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn bonne_south_orientated() {
+        let ell = Ellipsoid::from_a_f_inv(6377397.155, 299.1528128);
+        let lon_orig = (-8.0 - 7.0 / 60.0 - 54.862 / 3600.0f64).to_radians();
+        let lat_orig = (39.0 + 40.0 / 60.0f64).to_radians();
+        let projection =
+            BonneProjection::new(&ell, &BonneParams::new(lon_orig, lat_orig, 0.0, 0.0, false));
+        let south =
+            BonneProjection::new(&ell, &BonneParams::new(lon_orig, lat_orig, 0.0, 0.0, true));
+
+        for (lon, lat) in [
+            (-9.14, 38.71),
+            (-8.61, 41.15),
+            (-7.93, 37.02),
+            (-6.19, 41.81),
+        ] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (westing, southing) = south.deg_to_projected(lon, lat);
+            eprintln!("{lon}, {lat}: {easting}, {northing} - {westing}, {southing}");
+            assert!((easting + westing).abs() < 1e-6);
+            assert!((northing + southing).abs() < 1e-6);
+
+            let (lon_r, lat_r) = south.projected_to_deg(westing, southing);
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,455 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::{PI, TAU};
+
+use crate::{
+    ellipsoid::{Ellipsoid, MeridianArc},
+    traits::GetterContstruct,
+    DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CassiniSoldnerParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// latitude of natural origin
+    lat_orig: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(CassiniSoldnerParams {
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n
+});
+
+impl CassiniSoldnerParams {
+    pub const fn new(lon_orig: f64, lat_orig: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            lat_orig,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of natural origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Cassini-Soldner coordinate operation (EPSG:9806).
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct CassiniSoldnerProjection {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_e_squared: f64,
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+
+    pub meridian_arc: MeridianArc,
+    pub M_orig: f64,
+}
+
+impl_param_eq_hash!(CassiniSoldnerProjection {
+    ellipsoid_a,
+    ellipsoid_e_squared,
+    lon_orig,
+    false_e,
+    false_n,
+    M_orig;
+    meridian_arc,
+});
+
+impl CassiniSoldnerProjection {
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &CassiniSoldnerParams) -> Self {
+        let meridian_arc = MeridianArc::new(ell);
+        Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_e_squared: ell.e_squared(),
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+
+            meridian_arc,
+            M_orig: meridian_arc.distance(params.lat_orig()),
+        }
+    }
+
+    /// Radius of curvature in the meridian.
+    fn rho(&self, latitude: f64) -> f64 {
+        self.ellipsoid_a * (1.0 - self.ellipsoid_e_squared)
+            / (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).powf(1.5)
+    }
+
+    /// Radius of curvature in the prime vertical.
+    fn nu(&self, latitude: f64) -> f64 {
+        self.ellipsoid_a / (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).sqrt()
+    }
+
+    /// Longitude and latitude from easting and the footpoint latitude on the central meridian.
+    #[allow(non_snake_case)]
+    pub(crate) fn footpoint_to_rad(&self, easting: f64, lat_1: f64) -> (f64, f64) {
+        let T_1 = lat_1.tan().powi(2);
+        let nu_1 = self.nu(lat_1);
+        let rho_1 = self.rho(lat_1);
+        let D = (easting - self.false_e) / nu_1;
+        (
+            self.lon_orig
+                + (D - T_1 * D.powi(3) / 3.0 + (1.0 + 3.0 * T_1) * T_1 * D.powi(5) / 15.0)
+                    / lat_1.cos(),
+            lat_1
+                - (nu_1 * lat_1.tan() / rho_1)
+                    * (D.powi(2) / 2.0 - (1.0 + 3.0 * T_1) * D.powi(4) / 24.0),
+        )
+    }
+}
+
+impl Projection for CassiniSoldnerProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    #[allow(non_snake_case)]
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        // the series diverge quickly, so grids crossing the antimeridian need the short way round
+        let A = ((longitude - self.lon_orig + PI).rem_euclid(TAU) - PI) * latitude.cos();
+        let T = latitude.tan().powi(2);
+        let C =
+            self.ellipsoid_e_squared * latitude.cos().powi(2) / (1.0 - self.ellipsoid_e_squared);
+        let nu = self.nu(latitude);
+        (
+            self.false_e
+                + nu * (A - T * A.powi(3) / 6.0 - (8.0 - T + 8.0 * C) * T * A.powi(5) / 120.0),
+            self.false_n + self.meridian_arc.distance(latitude) - self.M_orig
+                + nu * latitude.tan() * (A.powi(2) / 2.0 + (5.0 - T + 6.0 * C) * A.powi(4) / 24.0),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        self.footpoint_to_rad(
+            easting,
+            self.meridian_arc
+                .footpoint_latitude(self.M_orig + (northing - self.false_n)),
+        )
+    }
+}
+
+impl PseudoSerialize for CassiniSoldnerProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"CassiniSoldnerProjection{{
+    ellipsoid_a: {}f64,
+    ellipsoid_e_squared: {}f64,
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    meridian_arc: {},
+    M_orig: {}f64,
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_e_squared,
+            self.lon_orig,
+            self.false_e,
+            self.false_n,
+            self.meridian_arc.to_constructed(),
+            self.M_orig,
+        )
+    }
+}
+
+impl DbContstruct for CassiniSoldnerProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = CassiniSoldnerParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for CassiniSoldnerProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params =
+            CassiniSoldnerParams::new(getter(8802)?, getter(8801)?, getter(8806)?, getter(8807)?);
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyperbolicCassiniSoldnerParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// latitude of natural origin
+    lat_orig: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(HyperbolicCassiniSoldnerParams {
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n
+});
+
+impl HyperbolicCassiniSoldnerParams {
+    pub const fn new(lon_orig: f64, lat_orig: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            lat_orig,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of natural origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Hyperbolic Cassini-Soldner coordinate operation (EPSG:9833).
+///
+/// Cassini-Soldner with an additional cubic term in the northing, as used for the Vanua Levu grid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HyperbolicCassiniSoldnerProjection(pub CassiniSoldnerProjection);
+
+impl HyperbolicCassiniSoldnerProjection {
+    const MAX_ITERATIONS: usize = 4;
+
+    pub fn new(ell: &Ellipsoid, params: &HyperbolicCassiniSoldnerParams) -> Self {
+        Self(CassiniSoldnerProjection::new(
+            ell,
+            &CassiniSoldnerParams::new(
+                params.lon_orig(),
+                params.lat_orig(),
+                params.false_e(),
+                params.false_n(),
+            ),
+        ))
+    }
+}
+
+impl Projection for HyperbolicCassiniSoldnerProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let (easting, northing) = self.0.rad_to_projected(longitude, latitude);
+        let x = northing - self.0.false_n;
+        (
+            easting,
+            self.0.false_n + x - x.powi(3) / (6.0 * self.0.rho(latitude) * self.0.nu(latitude)),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        // the cubic term is evaluated at the footpoint latitude, which converges quickly
+        let mut x = northing - self.0.false_n;
+        let mut lat_1 = self.0.meridian_arc.footpoint_latitude(self.0.M_orig + x);
+        for _ in 0..Self::MAX_ITERATIONS {
+            x = northing - self.0.false_n
+                + x.powi(3) / (6.0 * self.0.rho(lat_1) * self.0.nu(lat_1));
+            lat_1 = self.0.meridian_arc.footpoint_latitude(self.0.M_orig + x);
+        }
+        self.0.footpoint_to_rad(easting, lat_1)
+    }
+}
+
+impl PseudoSerialize for HyperbolicCassiniSoldnerProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            "HyperbolicCassiniSoldnerProjection({})",
+            self.0.to_constructed()
+        )
+    }
+}
+
+impl DbContstruct for HyperbolicCassiniSoldnerProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = HyperbolicCassiniSoldnerParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for HyperbolicCassiniSoldnerProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = HyperbolicCassiniSoldnerParams::new(
+            getter(8802)?,
+            getter(8801)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    CassiniSoldnerProjection::from_database_params(params, &ell).to_constructed()
+}
+
+pub fn direct_projection_hyperbolic(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    HyperbolicCassiniSoldnerProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::cassini_soldner::*;
+    use crate::ellipsoid::Ellipsoid;
+    use crate::traits::*;
+
+    #[test]
+    fn cassini_soldner_consistency() {
+        // Clarke 1858, in links
+        let ell = Ellipsoid::from_a_b(31706587.88, 20855233.0 * 31706587.88 / 20926348.0);
+        let params = CassiniSoldnerParams::new(
+            -(61.0 + 20.0 / 60.0f64).to_radians(),
+            (10.0 + 26.0 / 60.0 + 30.0 / 3600.0f64).to_radians(),
+            430000.0,
+            325000.0,
+        );
+
+        let projection = CassiniSoldnerProjection::new(&ell, &params);
+        eprintln!("{projection:#?}");
+
+        let easting_goal = 66644.94;
+        let northing_goal = 82536.22;
+        let (easting, northing) = projection.deg_to_projected(-62.0, 10.0);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon, lat) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: -62 - {lon}");
+        eprintln!("lat: 10 - {lat}");
+
+        assert!((lon - -62.0).abs() < 1e-7);
+        assert!((lat - 10.0).abs() < 1e-7);
+    }
+
+    #[test]
+    fn hyperbolic_cassini_soldner_consistency() {
+        // Clarke 1880 (international foot), in links
+        let ell = Ellipsoid::from_a_f_inv(20926202.0 / 0.66, 293.465);
+        let params = HyperbolicCassiniSoldnerParams::new(
+            179.2f64.to_radians(),
+            -16.15f64.to_radians(),
+            1251331.8,
+            1662888.5,
+        );
+
+        let projection = HyperbolicCassiniSoldnerProjection::new(&ell, &params);
+        eprintln!("{projection:#?}");
+
+        let cassini = CassiniSoldnerProjection::new(
+            &ell,
+            &CassiniSoldnerParams::new(params.lon_orig(), params.lat_orig(), 1251331.8, 1662888.5),
+        );
+
+        for (lon, lat) in [
+            (178.9, -16.6),
+            (179.2, -16.15),
+            (179.99, -16.84),
+            (-179.95, -16.2),
+        ] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (easting_c, northing_c) = cassini.deg_to_projected(lon, lat);
+            // the hyperbolic variant only shortens the northing by X³ / 6ρν
+            let x = northing_c - 1662888.5;
+            let rho = ell.rho(lat.to_radians());
+            let nu = ell.ny(lat.to_radians());
+            eprintln!("{lon}, {lat}: {easting}, {northing} - {easting_c}, {northing_c}");
+            assert_eq!(easting, easting_c);
+            assert!((northing - (northing_c - x.powi(3) / (6.0 * rho * nu))).abs() < 1e-6);
+
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon_r}, {lat_r}");
+            assert!(((lon - lon_r + 180.0).rem_euclid(360.0) - 180.0).abs() < 1e-8);
+            assert!((lat - lat_r).abs() < 1e-8);
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColombiaUrbanParams {
+    /// latitude of natural origin
+    lat_orig: f64,
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+    /// projection plane origin height
+    height_orig: f64,
+}
+
+impl_param_eq_hash!(ColombiaUrbanParams {
+    lat_orig,
+    lon_orig,
+    false_e,
+    false_n,
+    height_orig
+});
+
+impl ColombiaUrbanParams {
+    pub const fn new(
+        lat_orig: f64,
+        lon_orig: f64,
+        false_e: f64,
+        false_n: f64,
+        height_orig: f64,
+    ) -> Self {
+        Self {
+            lat_orig,
+            lon_orig,
+            false_e,
+            false_n,
+            height_orig,
+        }
+    }
+
+    /// Get latitude of natural origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+
+    /// Get projection plane origin height, metres above the ellipsoid.
+    pub fn height_orig(&self) -> f64 {
+        self.height_orig
+    }
+}
+
+/// Colombia Urban coordinate operation (EPSG:1052).
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct ColombiaUrbanProjection {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_e_squared: f64,
+    pub lat_orig: f64,
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+
+    pub A: f64,
+    pub B: f64,
+    pub D: f64,
+}
+
+impl_param_eq_hash!(ColombiaUrbanProjection {
+    ellipsoid_a,
+    ellipsoid_e_squared,
+    lat_orig,
+    lon_orig,
+    false_e,
+    false_n,
+    A,
+    B,
+    D,
+});
+
+impl ColombiaUrbanProjection {
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &ColombiaUrbanParams) -> Self {
+        let nu_O = ell.ny(params.lat_orig());
+        let rho_O = ell.rho(params.lat_orig());
+        Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_e_squared: ell.e_squared(),
+            lat_orig: params.lat_orig(),
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+
+            A: 1.0 + params.height_orig() / nu_O,
+            B: params.lat_orig().tan() / (2.0 * rho_O * nu_O),
+            D: rho_O + params.height_orig(),
+        }
+    }
+
+    fn ny(&self, lat: f64) -> f64 {
+        self.ellipsoid_a / (1.0 - self.ellipsoid_e_squared * lat.sin().powi(2)).sqrt()
+    }
+}
+
+impl Projection for ColombiaUrbanProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let nu_cos_lat = self.ny(latitude) * latitude.cos();
+        let d_lon = longitude - self.lon_orig;
+        (
+            self.false_e + self.A * nu_cos_lat * d_lon,
+            self.false_n
+                + self.D * (latitude - self.lat_orig + self.B * (d_lon * nu_cos_lat).powi(2)),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let latitude = self.lat_orig + (northing - self.false_n) / self.D
+            - self.B * ((easting - self.false_e) / self.A).powi(2);
+        (
+            self.lon_orig
+                + (easting - self.false_e) / (self.A * self.ny(latitude) * latitude.cos()),
+            latitude,
+        )
+    }
+}
+
+impl PseudoSerialize for ColombiaUrbanProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"ColombiaUrbanProjection{{
+    ellipsoid_a: {}f64,
+    ellipsoid_e_squared: {}f64,
+    lat_orig: {}f64,
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    A: {}f64,
+    B: {}f64,
+    D: {}f64,
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_e_squared,
+            self.lat_orig,
+            self.lon_orig,
+            self.false_e,
+            self.false_n,
+            self.A,
+            self.B,
+            self.D,
+        )
+    }
+}
+
+impl DbContstruct for ColombiaUrbanProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = ColombiaUrbanParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 1039 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for ColombiaUrbanProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = ColombiaUrbanParams::new(
+            getter(8801)?,
+            getter(8802)?,
+            getter(8806)?,
+            getter(8807)?,
+            getter(1039)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    ColombiaUrbanProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::colombia_urban::*;
+    use crate::ellipsoid::Ellipsoid;
+    use crate::traits::*;
+
+    #[test]
+    fn colombia_urban_consistency() {
+        // MAGNA-SIRGAS / Bogota urban grid
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257222101);
+        let params = ColombiaUrbanParams::new(
+            (4.0 + 40.0 / 60.0 + 49.75 / 3600.0f64).to_radians(),
+            (-(74.0 + 8.0 / 60.0 + 47.73 / 3600.0f64)).to_radians(),
+            92334.879,
+            109320.965,
+            2550.0,
+        );
+
+        let projection = ColombiaUrbanProjection::new(&ell, &params);
+        let easting_goal = 80859.033;
+        let northing_goal = 122543.174;
+        let (easting, northing) = projection.deg_to_projected(-74.25, 4.8);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon, lat) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: -74.25 - {lon}");
+        eprintln!("lat: 4.8 - {lat}");
+
+        assert!((lon + 74.25).abs() < 1e-7);
+        assert!((lat - 4.8).abs() < 1e-7);
+    }
+}
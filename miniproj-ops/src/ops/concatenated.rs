@@ -0,0 +1,74 @@
+//This file is licensed under EUPL v1.2
+
+use std::marker::PhantomData;
+
+use crate::CoordOperation;
+
+/// Concatenated coordinate operation, applying `first` and then `second`.
+///
+/// `M` is the type of the intermediate coordinates, which `first` converts to and `second` converts
+/// from.
+pub struct ConcatenatedCoordOp<A, B, M> {
+    pub first: A,
+    pub second: B,
+    intermediate: PhantomData<fn(M) -> M>,
+}
+
+impl<A, B, M> ConcatenatedCoordOp<A, B, M> {
+    pub const fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            intermediate: PhantomData,
+        }
+    }
+}
+
+impl<A: Clone, B: Clone, M> Clone for ConcatenatedCoordOp<A, B, M> {
+    fn clone(&self) -> Self {
+        Self::new(self.first.clone(), self.second.clone())
+    }
+}
+
+impl<A: std::fmt::Debug, B: std::fmt::Debug, M> std::fmt::Debug for ConcatenatedCoordOp<A, B, M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConcatenatedCoordOp")
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish()
+    }
+}
+
+impl<F, M, T, A, B> CoordOperation<F, T> for ConcatenatedCoordOp<A, B, M>
+where
+    A: CoordOperation<F, M>,
+    B: CoordOperation<M, T>,
+{
+    fn apply(&self, from: F) -> T {
+        self.second.apply(self.first.apply(from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::concatenated::*;
+    use crate::coordinate::Geographic3DCoordinate;
+    use crate::longitude_rotation::*;
+    use crate::traits::*;
+
+    #[test]
+    fn concatenated() {
+        let first = LongitudeRotationTransformation::new(&LongitudeRotationParams::new(0.1));
+        let second = LongitudeRotationTransformation::new(&LongitudeRotationParams::new(0.2));
+        let concatenated = ConcatenatedCoordOp::new(first, Box::new(second));
+        let from = Geographic3DCoordinate::from_deg(10.0, 50.0, 5.0);
+        let to: Geographic3DCoordinate = concatenated.apply(from);
+        assert!((to.lon - (from.lon + 0.3)).abs() < 1e-15);
+        assert_eq!((to.lat, to.height), (from.lat, from.height));
+
+        let (first, second) = (first.reversed(), second.reversed());
+        let back: Geographic3DCoordinate = ConcatenatedCoordOp::new(&second, &first).apply(to);
+        assert!((back.lon - from.lon).abs() < 1e-15);
+    }
+}
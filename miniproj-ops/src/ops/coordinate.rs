@@ -0,0 +1,128 @@
+//This file is licensed under EUPL v1.2
+
+/// Position in a geographic 3D coordinate reference system.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geographic3DCoordinate {
+    /// longitude, radians
+    pub lon: f64,
+    /// latitude, radians
+    pub lat: f64,
+    /// ellipsoidal height, metres
+    pub height: f64,
+}
+
+impl Geographic3DCoordinate {
+    /// Construct a coordinate from longitude & latitude in radians and ellipsoidal height, metres.
+    pub const fn new(lon: f64, lat: f64, height: f64) -> Self {
+        Self { lon, lat, height }
+    }
+
+    /// Construct a coordinate from longitude & latitude in degrees and ellipsoidal height, metres.
+    pub fn from_deg(lon: f64, lat: f64, height: f64) -> Self {
+        Self::new(lon.to_radians(), lat.to_radians(), height)
+    }
+
+    /// Get longitude & latitude in degrees and ellipsoidal height in metres.
+    pub fn to_deg(&self) -> (f64, f64, f64) {
+        (self.lon.to_degrees(), self.lat.to_degrees(), self.height)
+    }
+}
+
+/// Position in a geographic 2D coordinate reference system.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geographic2DCoordinate {
+    /// longitude, radians
+    pub lon: f64,
+    /// latitude, radians
+    pub lat: f64,
+}
+
+impl Geographic2DCoordinate {
+    /// Construct a coordinate from longitude & latitude in radians.
+    pub const fn new(lon: f64, lat: f64) -> Self {
+        Self { lon, lat }
+    }
+
+    /// Construct a coordinate from longitude & latitude in degrees.
+    pub fn from_deg(lon: f64, lat: f64) -> Self {
+        Self::new(lon.to_radians(), lat.to_radians())
+    }
+
+    /// Get longitude & latitude in degrees.
+    pub fn to_deg(&self) -> (f64, f64) {
+        (self.lon.to_degrees(), self.lat.to_degrees())
+    }
+}
+
+/// Position in a geographic 2D coordinate reference system, with the vertical coordinate in a
+/// vertical coordinate reference system of the user's choice, like a gravity-related height.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geographic2DCoordinateUserVertical {
+    /// longitude, radians
+    pub lon: f64,
+    /// latitude, radians
+    pub lat: f64,
+    /// height or depth in the vertical CRS, metres
+    pub vertical: f64,
+}
+
+impl Geographic2DCoordinateUserVertical {
+    /// Construct a coordinate from longitude & latitude in radians and the vertical coordinate,
+    /// metres.
+    pub const fn new(lon: f64, lat: f64, vertical: f64) -> Self {
+        Self { lon, lat, vertical }
+    }
+
+    /// Construct a coordinate from longitude & latitude in degrees and the vertical coordinate,
+    /// metres.
+    pub fn from_deg(lon: f64, lat: f64, vertical: f64) -> Self {
+        Self::new(lon.to_radians(), lat.to_radians(), vertical)
+    }
+
+    /// Get longitude & latitude in degrees and the vertical coordinate in metres.
+    pub fn to_deg(&self) -> (f64, f64, f64) {
+        (self.lon.to_degrees(), self.lat.to_degrees(), self.vertical)
+    }
+}
+
+/// Position in a projected coordinate reference system, with the vertical coordinate in a
+/// vertical coordinate reference system of the user's choice.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectedCoordinateUserVertical {
+    /// easting, usually metres
+    pub x: f64,
+    /// northing, usually metres
+    pub y: f64,
+    /// height or depth in the vertical CRS, metres
+    pub vertical: f64,
+}
+
+impl ProjectedCoordinateUserVertical {
+    /// Construct a coordinate from projected coordinates and the vertical coordinate, metres.
+    pub const fn new(x: f64, y: f64, vertical: f64) -> Self {
+        Self { x, y, vertical }
+    }
+}
+
+/// Position in a geocentric coordinate reference system.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeocentricCoordinate {
+    /// metres, towards the intersection of equator and prime meridian
+    pub x: f64,
+    /// metres, towards the intersection of equator and 90°E
+    pub y: f64,
+    /// metres, towards the north pole
+    pub z: f64,
+}
+
+impl GeocentricCoordinate {
+    /// Construct a coordinate from cartesian coordinates in metres.
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+}
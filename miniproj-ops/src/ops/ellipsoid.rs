@@ -16,6 +16,15 @@ pub struct Ellipsoid {
     /// eccentricity squared
     pub e_squared: f64,
 }
+
+impl_param_eq_hash!(Ellipsoid {
+    a,
+    b,
+    f,
+    e,
+    e_squared
+});
+
 impl Ellipsoid {
     /// Construct an ellipsoid from major and minor half axis.
     #[must_use]
@@ -93,12 +102,7 @@ impl Ellipsoid {
 
     /// Calculate radius of authalic sphere (sphere with the same surface area as the ellipsoid).
     pub fn rad_auth(&self) -> f64 {
-        self.a
-            * ((1.0
-                - ((1.0 - self.e_squared()) / (2.0 * self.e()))
-                    * f64::ln((1.0 - self.e()) / (1.0 + self.e())))
-                * 0.5)
-                .sqrt()
+        self.a * (authalic_q(self.e(), self.e_squared(), 1.0) * 0.5).sqrt()
     }
 
     /// Calculate radius of conformal sphere.
@@ -141,6 +145,183 @@ impl Ellipsoid {
     }
 }
 
+/// Calculate `q` of the authalic latitude `β = asin(q / q_P)` for the sine of a latitude.
+/// This reduces to `2 sin(φ)` on a sphere.
+pub(crate) fn authalic_q(e: f64, e_squared: f64, sin_lat: f64) -> f64 {
+    if e == 0.0 {
+        return 2.0 * sin_lat;
+    }
+    (1.0 - e_squared)
+        * ((sin_lat / (1.0 - e_squared * sin_lat.powi(2)))
+            - ((0.5 / e) * f64::ln((1.0 - e * sin_lat) / (1.0 + e * sin_lat))))
+}
+
+/// Calculate the latitude for an authalic latitude, both in radians.
+pub(crate) fn authalic_to_lat(e_squared: f64, beta: f64) -> f64 {
+    beta + (e_squared / 3.0 + 31.0 * e_squared.powi(2) / 180.0 + 517.0 * e_squared.powi(3) / 5040.0)
+        * (2.0 * beta).sin()
+        + (23.0 * e_squared.powi(2) / 360.0 + 251.0 * e_squared.powi(3) / 3780.0)
+            * (4.0 * beta).sin()
+        + 761.0 * e_squared.powi(3) / 45360.0 * (6.0 * beta).sin()
+}
+
+/// Series for the distance along the meridian from the equator to a latitude and its inverse, the
+/// footpoint latitude, as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2
+/// for the Equidistant Cylindrical projection. They are accurate to the millimetre for all
+/// latitudes.
+#[derive(Copy, Clone, Debug)]
+pub struct MeridianArc {
+    /// factor of the latitude, in the units of the semi-major axis
+    pub lat_factor: f64,
+    /// factors of `sin(2φ)`, `sin(4φ)`, … `sin(14φ)`, in the units of the semi-major axis
+    pub sin_lat_factors: [f64; 7],
+    /// factors of `sin(2μ)`, `sin(4μ)`, … `sin(14μ)` of the rectifying latitude `μ`
+    pub sin_mu_factors: [f64; 7],
+}
+
+impl MeridianArc {
+    pub fn new(ell: &Ellipsoid) -> Self {
+        let a = ell.a();
+        let e2 = ell.e_squared();
+        let e4 = e2.powi(2);
+        let e6 = e2.powi(3);
+        let e8 = e2.powi(4);
+        let e10 = e2.powi(5);
+        let e12 = e2.powi(6);
+        let e14 = e2.powi(7);
+        let n = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+        Self {
+            lat_factor: a
+                * (1.0
+                    - e2 / 4.0
+                    - 3.0 * e4 / 64.0
+                    - 5.0 * e6 / 256.0
+                    - 175.0 * e8 / 16384.0
+                    - 441.0 * e10 / 65536.0
+                    - 4851.0 * e12 / 1048576.0
+                    - 14157.0 * e14 / 4194304.0),
+            sin_lat_factors: [
+                a * (-3.0 * e2 / 8.0
+                    - 3.0 * e4 / 32.0
+                    - 45.0 * e6 / 1024.0
+                    - 105.0 * e8 / 4096.0
+                    - 2205.0 * e10 / 131072.0
+                    - 6237.0 * e12 / 524288.0
+                    - 297297.0 * e14 / 33554432.0),
+                a * (15.0 * e4 / 256.0
+                    + 45.0 * e6 / 1024.0
+                    + 525.0 * e8 / 16384.0
+                    + 1575.0 * e10 / 65536.0
+                    + 155925.0 * e12 / 8388608.0
+                    + 495495.0 * e14 / 33554432.0),
+                a * (-35.0 * e6 / 3072.0
+                    - 175.0 * e8 / 12288.0
+                    - 3675.0 * e10 / 262144.0
+                    - 13475.0 * e12 / 1048576.0
+                    - 385385.0 * e14 / 33554432.0),
+                a * (315.0 * e8 / 131072.0
+                    + 2205.0 * e10 / 524288.0
+                    + 43659.0 * e12 / 8388608.0
+                    + 189189.0 * e14 / 33554432.0),
+                a * (-693.0 * e10 / 1310720.0
+                    - 6237.0 * e12 / 5242880.0
+                    - 297297.0 * e14 / 167772160.0),
+                a * (1001.0 * e12 / 8388608.0 + 11011.0 * e14 / 33554432.0),
+                a * (-6435.0 * e14 / 234881024.0),
+            ],
+            sin_mu_factors: [
+                3.0 * n / 2.0 - 27.0 * n.powi(3) / 32.0 + 269.0 * n.powi(5) / 512.0
+                    - 6607.0 * n.powi(7) / 24576.0,
+                21.0 * n.powi(2) / 16.0 - 55.0 * n.powi(4) / 32.0 + 6759.0 * n.powi(6) / 4096.0,
+                151.0 * n.powi(3) / 96.0 - 417.0 * n.powi(5) / 128.0
+                    + 87963.0 * n.powi(7) / 20480.0,
+                1097.0 * n.powi(4) / 512.0 - 15543.0 * n.powi(6) / 2560.0,
+                8011.0 * n.powi(5) / 2560.0 - 69119.0 * n.powi(7) / 6144.0,
+                293393.0 * n.powi(6) / 61440.0,
+                6845701.0 * n.powi(7) / 1720320.0,
+            ],
+        }
+    }
+
+    /// Distance along the meridian from the equator to `latitude`.
+    pub(crate) fn distance(&self, latitude: f64) -> f64 {
+        self.lat_factor * latitude
+            + self
+                .sin_lat_factors
+                .iter()
+                .zip(1..)
+                .map(|(f, k)| f * (2.0 * k as f64 * latitude).sin())
+                .sum::<f64>()
+    }
+
+    /// Derivative of the distance along the meridian by latitude, the radius of curvature in the
+    /// meridian.
+    pub(crate) fn distance_derivative(&self, latitude: f64) -> f64 {
+        self.lat_factor
+            + self
+                .sin_lat_factors
+                .iter()
+                .zip(1..)
+                .map(|(f, k)| 2.0 * k as f64 * f * (2.0 * k as f64 * latitude).cos())
+                .sum::<f64>()
+    }
+
+    /// Latitude of the point on a meridian at the distance `m` from the equator.
+    pub(crate) fn footpoint_latitude(&self, m: f64) -> f64 {
+        let mu = m / self.lat_factor;
+        mu + self
+            .sin_mu_factors
+            .iter()
+            .zip(1..)
+            .map(|(f, k)| f * (2.0 * k as f64 * mu).sin())
+            .sum::<f64>()
+    }
+}
+
+impl PartialEq for MeridianArc {
+    fn eq(&self, other: &Self) -> bool {
+        self.lat_factor.to_bits() == other.lat_factor.to_bits()
+            && self
+                .sin_lat_factors
+                .iter()
+                .chain(&self.sin_mu_factors)
+                .zip(other.sin_lat_factors.iter().chain(&other.sin_mu_factors))
+                .all(|(a, b)| a.to_bits() == b.to_bits())
+    }
+}
+
+impl Eq for MeridianArc {}
+
+impl std::hash::Hash for MeridianArc {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.lat_factor.to_bits().hash(state);
+        for f in self.sin_lat_factors.iter().chain(&self.sin_mu_factors) {
+            f.to_bits().hash(state);
+        }
+    }
+}
+
+impl PseudoSerialize for MeridianArc {
+    fn to_constructed(&self) -> String {
+        let factors = |fs: &[f64; 7]| {
+            fs.iter()
+                .map(|f| format!("{f}f64"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        format!(
+            r"MeridianArc{{
+    lat_factor: {}f64,
+    sin_lat_factors: [{}],
+    sin_mu_factors: [{}],
+}}",
+            self.lat_factor,
+            factors(&self.sin_lat_factors),
+            factors(&self.sin_mu_factors),
+        )
+    }
+}
+
 impl PseudoSerialize for Ellipsoid {
     fn to_constructed(&self) -> String {
         format! {
@@ -162,7 +343,7 @@ r"Ellipsoid{{
 
 #[cfg(test)]
 mod tests {
-    use super::Ellipsoid;
+    use super::{Ellipsoid, MeridianArc};
 
     #[test]
     fn geocentric_roundtrip() {
@@ -192,4 +373,21 @@ mod tests {
         assert!((expected_y - y).abs() < 0.01);
         assert!((expected_z - z).abs() < 0.01);
     }
+
+    #[test]
+    fn meridian_arc_roundtrip() {
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let arc = MeridianArc::new(&ell);
+
+        // a quarter meridian of WGS 84
+        let quarter = arc.distance(90f64.to_radians());
+        eprintln!("quarter meridian: 10001965.729 - {quarter}");
+        assert!((quarter - 10001965.729).abs() < 0.001);
+
+        for lat in [-89.0, -45.0, 0.0, 12.5, 55.0, 89.9] {
+            let lat_r = arc.footpoint_latitude(arc.distance(f64::to_radians(lat)));
+            eprintln!("{lat}: {}", lat_r.to_degrees());
+            assert!((lat - lat_r.to_degrees()).abs() < 1e-9);
+        }
+    }
 }
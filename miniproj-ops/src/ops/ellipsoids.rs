@@ -0,0 +1,61 @@
+//This file is licensed under EUPL v1.2
+
+//! Commonly used ellipsoids as constants, for constructing custom projections without a registry lookup.
+//!
+//! The values are identical to the ones generated from the EPSG registry for the respective codes.
+
+use crate::ellipsoid::Ellipsoid;
+
+/// WGS 84 (EPSG:7030)
+pub const WGS84: Ellipsoid = Ellipsoid {
+    a: 6378137.0,
+    b: 6356752.314245179,
+    f: 0.0033528106647474805,
+    e: 0.08181919084262149,
+    e_squared: 0.0066943799901413165,
+};
+
+/// GRS 1980 (EPSG:7019)
+pub const GRS80: Ellipsoid = Ellipsoid {
+    a: 6378137.0,
+    b: 6356752.314140356,
+    f: 0.003352810681182319,
+    e: 0.08181919104281579,
+    e_squared: 0.006694380022900787,
+};
+
+/// Bessel 1841 (EPSG:7004)
+pub const BESSEL_1841: Ellipsoid = Ellipsoid {
+    a: 6377397.155,
+    b: 6356078.962818189,
+    f: 0.003342773182174806,
+    e: 0.08169683122252751,
+    e_squared: 0.006674372231802145,
+};
+
+/// Clarke 1866 (EPSG:7008)
+pub const CLARKE_1866: Ellipsoid = Ellipsoid {
+    a: 6378206.4,
+    b: 6356583.8,
+    f: 0.0033900753039287908,
+    e: 0.08227185422300431,
+    e_squared: 0.006768657997291274,
+};
+
+/// International 1924 (EPSG:7022)
+pub const INTERNATIONAL_1924: Ellipsoid = Ellipsoid {
+    a: 6378388.0,
+    b: 6356911.9461279465,
+    f: 0.003367003367003367,
+    e: 0.08199188997902977,
+    e_squared: 0.006722670022333322,
+};
+
+/// GRS 1980 Authalic Sphere (EPSG:7048)
+pub const SPHERE: Ellipsoid = Ellipsoid {
+    a: 6371007.0,
+    b: 6371007.0,
+    f: 0.0,
+    e: 0.0,
+    e_squared: 0.0,
+};
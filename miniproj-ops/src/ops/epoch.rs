@@ -0,0 +1,104 @@
+//This file is licensed under EUPL v1.2
+
+/// Coordinate epoch as a decimal year, as used by time-dependent reference frames and
+/// transformations, e.g. 2010.0 for the ITRF2008 reference epoch.
+///
+/// Conversion to and from calendar dates counts days from the start of the year, so
+/// 2024-01-01 is 2024.0 and 2024-07-02 is 2024.5 in that leap year.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Epoch(f64);
+
+impl Epoch {
+    /// Length of a year in seconds, as used for the time units of the EPSG dataset.
+    pub const SECONDS_PER_YEAR: f64 = 31556925.445;
+
+    /// Construct an epoch from a decimal year. Returns `None` if the value is not finite.
+    pub fn from_decimal_year(year: f64) -> Option<Self> {
+        year.is_finite().then_some(Self(year))
+    }
+
+    /// Construct an epoch from a calendar date at midnight.
+    /// Returns `None` if the month or day are out of range.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day < 1 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+        let day_of_year = (1..month)
+            .map(|m| Self::days_in_month(year, m))
+            .sum::<u32>()
+            + day
+            - 1;
+        Some(Self(
+            year as f64 + day_of_year as f64 / Self::days_in_year(year) as f64,
+        ))
+    }
+
+    /// Get the epoch as a decimal year.
+    pub fn decimal_year(&self) -> f64 {
+        self.0
+    }
+
+    /// Get the calendar date `(year, month, day)` the epoch falls on.
+    pub fn to_ymd(&self) -> (i32, u32, u32) {
+        let year = self.0.floor() as i32;
+        let mut day_of_year =
+            ((self.0 - year as f64) * Self::days_in_year(year) as f64 + 1e-9).floor() as u32;
+        let mut month = 1;
+        while month < 12 && day_of_year >= Self::days_in_month(year, month) {
+            day_of_year -= Self::days_in_month(year, month);
+            month += 1;
+        }
+        (year, month, day_of_year + 1)
+    }
+
+    /// Get the time span from `other` to `self` in years.
+    pub fn years_since(&self, other: Epoch) -> f64 {
+        self.0 - other.0
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    fn days_in_year(year: i32) -> u32 {
+        if Self::is_leap_year(year) {
+            366
+        } else {
+            365
+        }
+    }
+
+    fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+impl From<Epoch> for f64 {
+    fn from(epoch: Epoch) -> Self {
+        epoch.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::epoch::*;
+
+    #[test]
+    fn epoch_dates() {
+        assert_eq!(Epoch::from_ymd(2024, 1, 1).unwrap().decimal_year(), 2024.0);
+        assert_eq!(Epoch::from_ymd(2024, 7, 2).unwrap().decimal_year(), 2024.5);
+        assert!(Epoch::from_ymd(2023, 2, 29).is_none());
+        assert!(Epoch::from_ymd(2023, 13, 1).is_none());
+        assert!(Epoch::from_decimal_year(f64::NAN).is_none());
+        for (y, m, d) in [(2023, 12, 31), (2000, 2, 29), (1989, 3, 1), (2010, 1, 1)] {
+            let epoch = Epoch::from_ymd(y, m, d).unwrap();
+            eprintln!("{y}-{m}-{d}: {}", epoch.decimal_year());
+            assert_eq!(epoch.to_ymd(), (y, m, d));
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    ellipsoid::{authalic_q, authalic_to_lat, Ellipsoid},
+    traits::GetterContstruct,
+    DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EqualEarthParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(EqualEarthParams {
+    lon_orig,
+    false_e,
+    false_n
+});
+
+impl EqualEarthParams {
+    pub const fn new(lon_orig: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Equal Earth coordinate operation (EPSG:1078).
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct EqualEarthProjection {
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+    pub ellipsoid_e: f64,
+    pub ellipsoid_e_squared: f64,
+
+    pub q_P: f64,
+    pub R_q: f64,
+}
+
+impl_param_eq_hash!(EqualEarthProjection {
+    lon_orig,
+    false_e,
+    false_n,
+    ellipsoid_e,
+    ellipsoid_e_squared,
+    q_P,
+    R_q,
+});
+
+impl EqualEarthProjection {
+    const A_1: f64 = 1.340264;
+    const A_2: f64 = -0.081106;
+    const A_3: f64 = 0.000893;
+    const A_4: f64 = 0.003796;
+    const MAX_ITERATIONS: usize = 16;
+
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &EqualEarthParams) -> Self {
+        let q_P = authalic_q(ell.e(), ell.e_squared(), 1.0);
+        Self {
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+            ellipsoid_e: ell.e(),
+            ellipsoid_e_squared: ell.e_squared(),
+
+            q_P,
+            R_q: ell.a() * (q_P / 2.0).sqrt(),
+        }
+    }
+
+    /// The polynomial in θ for the northing, divided by θ.
+    fn northing_polynomial(theta: f64) -> f64 {
+        let theta_sq = theta.powi(2);
+        Self::A_1 + Self::A_2 * theta_sq + theta_sq.powi(3) * (Self::A_3 + Self::A_4 * theta_sq)
+    }
+
+    /// Derivative of the northing polynomial with respect to θ.
+    fn northing_derivative(theta: f64) -> f64 {
+        let theta_sq = theta.powi(2);
+        Self::A_1
+            + 3.0 * Self::A_2 * theta_sq
+            + theta_sq.powi(3) * (7.0 * Self::A_3 + 9.0 * Self::A_4 * theta_sq)
+    }
+}
+
+impl Projection for EqualEarthProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let q = authalic_q(self.ellipsoid_e, self.ellipsoid_e_squared, latitude.sin());
+        let beta = (q / self.q_P).clamp(-1.0, 1.0).asin();
+        let theta = (beta.sin() * 3.0f64.sqrt() / 2.0).asin();
+        (
+            self.false_e
+                + self.R_q * 2.0 * (longitude - self.lon_orig) * theta.cos()
+                    / (3.0f64.sqrt() * Self::northing_derivative(theta)),
+            self.false_n + self.R_q * theta * Self::northing_polynomial(theta),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let y = (northing - self.false_n) / self.R_q;
+        let mut theta = y;
+        for _ in 0..Self::MAX_ITERATIONS {
+            let delta =
+                (theta * Self::northing_polynomial(theta) - y) / Self::northing_derivative(theta);
+            theta -= delta;
+            if delta.abs() < 1e-14 {
+                break;
+            }
+        }
+        let beta = (2.0 * theta.sin() / 3.0f64.sqrt()).clamp(-1.0, 1.0).asin();
+        (
+            self.lon_orig
+                + 3.0f64.sqrt() * (easting - self.false_e) * Self::northing_derivative(theta)
+                    / (2.0 * self.R_q * theta.cos()),
+            authalic_to_lat(self.ellipsoid_e_squared, beta),
+        )
+    }
+}
+
+impl PseudoSerialize for EqualEarthProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"EqualEarthProjection{{
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    ellipsoid_e: {}f64,
+    ellipsoid_e_squared: {}f64,
+    q_P: {}f64,
+    R_q: {}f64,
+}}",
+            self.lon_orig,
+            self.false_e,
+            self.false_n,
+            self.ellipsoid_e,
+            self.ellipsoid_e_squared,
+            self.q_P,
+            self.R_q,
+        )
+    }
+}
+
+impl DbContstruct for EqualEarthProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = EqualEarthParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for EqualEarthProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = EqualEarthParams::new(getter(8802)?, getter(8806)?, getter(8807)?);
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    EqualEarthProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::equal_earth::*;
+    use crate::traits::*;
+
+    #[test]
+    fn equal_earth_consistency() {
+        // WGS 84 / Equal Earth Americas
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params = EqualEarthParams::new((-90.0f64).to_radians(), 0.0, 0.0);
+
+        let projection = EqualEarthProjection::new(&ell, &params);
+        let easting_goal = -2390749.042;
+        let northing_goal = 4242849.758;
+        let lon = -(117.0 + 11.0 / 60.0 + 48.349 / 3600.0);
+        let lat = 34.0 + 3.0 / 60.0 + 27.169 / 3600.0;
+        let (easting, northing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+
+        for (lon, lat) in [(90.0, 90.0), (-270.0, -89.0), (0.0, 0.0), (10.0, 54.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lat - lat_r).abs() < 1e-7);
+            if lat.abs() < 90.0 {
+                assert!((lon - lon_r).abs() < 1e-7);
+            }
+        }
+    }
+}
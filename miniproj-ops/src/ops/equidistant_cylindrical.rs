@@ -0,0 +1,357 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    ellipsoid::{Ellipsoid, MeridianArc},
+    traits::GetterContstruct,
+    DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EquidistantCylindricalParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// latitude of 1st standard parallel
+    lat_sp1: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(EquidistantCylindricalParams {
+    lon_orig,
+    lat_sp1,
+    false_e,
+    false_n
+});
+
+impl EquidistantCylindricalParams {
+    pub const fn new(lon_orig: f64, lat_sp1: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            lat_sp1,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of 1st standard parallel, radians.
+    pub fn lat_sp1(&self) -> f64 {
+        self.lat_sp1
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Equidistant Cylindrical coordinate operation (EPSG:1028).
+#[derive(Copy, Clone, Debug)]
+pub struct EquidistantCylindricalProjection {
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+    /// radius of the standard parallel, `ν1 cos φ1`
+    pub parallel_radius: f64,
+
+    pub meridian_arc: MeridianArc,
+}
+
+impl_param_eq_hash!(EquidistantCylindricalProjection {
+    lon_orig,
+    false_e,
+    false_n,
+    parallel_radius;
+    meridian_arc,
+});
+
+impl EquidistantCylindricalProjection {
+    pub fn new(ell: &Ellipsoid, params: &EquidistantCylindricalParams) -> Self {
+        let lat_sp1 = params.lat_sp1();
+        Self {
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+            parallel_radius: ell.a() * lat_sp1.cos()
+                / (1.0 - ell.e_squared() * lat_sp1.sin().powi(2)).sqrt(),
+
+            meridian_arc: MeridianArc::new(ell),
+        }
+    }
+}
+
+impl Projection for EquidistantCylindricalProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        (
+            self.false_e + self.parallel_radius * (longitude - self.lon_orig),
+            self.false_n + self.meridian_arc.distance(latitude),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        (
+            self.lon_orig + (easting - self.false_e) / self.parallel_radius,
+            self.meridian_arc
+                .footpoint_latitude(northing - self.false_n),
+        )
+    }
+
+    fn jacobian(&self, _longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        let dm_dlat = self.meridian_arc.distance_derivative(latitude);
+        [[self.parallel_radius, 0.0], [0.0, dm_dlat]]
+    }
+}
+
+impl PseudoSerialize for EquidistantCylindricalProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"EquidistantCylindricalProjection{{
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    parallel_radius: {}f64,
+    meridian_arc: {},
+}}",
+            self.lon_orig,
+            self.false_e,
+            self.false_n,
+            self.parallel_radius,
+            self.meridian_arc.to_constructed(),
+        )
+    }
+}
+
+impl DbContstruct for EquidistantCylindricalProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = EquidistantCylindricalParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8823 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for EquidistantCylindricalProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = EquidistantCylindricalParams::new(
+            getter(8802)?,
+            getter(8823)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+/// Equidistant Cylindrical (Spherical) coordinate operation (EPSG:1029).
+///
+/// On an ellipsoid the radius of the authalic sphere is used.
+#[derive(Copy, Clone, Debug)]
+pub struct EquidistantCylindricalSphericalProjection {
+    pub false_e: f64,
+    pub false_n: f64,
+    pub lon_orig: f64,
+    pub radius: f64,
+    pub cos_lat_sp1: f64,
+}
+
+impl_param_eq_hash!(EquidistantCylindricalSphericalProjection {
+    false_e,
+    false_n,
+    lon_orig,
+    radius,
+    cos_lat_sp1,
+});
+
+impl EquidistantCylindricalSphericalProjection {
+    pub fn new(ell: &Ellipsoid, params: &EquidistantCylindricalParams) -> Self {
+        Self {
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+            lon_orig: params.lon_orig(),
+            radius: ell.rad_auth(),
+            cos_lat_sp1: params.lat_sp1().cos(),
+        }
+    }
+}
+
+impl Projection for EquidistantCylindricalSphericalProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        (
+            self.false_e + self.radius * (longitude - self.lon_orig) * self.cos_lat_sp1,
+            self.false_n + self.radius * latitude,
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        (
+            self.lon_orig + (easting - self.false_e) / (self.radius * self.cos_lat_sp1),
+            (northing - self.false_n) / self.radius,
+        )
+    }
+
+    fn jacobian(&self, _longitude: f64, _latitude: f64) -> [[f64; 2]; 2] {
+        [[self.radius * self.cos_lat_sp1, 0.0], [0.0, self.radius]]
+    }
+}
+
+impl PseudoSerialize for EquidistantCylindricalSphericalProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"EquidistantCylindricalSphericalProjection{{
+    false_e: {}f64,
+    false_n: {}f64,
+    lon_orig: {}f64,
+    radius: {}f64,
+    cos_lat_sp1: {}f64,
+}}",
+            self.false_e, self.false_n, self.lon_orig, self.radius, self.cos_lat_sp1,
+        )
+    }
+}
+
+impl DbContstruct for EquidistantCylindricalSphericalProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = EquidistantCylindricalParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8823 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for EquidistantCylindricalSphericalProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = EquidistantCylindricalParams::new(
+            getter(8802)?,
+            getter(8823)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    EquidistantCylindricalProjection::from_database_params(params, &ell).to_constructed()
+}
+
+pub fn direct_projection_spherical(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    EquidistantCylindricalSphericalProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::equidistant_cylindrical::*;
+    use crate::traits::*;
+
+    #[test]
+    fn equidistant_cylindrical_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params = EquidistantCylindricalParams::new(0.0, 0.0, 0.0, 0.0);
+
+        let projection = EquidistantCylindricalProjection::new(&ell, &params);
+        let easting_goal = 1113194.91;
+        let northing_goal = 6097230.31;
+        let (easting, northing) = projection.deg_to_projected(10.0, 55.0);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        // a quarter meridian of WGS 84
+        let (_, northing) = projection.deg_to_projected(0.0, 90.0);
+        eprintln!("northing: 10001965.729 - {northing}");
+        assert!((northing - 10001965.729).abs() < 0.001);
+
+        for (lon, lat) in [(10.0, 55.0), (-170.0, -89.0), (0.0, 0.0), (120.0, 30.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+            assert_jacobian(&projection, lon, lat);
+        }
+    }
+    #[test]
+    fn equidistant_cylindrical_spherical_consistency() {
+        // World Equidistant Cylindrical (Sphere), EPSG:4088
+        let ell = Ellipsoid::from_a_b(6371007.0, 6371007.0);
+        let params = EquidistantCylindricalParams::new(0.0, 0.0, 0.0, 0.0);
+
+        let projection = EquidistantCylindricalSphericalProjection::new(&ell, &params);
+        // the antimeridian and the pole lie at R·π and R·π/2 from the origin
+        let (easting, northing) = projection.deg_to_projected(180.0, 90.0);
+        eprintln!("easting: 20015108.79 - {easting}");
+        eprintln!("northing: 10007554.39 - {northing}");
+        assert!((easting - 20015108.79).abs() < 0.01);
+        assert!((northing - 10007554.39).abs() < 0.01);
+
+        for (lon, lat) in [(-100.0, 24.0), (0.0, 0.0), (10.0, 54.0), (170.0, -80.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+            assert_jacobian(&projection, lon, lat);
+        }
+    }
+}
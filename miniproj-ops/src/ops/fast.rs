@@ -0,0 +1,33 @@
+//This file is licensed under EUPL v1.2
+
+/// Low-precision variant of a projection, trading accuracy for throughput.
+///
+/// `Fast<P>` implements `Projection` for projections that have a cheaper approximation available
+/// with at least twice the throughput, by truncating series expansions to a lower order. This is
+/// meant for screen-space visualisation, where sub-metre accuracy is irrelevant. The error bounds
+/// are documented on the respective `Projection` implementations.
+///
+/// Throughput measured with `cargo bench -p miniproj-ops --bench fast` on an x86_64 machine, in
+/// nanoseconds per point (full / fast):
+///
+/// | Projection          | Forward         | Inverse           |
+/// |---------------------|-----------------|-------------------|
+/// | Transverse Mercator | 263 / 92 (2.9×) | 503 / 118 (4.3×)  |
+///
+/// Only Transverse Mercator has a fast variant. The forward Lambert Conic Conformal and Oblique
+/// Stereographic projections are closed-form already, and fewer iterations of their inverses gave
+/// no more than 1.8× the throughput, so they do not meet the target.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fast<P>(pub P);
+
+impl<P> Fast<P> {
+    /// Wrap a projection to use its low-precision approximation.
+    pub const fn new(projection: P) -> Self {
+        Self(projection)
+    }
+
+    /// Get the wrapped full-precision projection.
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+}
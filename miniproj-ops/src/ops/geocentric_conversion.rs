@@ -0,0 +1,114 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::{GeocentricCoordinate, Geographic3DCoordinate},
+    ellipsoid::Ellipsoid,
+    traits::InvertibleCoordOperation,
+    CoordOperation, DbContstruct, PseudoSerialize,
+};
+
+/// Geographic/geocentric conversion (EPSG:9602).
+///
+/// Converts between geographic 3D coordinates and geocentric Cartesian (ECEF) coordinates on the
+/// ellipsoid of a datum. The conversion has no parameters of its own, the ellipsoid is taken from
+/// the CRSs it connects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GeocentricConversion {
+    pub ellipsoid: Ellipsoid,
+}
+
+impl GeocentricConversion {
+    pub fn new(ellipsoid: &Ellipsoid) -> Self {
+        Self {
+            ellipsoid: *ellipsoid,
+        }
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, GeocentricCoordinate> for GeocentricConversion {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> GeocentricCoordinate {
+        let (x, y, z) = self
+            .ellipsoid
+            .rad_to_geocentric(from.lon, from.lat, from.height);
+        GeocentricCoordinate::new(x, y, z)
+    }
+}
+
+impl CoordOperation<GeocentricCoordinate, Geographic3DCoordinate> for GeocentricConversion {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: GeocentricCoordinate) -> Geographic3DCoordinate {
+        let (lon, lat, height) = self.ellipsoid.geocentric_to_rad(from.x, from.y, from.z);
+        Geographic3DCoordinate::new(lon, lat, height)
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, GeocentricCoordinate>
+    for GeocentricConversion
+{
+    fn apply_inverse(&self, to: GeocentricCoordinate) -> Geographic3DCoordinate {
+        self.apply(to)
+    }
+}
+
+impl InvertibleCoordOperation<GeocentricCoordinate, Geographic3DCoordinate>
+    for GeocentricConversion
+{
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> GeocentricCoordinate {
+        self.apply(to)
+    }
+}
+
+impl PseudoSerialize for GeocentricConversion {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"GeocentricConversion{{
+    ellipsoid: {},
+}}",
+            self.ellipsoid.to_constructed(),
+        )
+    }
+}
+
+impl DbContstruct for GeocentricConversion {
+    fn from_database_params(_params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        Self::new(ellipsoid)
+    }
+}
+
+pub fn direct_conversion(params: &[(u32, f64)], ellipsoid: Ellipsoid) -> String {
+    GeocentricConversion::from_database_params(params, &ellipsoid).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::{GeocentricCoordinate, Geographic3DCoordinate};
+    use crate::ellipsoid::Ellipsoid;
+    use crate::geocentric_conversion::*;
+    use crate::traits::*;
+
+    #[test]
+    fn geocentric_conversion_consistency() {
+        // ETRS89 example of the guidance note
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.2572221);
+        let conversion = GeocentricConversion::new(&ell);
+        let from = Geographic3DCoordinate::from_deg(
+            2.0 + 7.0 / 60.0 + 46.38 / 3600.0,
+            53.0 + 48.0 / 60.0 + 33.82 / 3600.0,
+            73.0,
+        );
+        let to: GeocentricCoordinate = conversion.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.x - 3771793.968).abs() < 1e-3);
+        assert!((to.y - 140253.342).abs() < 1e-3);
+        assert!((to.z - 5124304.349).abs() < 1e-3);
+
+        let back: Geographic3DCoordinate = conversion.apply(to);
+        assert!((back.lon - from.lon).abs() < 1e-12);
+        assert!((back.lat - from.lat).abs() < 1e-12);
+        assert!((back.height - from.height).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,124 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::{Geographic2DCoordinate, Geographic3DCoordinate},
+    ellipsoid::Ellipsoid,
+    traits::InvertibleCoordOperation,
+    CoordOperation, DbContstruct, PseudoSerialize,
+};
+
+/// Geographic3D to 2D conversion (EPSG:9659).
+///
+/// Drops the ellipsoidal height of a geographic 3D coordinate, leaving longitude and latitude
+/// unchanged. In the reverse direction, the height is set to `height`, by default zero.
+///
+/// Between two `Geographic3DCoordinate`s, as used for the geographic 2D CRSs when routing between
+/// CRSs, the height is carried along unchanged.
+#[derive(Copy, Clone, Debug)]
+pub struct Geographic3DTo2DConversion {
+    /// ellipsoidal height assigned to geographic 2D coordinates, metres
+    pub height: f64,
+}
+
+impl_param_eq_hash!(Geographic3DTo2DConversion { height });
+
+impl Default for Geographic3DTo2DConversion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Geographic3DTo2DConversion {
+    pub const fn new() -> Self {
+        Self { height: 0.0 }
+    }
+
+    /// Conversion assigning the ellipsoidal height `height` in metres to geographic 2D coordinates.
+    pub const fn with_height(height: f64) -> Self {
+        Self { height }
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic2DCoordinate> for Geographic3DTo2DConversion {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic2DCoordinate {
+        Geographic2DCoordinate::new(from.lon, from.lat)
+    }
+}
+
+impl CoordOperation<Geographic2DCoordinate, Geographic3DCoordinate> for Geographic3DTo2DConversion {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic2DCoordinate) -> Geographic3DCoordinate {
+        Geographic3DCoordinate::new(from.lon, from.lat, self.height)
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate> for Geographic3DTo2DConversion {
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        from
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic2DCoordinate>
+    for Geographic3DTo2DConversion
+{
+    fn apply_inverse(&self, to: Geographic2DCoordinate) -> Geographic3DCoordinate {
+        self.apply(to)
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for Geographic3DTo2DConversion
+{
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        to
+    }
+}
+
+impl PseudoSerialize for Geographic3DTo2DConversion {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"Geographic3DTo2DConversion{{
+    height: {}f64,
+}}",
+            self.height,
+        )
+    }
+}
+
+impl DbContstruct for Geographic3DTo2DConversion {
+    fn from_database_params(_params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        Self::new()
+    }
+}
+
+pub fn direct_conversion(params: &[(u32, f64)], source: Ellipsoid, _target: Ellipsoid) -> String {
+    Geographic3DTo2DConversion::from_database_params(params, &source).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::{Geographic2DCoordinate, Geographic3DCoordinate};
+    use crate::geographic_3d_to_2d::*;
+    use crate::traits::*;
+
+    #[test]
+    fn geographic_3d_to_2d_consistency() {
+        let conversion = Geographic3DTo2DConversion::new();
+        let from = Geographic3DCoordinate::from_deg(2.12955, 53.80939444, 73.0);
+        let to: Geographic2DCoordinate = conversion.apply(from);
+        assert_eq!((to.lon, to.lat), (from.lon, from.lat));
+
+        let back: Geographic3DCoordinate = conversion.apply(to);
+        assert_eq!((back.lon, back.lat, back.height), (from.lon, from.lat, 0.0));
+        let back: Geographic3DCoordinate = Geographic3DTo2DConversion::with_height(73.0).apply(to);
+        assert_eq!(back, from);
+
+        let carried: Geographic3DCoordinate = conversion.apply(from);
+        assert_eq!(carried, from);
+    }
+}
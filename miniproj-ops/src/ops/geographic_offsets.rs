@@ -0,0 +1,348 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::Geographic3DCoordinate,
+    ellipsoid::Ellipsoid,
+    inverse::iterative_geographic_inverse,
+    traits::{GetterContstruct, InvertibleCoordOperation},
+    CoordOperation, DbContstruct, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geographic2DOffsetsParams {
+    /// latitude offset
+    lat_offset: f64,
+    /// longitude offset
+    lon_offset: f64,
+}
+
+impl_param_eq_hash!(Geographic2DOffsetsParams {
+    lat_offset,
+    lon_offset
+});
+
+impl Geographic2DOffsetsParams {
+    pub const fn new(lat_offset: f64, lon_offset: f64) -> Self {
+        Self {
+            lat_offset,
+            lon_offset,
+        }
+    }
+
+    /// Get latitude offset, radians.
+    pub fn lat_offset(&self) -> f64 {
+        self.lat_offset
+    }
+
+    /// Get longitude offset, radians.
+    pub fn lon_offset(&self) -> f64 {
+        self.lon_offset
+    }
+}
+
+/// Geographic2D offsets transformation (EPSG:9619).
+///
+/// Adds constant offsets to latitude and longitude, the height is passed through unchanged.
+#[derive(Copy, Clone, Debug)]
+pub struct Geographic2DOffsetsTransformation {
+    pub lat_offset: f64,
+    pub lon_offset: f64,
+}
+
+impl_param_eq_hash!(Geographic2DOffsetsTransformation {
+    lat_offset,
+    lon_offset
+});
+
+impl Geographic2DOffsetsTransformation {
+    pub fn new(params: &Geographic2DOffsetsParams) -> Self {
+        Self {
+            lat_offset: params.lat_offset(),
+            lon_offset: params.lon_offset(),
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which subtracts the offsets.
+    pub fn reversed(&self) -> Self {
+        Self {
+            lat_offset: -self.lat_offset,
+            lon_offset: -self.lon_offset,
+        }
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for Geographic2DOffsetsTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        Geographic3DCoordinate {
+            lon: from.lon + self.lon_offset,
+            lat: from.lat + self.lat_offset,
+            height: from.height,
+        }
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for Geographic2DOffsetsTransformation
+{
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        iterative_geographic_inverse(self, to)
+    }
+}
+
+impl PseudoSerialize for Geographic2DOffsetsTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"Geographic2DOffsetsTransformation{{
+    lat_offset: {}f64,
+    lon_offset: {}f64,
+}}",
+            self.lat_offset, self.lon_offset,
+        )
+    }
+}
+
+impl DbContstruct for Geographic2DOffsetsTransformation {
+    fn from_database_params(params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        let params = Geographic2DOffsetsParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8601 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8602 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(&params)
+    }
+}
+
+impl GetterContstruct for Geographic2DOffsetsTransformation {
+    fn with_db_getter<G>(mut getter: G, _ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = Geographic2DOffsetsParams::new(getter(8601)?, getter(8602)?);
+        Some(Self::new(&params))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geographic3DOffsetsParams {
+    /// latitude offset
+    lat_offset: f64,
+    /// longitude offset
+    lon_offset: f64,
+    /// vertical offset
+    vertical_offset: f64,
+}
+
+impl_param_eq_hash!(Geographic3DOffsetsParams {
+    lat_offset,
+    lon_offset,
+    vertical_offset
+});
+
+impl Geographic3DOffsetsParams {
+    pub const fn new(lat_offset: f64, lon_offset: f64, vertical_offset: f64) -> Self {
+        Self {
+            lat_offset,
+            lon_offset,
+            vertical_offset,
+        }
+    }
+
+    /// Get latitude offset, radians.
+    pub fn lat_offset(&self) -> f64 {
+        self.lat_offset
+    }
+
+    /// Get longitude offset, radians.
+    pub fn lon_offset(&self) -> f64 {
+        self.lon_offset
+    }
+
+    /// Get ellipsoidal height offset, metres.
+    pub fn vertical_offset(&self) -> f64 {
+        self.vertical_offset
+    }
+}
+
+/// Geographic3D offsets transformation (EPSG:9660).
+///
+/// Adds constant offsets to latitude, longitude and ellipsoidal height.
+#[derive(Copy, Clone, Debug)]
+pub struct Geographic3DOffsetsTransformation {
+    pub lat_offset: f64,
+    pub lon_offset: f64,
+    pub vertical_offset: f64,
+}
+
+impl_param_eq_hash!(Geographic3DOffsetsTransformation {
+    lat_offset,
+    lon_offset,
+    vertical_offset
+});
+
+impl Geographic3DOffsetsTransformation {
+    pub fn new(params: &Geographic3DOffsetsParams) -> Self {
+        Self {
+            lat_offset: params.lat_offset(),
+            lon_offset: params.lon_offset(),
+            vertical_offset: params.vertical_offset(),
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which subtracts the offsets.
+    pub fn reversed(&self) -> Self {
+        Self {
+            lat_offset: -self.lat_offset,
+            lon_offset: -self.lon_offset,
+            vertical_offset: -self.vertical_offset,
+        }
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for Geographic3DOffsetsTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        Geographic3DCoordinate {
+            lon: from.lon + self.lon_offset,
+            lat: from.lat + self.lat_offset,
+            height: from.height + self.vertical_offset,
+        }
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for Geographic3DOffsetsTransformation
+{
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        iterative_geographic_inverse(self, to)
+    }
+}
+
+impl PseudoSerialize for Geographic3DOffsetsTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"Geographic3DOffsetsTransformation{{
+    lat_offset: {}f64,
+    lon_offset: {}f64,
+    vertical_offset: {}f64,
+}}",
+            self.lat_offset, self.lon_offset, self.vertical_offset,
+        )
+    }
+}
+
+impl DbContstruct for Geographic3DOffsetsTransformation {
+    fn from_database_params(params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        let params = Geographic3DOffsetsParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8601 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8602 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8603 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(&params)
+    }
+}
+
+impl GetterContstruct for Geographic3DOffsetsTransformation {
+    fn with_db_getter<G>(mut getter: G, _ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = Geographic3DOffsetsParams::new(getter(8601)?, getter(8602)?, getter(8603)?);
+        Some(Self::new(&params))
+    }
+}
+
+pub fn direct_transformation_2d(
+    params: &[(u32, f64)],
+    source: Ellipsoid,
+    _target: Ellipsoid,
+) -> String {
+    Geographic2DOffsetsTransformation::from_database_params(params, &source).to_constructed()
+}
+
+pub fn direct_transformation_3d(
+    params: &[(u32, f64)],
+    source: Ellipsoid,
+    _target: Ellipsoid,
+) -> String {
+    Geographic3DOffsetsTransformation::from_database_params(params, &source).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::Geographic3DCoordinate;
+    use crate::geographic_offsets::*;
+    use crate::traits::*;
+
+    #[test]
+    fn geographic_2d_offsets_consistency() {
+        // EPSG example, Greek to GGRS87
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let params = Geographic2DOffsetsParams::new(-5.86 * arcsec, 0.28 * arcsec);
+        let transformation = Geographic2DOffsetsTransformation::new(&params);
+
+        let from = Geographic3DCoordinate::from_deg(
+            23.0 + 47.0 / 60.0 + 3.54 / 3600.0,
+            38.0 + 8.0 / 60.0 + 36.565 / 3600.0,
+            100.0,
+        );
+        let (lon, lat, height) = transformation.apply(from).to_deg();
+        let lon_goal = 23.0 + 47.0 / 60.0 + 3.82 / 3600.0;
+        let lat_goal = 38.0 + 8.0 / 60.0 + 30.705 / 3600.0;
+
+        eprintln!("lon: {lon_goal} - {lon}");
+        eprintln!("lat: {lat_goal} - {lat}");
+
+        assert!((lon - lon_goal).abs() < 1e-9);
+        assert!((lat - lat_goal).abs() < 1e-9);
+        assert_eq!(height, 100.0);
+
+        let back = transformation.reversed().apply(transformation.apply(from));
+        assert!((back.lon - from.lon).abs() < 1e-15);
+        assert!((back.lat - from.lat).abs() < 1e-15);
+    }
+
+    #[test]
+    fn geographic_3d_offsets_consistency() {
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let params = Geographic3DOffsetsParams::new(-5.86 * arcsec, 0.28 * arcsec, 2.55);
+        let transformation = Geographic3DOffsetsTransformation::new(&params);
+
+        let from = Geographic3DCoordinate::from_deg(23.0, 38.0, 100.0);
+        let (lon, lat, height) = transformation.apply(from).to_deg();
+
+        eprintln!("{lon}, {lat}, {height}");
+
+        assert!((lon - (23.0 + 0.28 / 3600.0)).abs() < 1e-9);
+        assert!((lat - (38.0 - 5.86 / 3600.0)).abs() < 1e-9);
+        assert!((height - 102.55).abs() < 1e-9);
+
+        let back = transformation.reversed().apply(transformation.apply(from));
+        assert!((back.lon - from.lon).abs() < 1e-15);
+        assert!((back.lat - from.lat).abs() < 1e-15);
+        assert!((back.height - from.height).abs() < 1e-12);
+    }
+}
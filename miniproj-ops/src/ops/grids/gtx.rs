@@ -0,0 +1,290 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::TAU;
+use std::io::{Error, ErrorKind, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::{
+    coordinate::{Geographic2DCoordinateUserVertical, Geographic3DCoordinate},
+    CoordOperation,
+};
+
+/// Length of the header of a GTX file, in bytes.
+const HEADER_LEN: usize = 40;
+
+/// Value marking grid nodes without data.
+const NODATA: f32 = -88.8888;
+
+fn invalid_data(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::InvalidData, message.into())
+}
+
+/// Geoid model grid, read from a file in the NOAA `.gtx` format.
+///
+/// The file starts with a big endian header giving the south-west corner and increments in
+/// degrees and the grid size, followed by the geoid heights in metres as big endian single
+/// precision values, row by row from south to north, each from west to east. Nodes without data
+/// hold -88.8888 and are read as NaN.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GtxGrid {
+    /// latitude of the southern boundary, radians
+    pub south: f64,
+    /// longitude of the western boundary, radians
+    pub west: f64,
+    pub lat_inc: f64,
+    pub lon_inc: f64,
+    pub rows: usize,
+    pub columns: usize,
+    /// Geoid heights at the grid nodes, metres
+    pub values: Vec<f64>,
+}
+
+impl GtxGrid {
+    /// Reads a GTX file from `path`.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Reads a GTX file from `reader` until the end.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses the contents of a GTX file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let header = bytes
+            .get(..HEADER_LEN)
+            .ok_or_else(|| invalid_data("GTX file too short"))?;
+        let double = |i: usize| f64::from_be_bytes(header[i * 8..i * 8 + 8].try_into().unwrap());
+        let int = |i: usize| i32::from_be_bytes(header[32 + i * 4..36 + i * 4].try_into().unwrap());
+        let (south, west, lat_inc, lon_inc) = (double(0), double(1), double(2), double(3));
+        let (Ok(rows), Ok(columns)) = (usize::try_from(int(0)), usize::try_from(int(1))) else {
+            return Err(invalid_data("Invalid GTX grid size"));
+        };
+        if rows < 2 || columns < 2 || !(lat_inc > 0.0 && lon_inc > 0.0) {
+            return Err(invalid_data("Invalid GTX grid size"));
+        }
+        let len = rows
+            .checked_mul(columns)
+            .and_then(|nodes| nodes.checked_mul(4))
+            .ok_or_else(|| invalid_data("Invalid GTX grid size"))?;
+        let values = bytes
+            .get(HEADER_LEN..HEADER_LEN + len)
+            .ok_or_else(|| invalid_data("Unexpected end of GTX file"))?
+            .chunks_exact(4)
+            .map(|v| match f32::from_be_bytes(v.try_into().unwrap()) {
+                v if (v - NODATA).abs() < 1e-4 => f64::NAN,
+                v => f64::from(v),
+            })
+            .collect();
+        Ok(Self {
+            south: south.to_radians(),
+            west: west.to_radians(),
+            lat_inc: lat_inc.to_radians(),
+            lon_inc: lon_inc.to_radians(),
+            rows,
+            columns,
+            values,
+        })
+    }
+
+    /// Get the geoid height at a position in radians by bilinear interpolation between the four
+    /// surrounding grid nodes. Longitudes may be given in either the [-π, π] or the [0, 2π] range.
+    /// Returns `None` outside of the grid or next to nodes without data.
+    pub fn value(&self, lon: f64, lat: f64) -> Option<f64> {
+        let x = (lon - self.west).rem_euclid(TAU) / self.lon_inc;
+        let y = (lat - self.south) / self.lat_inc;
+        // allow for rounding errors of positions on the boundary
+        const EPS: f64 = 1e-9;
+        let (max_x, max_y) = ((self.columns - 1) as f64, (self.rows - 1) as f64);
+        if !((-EPS..=max_x + EPS).contains(&x) && (-EPS..=max_y + EPS).contains(&y)) {
+            return None;
+        }
+        // the south-west node of the cell, which must not be on the northern or eastern boundary
+        let col = (x.floor().max(0.0) as usize).min(self.columns - 2);
+        let row = (y.floor().max(0.0) as usize).min(self.rows - 2);
+        let (fx, fy) = (x - col as f64, y - row as f64);
+        let i = row * self.columns + col;
+        let (sw, se) = (self.values[i], self.values[i + 1]);
+        let (nw, ne) = (
+            self.values[i + self.columns],
+            self.values[i + self.columns + 1],
+        );
+        let value = (1.0 - fy) * ((1.0 - fx) * sw + fx * se) + fy * ((1.0 - fx) * nw + fx * ne);
+        (!value.is_nan()).then_some(value)
+    }
+}
+
+/// Geographic3D to GravityRelatedHeight transformation using a geoid model grid, like EPSG:9665
+/// for `.gtx` files.
+///
+/// Subtracts the geoid height interpolated from a `GtxGrid` from the ellipsoidal height, which
+/// results in the gravity-related height of the vertical CRS at the unchanged horizontal position.
+/// The reverse direction adds the geoid height again. The grid has to be loaded at runtime.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GravityRelatedHeightTransformation {
+    pub grid: Arc<GtxGrid>,
+}
+
+impl GravityRelatedHeightTransformation {
+    pub fn new(grid: Arc<GtxGrid>) -> Self {
+        Self { grid }
+    }
+
+    /// Converts an ellipsoidal height to a gravity-related height, returning `None` if the
+    /// position is not covered by the grid.
+    pub fn try_to_gravity_related(
+        &self,
+        from: Geographic3DCoordinate,
+    ) -> Option<Geographic2DCoordinateUserVertical> {
+        let geoid_height = self.grid.value(from.lon, from.lat)?;
+        Some(Geographic2DCoordinateUserVertical {
+            lon: from.lon,
+            lat: from.lat,
+            vertical: from.height - geoid_height,
+        })
+    }
+
+    /// Converts a gravity-related height to an ellipsoidal height, returning `None` if the
+    /// position is not covered by the grid.
+    pub fn try_to_ellipsoidal(
+        &self,
+        from: Geographic2DCoordinateUserVertical,
+    ) -> Option<Geographic3DCoordinate> {
+        let geoid_height = self.grid.value(from.lon, from.lat)?;
+        Some(Geographic3DCoordinate {
+            lon: from.lon,
+            lat: from.lat,
+            height: from.vertical + geoid_height,
+        })
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic2DCoordinateUserVertical>
+    for GravityRelatedHeightTransformation
+{
+    /// longitude & latitude in radians, the height is NaN if the position is not covered by the
+    /// grid
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic2DCoordinateUserVertical {
+        self.try_to_gravity_related(from)
+            .unwrap_or(Geographic2DCoordinateUserVertical {
+                lon: from.lon,
+                lat: from.lat,
+                vertical: f64::NAN,
+            })
+    }
+}
+
+impl CoordOperation<Geographic2DCoordinateUserVertical, Geographic3DCoordinate>
+    for GravityRelatedHeightTransformation
+{
+    /// longitude & latitude in radians, the height is NaN if the position is not covered by the
+    /// grid
+    fn apply(&self, from: Geographic2DCoordinateUserVertical) -> Geographic3DCoordinate {
+        self.try_to_ellipsoidal(from)
+            .unwrap_or(Geographic3DCoordinate {
+                lon: from.lon,
+                lat: from.lat,
+                height: f64::NAN,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use crate::coordinate::{Geographic2DCoordinateUserVertical, Geographic3DCoordinate};
+    use crate::grids::gtx::*;
+    use crate::traits::*;
+
+    /// Grid from 53°N, 9°E in 0.25° steps with geoid heights given by `f` at each node in degrees.
+    fn gtx_file(rows: i32, columns: i32, f: impl Fn(f64, f64) -> f32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for v in [53.0f64, 9.0, 0.25, 0.25] {
+            bytes.extend(v.to_be_bytes());
+        }
+        for v in [rows, columns] {
+            bytes.extend(v.to_be_bytes());
+        }
+        for row in 0..rows {
+            for column in 0..columns {
+                let v = f(9.0 + 0.25 * f64::from(column), 53.0 + 0.25 * f64::from(row));
+                bytes.extend(v.to_be_bytes());
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn gtx_reader() {
+        // bilinear functions are interpolated exactly
+        let f = |lon: f64, lat: f64| (40.0 + 0.5 * (lon - 9.0) - 0.2 * (lat - 53.0) * lon) as f32;
+        let grid = GtxGrid::from_bytes(&gtx_file(4, 5, f)).unwrap();
+        assert_eq!((grid.rows, grid.columns), (4, 5));
+        for (lon, lat) in [(9.1f64, 53.1f64), (9.6, 53.4), (9.0, 53.0), (10.0, 53.75)] {
+            let value = grid.value(lon.to_radians(), lat.to_radians()).unwrap();
+            eprintln!("{lon}, {lat}: {value} - {}", f(lon, lat));
+            assert!((value - f64::from(f(lon, lat))).abs() < 1e-4);
+        }
+        assert!(grid
+            .value(8.9f64.to_radians(), 53.1f64.to_radians())
+            .is_none());
+        assert!(grid
+            .value(9.1f64.to_radians(), 53.8f64.to_radians())
+            .is_none());
+
+        // a grid in the [0°, 360°] range covers negative longitudes
+        let mut bytes = gtx_file(4, 5, f);
+        bytes[8..16].copy_from_slice(&359.5f64.to_be_bytes());
+        let grid = GtxGrid::from_bytes(&bytes).unwrap();
+        assert!(grid
+            .value(f64::to_radians(-0.25), 53.1f64.to_radians())
+            .is_some());
+        assert!(grid
+            .value(f64::to_radians(0.75), 53.1f64.to_radians())
+            .is_none());
+
+        assert!(GtxGrid::from_bytes(&gtx_file(4, 5, f)[..100]).is_err());
+        assert!(GtxGrid::from_bytes(&[0; 40]).is_err());
+    }
+
+    #[test]
+    fn gravity_related_height() {
+        let path = std::env::temp_dir().join(format!("miniproj-gtx-{}.gtx", std::process::id()));
+        std::fs::write(&path, gtx_file(4, 5, |lon, _| 39.0 + (lon - 9.0) as f32)).unwrap();
+        let grid = GtxGrid::from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        let transformation = GravityRelatedHeightTransformation::new(Arc::new(grid.unwrap()));
+
+        let from = Geographic3DCoordinate::from_deg(9.5, 53.5, 100.0);
+        let to: Geographic2DCoordinateUserVertical = transformation.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.vertical - 60.5).abs() < 1e-6);
+        assert_eq!((to.lon, to.lat), (from.lon, from.lat));
+        let back: Geographic3DCoordinate = transformation.apply(to);
+        assert!((back.height - from.height).abs() < 1e-9);
+
+        // nodes without data are not interpolated
+        let mut bytes = gtx_file(4, 5, |_, _| 39.0);
+        bytes[40..44].copy_from_slice(&(-88.8888f32).to_be_bytes());
+        let transformation =
+            GravityRelatedHeightTransformation::new(Arc::new(GtxGrid::from_bytes(&bytes).unwrap()));
+        let nodata = Geographic3DCoordinate::from_deg(9.1, 53.1, 100.0);
+        assert!(transformation.try_to_gravity_related(nodata).is_none());
+        let to: Geographic2DCoordinateUserVertical = transformation.apply(nodata);
+        assert!(to.vertical.is_nan());
+        let valid = Geographic3DCoordinate::from_deg(9.6, 53.1, 100.0);
+        assert_eq!(
+            transformation
+                .try_to_gravity_related(valid)
+                .unwrap()
+                .vertical,
+            61.0
+        );
+    }
+}
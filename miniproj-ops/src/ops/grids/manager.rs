@@ -0,0 +1,271 @@
+//This file is licensed under EUPL v1.2
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::vertical_pipeline::VerticalSeparation;
+
+use super::{
+    gtx::{GravityRelatedHeightTransformation, GtxGrid},
+    nadcon5::{Nadcon5Grid, Nadcon5Transformation},
+    ntv2::{NTv2Grid, NTv2Transformation},
+};
+
+/// Grid file format that can be loaded by a `GridManager`.
+pub trait GridFile: Sized + Send + Sync + 'static {
+    /// Parses the contents of a grid file.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+impl GridFile for GtxGrid {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl GridFile for NTv2Grid {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl GridFile for Nadcon5Grid {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// Where the contents of a registered grid come from.
+#[derive(Clone, Debug)]
+enum GridSource {
+    Path(PathBuf),
+    Bytes(Arc<[u8]>),
+}
+
+/// Resolves grid names, like `"ntv20.gsb"`, to grid files and keeps the loaded grids, so that the
+/// grid-based transformations of several operations share them.
+///
+/// A name is looked up in the grids registered in memory or with an explicit path first, then as a
+/// file name relative to each of the search paths in the order they were added. Loaded grids are
+/// cached by name and format until the name is registered again.
+#[derive(Debug, Default)]
+pub struct GridManager {
+    search_paths: Vec<PathBuf>,
+    registered: HashMap<String, GridSource>,
+    loaded: Mutex<HashMap<(TypeId, String), Arc<dyn Any + Send + Sync>>>,
+}
+
+impl GridManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a directory to look up grid names in, after the ones added before.
+    pub fn add_search_path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.search_paths.push(path.into());
+        self
+    }
+
+    /// Get the directories grid names are looked up in.
+    pub fn search_paths(&self) -> &[PathBuf] {
+        &self.search_paths
+    }
+
+    /// Registers the grid file at `path` under `name`, replacing an earlier registration.
+    pub fn register_file(
+        &mut self,
+        name: impl Into<String>,
+        path: impl Into<PathBuf>,
+    ) -> &mut Self {
+        self.register(name.into(), GridSource::Path(path.into()))
+    }
+
+    /// Registers the contents of a grid file under `name`, replacing an earlier registration. This
+    /// allows embedding grids in the binary, e.g. with `include_bytes!`.
+    pub fn register_bytes(
+        &mut self,
+        name: impl Into<String>,
+        bytes: impl Into<Arc<[u8]>>,
+    ) -> &mut Self {
+        self.register(name.into(), GridSource::Bytes(bytes.into()))
+    }
+
+    fn register(&mut self, name: String, source: GridSource) -> &mut Self {
+        self.loaded
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|(_, loaded), _| *loaded != name);
+        self.registered.insert(name, source);
+        self
+    }
+
+    /// Get the path of the grid file `name`, if it is registered with a path or found in one of
+    /// the search paths. Grids registered in memory have no path.
+    pub fn find(&self, name: &str) -> Option<PathBuf> {
+        match self.registered.get(name) {
+            Some(GridSource::Path(path)) => Some(path.clone()),
+            Some(GridSource::Bytes(_)) => None,
+            None => self
+                .search_paths
+                .iter()
+                .map(|directory| directory.join(name))
+                .find(|path| path.is_file()),
+        }
+    }
+
+    /// Loads the grid `name` in the format `G`, or returns the grid loaded before.
+    pub fn load<G: GridFile>(&self, name: &str) -> Result<Arc<G>, Error> {
+        let key = (TypeId::of::<G>(), name.to_owned());
+        let cached = self
+            .loaded
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+            .cloned();
+        if let Some(grid) = cached.and_then(|grid| grid.downcast::<G>().ok()) {
+            return Ok(grid);
+        }
+        let grid = Arc::new(match self.registered.get(name) {
+            Some(GridSource::Bytes(bytes)) => G::from_bytes(bytes)?,
+            _ => {
+                let path = self.find(name).ok_or_else(|| {
+                    Error::new(ErrorKind::NotFound, format!("grid {name} not found"))
+                })?;
+                #[cfg(feature = "tracing")]
+                tracing::debug!(name, path = %path.display(), "reading grid file");
+                G::from_bytes(&std::fs::read(path)?)?
+            }
+        });
+        #[cfg(feature = "tracing")]
+        tracing::debug!(name, format = std::any::type_name::<G>(), "loaded grid");
+        self.loaded
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, grid.clone());
+        Ok(grid)
+    }
+
+    /// Get the NTv2 transformation with the grid `name`.
+    pub fn ntv2_transformation(&self, name: &str) -> Result<NTv2Transformation, Error> {
+        Ok(NTv2Transformation::new(self.load(name)?))
+    }
+
+    /// Get the NADCON5 (2D) transformation with the latitude and longitude shift grids `lat` and
+    /// `lon`.
+    pub fn nadcon5_transformation_2d(
+        &self,
+        lat: &str,
+        lon: &str,
+    ) -> Result<Nadcon5Transformation, Error> {
+        Ok(Nadcon5Transformation::new_2d(
+            self.load(lat)?,
+            self.load(lon)?,
+        ))
+    }
+
+    /// Get the NADCON5 (3D) transformation with the latitude, longitude and ellipsoidal height
+    /// shift grids `lat`, `lon` and `height`.
+    pub fn nadcon5_transformation_3d(
+        &self,
+        lat: &str,
+        lon: &str,
+        height: &str,
+    ) -> Result<Nadcon5Transformation, Error> {
+        Ok(Nadcon5Transformation::new_3d(
+            self.load(lat)?,
+            self.load(lon)?,
+            self.load(height)?,
+        ))
+    }
+
+    /// Get the transformation from ellipsoidal to gravity-related heights with the geoid model grid
+    /// `name`.
+    pub fn gravity_related_height_transformation(
+        &self,
+        name: &str,
+    ) -> Result<GravityRelatedHeightTransformation, Error> {
+        Ok(GravityRelatedHeightTransformation::new(self.load(name)?))
+    }
+
+    /// Get the separation between two vertical datums interpolated from the grid `name`, for use
+    /// in a `VerticalPipeline`.
+    pub fn vertical_separation(&self, name: &str) -> Result<VerticalSeparation, Error> {
+        Ok(VerticalSeparation::Grid(self.load(name)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::ErrorKind;
+    use std::sync::Arc;
+
+    use crate::coordinate::{Geographic2DCoordinateUserVertical, Geographic3DCoordinate};
+    use crate::grids::gtx::GtxGrid;
+    use crate::grids::manager::*;
+    use crate::grids::ntv2::NTv2Grid;
+    use crate::traits::*;
+
+    /// Grid from 53°N, 9°E in 0.25° steps with the constant geoid height `height`.
+    fn gtx_file(height: f32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for v in [53.0f64, 9.0, 0.25, 0.25] {
+            bytes.extend(v.to_be_bytes());
+        }
+        for v in [4i32, 5] {
+            bytes.extend(v.to_be_bytes());
+        }
+        for _ in 0..20 {
+            bytes.extend(height.to_be_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn grid_manager() {
+        let directory = std::env::temp_dir().join(format!("miniproj-grids-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("search.gtx"), gtx_file(39.0)).unwrap();
+        std::fs::write(directory.join("explicit.gtx"), gtx_file(40.0)).unwrap();
+
+        let mut manager = GridManager::new();
+        manager
+            .add_search_path(directory.join("missing"))
+            .add_search_path(&directory)
+            .register_file("renamed.gtx", directory.join("explicit.gtx"))
+            .register_bytes("memory.gtx", gtx_file(41.0));
+        let from = Geographic3DCoordinate::from_deg(9.5, 53.5, 100.0);
+        for (name, height) in [
+            ("search.gtx", 61.0),
+            ("renamed.gtx", 60.0),
+            ("memory.gtx", 59.0),
+        ] {
+            let transformation = manager.gravity_related_height_transformation(name).unwrap();
+            let to: Geographic2DCoordinateUserVertical = transformation.apply(from);
+            eprintln!("{name}: {to:?}");
+            assert!((to.vertical - height).abs() < 1e-6);
+        }
+        assert_eq!(
+            manager.find("search.gtx"),
+            Some(directory.join("search.gtx"))
+        );
+        assert_eq!(manager.find("memory.gtx"), None);
+        let grid = manager.load::<GtxGrid>("search.gtx").unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        // loaded grids are shared until the name is registered again
+        assert!(Arc::ptr_eq(&grid, &manager.load("search.gtx").unwrap()));
+        manager.register_bytes("search.gtx", gtx_file(42.0));
+        assert_eq!(
+            manager.load::<GtxGrid>("search.gtx").unwrap().values[0],
+            42.0
+        );
+
+        let missing = manager.load::<GtxGrid>("missing.gtx").unwrap_err();
+        assert_eq!(missing.kind(), ErrorKind::NotFound);
+        assert!(manager.load::<NTv2Grid>("memory.gtx").is_err());
+    }
+}
@@ -0,0 +1,8 @@
+//This file is licensed under EUPL v1.2
+
+//! Transformations interpolating between the nodes of a grid file, which is loaded at runtime.
+
+pub mod gtx;
+pub mod manager;
+pub mod nadcon5;
+pub mod ntv2;
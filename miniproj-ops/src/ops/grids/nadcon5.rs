@@ -0,0 +1,395 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::TAU;
+use std::io::{Error, ErrorKind, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::{coordinate::Geographic3DCoordinate, CoordOperation};
+
+/// Length of the header record of a NADCON5 grid file, in bytes.
+const HEADER_LEN: u32 = 44;
+
+fn invalid_data(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::InvalidData, message.into())
+}
+
+/// Single quantity grid of NADCON5, read from a file in the NGS `.b` format.
+///
+/// NADCON5 publishes the latitude shifts, longitude shifts and ellipsoidal height shifts of a
+/// transformation as separate files, the angular ones in arc-seconds and the heights in metres.
+/// The files are Fortran sequential records in either byte order: a header with the south-west
+/// corner and increments in degrees and the grid size, followed by one record of single precision
+/// values per row from south to north, each from west to east.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nadcon5Grid {
+    /// latitude of the southern boundary, radians
+    pub south: f64,
+    /// longitude of the western boundary, radians
+    pub west: f64,
+    pub lat_inc: f64,
+    pub lon_inc: f64,
+    pub rows: usize,
+    pub columns: usize,
+    /// Values at the grid nodes, in the unit of the quantity
+    pub values: Vec<f64>,
+}
+
+impl Nadcon5Grid {
+    /// Reads a NADCON5 grid file from `path`.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Reads a NADCON5 grid file from `reader` until the end.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses the contents of a NADCON5 grid file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let marker = bytes
+            .get(0..4)
+            .ok_or_else(|| invalid_data("NADCON5 file too short"))?;
+        let big_endian = match marker {
+            [0, 0, 0, 44] => true,
+            [44, 0, 0, 0] => false,
+            _ => return Err(invalid_data("Not a NADCON5 grid file")),
+        };
+        let mut offset = 0;
+        // reads a Fortran record, checking the length markers before and after it
+        let mut record = |len: u32| -> Result<&[u8], Error> {
+            let end = offset + len as usize + 8;
+            let bytes = bytes
+                .get(offset..end)
+                .ok_or_else(|| invalid_data("Unexpected end of NADCON5 file"))?;
+            let marker = if big_endian {
+                len.to_be_bytes()
+            } else {
+                len.to_le_bytes()
+            };
+            if bytes[..4] != marker || bytes[bytes.len() - 4..] != marker {
+                return Err(invalid_data("Malformed record in NADCON5 file"));
+            }
+            offset = end;
+            Ok(&bytes[4..bytes.len() - 4])
+        };
+        let header = record(HEADER_LEN)?;
+        let double = |i: usize| {
+            let value = header[i * 8..i * 8 + 8].try_into().unwrap();
+            if big_endian {
+                f64::from_be_bytes(value)
+            } else {
+                f64::from_le_bytes(value)
+            }
+        };
+        let int = |i: usize| {
+            let value = header[32 + i * 4..36 + i * 4].try_into().unwrap();
+            if big_endian {
+                i32::from_be_bytes(value)
+            } else {
+                i32::from_le_bytes(value)
+            }
+        };
+        let (south, lat_inc, west, lon_inc) = (double(0), double(1), double(2), double(3));
+        let (rows, columns, kind) = (int(0), int(1), int(2));
+        if kind != 1 {
+            return Err(invalid_data(format!(
+                "Unsupported NADCON5 value kind {kind}"
+            )));
+        }
+        let (Ok(rows), Ok(columns)) = (usize::try_from(rows), usize::try_from(columns)) else {
+            return Err(invalid_data("Invalid NADCON5 grid size"));
+        };
+        if rows < 3 || columns < 3 || !(lat_inc > 0.0 && lon_inc > 0.0) {
+            return Err(invalid_data("Invalid NADCON5 grid size"));
+        }
+        let row_len = u32::try_from(columns * 4).map_err(|_| invalid_data("Row too long"))?;
+        // each row is a record of its own, check that the file holds them before allocating
+        let rows_len = rows.checked_mul(row_len as usize + 8);
+        if rows_len.is_none_or(|len| len > bytes.len() - (HEADER_LEN as usize + 8)) {
+            return Err(invalid_data("Unexpected end of NADCON5 file"));
+        }
+        let mut values = Vec::with_capacity(rows * columns);
+        for _ in 0..rows {
+            let row = record(row_len)?;
+            values.extend(row.chunks_exact(4).map(|v| {
+                let v = v.try_into().unwrap();
+                f64::from(if big_endian {
+                    f32::from_be_bytes(v)
+                } else {
+                    f32::from_le_bytes(v)
+                })
+            }));
+        }
+        Ok(Self {
+            south: south.to_radians(),
+            west: west.to_radians(),
+            lat_inc: lat_inc.to_radians(),
+            lon_inc: lon_inc.to_radians(),
+            rows,
+            columns,
+            values,
+        })
+    }
+
+    /// Get the value at a position in radians by biquadratic interpolation between the nine
+    /// closest grid nodes, like the NGS implementation. Longitudes may be given in either the
+    /// [-π, π] or the [0, 2π] range. Returns `None` outside of the grid.
+    pub fn value(&self, lon: f64, lat: f64) -> Option<f64> {
+        let x = (lon - self.west).rem_euclid(TAU) / self.lon_inc;
+        let y = (lat - self.south) / self.lat_inc;
+        // allow for rounding errors of positions on the boundary
+        const EPS: f64 = 1e-9;
+        let (max_x, max_y) = ((self.columns - 1) as f64, (self.rows - 1) as f64);
+        if !((-EPS..=max_x + EPS).contains(&x) && (-EPS..=max_y + EPS).contains(&y)) {
+            return None;
+        }
+        // the middle node of the 3x3 block, which must not be on the boundary
+        let col = (x.round() as usize).clamp(1, self.columns - 2);
+        let row = (y.round() as usize).clamp(1, self.rows - 2);
+        let (fx, fy) = (x - col as f64, y - row as f64);
+        // quadratic through the values at -1, 0 and 1
+        let quadratic =
+            |t: f64, [a, b, c]: [f64; 3]| b + t * (c - a) / 2.0 + t * t * (a - 2.0 * b + c) / 2.0;
+        let along_row = |r: usize| {
+            let i = r * self.columns + col;
+            quadratic(fx, [self.values[i - 1], self.values[i], self.values[i + 1]])
+        };
+        Some(quadratic(
+            fy,
+            [along_row(row - 1), along_row(row), along_row(row + 1)],
+        ))
+    }
+}
+
+/// NADCON5 transformation, in the 2D (EPSG:1075) and 3D (EPSG:1074) variants.
+///
+/// Adds the latitude and longitude shifts, and for the 3D variant the ellipsoidal height shifts,
+/// interpolated from their respective `Nadcon5Grid`s. The grids have to be loaded at runtime and
+/// are shared between the transformation and its reverse.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nadcon5Transformation {
+    /// latitude shifts, arc-seconds
+    pub lat_grid: Arc<Nadcon5Grid>,
+    /// longitude shifts, arc-seconds
+    pub lon_grid: Arc<Nadcon5Grid>,
+    /// ellipsoidal height shifts, metres. The height is passed through unchanged without them.
+    pub height_grid: Option<Arc<Nadcon5Grid>>,
+    /// Whether the shifts are subtracted instead of added
+    pub reverse: bool,
+}
+
+impl Nadcon5Transformation {
+    /// Maximum number of iterations for the reverse transformation.
+    const MAX_ITERATIONS: usize = 10;
+
+    /// Construct a NADCON5 (2D) transformation, which leaves heights unchanged.
+    pub fn new_2d(lat_grid: Arc<Nadcon5Grid>, lon_grid: Arc<Nadcon5Grid>) -> Self {
+        Self {
+            lat_grid,
+            lon_grid,
+            height_grid: None,
+            reverse: false,
+        }
+    }
+
+    /// Construct a NADCON5 (3D) transformation.
+    pub fn new_3d(
+        lat_grid: Arc<Nadcon5Grid>,
+        lon_grid: Arc<Nadcon5Grid>,
+        height_grid: Arc<Nadcon5Grid>,
+    ) -> Self {
+        Self {
+            height_grid: Some(height_grid),
+            ..Self::new_2d(lat_grid, lon_grid)
+        }
+    }
+
+    /// Get the transformation in the reverse direction.
+    pub fn reversed(&self) -> Self {
+        Self {
+            reverse: !self.reverse,
+            ..self.clone()
+        }
+    }
+
+    /// Get the latitude, longitude and height shifts at a position in radians.
+    fn shifts(&self, lon: f64, lat: f64) -> Option<(f64, f64, f64)> {
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let height_shift = match &self.height_grid {
+            Some(grid) => grid.value(lon, lat)?,
+            None => 0.0,
+        };
+        Some((
+            self.lat_grid.value(lon, lat)? * arcsec,
+            self.lon_grid.value(lon, lat)? * arcsec,
+            height_shift,
+        ))
+    }
+
+    /// Applies the transformation, returning `None` if the position is not covered by the grids.
+    ///
+    /// The reverse direction is computed iteratively, as the shifts are given at positions in the
+    /// source system.
+    pub fn try_apply(&self, from: Geographic3DCoordinate) -> Option<Geographic3DCoordinate> {
+        if !self.reverse {
+            let (lat_shift, lon_shift, height_shift) = self.shifts(from.lon, from.lat)?;
+            return Some(Geographic3DCoordinate {
+                lon: from.lon + lon_shift,
+                lat: from.lat + lat_shift,
+                height: from.height + height_shift,
+            });
+        }
+        let (mut lon, mut lat, mut height) = (from.lon, from.lat, from.height);
+        for _ in 0..Self::MAX_ITERATIONS {
+            let (lat_shift, lon_shift, height_shift) = self.shifts(lon, lat)?;
+            let (lon_next, lat_next) = (from.lon - lon_shift, from.lat - lat_shift);
+            let converged = (lon_next - lon).abs() < 1e-12 && (lat_next - lat).abs() < 1e-12;
+            (lon, lat, height) = (lon_next, lat_next, from.height - height_shift);
+            if converged {
+                break;
+            }
+        }
+        Some(Geographic3DCoordinate { lon, lat, height })
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate> for Nadcon5Transformation {
+    /// longitude & latitude in radians, NaN if the position is not covered by the grids
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        self.try_apply(from).unwrap_or(Geographic3DCoordinate {
+            lon: f64::NAN,
+            lat: f64::NAN,
+            height: f64::NAN,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use crate::coordinate::Geographic3DCoordinate;
+    use crate::grids::nadcon5::*;
+    use crate::traits::*;
+
+    fn record(big_endian: bool, payload: &[u8]) -> Vec<u8> {
+        let len = payload.len() as u32;
+        let marker = if big_endian {
+            len.to_be_bytes()
+        } else {
+            len.to_le_bytes()
+        };
+        [&marker, payload, &marker].concat()
+    }
+
+    /// Grid from 30°N, 260°E in 0.5° steps with values given by `f` at each node in degrees.
+    fn grid(big_endian: bool, rows: i32, columns: i32, f: impl Fn(f64, f64) -> f32) -> Vec<u8> {
+        let mut header = Vec::new();
+        for v in [30.0f64, 0.5, 260.0, 0.5] {
+            header.extend(if big_endian {
+                v.to_be_bytes()
+            } else {
+                v.to_le_bytes()
+            });
+        }
+        for v in [rows, columns, 1] {
+            header.extend(if big_endian {
+                v.to_be_bytes()
+            } else {
+                v.to_le_bytes()
+            });
+        }
+        let mut bytes = record(big_endian, &header);
+        for row in 0..rows {
+            let mut values = Vec::new();
+            for column in 0..columns {
+                let v = f(260.0 + 0.5 * f64::from(column), 30.0 + 0.5 * f64::from(row));
+                values.extend(if big_endian {
+                    v.to_be_bytes()
+                } else {
+                    v.to_le_bytes()
+                });
+            }
+            bytes.extend(record(big_endian, &values));
+        }
+        bytes
+    }
+
+    #[test]
+    fn nadcon5_reader() {
+        // quadratic functions are interpolated exactly
+        let f = |lon: f64, lat: f64| (0.1 * (lon - 261.0).powi(2) - 0.2 * lat * lon) as f32;
+        for big_endian in [true, false] {
+            let grid = Nadcon5Grid::from_bytes(&grid(big_endian, 5, 6, f)).unwrap();
+            assert_eq!((grid.rows, grid.columns), (5, 6));
+            for (lon, lat) in [
+                (261.2f64, 30.7f64),
+                (262.4, 31.9),
+                (260.0, 30.0),
+                (262.5, 32.0),
+            ] {
+                let value = grid
+                    .value((lon - 360.0).to_radians(), lat.to_radians())
+                    .unwrap();
+                eprintln!("{lon}, {lat}: {value} - {}", f(lon, lat));
+                assert!((value - f64::from(f(lon, lat))).abs() < 1e-3);
+            }
+            assert!(grid
+                .value(f64::to_radians(-90.0), 31f64.to_radians())
+                .is_none());
+            assert!(grid
+                .value(f64::to_radians(-99.0), 33f64.to_radians())
+                .is_none());
+        }
+        assert!(Nadcon5Grid::from_bytes(&grid(true, 5, 6, f)[..200]).is_err());
+        assert!(Nadcon5Grid::from_bytes(&[0; 64]).is_err());
+
+        // a header promising more rows than the file holds is rejected before allocating them
+        let mut header = grid(true, 5, 6, f)[..HEADER_LEN as usize + 8].to_vec();
+        header[36..40].copy_from_slice(&i32::MAX.to_be_bytes());
+        let error = Nadcon5Grid::from_bytes(&header).unwrap_err();
+        eprintln!("{error}");
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn nadcon5_transformation() {
+        let path = std::env::temp_dir().join(format!("miniproj-nadcon5-{}.b", std::process::id()));
+        std::fs::write(&path, grid(true, 5, 6, |lon, _| (lon - 260.0) as f32 * 0.1)).unwrap();
+        let lat_grid = Nadcon5Grid::from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        let lat_grid = Arc::new(lat_grid.unwrap());
+        let lon_grid = Arc::new(Nadcon5Grid::from_bytes(&grid(false, 5, 6, |_, _| -0.5)).unwrap());
+        let height_grid = Arc::new(
+            Nadcon5Grid::from_bytes(&grid(false, 5, 6, |_, lat| (lat - 30.0) as f32)).unwrap(),
+        );
+
+        let transformation_2d = Nadcon5Transformation::new_2d(lat_grid.clone(), lon_grid.clone());
+        let transformation = Nadcon5Transformation::new_3d(lat_grid, lon_grid, height_grid);
+        let from = Geographic3DCoordinate::from_deg(-99.0, 31.0, 100.0);
+        let (lon, lat, height) = transformation.apply(from).to_deg();
+        eprintln!("{lon}, {lat}, {height}");
+        assert!((lon - (-99.0 - 0.5 / 3600.0)).abs() < 1e-9);
+        assert!((lat - (31.0 + 0.1 / 3600.0)).abs() < 1e-9);
+        assert!((height - 101.0).abs() < 1e-6);
+        assert_eq!(transformation_2d.apply(from).height, 100.0);
+
+        for (lon, lat) in [(-99.0, 31.0), (-98.3, 31.7), (-99.99, 30.01)] {
+            let from = Geographic3DCoordinate::from_deg(lon, lat, 10.0);
+            let back = transformation.reversed().apply(transformation.apply(from));
+            eprintln!("{from:?} - {back:?}");
+            assert!((back.lon - from.lon).abs() < 1e-12);
+            assert!((back.lat - from.lat).abs() < 1e-12);
+            assert!((back.height - from.height).abs() < 1e-6);
+        }
+
+        let outside = Geographic3DCoordinate::from_deg(-80.0, 31.0, 0.0);
+        assert!(transformation.try_apply(outside).is_none());
+        assert!(transformation.apply(outside).lon.is_nan());
+    }
+}
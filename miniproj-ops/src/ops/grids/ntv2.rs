@@ -0,0 +1,609 @@
+//This file is licensed under EUPL v1.2
+
+use std::io::{Error, ErrorKind, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::{coordinate::Geographic3DCoordinate, CoordOperation};
+
+/// Length of a header record or a grid node record in an NTv2 file, in bytes.
+const RECORD_LEN: usize = 16;
+
+fn invalid_data(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::InvalidData, message.into())
+}
+
+/// Reads the records of an NTv2 file, which may be stored in either byte order.
+struct RecordReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    big_endian: bool,
+}
+
+impl<'a> RecordReader<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, Error> {
+        // the first record is NUM_OREC, which is 11 for all NTv2 files
+        let value = bytes
+            .get(8..12)
+            .ok_or_else(|| invalid_data("NTv2 file too short"))?;
+        let big_endian = match value {
+            [11, 0, 0, 0] => false,
+            [0, 0, 0, 11] => true,
+            _ => return Err(invalid_data("Not an NTv2 file")),
+        };
+        Ok(Self {
+            bytes,
+            offset: 0,
+            big_endian,
+        })
+    }
+
+    fn next_record(&mut self) -> Result<&'a [u8], Error> {
+        let record = self
+            .bytes
+            .get(self.offset..self.offset + RECORD_LEN)
+            .ok_or_else(|| invalid_data("Unexpected end of NTv2 file"))?;
+        self.offset += RECORD_LEN;
+        Ok(record)
+    }
+
+    /// Number of bytes left after the current record.
+    fn remaining(&self) -> usize {
+        self.bytes.len().saturating_sub(self.offset)
+    }
+
+    /// Reads a header record, returning its trimmed keyword and its 8 byte value.
+    fn header(&mut self) -> Result<(String, [u8; 8]), Error> {
+        let record = self.next_record()?;
+        let key = String::from_utf8_lossy(&record[..8]).trim_end().to_owned();
+        let mut value = [0; 8];
+        value.copy_from_slice(&record[8..]);
+        Ok((key, value))
+    }
+
+    fn int(&self, value: [u8; 8]) -> i32 {
+        let bytes = [value[0], value[1], value[2], value[3]];
+        if self.big_endian {
+            i32::from_be_bytes(bytes)
+        } else {
+            i32::from_le_bytes(bytes)
+        }
+    }
+
+    fn double(&self, value: [u8; 8]) -> f64 {
+        if self.big_endian {
+            f64::from_be_bytes(value)
+        } else {
+            f64::from_le_bytes(value)
+        }
+    }
+
+    fn float(&self, bytes: &[u8]) -> f32 {
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        if self.big_endian {
+            f32::from_be_bytes(bytes)
+        } else {
+            f32::from_le_bytes(bytes)
+        }
+    }
+}
+
+fn text(value: [u8; 8]) -> String {
+    String::from_utf8_lossy(&value).trim_end().to_owned()
+}
+
+/// Subgrid of an NTv2 grid file.
+///
+/// Unlike in the file, longitudes are positive east and all angles are in radians. The nodes are
+/// stored row by row from south to north, each row from west to east.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NTv2Subgrid {
+    pub name: String,
+    /// Name of the subgrid this one refines, `"NONE"` for top-level subgrids
+    pub parent: String,
+    pub south: f64,
+    pub north: f64,
+    pub west: f64,
+    pub east: f64,
+    pub lat_inc: f64,
+    pub lon_inc: f64,
+    pub rows: usize,
+    pub columns: usize,
+    /// Latitude and longitude shifts at the grid nodes, radians
+    pub shifts: Vec<(f64, f64)>,
+}
+
+impl NTv2Subgrid {
+    /// Whether the position in radians lies within the subgrid, including its boundary.
+    pub fn contains(&self, lon: f64, lat: f64) -> bool {
+        (self.west..=self.east).contains(&lon) && (self.south..=self.north).contains(&lat)
+    }
+
+    /// Get the latitude and longitude shifts at a position in radians by bilinear interpolation
+    /// between the surrounding grid nodes. Returns `None` outside of the subgrid.
+    pub fn shift(&self, lon: f64, lat: f64) -> Option<(f64, f64)> {
+        if !self.contains(lon, lat) {
+            return None;
+        }
+        let x = (lon - self.west) / self.lon_inc;
+        let y = (lat - self.south) / self.lat_inc;
+        // nodes on the east and north boundaries are interpolated in the last cell
+        let col = (x.floor() as usize).min(self.columns - 2);
+        let row = (y.floor() as usize).min(self.rows - 2);
+        let (fx, fy) = (x - col as f64, y - row as f64);
+        let node = |r: usize, c: usize| self.shifts[r * self.columns + c];
+        let (sw, se) = (node(row, col), node(row, col + 1));
+        let (nw, ne) = (node(row + 1, col), node(row + 1, col + 1));
+        let interpolate = |sw: f64, se: f64, nw: f64, ne: f64| {
+            (1.0 - fy) * ((1.0 - fx) * sw + fx * se) + fy * ((1.0 - fx) * nw + fx * ne)
+        };
+        Some((
+            interpolate(sw.0, se.0, nw.0, ne.0),
+            interpolate(sw.1, se.1, nw.1, ne.1),
+        ))
+    }
+}
+
+/// Grid file in the NTv2 format (`.gsb`), as used by the NTv2 transformation method (EPSG:9615).
+///
+/// The file is read as a whole, in either byte order. Subgrids refining a coarser parent grid are
+/// preferred where they apply.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NTv2Grid {
+    /// Name of the source reference system as given in the file, e.g. `"NAD27"`
+    pub system_from: String,
+    /// Name of the target reference system as given in the file, e.g. `"NAD83"`
+    pub system_to: String,
+    pub subgrids: Vec<NTv2Subgrid>,
+}
+
+impl NTv2Grid {
+    /// Reads an NTv2 grid file from `path`.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Reads an NTv2 grid file from `reader` until the end.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses the contents of an NTv2 grid file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = RecordReader::new(bytes)?;
+        let (_, value) = reader.header()?;
+        let overview_records = reader.int(value);
+        let mut subfile_records = 0;
+        let mut subfiles = 0;
+        let mut units = String::new();
+        let mut system_from = String::new();
+        let mut system_to = String::new();
+        for _ in 1..overview_records {
+            let (key, value) = reader.header()?;
+            match key.as_str() {
+                "NUM_SREC" => subfile_records = reader.int(value),
+                "NUM_FILE" => subfiles = reader.int(value),
+                "GS_TYPE" => units = text(value),
+                "SYSTEM_F" => system_from = text(value),
+                "SYSTEM_T" => system_to = text(value),
+                _ => {}
+            }
+        }
+        let factor = match units.as_str() {
+            "SECONDS" => 1f64.to_radians() / 3600.0,
+            "MINUTES" => 1f64.to_radians() / 60.0,
+            "DEGREES" => 1f64.to_radians(),
+            "RADIANS" => 1.0,
+            _ => return Err(invalid_data(format!("Unknown NTv2 GS_TYPE {units:?}"))),
+        };
+        let subgrids = (0..subfiles)
+            .map(|_| Self::read_subgrid(&mut reader, subfile_records, factor))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::check_parents(&subgrids)?;
+        Ok(Self {
+            system_from,
+            system_to,
+            subgrids,
+        })
+    }
+
+    fn read_subgrid(
+        reader: &mut RecordReader,
+        records: i32,
+        factor: f64,
+    ) -> Result<NTv2Subgrid, Error> {
+        let mut name = String::new();
+        let mut parent = String::new();
+        let [mut s_lat, mut n_lat, mut e_long, mut w_long] = [f64::NAN; 4];
+        let [mut lat_inc, mut long_inc] = [f64::NAN; 2];
+        let mut count = 0;
+        for _ in 0..records {
+            let (key, value) = reader.header()?;
+            match key.as_str() {
+                "SUB_NAME" => name = text(value),
+                "PARENT" => parent = text(value),
+                "S_LAT" => s_lat = reader.double(value),
+                "N_LAT" => n_lat = reader.double(value),
+                "E_LONG" => e_long = reader.double(value),
+                "W_LONG" => w_long = reader.double(value),
+                "LAT_INC" => lat_inc = reader.double(value),
+                "LONG_INC" => long_inc = reader.double(value),
+                "GS_COUNT" => count = reader.int(value),
+                _ => {}
+            }
+        }
+        if !(lat_inc > 0.0 && long_inc > 0.0) {
+            return Err(invalid_data(format!(
+                "Invalid increments in NTv2 subgrid {name:?}"
+            )));
+        }
+        // number of nodes along an axis, rejecting NaN and extents beyond the size of any file
+        let nodes = |extent: f64, inc: f64| {
+            let cells = (extent / inc).round();
+            (1.0..f64::from(i32::MAX))
+                .contains(&cells)
+                .then(|| cells as usize + 1)
+        };
+        let invalid_extent = || invalid_data(format!("Invalid extent of NTv2 subgrid {name:?}"));
+        let (Some(rows), Some(columns)) = (
+            nodes(n_lat - s_lat, lat_inc),
+            nodes(w_long - e_long, long_inc),
+        ) else {
+            return Err(invalid_extent());
+        };
+        let len = rows
+            .checked_mul(columns)
+            .filter(|nodes| usize::try_from(count).ok() == Some(*nodes))
+            .ok_or_else(invalid_extent)?;
+        if len
+            .checked_mul(RECORD_LEN)
+            .is_none_or(|bytes| bytes > reader.remaining())
+        {
+            return Err(invalid_data("Unexpected end of NTv2 file"));
+        }
+        // nodes are stored from east to west in the file, with longitudes positive west
+        let mut shifts = vec![(0.0, 0.0); len];
+        for row in 0..rows {
+            for column in (0..columns).rev() {
+                let record = reader.next_record()?;
+                let lat_shift = f64::from(reader.float(&record[0..4])) * factor;
+                let lon_shift = f64::from(reader.float(&record[4..8])) * factor;
+                shifts[row * columns + column] = (lat_shift, -lon_shift);
+            }
+        }
+        Ok(NTv2Subgrid {
+            name,
+            parent,
+            south: s_lat * factor,
+            north: n_lat * factor,
+            west: -w_long * factor,
+            east: -e_long * factor,
+            lat_inc: lat_inc * factor,
+            lon_inc: long_inc * factor,
+            rows,
+            columns,
+            shifts,
+        })
+    }
+
+    /// Checks that the subgrid names are unique and that following the parents of any subgrid
+    /// ends at a top-level subgrid or an unknown name, rather than going around in a cycle.
+    fn check_parents(subgrids: &[NTv2Subgrid]) -> Result<(), Error> {
+        for (i, grid) in subgrids.iter().enumerate() {
+            if subgrids[..i].iter().any(|g| g.name == grid.name) {
+                return Err(invalid_data(format!(
+                    "Duplicate NTv2 subgrid {:?}",
+                    grid.name
+                )));
+            }
+            let mut parent = Some(grid);
+            // an acyclic chain of parents visits every subgrid at most once
+            for _ in 0..=subgrids.len() {
+                parent = parent.and_then(|p| subgrids.iter().find(|g| g.name == p.parent));
+            }
+            if parent.is_some() {
+                return Err(invalid_data(format!(
+                    "Cyclic parents of NTv2 subgrid {:?}",
+                    grid.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the most detailed subgrid containing the position in radians, descending from the
+    /// top-level subgrids into the subgrids refining them.
+    pub fn subgrid(&self, lon: f64, lat: f64) -> Option<&NTv2Subgrid> {
+        let mut grid = self
+            .subgrids
+            .iter()
+            .find(|g| g.parent == "NONE" && g.contains(lon, lat))?;
+        // bounded by the number of subgrids in case the grid was built with cyclic parents
+        for _ in 0..self.subgrids.len() {
+            match self
+                .subgrids
+                .iter()
+                .find(|g| g.parent == grid.name && g.contains(lon, lat))
+            {
+                Some(child) => grid = child,
+                None => break,
+            }
+        }
+        Some(grid)
+    }
+
+    /// Get the latitude and longitude shifts at a position in radians. Returns `None` if no
+    /// subgrid contains the position.
+    pub fn shift(&self, lon: f64, lat: f64) -> Option<(f64, f64)> {
+        self.subgrid(lon, lat)?.shift(lon, lat)
+    }
+}
+
+/// NTv2 transformation (EPSG:9615).
+///
+/// Applies the latitude and longitude shifts interpolated from an `NTv2Grid`, the height is
+/// passed through unchanged. The grid has to be loaded at runtime and is shared between the
+/// transformation and its reverse.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NTv2Transformation {
+    pub grid: Arc<NTv2Grid>,
+    /// Whether the shifts are subtracted instead of added, i.e. the transformation goes from the
+    /// target to the source system of the grid
+    pub reverse: bool,
+}
+
+impl NTv2Transformation {
+    /// Maximum number of iterations for the reverse transformation.
+    const MAX_ITERATIONS: usize = 10;
+
+    pub fn new(grid: Arc<NTv2Grid>) -> Self {
+        Self {
+            grid,
+            reverse: false,
+        }
+    }
+
+    /// Get the transformation in the reverse direction.
+    pub fn reversed(&self) -> Self {
+        Self {
+            grid: self.grid.clone(),
+            reverse: !self.reverse,
+        }
+    }
+
+    /// Applies the transformation, returning `None` if the position is not covered by the grid.
+    ///
+    /// The reverse direction is computed iteratively, as the shifts are given at positions in the
+    /// source system.
+    pub fn try_apply(&self, from: Geographic3DCoordinate) -> Option<Geographic3DCoordinate> {
+        let (lon, lat) = if self.reverse {
+            let (mut lon, mut lat) = (from.lon, from.lat);
+            for _ in 0..Self::MAX_ITERATIONS {
+                let (lat_shift, lon_shift) = self.grid.shift(lon, lat)?;
+                let (lon_next, lat_next) = (from.lon - lon_shift, from.lat - lat_shift);
+                let converged = (lon_next - lon).abs() < 1e-12 && (lat_next - lat).abs() < 1e-12;
+                (lon, lat) = (lon_next, lat_next);
+                if converged {
+                    break;
+                }
+            }
+            (lon, lat)
+        } else {
+            let (lat_shift, lon_shift) = self.grid.shift(from.lon, from.lat)?;
+            (from.lon + lon_shift, from.lat + lat_shift)
+        };
+        Some(Geographic3DCoordinate { lon, lat, ..from })
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate> for NTv2Transformation {
+    /// longitude & latitude in radians, NaN if the position is not covered by the grid
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        self.try_apply(from).unwrap_or(Geographic3DCoordinate {
+            lon: f64::NAN,
+            lat: f64::NAN,
+            ..from
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::ErrorKind;
+    use std::sync::Arc;
+
+    use crate::coordinate::Geographic3DCoordinate;
+    use crate::grids::ntv2::*;
+    use crate::traits::*;
+
+    fn record(key: &str, value: [u8; 8]) -> Vec<u8> {
+        let mut record = format!("{key:<8}").into_bytes();
+        record.extend_from_slice(&value);
+        record
+    }
+
+    fn int(v: i32) -> [u8; 8] {
+        let mut value = [0; 8];
+        value[..4].copy_from_slice(&v.to_le_bytes());
+        value
+    }
+
+    fn text(v: &str) -> [u8; 8] {
+        format!("{v:<8}").into_bytes().try_into().unwrap()
+    }
+
+    /// Header of a subgrid in arc-seconds, positive west, with `count` nodes.
+    fn subgrid_header(
+        name: &str,
+        parent: &str,
+        [s, n, e, w]: [f64; 4],
+        inc: f64,
+        count: i32,
+    ) -> Vec<u8> {
+        [
+            record("SUB_NAME", text(name)),
+            record("PARENT", text(parent)),
+            record("CREATED", text("")),
+            record("UPDATED", text("")),
+            record("S_LAT", s.to_le_bytes()),
+            record("N_LAT", n.to_le_bytes()),
+            record("E_LONG", e.to_le_bytes()),
+            record("W_LONG", w.to_le_bytes()),
+            record("LAT_INC", inc.to_le_bytes()),
+            record("LONG_INC", inc.to_le_bytes()),
+            record("GS_COUNT", int(count)),
+        ]
+        .concat()
+    }
+
+    /// Subgrid in arc-seconds, positive west, with shifts given by `f` at each node.
+    fn subgrid(
+        name: &str,
+        parent: &str,
+        [s, n, e, w]: [f64; 4],
+        inc: f64,
+        f: impl Fn(f64, f64) -> (f32, f32),
+    ) -> Vec<u8> {
+        let rows = ((n - s) / inc) as i32 + 1;
+        let columns = ((w - e) / inc) as i32 + 1;
+        let mut bytes = subgrid_header(name, parent, [s, n, e, w], inc, rows * columns);
+        for row in 0..rows {
+            for column in 0..columns {
+                let (lat_shift, lon_shift) =
+                    f(e + f64::from(column) * inc, s + f64::from(row) * inc);
+                for v in [lat_shift, lon_shift, 0.0, 0.0] {
+                    bytes.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+        }
+        bytes
+    }
+
+    /// NTv2 file in arc-seconds made up of the given subgrids.
+    fn grid_file(subgrids: &[Vec<u8>]) -> Vec<u8> {
+        let mut bytes = [
+            record("NUM_OREC", int(11)),
+            record("NUM_SREC", int(11)),
+            record("NUM_FILE", int(subgrids.len() as i32)),
+            record("GS_TYPE", text("SECONDS")),
+            record("VERSION", text("NTv2.0")),
+            record("SYSTEM_F", text("TEST_F")),
+            record("SYSTEM_T", text("TEST_T")),
+            record("MAJOR_F", 6378206.4f64.to_le_bytes()),
+            record("MINOR_F", 6356583.8f64.to_le_bytes()),
+            record("MAJOR_T", 6378137.0f64.to_le_bytes()),
+            record("MINOR_T", 6356752.314f64.to_le_bytes()),
+        ]
+        .concat();
+        bytes.extend(subgrids.concat());
+        bytes.extend(record("END", [0; 8]));
+        bytes
+    }
+
+    fn test_grid() -> Vec<u8> {
+        // 50°N to 52°N, 2°W to 0°, shifts linear in the position
+        let parent = [180000.0, 187200.0, 0.0, 7200.0];
+        let parent = subgrid("PARENT", "NONE", parent, 3600.0, |w, s| {
+            ((s / 3600.0 - 50.0) as f32, (1.0 + w / 3600.0) as f32)
+        });
+        // 50.5°N to 51°N, 1°W to 0.5°W, constant shifts
+        let child = [181800.0, 183600.0, 1800.0, 3600.0];
+        let child = subgrid("CHILD", "PARENT", child, 900.0, |_, _| (5.0, -5.0));
+        grid_file(&[parent, child])
+    }
+
+    #[test]
+    fn ntv2_reader() {
+        let grid = NTv2Grid::from_bytes(&test_grid()).unwrap();
+        assert_eq!(grid.system_from, "TEST_F");
+        assert_eq!(grid.system_to, "TEST_T");
+        assert_eq!(grid.subgrids.len(), 2);
+        let parent = &grid.subgrids[0];
+        assert_eq!((parent.rows, parent.columns), (3, 3));
+        assert!((parent.west + 2f64.to_radians()).abs() < 1e-15);
+        assert!(parent.east.abs() < 1e-15);
+
+        let arcsec = 1f64.to_radians() / 3600.0;
+        // positive west shifts are positive east after reading
+        let (lon, lat) = ((-1.5f64).to_radians(), 51.25f64.to_radians());
+        let (lat_shift, lon_shift) = grid.shift(lon, lat).unwrap();
+        eprintln!("{} {}", lat_shift / arcsec, lon_shift / arcsec);
+        assert!((lat_shift / arcsec - 1.25).abs() < 1e-9);
+        assert!((lon_shift / arcsec + 2.5).abs() < 1e-9);
+
+        let (lon, lat) = ((-0.75f64).to_radians(), 50.75f64.to_radians());
+        assert_eq!(grid.subgrid(lon, lat).unwrap().name, "CHILD");
+        let (lat_shift, lon_shift) = grid.shift(lon, lat).unwrap();
+        assert!((lat_shift / arcsec - 5.0).abs() < 1e-9);
+        assert!((lon_shift / arcsec - 5.0).abs() < 1e-9);
+
+        assert!(grid.shift(1f64.to_radians(), 51f64.to_radians()).is_none());
+        assert!(NTv2Grid::from_bytes(&test_grid()[..100]).is_err());
+        assert!(NTv2Grid::from_bytes(&[0; 64]).is_err());
+    }
+
+    #[test]
+    fn ntv2_invalid_headers() {
+        // a header promising more nodes than the file holds is rejected before allocating them
+        let big = subgrid_header("BIG", "NONE", [0.0, 45999.0, 0.0, 45999.0], 1.0, 2116000000);
+        let error = NTv2Grid::from_bytes(&grid_file(&[big])).unwrap_err();
+        eprintln!("{error}");
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        for extent in [1e300, f64::NAN, -1.0] {
+            let header = subgrid_header("X", "NONE", [0.0, extent, 0.0, 1.0], 1.0, 4);
+            assert!(NTv2Grid::from_bytes(&grid_file(&[header])).is_err());
+        }
+        let mut truncated = test_grid();
+        truncated.truncate(truncated.len() - 100);
+        assert!(NTv2Grid::from_bytes(&truncated).is_err());
+
+        // subgrids must not refine themselves or each other in a cycle
+        let extent = [0.0, 3600.0, 0.0, 3600.0];
+        let grid = |name, parent| subgrid(name, parent, extent, 3600.0, |_, _| (0.0, 0.0));
+        let own_parent = grid_file(&[grid("T", "NONE"), grid("T", "T")]);
+        assert!(NTv2Grid::from_bytes(&own_parent).is_err());
+        let cycle = grid_file(&[grid("T", "NONE"), grid("A", "B"), grid("B", "A")]);
+        assert!(NTv2Grid::from_bytes(&cycle).is_err());
+        let nested = grid_file(&[grid("T", "NONE"), grid("A", "T"), grid("B", "A")]);
+        let nested = NTv2Grid::from_bytes(&nested).unwrap();
+        assert_eq!(nested.subgrid(0.0, 0.0).unwrap().name, "B");
+
+        // grids built in code are descended into a bounded number of times
+        let mut looping = nested.clone();
+        looping.subgrids[1].name = "T".into();
+        assert_eq!(looping.subgrid(0.0, 0.0).unwrap().name, "T");
+        assert!(looping.shift(0.0, 0.0).is_some());
+    }
+
+    #[test]
+    fn ntv2_transformation() {
+        let path = std::env::temp_dir().join(format!("miniproj-ntv2-{}.gsb", std::process::id()));
+        std::fs::write(&path, test_grid()).unwrap();
+        let grid = NTv2Grid::from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        let transformation = NTv2Transformation::new(Arc::new(grid.unwrap()));
+
+        let from = Geographic3DCoordinate::from_deg(-1.5, 51.25, 10.0);
+        let (lon, lat, height) = transformation.apply(from).to_deg();
+        eprintln!("{lon}, {lat}, {height}");
+        assert!((lon - (-1.5 - 2.5 / 3600.0)).abs() < 1e-9);
+        assert!((lat - (51.25 + 1.25 / 3600.0)).abs() < 1e-9);
+        assert_eq!(height, 10.0);
+
+        for (lon, lat) in [(-1.5, 51.25), (-0.75, 50.75), (-1.99, 50.01)] {
+            let from = Geographic3DCoordinate::from_deg(lon, lat, 0.0);
+            let back = transformation.reversed().apply(transformation.apply(from));
+            eprintln!("{from:?} - {back:?}");
+            assert!((back.lon - from.lon).abs() < 1e-12);
+            assert!((back.lat - from.lat).abs() < 1e-12);
+        }
+
+        let outside = Geographic3DCoordinate::from_deg(5.0, 51.0, 0.0);
+        assert!(transformation.try_apply(outside).is_none());
+        let outside = transformation.apply(outside);
+        assert!(outside.lon.is_nan() && outside.lat.is_nan());
+    }
+}
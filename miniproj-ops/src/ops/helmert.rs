@@ -0,0 +1,584 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::{GeocentricCoordinate, Geographic3DCoordinate},
+    ellipsoid::Ellipsoid,
+    epoch::Epoch,
+    inverse::iterative_geocentric_inverse,
+    traits::{GetterContstruct, InvertibleCoordOperation},
+    CoordOperation, DbContstruct, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelmertParams {
+    /// X-axis translation
+    tx: f64,
+    /// Y-axis translation
+    ty: f64,
+    /// Z-axis translation
+    tz: f64,
+    /// X-axis rotation
+    rx: f64,
+    /// Y-axis rotation
+    ry: f64,
+    /// Z-axis rotation
+    rz: f64,
+    /// scale difference
+    ds: f64,
+}
+
+impl_param_eq_hash!(HelmertParams {
+    tx,
+    ty,
+    tz,
+    rx,
+    ry,
+    rz,
+    ds
+});
+
+impl HelmertParams {
+    pub const fn new(tx: f64, ty: f64, tz: f64, rx: f64, ry: f64, rz: f64, ds: f64) -> Self {
+        Self {
+            tx,
+            ty,
+            tz,
+            rx,
+            ry,
+            rz,
+            ds,
+        }
+    }
+
+    /// Get X-axis translation, metres.
+    pub fn tx(&self) -> f64 {
+        self.tx
+    }
+
+    /// Get Y-axis translation, metres.
+    pub fn ty(&self) -> f64 {
+        self.ty
+    }
+
+    /// Get Z-axis translation, metres.
+    pub fn tz(&self) -> f64 {
+        self.tz
+    }
+
+    /// Get X-axis rotation, radians.
+    pub fn rx(&self) -> f64 {
+        self.rx
+    }
+
+    /// Get Y-axis rotation, radians.
+    pub fn ry(&self) -> f64 {
+        self.ry
+    }
+
+    /// Get Z-axis rotation, radians.
+    pub fn rz(&self) -> f64 {
+        self.rz
+    }
+
+    /// Get scale difference, unity.
+    pub fn ds(&self) -> f64 {
+        self.ds
+    }
+}
+
+/// Reads the seven Helmert parameters, rotations and scale difference are zero if not given like
+/// for geocentric translations.
+pub(crate) fn helmert_params(params: &[(u32, f64)], codes: [u32; 7]) -> HelmertParams {
+    let get = |code: u32| params.iter().find_map(|(c, v)| (*c == code).then_some(*v));
+    HelmertParams::new(
+        get(codes[0]).unwrap(),
+        get(codes[1]).unwrap(),
+        get(codes[2]).unwrap(),
+        get(codes[3]).unwrap_or(0.0),
+        get(codes[4]).unwrap_or(0.0),
+        get(codes[5]).unwrap_or(0.0),
+        get(codes[6]).unwrap_or(0.0),
+    )
+}
+
+/// Codes of the seven Helmert parameters.
+pub(crate) const PARAMETERS: [u32; 7] = [8605, 8606, 8607, 8608, 8609, 8610, 8611];
+/// Codes of the rates of change of the seven Helmert parameters.
+const RATE_PARAMETERS: [u32; 7] = [1040, 1041, 1042, 1043, 1044, 1045, 1046];
+
+/// Seven parameter Helmert transformation between geocentric coordinates, in the geocentric domain
+/// of the Position Vector (EPSG:1033) and Coordinate Frame rotation (EPSG:1032) methods, or
+/// geocentric translations (EPSG:1031) with zero rotations and scale difference.
+///
+/// The rotations are stored in the position vector convention, the coordinate frame convention
+/// differs only by their sign. Uses the linearised rotation matrix of the EPSG formulas, which
+/// assume rotations of at most a few arc-seconds.
+#[derive(Copy, Clone, Debug)]
+pub struct HelmertTransformation {
+    pub tx: f64,
+    pub ty: f64,
+    pub tz: f64,
+    pub rx: f64,
+    pub ry: f64,
+    pub rz: f64,
+    pub ds: f64,
+}
+
+impl_param_eq_hash!(HelmertTransformation {
+    tx,
+    ty,
+    tz,
+    rx,
+    ry,
+    rz,
+    ds
+});
+
+impl HelmertTransformation {
+    /// Construct a transformation from parameters in the position vector convention.
+    pub fn new_position_vector(params: &HelmertParams) -> Self {
+        Self {
+            tx: params.tx(),
+            ty: params.ty(),
+            tz: params.tz(),
+            rx: params.rx(),
+            ry: params.ry(),
+            rz: params.rz(),
+            ds: params.ds(),
+        }
+    }
+
+    /// Construct a transformation from parameters in the coordinate frame rotation convention.
+    pub fn new_coordinate_frame(params: &HelmertParams) -> Self {
+        Self {
+            rx: -params.rx(),
+            ry: -params.ry(),
+            rz: -params.rz(),
+            ..Self::new_position_vector(params)
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which negates the parameters as the
+    /// EPSG dataset does for reversible Helmert transformations.
+    pub fn reversed(&self) -> Self {
+        Self {
+            tx: -self.tx,
+            ty: -self.ty,
+            tz: -self.tz,
+            rx: -self.rx,
+            ry: -self.ry,
+            rz: -self.rz,
+            ds: -self.ds,
+        }
+    }
+}
+
+impl CoordOperation<GeocentricCoordinate, GeocentricCoordinate> for HelmertTransformation {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// cartesian coordinates in metres
+    fn apply(&self, from: GeocentricCoordinate) -> GeocentricCoordinate {
+        let m = 1.0 + self.ds;
+        GeocentricCoordinate {
+            x: m * (from.x - self.rz * from.y + self.ry * from.z) + self.tx,
+            y: m * (self.rz * from.x + from.y - self.rx * from.z) + self.ty,
+            z: m * (-self.ry * from.x + self.rx * from.y + from.z) + self.tz,
+        }
+    }
+}
+
+impl InvertibleCoordOperation<GeocentricCoordinate, GeocentricCoordinate>
+    for HelmertTransformation
+{
+    fn apply_inverse(&self, to: GeocentricCoordinate) -> GeocentricCoordinate {
+        iterative_geocentric_inverse(self, to)
+    }
+}
+
+impl PseudoSerialize for HelmertTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"HelmertTransformation{{
+    tx: {}f64,
+    ty: {}f64,
+    tz: {}f64,
+    rx: {}f64,
+    ry: {}f64,
+    rz: {}f64,
+    ds: {}f64,
+}}",
+            self.tx, self.ty, self.tz, self.rx, self.ry, self.rz, self.ds,
+        )
+    }
+}
+
+impl DbContstruct for HelmertTransformation {
+    /// Parameters in the position vector convention.
+    fn from_database_params(params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        Self::new_position_vector(&helmert_params(params, PARAMETERS))
+    }
+}
+
+impl GetterContstruct for HelmertTransformation {
+    /// Parameters in the position vector convention.
+    fn with_db_getter<G>(mut getter: G, _ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = HelmertParams::new(
+            getter(8605)?,
+            getter(8606)?,
+            getter(8607)?,
+            getter(8608).unwrap_or(0.0),
+            getter(8609).unwrap_or(0.0),
+            getter(8610).unwrap_or(0.0),
+            getter(8611).unwrap_or(0.0),
+        );
+        Some(Self::new_position_vector(&params))
+    }
+}
+
+/// Helmert transformation between geographic coordinates, in the geographic domain of the
+/// Geocentric translations (EPSG:9603, 1035), Position Vector (EPSG:9606, 1037) and Coordinate
+/// Frame rotation (EPSG:9607, 1038) methods.
+///
+/// Converts to geocentric coordinates on the source ellipsoid, applies the `HelmertTransformation`
+/// and converts back on the target ellipsoid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GeographicHelmertTransformation {
+    pub helmert: HelmertTransformation,
+    pub source_ellipsoid: Ellipsoid,
+    pub target_ellipsoid: Ellipsoid,
+}
+
+impl GeographicHelmertTransformation {
+    pub fn new(helmert: HelmertTransformation, source: &Ellipsoid, target: &Ellipsoid) -> Self {
+        Self {
+            helmert,
+            source_ellipsoid: *source,
+            target_ellipsoid: *target,
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which also swaps the ellipsoids.
+    pub fn reversed(&self) -> Self {
+        Self::new(
+            self.helmert.reversed(),
+            &self.target_ellipsoid,
+            &self.source_ellipsoid,
+        )
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for GeographicHelmertTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        let (x, y, z) = self
+            .source_ellipsoid
+            .rad_to_geocentric(from.lon, from.lat, from.height);
+        let to = self.helmert.apply(GeocentricCoordinate::new(x, y, z));
+        let (lon, lat, height) = self.target_ellipsoid.geocentric_to_rad(to.x, to.y, to.z);
+        Geographic3DCoordinate { lon, lat, height }
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for GeographicHelmertTransformation
+{
+    /// longitude & latitude in radians
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        let (x, y, z) = self
+            .target_ellipsoid
+            .rad_to_geocentric(to.lon, to.lat, to.height);
+        let from = self
+            .helmert
+            .apply_inverse(GeocentricCoordinate::new(x, y, z));
+        let (lon, lat, height) = self
+            .source_ellipsoid
+            .geocentric_to_rad(from.x, from.y, from.z);
+        Geographic3DCoordinate { lon, lat, height }
+    }
+}
+
+impl PseudoSerialize for GeographicHelmertTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"GeographicHelmertTransformation{{
+    helmert: {},
+    source_ellipsoid: {},
+    target_ellipsoid: {},
+}}",
+            self.helmert.to_constructed(),
+            self.source_ellipsoid.to_constructed(),
+            self.target_ellipsoid.to_constructed(),
+        )
+    }
+}
+
+/// Fifteen parameter Helmert transformation between geocentric coordinates, in the geocentric
+/// domain of the Time-dependent Position Vector (EPSG:1053) and Coordinate Frame rotation
+/// (EPSG:1056) methods.
+///
+/// The seven parameters change linearly with the coordinate epoch, `at_epoch` gives the
+/// `HelmertTransformation` at a specific epoch.
+#[derive(Copy, Clone, Debug)]
+pub struct HelmertTimeDependentTransformation {
+    /// parameters at the reference epoch, position vector convention
+    pub helmert: HelmertTransformation,
+    /// rates of change of the parameters per year, position vector convention
+    pub rates: HelmertTransformation,
+    /// reference epoch of the parameters, decimal year
+    pub reference_epoch: f64,
+}
+
+impl PartialEq for HelmertTimeDependentTransformation {
+    fn eq(&self, other: &Self) -> bool {
+        self.helmert == other.helmert
+            && self.rates == other.rates
+            && self.reference_epoch.to_bits() == other.reference_epoch.to_bits()
+    }
+}
+
+impl Eq for HelmertTimeDependentTransformation {}
+
+impl std::hash::Hash for HelmertTimeDependentTransformation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.helmert.hash(state);
+        self.rates.hash(state);
+        self.reference_epoch.to_bits().hash(state);
+    }
+}
+
+impl HelmertTimeDependentTransformation {
+    pub fn new(
+        helmert: HelmertTransformation,
+        rates: HelmertTransformation,
+        reference_epoch: f64,
+    ) -> Self {
+        Self {
+            helmert,
+            rates,
+            reference_epoch,
+        }
+    }
+
+    /// Get the seven parameter transformation at the coordinate epoch `epoch`.
+    pub fn at_epoch(&self, epoch: Epoch) -> HelmertTransformation {
+        let dt = epoch.decimal_year() - self.reference_epoch;
+        let (p, r) = (&self.helmert, &self.rates);
+        HelmertTransformation {
+            tx: p.tx + r.tx * dt,
+            ty: p.ty + r.ty * dt,
+            tz: p.tz + r.tz * dt,
+            rx: p.rx + r.rx * dt,
+            ry: p.ry + r.ry * dt,
+            rz: p.rz + r.rz * dt,
+            ds: p.ds + r.ds * dt,
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which negates the parameters and rates.
+    pub fn reversed(&self) -> Self {
+        Self::new(
+            self.helmert.reversed(),
+            self.rates.reversed(),
+            self.reference_epoch,
+        )
+    }
+}
+
+impl PseudoSerialize for HelmertTimeDependentTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"HelmertTimeDependentTransformation{{
+    helmert: {},
+    rates: {},
+    reference_epoch: {}f64,
+}}",
+            self.helmert.to_constructed(),
+            self.rates.to_constructed(),
+            self.reference_epoch,
+        )
+    }
+}
+
+impl DbContstruct for HelmertTimeDependentTransformation {
+    /// Parameters in the position vector convention and in EPSG base units, i.e. rates per second
+    /// and the reference epoch in seconds.
+    fn from_database_params(params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        Self::new(
+            HelmertTransformation::new_position_vector(&helmert_params(params, PARAMETERS)),
+            HelmertTransformation::new_position_vector(&rate_params(params)),
+            reference_epoch(params),
+        )
+    }
+}
+
+/// Reads the rates of change of the seven Helmert parameters, converted from per second to per
+/// year.
+fn rate_params(params: &[(u32, f64)]) -> HelmertParams {
+    let p = helmert_params(params, RATE_PARAMETERS);
+    let year = Epoch::SECONDS_PER_YEAR;
+    HelmertParams::new(
+        p.tx() * year,
+        p.ty() * year,
+        p.tz() * year,
+        p.rx() * year,
+        p.ry() * year,
+        p.rz() * year,
+        p.ds() * year,
+    )
+}
+
+/// Reads the parameter reference epoch, converted from seconds to a decimal year.
+fn reference_epoch(params: &[(u32, f64)]) -> f64 {
+    params
+        .iter()
+        .find_map(|(c, v)| if *c == 1047 { Some(*v) } else { None })
+        .unwrap()
+        / Epoch::SECONDS_PER_YEAR
+}
+
+pub fn direct_transformation_position_vector(
+    params: &[(u32, f64)],
+    source: Ellipsoid,
+    target: Ellipsoid,
+) -> String {
+    let helmert = HelmertTransformation::from_database_params(params, &source);
+    GeographicHelmertTransformation::new(helmert, &source, &target).to_constructed()
+}
+
+pub fn direct_transformation_coordinate_frame(
+    params: &[(u32, f64)],
+    source: Ellipsoid,
+    target: Ellipsoid,
+) -> String {
+    let helmert = HelmertTransformation::new_coordinate_frame(&helmert_params(params, PARAMETERS));
+    GeographicHelmertTransformation::new(helmert, &source, &target).to_constructed()
+}
+
+pub fn direct_geocentric_position_vector(params: &[(u32, f64)]) -> String {
+    HelmertTransformation::new_position_vector(&helmert_params(params, PARAMETERS)).to_constructed()
+}
+
+pub fn direct_geocentric_coordinate_frame(params: &[(u32, f64)]) -> String {
+    HelmertTransformation::new_coordinate_frame(&helmert_params(params, PARAMETERS))
+        .to_constructed()
+}
+
+pub fn direct_time_dependent_position_vector(params: &[(u32, f64)]) -> String {
+    HelmertTimeDependentTransformation::from_database_params(params, &crate::ellipsoids::WGS84)
+        .to_constructed()
+}
+
+pub fn direct_time_dependent_coordinate_frame(params: &[(u32, f64)]) -> String {
+    HelmertTimeDependentTransformation::new(
+        HelmertTransformation::new_coordinate_frame(&helmert_params(params, PARAMETERS)),
+        HelmertTransformation::new_coordinate_frame(&rate_params(params)),
+        reference_epoch(params),
+    )
+    .to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::{GeocentricCoordinate, Geographic3DCoordinate};
+    use crate::ellipsoid::Ellipsoid;
+    use crate::epoch::Epoch;
+    use crate::helmert::*;
+    use crate::traits::*;
+
+    #[test]
+    fn position_vector_consistency() {
+        // EPSG example, WGS 72 to WGS 84
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let params = HelmertParams::new(0.0, 0.0, 4.5, 0.0, 0.0, 0.554 * arcsec, 0.219e-6);
+        let transformation = HelmertTransformation::new_position_vector(&params);
+
+        let from = GeocentricCoordinate::new(3657660.66, 255768.55, 5201382.11);
+        let to = transformation.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.x - 3657660.78).abs() < 0.01);
+        assert!((to.y - 255778.43).abs() < 0.01);
+        assert!((to.z - 5201387.75).abs() < 0.01);
+
+        // the same transformation in the coordinate frame convention
+        let params = HelmertParams::new(0.0, 0.0, 4.5, 0.0, 0.0, -0.554 * arcsec, 0.219e-6);
+        let coordinate_frame = HelmertTransformation::new_coordinate_frame(&params);
+        assert_eq!(coordinate_frame.apply(from), to);
+
+        let back = transformation.reversed().apply(to);
+        assert!((back.x - from.x).abs() < 1e-3);
+        assert!((back.y - from.y).abs() < 1e-3);
+        assert!((back.z - from.z).abs() < 1e-3);
+    }
+
+    #[test]
+    fn geographic_helmert() {
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let wgs72 = Ellipsoid::from_a_f_inv(6378135.0, 298.26);
+        let wgs84 = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params = HelmertParams::new(0.0, 0.0, 4.5, 0.0, 0.0, 0.554 * arcsec, 0.219e-6);
+        let transformation = GeographicHelmertTransformation::new(
+            HelmertTransformation::new_position_vector(&params),
+            &wgs72,
+            &wgs84,
+        );
+
+        let from = Geographic3DCoordinate::from_deg(4.0, 55.0, 0.0);
+        let to = transformation.apply(from);
+        let (x, y, z) = wgs84.rad_to_geocentric(to.lon, to.lat, to.height);
+        eprintln!("{:?}: {x}, {y}, {z}", to.to_deg());
+        assert!((x - 3657660.78).abs() < 0.01);
+        assert!((y - 255778.43).abs() < 0.01);
+        assert!((z - 5201387.75).abs() < 0.01);
+
+        let back = transformation.reversed().apply(to);
+        assert!((back.lon - from.lon).abs() < 1e-10);
+        assert!((back.lat - from.lat).abs() < 1e-10);
+        assert!((back.height - from.height).abs() < 1e-3);
+    }
+
+    #[test]
+    fn time_dependent_helmert() {
+        let mas = 1f64.to_radians() / 3600.0 / 1000.0;
+        // rates and epoch in EPSG base units, per second and seconds
+        let year = Epoch::SECONDS_PER_YEAR;
+        let params = [
+            (8605, 0.0),
+            (8606, 0.0),
+            (8607, 0.0),
+            (8608, 1.0 * mas),
+            (1040, 0.001 / year),
+            (1041, 0.0),
+            (1042, -0.002 / year),
+            (1043, 0.1 * mas / year),
+            (1046, 0.1e-9 / year),
+            (1047, 2010.0 * year),
+        ];
+        let transformation = HelmertTimeDependentTransformation::from_database_params(
+            &params,
+            &crate::ellipsoids::WGS84,
+        );
+
+        assert!((transformation.reference_epoch - 2010.0).abs() < 1e-9);
+        let at_reference = transformation.at_epoch(Epoch::from_decimal_year(2010.0).unwrap());
+        assert!(at_reference.tx.abs() < 1e-12);
+        assert!((at_reference.rx - mas).abs() < 1e-18);
+        let later = transformation.at_epoch(Epoch::from_decimal_year(2020.0).unwrap());
+        eprintln!("{later:?}");
+        assert!((later.tx - 0.01).abs() < 1e-12);
+        assert!((later.tz + 0.02).abs() < 1e-12);
+        assert!((later.rx - 2.0 * mas).abs() < 1e-18);
+        assert!((later.ds - 1e-9).abs() < 1e-18);
+
+        let reversed = transformation.reversed();
+        let reversed_later = reversed.at_epoch(Epoch::from_decimal_year(2020.0).unwrap());
+        assert!((reversed_later.tx + later.tx).abs() < 1e-15);
+        assert!((reversed_later.rx + later.rx).abs() < 1e-18);
+    }
+}
@@ -0,0 +1,542 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use crate::{
+    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HotineObliqueMercatorParams {
+    /// latitude of projection centre
+    lat_c: f64,
+    /// longitude of projection centre
+    lon_c: f64,
+    /// azimuth of initial line
+    azimuth_c: f64,
+    /// angle from rectified to skew grid
+    gamma_c: f64,
+    /// scale factor on initial line
+    k_c: f64,
+    /// false easting, or easting at projection centre for variant B
+    false_e: f64,
+    /// false northing, or northing at projection centre for variant B
+    false_n: f64,
+}
+
+impl_param_eq_hash!(HotineObliqueMercatorParams {
+    lat_c,
+    lon_c,
+    azimuth_c,
+    gamma_c,
+    k_c,
+    false_e,
+    false_n,
+});
+
+impl HotineObliqueMercatorParams {
+    pub const fn new(
+        lat_c: f64,
+        lon_c: f64,
+        azimuth_c: f64,
+        gamma_c: f64,
+        k_c: f64,
+        false_e: f64,
+        false_n: f64,
+    ) -> Self {
+        Self {
+            lat_c,
+            lon_c,
+            azimuth_c,
+            gamma_c,
+            k_c,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get latitude of projection centre, radians.
+    pub fn lat_c(&self) -> f64 {
+        self.lat_c
+    }
+
+    /// Get longitude of projection centre, radians.
+    pub fn lon_c(&self) -> f64 {
+        self.lon_c
+    }
+
+    /// Get azimuth of initial line, radians.
+    pub fn azimuth_c(&self) -> f64 {
+        self.azimuth_c
+    }
+
+    /// Get angle from rectified to skew grid, radians.
+    pub fn gamma_c(&self) -> f64 {
+        self.gamma_c
+    }
+
+    /// Get scale factor on initial line.
+    pub fn k_c(&self) -> f64 {
+        self.k_c
+    }
+
+    /// Get false easting, or easting at projection centre for variant B.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing, or northing at projection centre for variant B.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Hotine Oblique Mercator (variant A) coordinate operation (EPSG:9812).
+///
+/// The false easting and northing are applied at the natural origin, where the initial line
+/// intersects the aposphere's equator.
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct HotineObliqueMercatorAProjection {
+    pub ellipsoid_e: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+
+    pub A: f64,
+    pub B: f64,
+    pub H: f64,
+    pub gamma_O: f64,
+    pub gamma_c: f64,
+    pub lon_O: f64,
+
+    pub phi_2_chi_sin_summand_factor: f64,
+    pub phi_4_chi_sin_summand_factor: f64,
+    pub phi_6_chi_sin_summand_factor: f64,
+    pub phi_8_chi_sin_summand_factor: f64,
+}
+
+impl_param_eq_hash!(HotineObliqueMercatorAProjection {
+    ellipsoid_e,
+    false_e,
+    false_n,
+    A,
+    B,
+    H,
+    gamma_O,
+    gamma_c,
+    lon_O,
+    phi_2_chi_sin_summand_factor,
+    phi_4_chi_sin_summand_factor,
+    phi_6_chi_sin_summand_factor,
+    phi_8_chi_sin_summand_factor,
+});
+
+impl HotineObliqueMercatorAProjection {
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &HotineObliqueMercatorParams) -> Self {
+        let e = ell.e();
+        let e_sq = ell.e_squared();
+        let lat_c = params.lat_c();
+        let B = (1.0 + e_sq * lat_c.cos().powi(4) / (1.0 - e_sq)).sqrt();
+        let A =
+            ell.a() * B * params.k_c() * (1.0 - e_sq).sqrt() / (1.0 - e_sq * lat_c.sin().powi(2));
+        let t_O = Self::t(e, lat_c);
+        let D = B * (1.0 - e_sq).sqrt() / (lat_c.cos() * (1.0 - e_sq * lat_c.sin().powi(2)).sqrt());
+        // D < 1 can only occur due to rounding, see Guidance Note 7-2
+        let F = D + (D.powi(2) - 1.0).max(0.0).sqrt() * lat_c.signum();
+        let H = F * t_O.powf(B);
+        let G = (F - 1.0 / F) / 2.0;
+        let gamma_O = (params.azimuth_c().sin() / D).asin();
+        // for an azimuth of 90° the argument is 1 and may be pushed out of range by rounding
+        let lon_O = params.lon_c() - (G * gamma_O.tan()).clamp(-1.0, 1.0).asin() / B;
+        Self {
+            ellipsoid_e: e,
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+
+            A,
+            B,
+            H,
+            gamma_O,
+            gamma_c: params.gamma_c(),
+            lon_O,
+
+            phi_2_chi_sin_summand_factor: e_sq / 2.0
+                + 5.0 * e_sq.powi(2) / 24.0
+                + e_sq.powi(3) / 12.0
+                + 13.0 * e_sq.powi(4) / 360.0,
+            phi_4_chi_sin_summand_factor: 7.0 * e_sq.powi(2) / 48.0
+                + 29.0 * e_sq.powi(3) / 240.0
+                + 811.0 * e_sq.powi(4) / 11520.0,
+            phi_6_chi_sin_summand_factor: 7.0 * e_sq.powi(3) / 120.0 + 81.0 * e_sq.powi(4) / 1120.0,
+            phi_8_chi_sin_summand_factor: 4279.0 * e_sq.powi(4) / 161280.0,
+        }
+    }
+
+    fn t(e: f64, latitude: f64) -> f64 {
+        (FRAC_PI_4 - latitude / 2.0).tan()
+            / ((1.0 - e * latitude.sin()) / (1.0 + e * latitude.sin())).powf(e / 2.0)
+    }
+
+    /// Unrectified skew coordinates `(u, v)` with their origin at the natural origin.
+    #[allow(non_snake_case)]
+    pub(crate) fn skew_uv(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let Q = self.H / Self::t(self.ellipsoid_e, latitude).powf(self.B);
+        let S = (Q - 1.0 / Q) / 2.0;
+        let T = (Q + 1.0 / Q) / 2.0;
+        let V = (self.B * (longitude - self.lon_O)).sin();
+        let U = (-V * self.gamma_O.cos() + S * self.gamma_O.sin()) / T;
+        let v = self.A * ((1.0 - U) / (1.0 + U)).ln() / (2.0 * self.B);
+        let u = self.A
+            * f64::atan2(
+                S * self.gamma_O.cos() + V * self.gamma_O.sin(),
+                (self.B * (longitude - self.lon_O)).cos(),
+            )
+            / self.B;
+        (u, v)
+    }
+
+    /// Longitude and latitude for unrectified skew coordinates with their origin at the natural origin.
+    #[allow(non_snake_case)]
+    pub(crate) fn skew_uv_to_rad(&self, u: f64, v: f64) -> (f64, f64) {
+        let Q = (-self.B * v / self.A).exp();
+        let S = (Q - 1.0 / Q) / 2.0;
+        let T = (Q + 1.0 / Q) / 2.0;
+        let V = (self.B * u / self.A).sin();
+        let U = (V * self.gamma_O.cos() + S * self.gamma_O.sin()) / T;
+        let t = (self.H / ((1.0 + U) / (1.0 - U)).sqrt()).powf(1.0 / self.B);
+        let chi = FRAC_PI_2 - 2.0 * t.atan();
+        (
+            self.lon_O
+                - f64::atan2(
+                    S * self.gamma_O.cos() - V * self.gamma_O.sin(),
+                    (self.B * u / self.A).cos(),
+                ) / self.B,
+            chi + self.phi_2_chi_sin_summand_factor * (2.0 * chi).sin()
+                + self.phi_4_chi_sin_summand_factor * (4.0 * chi).sin()
+                + self.phi_6_chi_sin_summand_factor * (6.0 * chi).sin()
+                + self.phi_8_chi_sin_summand_factor * (8.0 * chi).sin(),
+        )
+    }
+}
+
+impl Projection for HotineObliqueMercatorAProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let (u, v) = self.skew_uv(longitude, latitude);
+        (
+            v * self.gamma_c.cos() + u * self.gamma_c.sin() + self.false_e,
+            u * self.gamma_c.cos() - v * self.gamma_c.sin() + self.false_n,
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let v = (easting - self.false_e) * self.gamma_c.cos()
+            - (northing - self.false_n) * self.gamma_c.sin();
+        let u = (northing - self.false_n) * self.gamma_c.cos()
+            + (easting - self.false_e) * self.gamma_c.sin();
+        self.skew_uv_to_rad(u, v)
+    }
+}
+
+impl PseudoSerialize for HotineObliqueMercatorAProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"HotineObliqueMercatorAProjection{{
+    ellipsoid_e: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    A: {}f64,
+    B: {}f64,
+    H: {}f64,
+    gamma_O: {}f64,
+    gamma_c: {}f64,
+    lon_O: {}f64,
+    phi_2_chi_sin_summand_factor: {}f64,
+    phi_4_chi_sin_summand_factor: {}f64,
+    phi_6_chi_sin_summand_factor: {}f64,
+    phi_8_chi_sin_summand_factor: {}f64,
+}}",
+            self.ellipsoid_e,
+            self.false_e,
+            self.false_n,
+            self.A,
+            self.B,
+            self.H,
+            self.gamma_O,
+            self.gamma_c,
+            self.lon_O,
+            self.phi_2_chi_sin_summand_factor,
+            self.phi_4_chi_sin_summand_factor,
+            self.phi_6_chi_sin_summand_factor,
+            self.phi_8_chi_sin_summand_factor,
+        )
+    }
+}
+
+impl DbContstruct for HotineObliqueMercatorAProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = HotineObliqueMercatorParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8811 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8812 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8813 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8814 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8815 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for HotineObliqueMercatorAProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = HotineObliqueMercatorParams::new(
+            getter(8811)?,
+            getter(8812)?,
+            getter(8813)?,
+            getter(8814)?,
+            getter(8815)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+/// Hotine Oblique Mercator (variant B) coordinate operation (EPSG:9815).
+///
+/// Variant B only differs from variant A in that the false easting and northing are applied at the
+/// projection centre, so it is stored as variant A with the false origin moved to the natural origin.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HotineObliqueMercatorBProjection(pub HotineObliqueMercatorAProjection);
+
+impl HotineObliqueMercatorBProjection {
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &HotineObliqueMercatorParams) -> Self {
+        let mut projection = HotineObliqueMercatorAProjection::new(ell, params);
+        let lat_c = params.lat_c();
+        let e_sq = ell.e_squared();
+        let D = projection.B * (1.0 - e_sq).sqrt()
+            / (lat_c.cos() * (1.0 - e_sq * lat_c.sin().powi(2)).sqrt());
+        // for an azimuth of 90° this equals A (lon_c - lon_O), which Guidance Note 7-2 lists
+        // separately to avoid dividing by cos(azimuth)
+        let u_c = (projection.A / projection.B)
+            * f64::atan2((D.powi(2) - 1.0).max(0.0).sqrt(), params.azimuth_c().cos()).abs()
+            * lat_c.signum();
+        projection.false_e -= u_c * params.gamma_c().sin();
+        projection.false_n -= u_c * params.gamma_c().cos();
+        Self(projection)
+    }
+}
+
+impl Projection for HotineObliqueMercatorBProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        self.0.rad_to_projected(longitude, latitude)
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        self.0.projected_to_rad(easting, northing)
+    }
+}
+
+impl PseudoSerialize for HotineObliqueMercatorBProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            "HotineObliqueMercatorBProjection({})",
+            self.0.to_constructed()
+        )
+    }
+}
+
+impl DbContstruct for HotineObliqueMercatorBProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = HotineObliqueMercatorParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8811 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8812 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8813 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8814 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8815 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8816 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8817 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for HotineObliqueMercatorBProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = HotineObliqueMercatorParams::new(
+            getter(8811)?,
+            getter(8812)?,
+            getter(8813)?,
+            getter(8814)?,
+            getter(8815)?,
+            getter(8816)?,
+            getter(8817)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection_a(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    HotineObliqueMercatorAProjection::from_database_params(params, &ell).to_constructed()
+}
+
+pub fn direct_projection_b(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    HotineObliqueMercatorBProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::hotine_oblique_mercator::*;
+    use crate::traits::*;
+
+    fn borneo_rso(false_e: f64, false_n: f64) -> HotineObliqueMercatorParams {
+        HotineObliqueMercatorParams::new(
+            0.069813170,
+            2.007128640,
+            0.930536611,
+            0.927295218,
+            0.99984,
+            false_e,
+            false_n,
+        )
+    }
+
+    #[test]
+    fn hotine_oblique_mercator_a_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6377298.556, 300.8017);
+        let projection = HotineObliqueMercatorAProjection::new(&ell, &borneo_rso(0.0, 0.0));
+        eprintln!("{projection:#?}");
+
+        let easting_goal = 679245.73;
+        let northing_goal = 596562.78;
+        let (easting, northing) = projection.rad_to_projected(2.021187362, 0.094025313);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon, lat) = projection.projected_to_rad(easting_goal, northing_goal);
+        eprintln!("lon: 2.021187362 - {lon}");
+        eprintln!("lat: 0.094025313 - {lat}");
+
+        assert!((lon - 2.021187362).abs() < 1e-8);
+        assert!((lat - 0.094025313).abs() < 1e-8);
+    }
+
+    #[test]
+    fn hotine_oblique_mercator_b_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6377298.556, 300.8017);
+        let projection =
+            HotineObliqueMercatorBProjection::new(&ell, &borneo_rso(590476.87, 442857.65));
+        eprintln!("{projection:#?}");
+
+        let easting_goal = 679245.73;
+        let northing_goal = 596562.78;
+        let (easting, northing) = projection.rad_to_projected(2.021187362, 0.094025313);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon, lat) = projection.projected_to_rad(easting_goal, northing_goal);
+        eprintln!("lon: 2.021187362 - {lon}");
+        eprintln!("lat: 0.094025313 - {lat}");
+
+        assert!((lon - 2.021187362).abs() < 1e-8);
+        assert!((lat - 0.094025313).abs() < 1e-8);
+    }
+
+    #[test]
+    fn hotine_oblique_mercator_b_swiss() {
+        // CH1903+ / LV95, azimuth of 90°
+        let ell = Ellipsoid::from_a_f_inv(6377397.155, 299.1528128);
+        let params = HotineObliqueMercatorParams::new(
+            46.9524055555556f64.to_radians(),
+            7.43958333333333f64.to_radians(),
+            90f64.to_radians(),
+            90f64.to_radians(),
+            1.0,
+            2600000.0,
+            1200000.0,
+        );
+        let projection = HotineObliqueMercatorBProjection::new(&ell, &params);
+
+        let (easting, northing) = projection.deg_to_projected(7.43958333333333, 46.9524055555556);
+        eprintln!("projection centre: {easting}, {northing}");
+        assert!((easting - 2600000.0).abs() < 0.001);
+        assert!((northing - 1200000.0).abs() < 0.001);
+
+        for (lon, lat) in [(6.0, 46.0), (8.5, 47.5), (10.5, 45.8)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {easting}, {northing} -> {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+        }
+    }
+}
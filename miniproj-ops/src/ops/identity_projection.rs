@@ -1,8 +1,14 @@
 //This file is licensed under EUPL v1.2 as part of the Digital Earth Viewer
 
-use crate::traits::Projection;
+use crate::{
+    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+};
 
 /// Parameterless projection that is a no-op in degrees and otherwise converts between degrees and radians.
+///
+/// This is used for geographic CRSs and for the Pseudo Plate Carrée method (EPSG:9825), which
+/// depicts longitude and latitude in degrees as X and Y with the origin at 0°, 0°.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct IdentityProjection;
 
 impl Projection for IdentityProjection {
@@ -22,3 +28,64 @@ impl Projection for IdentityProjection {
         (lon, lat)
     }
 }
+
+impl PseudoSerialize for IdentityProjection {
+    fn to_constructed(&self) -> String {
+        "IdentityProjection".to_owned()
+    }
+}
+
+impl DbContstruct for IdentityProjection {
+    fn from_database_params(_params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        Self
+    }
+}
+
+impl GetterContstruct for IdentityProjection {
+    fn with_db_getter<G>(_getter: G, _ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        Some(Self)
+    }
+}
+
+pub fn direct_projection_pseudo_plate_carree(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    IdentityProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::custom_projection;
+    use crate::ellipsoids::WGS84;
+    use crate::{Direction, Projection};
+
+    use super::IdentityProjection;
+
+    #[test]
+    fn pseudo_plate_carree() {
+        let projection =
+            custom_projection(9825, |_| None, &WGS84).expect("Method not implemented.");
+        assert_eq!(projection.deg_to_projected(-100.5, 24.25), (-100.5, 24.25));
+        let (lon, lat) = projection.projected_to_rad(-100.5, 24.25);
+        assert!((lon - (-100.5f64).to_radians()).abs() < 1e-12);
+        assert!((lat - 24.25f64.to_radians()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn forwarding() {
+        // 7.7 does not survive a round trip through radians, so this checks that the degree methods
+        // are forwarded instead of falling back to the defaults going through radians.
+        assert_ne!(7.7f64.to_radians().to_degrees(), 7.7);
+        let boxed: Box<dyn Projection> = Box::new(IdentityProjection);
+        let projections: [&dyn Projection; 2] = [&boxed, &&IdentityProjection];
+        for projection in projections {
+            assert_eq!(projection.deg_to_projected(7.7, 7.7), (7.7, 7.7));
+            assert_eq!(projection.projected_to_deg(7.7, 7.7), (7.7, 7.7));
+            assert_eq!(
+                projection.transform(Direction::DegToProjected, 7.7, 7.7),
+                (7.7, 7.7)
+            );
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::{PI, TAU};
+
+use crate::{
+    coordinate::{GeocentricCoordinate, Geographic3DCoordinate},
+    traits::InvertibleCoordOperation,
+    CoordOperation, PseudoSerialize,
+};
+
+/// maximum number of iterations of the fixed-point inverses
+const MAX_ITERATIONS: usize = 20;
+
+/// Inverse of a coordinate operation, applying `O` in reverse.
+///
+/// The inverse of an `InvertibleCoordOperation<F, T>` is an `InvertibleCoordOperation<T, F>`, so
+/// that an operation can be used in both directions without constructing its reverse parameters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Inverse<O>(pub O);
+
+impl<O> Inverse<O> {
+    pub const fn new(operation: O) -> Self {
+        Self(operation)
+    }
+
+    /// Get the operation in the forward direction.
+    pub fn into_inner(self) -> O {
+        self.0
+    }
+}
+
+impl<F, T, O: InvertibleCoordOperation<F, T>> CoordOperation<T, F> for Inverse<O> {
+    fn apply(&self, from: T) -> F {
+        self.0.apply_inverse(from)
+    }
+}
+
+impl<F, T, O: InvertibleCoordOperation<F, T>> InvertibleCoordOperation<T, F> for Inverse<O> {
+    fn apply_inverse(&self, to: F) -> T {
+        self.0.apply(to)
+    }
+}
+
+impl<O: PseudoSerialize> PseudoSerialize for Inverse<O> {
+    fn to_constructed(&self) -> String {
+        format!("Inverse({})", self.0.to_constructed())
+    }
+}
+
+/// Applies the geographic operation `operation` in reverse by fixed-point iteration, which
+/// converges for operations that change coordinates by a small amount, like datum
+/// transformations. Longitude & latitude in radians.
+pub fn iterative_geographic_inverse<O>(
+    operation: &O,
+    to: Geographic3DCoordinate,
+) -> Geographic3DCoordinate
+where
+    O: CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate> + ?Sized,
+{
+    let mut from = to;
+    for _ in 0..MAX_ITERATIONS {
+        let at = operation.apply(from);
+        let d_lon = (at.lon - to.lon + PI).rem_euclid(TAU) - PI;
+        let (d_lat, d_height) = (at.lat - to.lat, at.height - to.height);
+        from =
+            Geographic3DCoordinate::new(from.lon - d_lon, from.lat - d_lat, from.height - d_height);
+        if d_lon.abs() < 1e-14 && d_lat.abs() < 1e-14 && d_height.abs() < 1e-7 {
+            break;
+        }
+    }
+    from
+}
+
+/// Applies the geocentric operation `operation` in reverse by fixed-point iteration, which
+/// converges for operations that change coordinates by a small amount, like datum
+/// transformations. Cartesian coordinates in metres.
+pub fn iterative_geocentric_inverse<O>(
+    operation: &O,
+    to: GeocentricCoordinate,
+) -> GeocentricCoordinate
+where
+    O: CoordOperation<GeocentricCoordinate, GeocentricCoordinate> + ?Sized,
+{
+    let mut from = to;
+    for _ in 0..MAX_ITERATIONS {
+        let at = operation.apply(from);
+        let (d_x, d_y, d_z) = (at.x - to.x, at.y - to.y, at.z - to.z);
+        from = GeocentricCoordinate::new(from.x - d_x, from.y - d_y, from.z - d_z);
+        if d_x.abs() < 1e-7 && d_y.abs() < 1e-7 && d_z.abs() < 1e-7 {
+            break;
+        }
+    }
+    from
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::{GeocentricCoordinate, Geographic3DCoordinate};
+    use crate::ellipsoid::Ellipsoid;
+    use crate::helmert::*;
+    use crate::inverse::*;
+    use crate::traits::*;
+
+    #[test]
+    fn inverse_consistency() {
+        // WGS 72 to WGS 84 (3) example of the guidance note
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let params = HelmertParams::new(0.0, 0.0, 4.5, 0.0, 0.0, 0.554 * arcsec, 0.219e-6);
+        let helmert = HelmertTransformation::new_position_vector(&params);
+        let from = GeocentricCoordinate::new(3657660.66, 255768.55, 5201382.11);
+        let to = helmert.apply(from);
+        let back = Inverse(helmert).apply(to);
+        eprintln!("{back:?}");
+        assert!((back.x - from.x).abs() < 1e-6);
+        assert!((back.y - from.y).abs() < 1e-6);
+        assert!((back.z - from.z).abs() < 1e-6);
+        assert_eq!(Inverse(helmert).apply_inverse(from), to);
+
+        let wgs72 = Ellipsoid::from_a_f_inv(6378135.0, 298.26);
+        let wgs84 = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let geographic = GeographicHelmertTransformation::new(helmert, &wgs72, &wgs84);
+        let from = Geographic3DCoordinate::from_deg(4.0, 55.0, 0.0);
+        let back = Inverse(geographic).apply(geographic.apply(from));
+        assert!((back.lon - from.lon).abs() < 1e-12);
+        assert!((back.lat - from.lat).abs() < 1e-12);
+        assert!((back.height - from.height).abs() < 1e-6);
+        // the reverse parameters of the registry approximate the inverse
+        let reversed = geographic.reversed().apply(geographic.apply(from));
+        assert!((reversed.lat - back.lat).abs() < 1e-9);
+    }
+}
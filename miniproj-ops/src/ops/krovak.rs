@@ -0,0 +1,348 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::FRAC_PI_4;
+
+use crate::{
+    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KrovakParams {
+    /// latitude of projection centre
+    lat_c: f64,
+    /// longitude of origin
+    lon_orig: f64,
+    /// co-latitude of cone axis
+    alpha_c: f64,
+    /// latitude of pseudo standard parallel
+    lat_p: f64,
+    /// scale factor on pseudo standard parallel
+    k_p: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(KrovakParams {
+    lat_c,
+    lon_orig,
+    alpha_c,
+    lat_p,
+    k_p,
+    false_e,
+    false_n
+});
+
+impl KrovakParams {
+    pub const fn new(
+        lat_c: f64,
+        lon_orig: f64,
+        alpha_c: f64,
+        lat_p: f64,
+        k_p: f64,
+        false_e: f64,
+        false_n: f64,
+    ) -> Self {
+        Self {
+            lat_c,
+            lon_orig,
+            alpha_c,
+            lat_p,
+            k_p,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get latitude of projection centre, radians.
+    pub fn lat_c(&self) -> f64 {
+        self.lat_c
+    }
+
+    /// Get longitude of origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get co-latitude of cone axis, radians.
+    pub fn alpha_c(&self) -> f64 {
+        self.alpha_c
+    }
+
+    /// Get latitude of pseudo standard parallel, radians.
+    pub fn lat_p(&self) -> f64 {
+        self.lat_p
+    }
+
+    /// Get scale factor on pseudo standard parallel.
+    pub fn k_p(&self) -> f64 {
+        self.k_p
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Krovak coordinate operation (EPSG:9819).
+///
+/// As defined by EPSG, the projected coordinates are `(westing, southing)`, both increasing
+/// towards the south-west.
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct KrovakProjection {
+    pub ellipsoid_e: f64,
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+
+    pub B: f64,
+    pub t_O: f64,
+    pub n: f64,
+    pub r_O: f64,
+    pub alpha_c: f64,
+    pub lat_p: f64,
+}
+
+impl_param_eq_hash!(KrovakProjection {
+    ellipsoid_e,
+    lon_orig,
+    false_e,
+    false_n,
+    B,
+    t_O,
+    n,
+    r_O,
+    alpha_c,
+    lat_p,
+});
+
+impl KrovakProjection {
+    const MAX_ITERATIONS: usize = 16;
+
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &KrovakParams) -> Self {
+        let e = ell.e();
+        let e_sq = ell.e_squared();
+        let lat_c = params.lat_c();
+        let A = ell.a() * (1.0 - e_sq).sqrt() / (1.0 - e_sq * lat_c.sin().powi(2));
+        let B = (1.0 + e_sq * lat_c.cos().powi(4) / (1.0 - e_sq)).sqrt();
+        let gamma_O = (lat_c.sin() / B).asin();
+        let t_O = (FRAC_PI_4 + gamma_O / 2.0).tan()
+            * ((1.0 + e * lat_c.sin()) / (1.0 - e * lat_c.sin())).powf(e * B / 2.0)
+            / (FRAC_PI_4 + lat_c / 2.0).tan().powf(B);
+        Self {
+            ellipsoid_e: e,
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+
+            B,
+            t_O,
+            n: params.lat_p().sin(),
+            r_O: params.k_p() * A / params.lat_p().tan(),
+            alpha_c: params.alpha_c(),
+            lat_p: params.lat_p(),
+        }
+    }
+}
+
+impl Projection for KrovakProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians, returns `(westing, southing)`
+    #[allow(non_snake_case)]
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let e_sin_lat = self.ellipsoid_e * latitude.sin();
+        let U = 2.0
+            * ((self.t_O * (latitude / 2.0 + FRAC_PI_4).tan().powf(self.B)
+                / ((1.0 + e_sin_lat) / (1.0 - e_sin_lat)).powf(self.ellipsoid_e * self.B / 2.0))
+            .atan()
+                - FRAC_PI_4);
+        let V = self.B * (self.lon_orig - longitude);
+        let T = (self.alpha_c.cos() * U.sin() + self.alpha_c.sin() * U.cos() * V.cos()).asin();
+        // the atan2 form of D is valid beyond ±90° pseudo-longitude from the central line
+        let D = f64::atan2(
+            U.cos() * V.sin() / T.cos(),
+            (self.alpha_c.cos() * T.sin() - U.sin()) / (self.alpha_c.sin() * T.cos()),
+        );
+        let theta = self.n * D;
+        let r = self.r_O * (FRAC_PI_4 + self.lat_p / 2.0).tan().powf(self.n)
+            / (T / 2.0 + FRAC_PI_4).tan().powf(self.n);
+        (
+            r * theta.sin() + self.false_e,
+            r * theta.cos() + self.false_n,
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// expects `(westing, southing)`, returns longitude & latitude in radians
+    #[allow(non_snake_case)]
+    fn projected_to_rad(&self, westing: f64, southing: f64) -> (f64, f64) {
+        let X_p = southing - self.false_n;
+        let Y_p = westing - self.false_e;
+        let r = X_p.hypot(Y_p);
+        let theta = f64::atan2(Y_p, X_p);
+        let D = theta / self.n;
+        let T = 2.0
+            * (((self.r_O / r).powf(1.0 / self.n) * (FRAC_PI_4 + self.lat_p / 2.0).tan()).atan()
+                - FRAC_PI_4);
+        let U = (self.alpha_c.cos() * T.sin() - self.alpha_c.sin() * T.cos() * D.cos()).asin();
+        let V = (T.cos() * D.sin() / U.cos()).asin();
+
+        let factor = self.t_O.powf(-1.0 / self.B) * (U / 2.0 + FRAC_PI_4).tan().powf(1.0 / self.B);
+        let mut latitude = U;
+        for _ in 0..Self::MAX_ITERATIONS {
+            let e_sin_lat = self.ellipsoid_e * latitude.sin();
+            let next = 2.0
+                * ((factor * ((1.0 + e_sin_lat) / (1.0 - e_sin_lat)).powf(self.ellipsoid_e / 2.0))
+                    .atan()
+                    - FRAC_PI_4);
+            let converged = (next - latitude).abs() < 1e-14;
+            latitude = next;
+            if converged {
+                break;
+            }
+        }
+        (self.lon_orig - V / self.B, latitude)
+    }
+}
+
+impl PseudoSerialize for KrovakProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"KrovakProjection{{
+    ellipsoid_e: {}f64,
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    B: {}f64,
+    t_O: {}f64,
+    n: {}f64,
+    r_O: {}f64,
+    alpha_c: {}f64,
+    lat_p: {}f64,
+}}",
+            self.ellipsoid_e,
+            self.lon_orig,
+            self.false_e,
+            self.false_n,
+            self.B,
+            self.t_O,
+            self.n,
+            self.r_O,
+            self.alpha_c,
+            self.lat_p,
+        )
+    }
+}
+
+impl DbContstruct for KrovakProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = KrovakParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8811 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8833 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 1036 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8818 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8819 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for KrovakProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = KrovakParams::new(
+            getter(8811)?,
+            getter(8833)?,
+            getter(1036)?,
+            getter(8818)?,
+            getter(8819)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    KrovakProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::krovak::*;
+    use crate::traits::*;
+
+    #[test]
+    fn krovak_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6377397.155, 299.15281);
+        // S-JTSK (Ferro) / Krovak, longitudes relative to Ferro
+        let params = KrovakParams::new(
+            49.5f64.to_radians(),
+            42.5f64.to_radians(),
+            (30.0 + 17.0 / 60.0 + 17.30311 / 3600.0f64).to_radians(),
+            78.5f64.to_radians(),
+            0.9999,
+            0.0,
+            0.0,
+        );
+
+        let projection = KrovakProjection::new(&ell, &params);
+        eprintln!("{projection:#?}");
+
+        let westing_goal = 568991.00;
+        let southing_goal = 1050538.64;
+        let lon = 34.0 + 30.0 / 60.0 + 59.179 / 3600.0;
+        let lat = 50.0 + 12.0 / 60.0 + 32.442 / 3600.0;
+        let (westing, southing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("westing: {westing_goal} - {westing}");
+        eprintln!("southing: {southing_goal} - {southing}");
+
+        assert!((westing - westing_goal).abs() < 0.01);
+        assert!((southing - southing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(westing_goal, southing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+    }
+}
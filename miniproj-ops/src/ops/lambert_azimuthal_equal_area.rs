@@ -1,8 +1,13 @@
 //This file is licensed under EUPL v1.2 as part of the Digital Earth Viewer
 
-use crate::{ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, PseudoSerialize};
+use crate::{
+    ellipsoid::{authalic_q, Ellipsoid},
+    traits::GetterContstruct,
+    DbContstruct, PseudoSerialize,
+};
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LambertAzimuthalEqualAreaParams {
     /// longitude of natural origin
     lon_orig: f64,
@@ -14,6 +19,13 @@ pub struct LambertAzimuthalEqualAreaParams {
     false_n: f64,
 }
 
+impl_param_eq_hash!(LambertAzimuthalEqualAreaParams {
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n
+});
+
 impl LambertAzimuthalEqualAreaParams {
     pub const fn new(lon_orig: f64, lat_orig: f64, false_e: f64, false_n: f64) -> Self {
         Self {
@@ -62,21 +74,24 @@ pub struct LambertAzimuthalEqualAreaProjection {
     pub D: f64,
 }
 
+impl_param_eq_hash!(LambertAzimuthalEqualAreaProjection {
+    lon_orig,
+    false_e,
+    false_n,
+    ellipsoid_e,
+    ellipsoid_e_squared,
+    q_P,
+    beta_O,
+    R_q,
+    D,
+});
+
 impl LambertAzimuthalEqualAreaProjection {
     #[allow(non_snake_case)]
     pub fn new(ell: &Ellipsoid, params: &LambertAzimuthalEqualAreaParams) -> Self {
-        let q_P = (1.0 - ell.e_squared())
-            * ((1.0 / (1.0 - ell.e_squared()))
-                - ((0.5 / ell.e()) * f64::ln((1.0 - ell.e()) / (1.0 + ell.e()))));
-
-        let q_O = (1.0 - ell.e_squared())
-            * ((params.lat_orig().sin()
-                / (1.0 - ell.e_squared() * params.lat_orig().sin().powi(2)))
-                - ((0.5 / ell.e())
-                    * f64::ln(
-                        (1.0 - ell.e() * params.lat_orig().sin())
-                            / (1.0 + ell.e() * params.lat_orig().sin()),
-                    )));
+        let q_P = authalic_q(ell.e(), ell.e_squared(), 1.0);
+
+        let q_O = authalic_q(ell.e(), ell.e_squared(), params.lat_orig().sin());
 
         let beta_O = (q_O / q_P).asin();
 
@@ -108,13 +123,7 @@ impl crate::traits::Projection for LambertAzimuthalEqualAreaProjection {
     /// longitude & latitude in radians
     #[allow(non_snake_case)]
     fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
-        let q = (1.0 - self.ellipsoid_e_squared)
-            * ((latitude.sin() / (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)))
-                - ((0.5 / self.ellipsoid_e)
-                    * f64::ln(
-                        (1.0 - self.ellipsoid_e * latitude.sin())
-                            / (1.0 + self.ellipsoid_e * latitude.sin()),
-                    )));
+        let q = authalic_q(self.ellipsoid_e, self.ellipsoid_e_squared, latitude.sin());
 
         let beta = (q / self.q_P).asin();
 
@@ -249,6 +258,149 @@ impl GetterContstruct for LambertAzimuthalEqualAreaProjection {
     }
 }
 
+/// Lambert Azimuthal Equal Area (Spherical) coordinate operation (EPSG:1027).
+///
+/// On an ellipsoid the radius of the authalic sphere is used.
+#[derive(Copy, Clone, Debug)]
+pub struct LambertAzimuthalEqualAreaSphericalProjection {
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+    pub radius: f64,
+    pub sin_lat_orig: f64,
+    pub cos_lat_orig: f64,
+}
+
+impl_param_eq_hash!(LambertAzimuthalEqualAreaSphericalProjection {
+    lon_orig,
+    false_e,
+    false_n,
+    radius,
+    sin_lat_orig,
+    cos_lat_orig,
+});
+
+impl LambertAzimuthalEqualAreaSphericalProjection {
+    pub fn new(ell: &Ellipsoid, params: &LambertAzimuthalEqualAreaParams) -> Self {
+        Self {
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+            radius: ell.rad_auth(),
+            sin_lat_orig: params.lat_orig().sin(),
+            cos_lat_orig: params.lat_orig().cos(),
+        }
+    }
+}
+
+impl crate::traits::Projection for LambertAzimuthalEqualAreaSphericalProjection {
+    /// as per USGS Professional Paper 1395 – Map Projections – A Working Manual
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let d_lon = longitude - self.lon_orig;
+        let k = (2.0
+            / (1.0
+                + self.sin_lat_orig * latitude.sin()
+                + self.cos_lat_orig * latitude.cos() * d_lon.cos()))
+        .sqrt();
+        (
+            self.false_e + self.radius * k * latitude.cos() * d_lon.sin(),
+            self.false_n
+                + self.radius
+                    * k
+                    * (self.cos_lat_orig * latitude.sin()
+                        - self.sin_lat_orig * latitude.cos() * d_lon.cos()),
+        )
+    }
+
+    /// as per USGS Professional Paper 1395 – Map Projections – A Working Manual
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let x = easting - self.false_e;
+        let y = northing - self.false_n;
+        let rho = x.hypot(y);
+        if rho == 0.0 {
+            return (self.lon_orig, self.sin_lat_orig.asin());
+        }
+        let c = 2.0 * (rho / (2.0 * self.radius)).clamp(-1.0, 1.0).asin();
+        (
+            self.lon_orig
+                + f64::atan2(
+                    x * c.sin(),
+                    rho * self.cos_lat_orig * c.cos() - y * self.sin_lat_orig * c.sin(),
+                ),
+            (c.cos() * self.sin_lat_orig + y * c.sin() * self.cos_lat_orig / rho)
+                .clamp(-1.0, 1.0)
+                .asin(),
+        )
+    }
+}
+
+impl PseudoSerialize for LambertAzimuthalEqualAreaSphericalProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"LambertAzimuthalEqualAreaSphericalProjection{{
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    radius: {}f64,
+    sin_lat_orig: {}f64,
+    cos_lat_orig: {}f64,
+}}",
+            self.lon_orig,
+            self.false_e,
+            self.false_n,
+            self.radius,
+            self.sin_lat_orig,
+            self.cos_lat_orig,
+        )
+    }
+}
+
+impl DbContstruct for LambertAzimuthalEqualAreaSphericalProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = LambertAzimuthalEqualAreaParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for LambertAzimuthalEqualAreaSphericalProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = LambertAzimuthalEqualAreaParams::new(
+            getter(8802)?,
+            getter(8801)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection_spherical(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    LambertAzimuthalEqualAreaSphericalProjection::from_database_params(params, &ell)
+        .to_constructed()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -278,4 +430,37 @@ mod tests {
 
         assert!((northing - northing_goal).abs() < 0.05);
     }
+    #[test]
+    fn lambert_azimuthal_equal_area_spherical_consistency() {
+        // NSIDC EASE-Grid North
+        let ell = Ellipsoid::from_a_b(6371228.0, 6371228.0);
+        let params = LambertAzimuthalEqualAreaParams::new(0.0, 90.0f64.to_radians(), 0.0, 0.0);
+
+        let projection = LambertAzimuthalEqualAreaSphericalProjection::new(&ell, &params);
+        // the distance from the pole is 2R·sin(c/2) for a colatitude c
+        let (easting, northing) = projection.deg_to_projected(0.0, 0.0);
+        eprintln!("{easting}, {northing}");
+        assert!(easting.abs() < 0.01);
+        assert!((northing + 9010277.05).abs() < 0.01);
+        let (easting, northing) = projection.deg_to_projected(90.0, 60.0);
+        eprintln!("{easting}, {northing}");
+        assert!((easting - 3297990.29).abs() < 0.01);
+        assert!(northing.abs() < 0.01);
+
+        // US National Atlas Equal Area
+        let params = LambertAzimuthalEqualAreaParams::new(
+            (-100.0f64).to_radians(),
+            45.0f64.to_radians(),
+            0.0,
+            0.0,
+        );
+        let projection = LambertAzimuthalEqualAreaSphericalProjection::new(&ell, &params);
+        for (lon, lat) in [(-100.0, 45.0), (-77.0, 38.9), (-150.0, 61.2), (10.0, 54.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+        }
+    }
 }
@@ -5,7 +5,16 @@ use crate::{
 };
 use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
 
+/// Jacobian of a Lambert conic projection given `r sin(theta)` and `r cos(theta)`,
+/// using `dr/dlat = -n r (1 - e²) / (cos(lat) (1 - e² sin²(lat)))` and `dtheta/dlon = n`.
+fn conic_jacobian(n: f64, e: f64, lat: f64, r_sin: f64, r_cos: f64) -> [[f64; 2]; 2] {
+    let e_sq = e * e;
+    let w = (1.0 - e_sq) / (lat.cos() * (1.0 - e_sq * lat.sin().powi(2)));
+    [[n * r_cos, -n * w * r_sin], [n * r_sin, n * w * r_cos]]
+}
+
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LambertConic2SPParams {
     /// longitude of false origin
     lon_orig: f64,
@@ -21,6 +30,15 @@ pub struct LambertConic2SPParams {
     false_n: f64,
 }
 
+impl_param_eq_hash!(LambertConic2SPParams {
+    lon_orig,
+    lat_orig,
+    lat_p1,
+    lat_p2,
+    false_e,
+    false_n
+});
+
 impl LambertConic2SPParams {
     pub fn new(
         lon_orig: f64,
@@ -89,6 +107,18 @@ pub struct LambertConic2SPProjection {
     pub F: f64,
 }
 
+impl_param_eq_hash!(LambertConic2SPProjection {
+    ellipsoid_e,
+    ellipsoid_a,
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n,
+    n,
+    r_F,
+    F,
+});
+
 impl LambertConic2SPProjection {
     const MAX_ITERATIONS: usize = 4;
 
@@ -96,7 +126,10 @@ impl LambertConic2SPProjection {
     pub fn new(ell: &Ellipsoid, params: &LambertConic2SPParams) -> Self {
         let n;
         let F;
-        let r_F;
+        let t_F = (FRAC_PI_4 - params.lat_orig() / 2f64).tan()
+            / ((1f64 - ell.e() * params.lat_orig().sin())
+                / (1f64 + ell.e() * params.lat_orig().sin()))
+            .powf(ell.e() / 2f64);
         if params.lat_p1() == params.lat_p2() {
             let m_O = params.lat_p1().cos()
                 / (1f64 - ell.e_squared() * params.lat_p1().sin().powi(2)).sqrt();
@@ -107,7 +140,6 @@ impl LambertConic2SPProjection {
                 .powf(ell.e() / 2f64);
             n = params.lat_p1().sin();
             F = m_O / (n * t_O.powf(n));
-            r_F = ell.a() * F * t_O.powf(n);
         } else {
             let m1 = params.lat_p1().cos()
                 / (1f64 - ell.e_squared() * params.lat_p1().sin().powi(2)).sqrt();
@@ -122,14 +154,11 @@ impl LambertConic2SPProjection {
                 / ((1f64 - ell.e() * params.lat_p2().sin())
                     / (1f64 + ell.e() * params.lat_p2().sin()))
                 .powf(ell.e() / 2f64);
-            let t_F = (FRAC_PI_4 - params.lat_orig() / 2f64).tan()
-                / ((1f64 - ell.e() * params.lat_orig().sin())
-                    / (1f64 + ell.e() * params.lat_orig().sin()))
-                .powf(ell.e() / 2f64);
             n = (m1.ln() - m2.ln()) / (t1.ln() - t2.ln());
             F = m1 / (n * t1.powf(n));
-            r_F = ell.a() * F * t_F.powf(n);
         }
+        // the false origin need not lie on the standard parallels, even if they coincide
+        let r_F = ell.a() * F * t_F.powf(n);
         Self {
             ellipsoid_e: ell.e(),
             ellipsoid_a: ell.a(),
@@ -188,6 +217,17 @@ impl Projection for LambertConic2SPProjection {
         }
         (theta_ / self.n + self.lon_orig, phi)
     }
+
+    fn jacobian(&self, longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        let (x, y) = self.rad_to_projected(longitude, latitude);
+        conic_jacobian(
+            self.n,
+            self.ellipsoid_e,
+            latitude,
+            x - self.false_e,
+            self.r_F - (y - self.false_n),
+        )
+    }
 }
 
 impl PseudoSerialize for LambertConic2SPProjection {
@@ -266,7 +306,297 @@ impl GetterContstruct for LambertConic2SPProjection {
     }
 }
 
+/// EPSG:9803: Lambert Conic Conformal (2SP Belgium).
+///
+/// The regular 2SP case with the cone rotated by 29.2985 seconds, which is stored as an offset of
+/// the longitude of false origin.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LambertConic2SPBelgiumProjection(pub LambertConic2SPProjection);
+
+impl LambertConic2SPBelgiumProjection {
+    /// Rotation of the cone, radians.
+    const ALPHA: f64 = 29.2985 / 3600.0 * std::f64::consts::PI / 180.0;
+
+    pub fn new(ell: &Ellipsoid, params: &LambertConic2SPParams) -> Self {
+        let mut projection = LambertConic2SPProjection::new(ell, params);
+        // n (lon - lon_orig) - alpha = n (lon - (lon_orig + alpha / n))
+        projection.lon_orig += Self::ALPHA / projection.n;
+        Self(projection)
+    }
+}
+
+impl Projection for LambertConic2SPBelgiumProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – May 2022
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        self.0.rad_to_projected(longitude, latitude)
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – May 2022
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        self.0.projected_to_rad(easting, northing)
+    }
+
+    fn jacobian(&self, longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        self.0.jacobian(longitude, latitude)
+    }
+}
+
+impl PseudoSerialize for LambertConic2SPBelgiumProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            "LambertConic2SPBelgiumProjection({})",
+            self.0.to_constructed()
+        )
+    }
+}
+
+impl DbContstruct for LambertConic2SPBelgiumProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = LambertConic2SPParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8822 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8821 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8823 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8824 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8826 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8827 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for LambertConic2SPBelgiumProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = LambertConic2SPParams::new(
+            getter(8822)?,
+            getter(8821)?,
+            getter(8823)?,
+            getter(8824)?,
+            getter(8826)?,
+            getter(8827)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LambertConic2SPMichiganParams {
+    /// longitude of false origin
+    lon_orig: f64,
+    /// latitude of false origin
+    lat_orig: f64,
+    /// latitude of 1st standard parallel
+    lat_p1: f64,
+    /// latitude of 2nd standard parallel
+    lat_p2: f64,
+    /// easting at false origin
+    false_e: f64,
+    /// northing at false origin
+    false_n: f64,
+    /// ellipsoid scaling factor
+    ellipsoid_k: f64,
+}
+
+impl_param_eq_hash!(LambertConic2SPMichiganParams {
+    lon_orig,
+    lat_orig,
+    lat_p1,
+    lat_p2,
+    false_e,
+    false_n,
+    ellipsoid_k,
+});
+
+impl LambertConic2SPMichiganParams {
+    pub fn new(
+        lon_orig: f64,
+        lat_orig: f64,
+        lat_p1: f64,
+        lat_p2: f64,
+        false_e: f64,
+        false_n: f64,
+        ellipsoid_k: f64,
+    ) -> Self {
+        Self {
+            lon_orig,
+            lat_orig,
+            lat_p1,
+            lat_p2,
+            false_e,
+            false_n,
+            ellipsoid_k,
+        }
+    }
+
+    /// Get longitude of false origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of false origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get latitude of 1st standard parallel.
+    pub fn lat_p1(&self) -> f64 {
+        self.lat_p1
+    }
+
+    /// Get latitude of 2nd standard parallel.
+    pub fn lat_p2(&self) -> f64 {
+        self.lat_p2
+    }
+
+    /// Get easting at false origin.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get northing at false origin.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+
+    /// Get ellipsoid scaling factor.
+    pub fn ellipsoid_k(&self) -> f64 {
+        self.ellipsoid_k
+    }
+}
+
+/// EPSG:1051: Lambert Conic Conformal (2SP Michigan).
+///
+/// The regular 2SP case on an ellipsoid whose semi-major axis is scaled by the ellipsoid scaling
+/// factor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LambertConic2SPMichiganProjection(pub LambertConic2SPProjection);
+
+impl LambertConic2SPMichiganProjection {
+    pub fn new(ell: &Ellipsoid, params: &LambertConic2SPMichiganParams) -> Self {
+        let mut projection = LambertConic2SPProjection::new(
+            ell,
+            &LambertConic2SPParams::new(
+                params.lon_orig(),
+                params.lat_orig(),
+                params.lat_p1(),
+                params.lat_p2(),
+                params.false_e(),
+                params.false_n(),
+            ),
+        );
+        // r = a K F t^n, F and n do not depend on a
+        projection.ellipsoid_a *= params.ellipsoid_k();
+        projection.r_F *= params.ellipsoid_k();
+        Self(projection)
+    }
+}
+
+impl Projection for LambertConic2SPMichiganProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – May 2022
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        self.0.rad_to_projected(longitude, latitude)
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – May 2022
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        self.0.projected_to_rad(easting, northing)
+    }
+
+    fn jacobian(&self, longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        self.0.jacobian(longitude, latitude)
+    }
+}
+
+impl PseudoSerialize for LambertConic2SPMichiganProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            "LambertConic2SPMichiganProjection({})",
+            self.0.to_constructed()
+        )
+    }
+}
+
+impl DbContstruct for LambertConic2SPMichiganProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = LambertConic2SPMichiganParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8822 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8821 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8823 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8824 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8826 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8827 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 1038 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for LambertConic2SPMichiganProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = LambertConic2SPMichiganParams::new(
+            getter(8822)?,
+            getter(8821)?,
+            getter(8823)?,
+            getter(8824)?,
+            getter(8826)?,
+            getter(8827)?,
+            getter(1038)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LambertConic1SPAParams {
     /// longitude of false origin
     lon_nat_orig: f64,
@@ -280,6 +610,14 @@ pub struct LambertConic1SPAParams {
     false_n: f64,
 }
 
+impl_param_eq_hash!(LambertConic1SPAParams {
+    lon_nat_orig,
+    lat_nat_orig,
+    k_nat_orig,
+    false_e,
+    false_n,
+});
+
 impl LambertConic1SPAParams {
     pub fn new(
         lon_nat_orig: f64,
@@ -338,6 +676,16 @@ pub struct LambertConic1SPAProjection {
     pub ellipsoid_e: f64,
 }
 
+impl_param_eq_hash!(LambertConic1SPAProjection {
+    false_e,
+    false_n,
+    r_O,
+    lon_O,
+    n,
+    t_r_fac,
+    ellipsoid_e,
+});
+
 impl LambertConic1SPAProjection {
     const MAX_ITERATIONS: usize = 4;
 
@@ -396,6 +744,17 @@ impl Projection for LambertConic1SPAProjection {
             self.false_n + self.r_O - r * theta.cos(),
         )
     }
+
+    fn jacobian(&self, lon: f64, lat: f64) -> [[f64; 2]; 2] {
+        let (x, y) = self.rad_to_projected(lon, lat);
+        conic_jacobian(
+            self.n,
+            self.ellipsoid_e,
+            lat,
+            x - self.false_e,
+            self.r_O - (y - self.false_n),
+        )
+    }
 }
 
 impl PseudoSerialize for LambertConic1SPAProjection {
@@ -466,14 +825,223 @@ impl GetterContstruct for LambertConic1SPAProjection {
     }
 }
 
+/// EPSG:9817: Lambert Conic Near-Conformal.
+///
+/// Uses the truncated series of the Levant grids instead of the conformal formulas.
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct LambertConicNearConformalProjection {
+    pub lon_orig: f64,
+    pub sin_lat_orig: f64,
+    pub k_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+
+    pub A: f64,
+    pub A_: f64,
+    pub B_: f64,
+    pub C_: f64,
+    pub D_: f64,
+    pub E_: f64,
+    pub r_O: f64,
+    pub s_O: f64,
+}
+
+impl_param_eq_hash!(LambertConicNearConformalProjection {
+    lon_orig,
+    sin_lat_orig,
+    k_orig,
+    false_e,
+    false_n,
+    A,
+    A_,
+    B_,
+    C_,
+    D_,
+    E_,
+    r_O,
+    s_O,
+});
+
+impl LambertConicNearConformalProjection {
+    const MAX_ITERATIONS: usize = 8;
+
+    #[allow(non_snake_case)]
+    pub fn new(ell: &Ellipsoid, params: &LambertConic1SPAParams) -> Self {
+        let lat_orig = params.lat_nat_orig();
+        let n = ell.f() / (2.0 - ell.f());
+        let a = ell.a();
+        let mut projection = Self {
+            lon_orig: params.lon_nat_orig(),
+            sin_lat_orig: lat_orig.sin(),
+            k_orig: params.k_nat_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+
+            A: 1.0 / (6.0 * ell.rho(lat_orig) * ell.ny(lat_orig)),
+            // per radian instead of per degree as in Guidance Note 7-2
+            A_: a
+                * (1.0 - n
+                    + 5.0 * (n.powi(2) - n.powi(3)) / 4.0
+                    + 81.0 * (n.powi(4) - n.powi(5)) / 64.0),
+            B_: 3.0
+                * a
+                * (n - n.powi(2) + 7.0 * (n.powi(3) - n.powi(4)) / 8.0 + 55.0 * n.powi(5) / 64.0)
+                / 2.0,
+            C_: 15.0 * a * (n.powi(2) - n.powi(3) + 3.0 * (n.powi(4) - n.powi(5)) / 4.0) / 16.0,
+            D_: 35.0 * a * (n.powi(3) - n.powi(4) + 11.0 * n.powi(5) / 16.0) / 48.0,
+            E_: 315.0 * a * (n.powi(4) - n.powi(5)) / 512.0,
+            r_O: params.k_nat_orig() * ell.ny(lat_orig) / lat_orig.tan(),
+            s_O: 0.0,
+        };
+        projection.s_O = projection.s(lat_orig);
+        projection
+    }
+
+    /// Meridian distance from the equator.
+    fn s(&self, latitude: f64) -> f64 {
+        self.A_ * latitude - self.B_ * (2.0 * latitude).sin() + self.C_ * (4.0 * latitude).sin()
+            - self.D_ * (6.0 * latitude).sin()
+            + self.E_ * (8.0 * latitude).sin()
+    }
+}
+
+impl Projection for LambertConicNearConformalProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – May 2022
+    /// longitude & latitude in radians
+    #[allow(non_snake_case)]
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let m = self.s(latitude) - self.s_O;
+        let M = self.k_orig * (m + self.A * m.powi(3));
+        let r = self.r_O - M;
+        let theta = (longitude - self.lon_orig) * self.sin_lat_orig;
+        (
+            self.false_e + r * theta.sin(),
+            self.false_n + M + r * theta.sin() * (theta / 2.0).tan(),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – May 2022
+    /// longitude & latitude in radians, using the iterative solution
+    #[allow(non_snake_case)]
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let theta_ = f64::atan2(easting - self.false_e, self.r_O - (northing - self.false_n));
+        let r_ = self.sin_lat_orig.signum()
+            * (easting - self.false_e).hypot(self.r_O - (northing - self.false_n));
+        let M_ = self.r_O - r_;
+
+        let mut m_ = M_;
+        for _ in 0..Self::MAX_ITERATIONS {
+            m_ -= (M_ - self.k_orig * m_ - self.k_orig * self.A * m_.powi(3))
+                / (-self.k_orig - 3.0 * self.k_orig * self.A * m_.powi(2));
+        }
+        let mut lat_ = self.sin_lat_orig.asin() + m_ / self.A_;
+        for _ in 0..Self::MAX_ITERATIONS {
+            lat_ += (m_ + self.s_O - self.s(lat_)) / self.A_;
+        }
+        (self.lon_orig + theta_ / self.sin_lat_orig, lat_)
+    }
+}
+
+impl PseudoSerialize for LambertConicNearConformalProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"LambertConicNearConformalProjection{{
+    lon_orig: {}f64,
+    sin_lat_orig: {}f64,
+    k_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    A: {}f64,
+    A_: {}f64,
+    B_: {}f64,
+    C_: {}f64,
+    D_: {}f64,
+    E_: {}f64,
+    r_O: {}f64,
+    s_O: {}f64,
+}}",
+            self.lon_orig,
+            self.sin_lat_orig,
+            self.k_orig,
+            self.false_e,
+            self.false_n,
+            self.A,
+            self.A_,
+            self.B_,
+            self.C_,
+            self.D_,
+            self.E_,
+            self.r_O,
+            self.s_O,
+        )
+    }
+}
+
+impl DbContstruct for LambertConicNearConformalProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = LambertConic1SPAParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8805 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for LambertConicNearConformalProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = LambertConic1SPAParams::new(
+            getter(8802)?,
+            getter(8801)?,
+            getter(8805)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
 pub fn direct_projection_2sp(params: &[(u32, f64)], ell: Ellipsoid) -> String {
     LambertConic2SPProjection::from_database_params(params, &ell).to_constructed()
 }
 
+pub fn direct_projection_2sp_belgium(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    LambertConic2SPBelgiumProjection::from_database_params(params, &ell).to_constructed()
+}
+
+pub fn direct_projection_2sp_michigan(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    LambertConic2SPMichiganProjection::from_database_params(params, &ell).to_constructed()
+}
+
 pub fn direct_projection_1sp_a(params: &[(u32, f64)], ell: Ellipsoid) -> String {
     LambertConic1SPAProjection::from_database_params(params, &ell).to_constructed()
 }
 
+pub fn direct_projection_near_conformal(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    LambertConicNearConformalProjection::from_database_params(params, &ell).to_constructed()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -507,6 +1075,102 @@ mod tests {
         assert!((northing - northing_goal).abs() < 0.001);
     }
 
+    #[test]
+    fn lambert_conic_2sp_equal_parallels() {
+        // a single standard parallel is the 1SP variant, shifted to the false origin
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257222101);
+        let params = LambertConic2SPParams::new(
+            0.0,
+            30f64.to_radians(),
+            45f64.to_radians(),
+            45f64.to_radians(),
+            1000.0,
+            2000.0,
+        );
+        let projection = LambertConic2SPProjection::new(&ell, &params);
+        let params_1sp = LambertConic1SPAParams::new(0.0, 45f64.to_radians(), 1.0, 0.0, 0.0);
+        let projection_1sp = LambertConic1SPAProjection::new(&ell, &params_1sp);
+
+        let (easting, northing) = projection.deg_to_projected(0.0, 30.0);
+        assert!((easting - 1000.0).abs() < 1e-6 && (northing - 2000.0).abs() < 1e-6);
+        let (_, northing_origin) = projection_1sp.deg_to_projected(0.0, 30.0);
+        for (lon, lat) in [(1.0, 30.0), (-3.0, 45.0), (5.0, 60.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (easting_1sp, northing_1sp) = projection_1sp.deg_to_projected(lon, lat);
+            eprintln!("{easting} {northing} - {easting_1sp} {northing_1sp}");
+            assert!((easting - 1000.0 - easting_1sp).abs() < 1e-6);
+            assert!((northing - 2000.0 - (northing_1sp - northing_origin)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn lambert_conic_2sp_belgium_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6378388.0, 297.0);
+        let params = LambertConic2SPParams::new(
+            (4.0 + 21.0 / 60.0 + 24.983 / 3600.0f64).to_radians(),
+            90f64.to_radians(),
+            (49.0 + 50.0 / 60.0f64).to_radians(),
+            (51.0 + 10.0 / 60.0f64).to_radians(),
+            150_000.01,
+            5_400_088.44,
+        );
+
+        let projection = LambertConic2SPBelgiumProjection::new(&ell, &params);
+        let lon = 5.0 + 48.0 / 60.0 + 26.533 / 3600.0;
+        let lat = 50.0 + 40.0 / 60.0 + 46.461 / 3600.0;
+        let easting_goal = 251763.20;
+        let northing_goal = 153034.13;
+        let (easting, northing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+    }
+
+    #[test]
+    fn lambert_conic_2sp_michigan_consistency() {
+        // Clarke 1866, in US survey feet
+        let ell = Ellipsoid::from_a_f_inv(20925832.16, 294.97870);
+        let params = LambertConic2SPMichiganParams::new(
+            -(84.0 + 20.0 / 60.0f64).to_radians(),
+            (43.0 + 19.0 / 60.0f64).to_radians(),
+            (44.0 + 11.0 / 60.0f64).to_radians(),
+            (45.0 + 42.0 / 60.0f64).to_radians(),
+            2_000_000.0,
+            0.0,
+            1.0000382,
+        );
+
+        let projection = LambertConic2SPMichiganProjection::new(&ell, &params);
+        let lon = -(83.0 + 10.0 / 60.0);
+        let lat = 43.75;
+        let easting_goal = 2308335.75;
+        let northing_goal = 160210.48;
+        let (easting, northing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+    }
+
     #[test]
     fn lambert_conic_1sp_a_consistency() {
         let ell = Ellipsoid::from_a_f_inv(6378206.400, 294.97870);
@@ -531,4 +1195,67 @@ mod tests {
 
         assert!((northing - northing_goal).abs() < 0.001);
     }
+
+    #[test]
+    fn lambert_conic_near_conformal_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6378249.2, 293.46602);
+        let params = LambertConic1SPAParams::new(
+            (37.0 + 21.0 / 60.0f64).to_radians(),
+            (34.0 + 39.0 / 60.0f64).to_radians(),
+            0.9996256,
+            300_000.0,
+            300_000.0,
+        );
+
+        let projection = LambertConicNearConformalProjection::new(&ell, &params);
+        eprintln!("{projection:#?}");
+        let lon = 34.0 + 8.0 / 60.0 + 11.291 / 3600.0;
+        let lat = 37.0 + 31.0 / 60.0 + 17.625 / 3600.0;
+        let easting_goal = 15707.96;
+        let northing_goal = 623165.96;
+        let (easting, northing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+    }
+
+    #[test]
+    fn lambert_conic_jacobian() {
+        let ell = Ellipsoid::from_a_f_inv(6378160.0, 298.25);
+        let params = LambertConic2SPParams::new(
+            145f64.to_radians(),
+            37f64.to_radians(),
+            36f64.to_radians(),
+            38f64.to_radians(),
+            2_500_000.0,
+            4_500_000.0,
+        );
+        let projection_2sp = LambertConic2SPProjection::new(&ell, &params);
+
+        let ell = Ellipsoid::from_a_f_inv(6378206.400, 294.97870);
+        let params = LambertConic1SPAParams::new(
+            18f64.to_radians(),
+            -77f64.to_radians(),
+            1.0,
+            250_000.0,
+            150_000.0,
+        );
+        let projection_1sp = LambertConic1SPAProjection::new(&ell, &params);
+        for (lon, lat) in [(140.0, 30.0), (145.0, 37.0), (151.0, 45.0), (130.0, -10.0)] {
+            assert_jacobian(&projection_2sp, lon, lat);
+        }
+        for (lon, lat) in [(-78.0, 17.0), (-77.0, 18.0), (-70.0, 25.0)] {
+            assert_jacobian(&projection_1sp, lon, lat);
+        }
+    }
 }
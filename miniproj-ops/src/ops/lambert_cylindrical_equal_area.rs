@@ -0,0 +1,397 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    ellipsoid::{authalic_q, authalic_to_lat, Ellipsoid},
+    traits::GetterContstruct,
+    DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LambertCylindricalEqualAreaParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// latitude of 1st standard parallel
+    lat_sp1: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(LambertCylindricalEqualAreaParams {
+    lon_orig,
+    lat_sp1,
+    false_e,
+    false_n
+});
+
+impl LambertCylindricalEqualAreaParams {
+    pub const fn new(lon_orig: f64, lat_sp1: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            lat_sp1,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of 1st standard parallel, radians.
+    pub fn lat_sp1(&self) -> f64 {
+        self.lat_sp1
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Lambert Cylindrical Equal Area coordinate operation (EPSG:9835).
+#[allow(non_snake_case)]
+#[derive(Copy, Clone, Debug)]
+pub struct LambertCylindricalEqualAreaProjection {
+    pub false_e: f64,
+    pub false_n: f64,
+    pub lon_orig: f64,
+    pub ellipsoid_a: f64,
+    pub ellipsoid_e: f64,
+    pub ellipsoid_e_squared: f64,
+
+    pub k_orig: f64,
+    pub q_P: f64,
+}
+
+impl_param_eq_hash!(LambertCylindricalEqualAreaProjection {
+    false_e,
+    false_n,
+    lon_orig,
+    ellipsoid_a,
+    ellipsoid_e,
+    ellipsoid_e_squared,
+    k_orig,
+    q_P,
+});
+
+impl LambertCylindricalEqualAreaProjection {
+    pub fn new(ell: &Ellipsoid, params: &LambertCylindricalEqualAreaParams) -> Self {
+        Self {
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+            lon_orig: params.lon_orig(),
+            ellipsoid_a: ell.a(),
+            ellipsoid_e: ell.e(),
+            ellipsoid_e_squared: ell.e_squared(),
+
+            k_orig: params.lat_sp1().cos()
+                / (1.0 - ell.e_squared() * params.lat_sp1().sin().powi(2)).sqrt(),
+            q_P: authalic_q(ell.e(), ell.e_squared(), 1.0),
+        }
+    }
+}
+
+impl Projection for LambertCylindricalEqualAreaProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let q = authalic_q(self.ellipsoid_e, self.ellipsoid_e_squared, latitude.sin());
+        (
+            self.false_e + self.ellipsoid_a * self.k_orig * (longitude - self.lon_orig),
+            self.false_n + self.ellipsoid_a * q / (2.0 * self.k_orig),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let beta = (2.0 * (northing - self.false_n) * self.k_orig / (self.ellipsoid_a * self.q_P))
+            .clamp(-1.0, 1.0)
+            .asin();
+        (
+            self.lon_orig + (easting - self.false_e) / (self.ellipsoid_a * self.k_orig),
+            authalic_to_lat(self.ellipsoid_e_squared, beta),
+        )
+    }
+
+    fn jacobian(&self, _longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        // dq/dφ = 2 (1 - e²) cos(φ) / (1 - e² sin²(φ))²
+        let dq = 2.0 * (1.0 - self.ellipsoid_e_squared) * latitude.cos()
+            / (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).powi(2);
+        [
+            [self.ellipsoid_a * self.k_orig, 0.0],
+            [0.0, self.ellipsoid_a * dq / (2.0 * self.k_orig)],
+        ]
+    }
+}
+
+impl PseudoSerialize for LambertCylindricalEqualAreaProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"LambertCylindricalEqualAreaProjection{{
+    false_e: {}f64,
+    false_n: {}f64,
+    lon_orig: {}f64,
+    ellipsoid_a: {}f64,
+    ellipsoid_e: {}f64,
+    ellipsoid_e_squared: {}f64,
+    k_orig: {}f64,
+    q_P: {}f64,
+}}",
+            self.false_e,
+            self.false_n,
+            self.lon_orig,
+            self.ellipsoid_a,
+            self.ellipsoid_e,
+            self.ellipsoid_e_squared,
+            self.k_orig,
+            self.q_P,
+        )
+    }
+}
+
+impl DbContstruct for LambertCylindricalEqualAreaProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = LambertCylindricalEqualAreaParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8823 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for LambertCylindricalEqualAreaProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = LambertCylindricalEqualAreaParams::new(
+            getter(8802)?,
+            getter(8823)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    LambertCylindricalEqualAreaProjection::from_database_params(params, &ell).to_constructed()
+}
+
+/// Lambert Cylindrical Equal Area (Spherical) coordinate operation (EPSG:9834).
+///
+/// On an ellipsoid the radius of the authalic sphere is used.
+#[derive(Copy, Clone, Debug)]
+pub struct LambertCylindricalEqualAreaSphericalProjection {
+    pub false_e: f64,
+    pub false_n: f64,
+    pub lon_orig: f64,
+    pub radius: f64,
+    pub cos_lat_sp1: f64,
+}
+
+impl_param_eq_hash!(LambertCylindricalEqualAreaSphericalProjection {
+    false_e,
+    false_n,
+    lon_orig,
+    radius,
+    cos_lat_sp1,
+});
+
+impl LambertCylindricalEqualAreaSphericalProjection {
+    pub fn new(ell: &Ellipsoid, params: &LambertCylindricalEqualAreaParams) -> Self {
+        Self {
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+            lon_orig: params.lon_orig(),
+            radius: ell.rad_auth(),
+            cos_lat_sp1: params.lat_sp1().cos(),
+        }
+    }
+}
+
+impl Projection for LambertCylindricalEqualAreaSphericalProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        (
+            self.false_e + self.radius * (longitude - self.lon_orig) * self.cos_lat_sp1,
+            self.false_n + self.radius * latitude.sin() / self.cos_lat_sp1,
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        (
+            self.lon_orig + (easting - self.false_e) / (self.radius * self.cos_lat_sp1),
+            ((northing - self.false_n) / self.radius * self.cos_lat_sp1)
+                .clamp(-1.0, 1.0)
+                .asin(),
+        )
+    }
+
+    fn jacobian(&self, _longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        [
+            [self.radius * self.cos_lat_sp1, 0.0],
+            [0.0, self.radius * latitude.cos() / self.cos_lat_sp1],
+        ]
+    }
+}
+
+impl PseudoSerialize for LambertCylindricalEqualAreaSphericalProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"LambertCylindricalEqualAreaSphericalProjection{{
+    false_e: {}f64,
+    false_n: {}f64,
+    lon_orig: {}f64,
+    radius: {}f64,
+    cos_lat_sp1: {}f64,
+}}",
+            self.false_e, self.false_n, self.lon_orig, self.radius, self.cos_lat_sp1,
+        )
+    }
+}
+
+impl DbContstruct for LambertCylindricalEqualAreaSphericalProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = LambertCylindricalEqualAreaParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8823 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for LambertCylindricalEqualAreaSphericalProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = LambertCylindricalEqualAreaParams::new(
+            getter(8802)?,
+            getter(8823)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection_spherical(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    LambertCylindricalEqualAreaSphericalProjection::from_database_params(params, &ell)
+        .to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::lambert_cylindrical_equal_area::*;
+    use crate::traits::*;
+
+    #[test]
+    fn lambert_cylindrical_equal_area_consistency() {
+        // WGS 84 / NSIDC EASE-Grid 2.0 Global
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params = LambertCylindricalEqualAreaParams::new(0.0, 30f64.to_radians(), 0.0, 0.0);
+
+        let projection = LambertCylindricalEqualAreaProjection::new(&ell, &params);
+        // corner of the global grid as published by NSIDC
+        let easting_goal = 17367530.45;
+        let northing_goal = 7314540.83;
+        let (easting, northing) = projection.deg_to_projected(180.0, 85.0445664);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        for (lon, lat) in [(-100.0, 24.0), (0.0, 0.0), (10.0, 54.0), (170.0, -80.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-7);
+            assert!((lat - lat_r).abs() < 1e-7);
+            assert_jacobian(&projection, lon, lat);
+        }
+    }
+
+    #[test]
+    fn lambert_cylindrical_equal_area_spherical_consistency() {
+        // International 1924 Authalic Sphere, as used by the original NSIDC EASE-Grid
+        let ell = Ellipsoid::from_a_b(6371228.0, 6371228.0);
+        let params = LambertCylindricalEqualAreaParams::new(0.0, 30f64.to_radians(), 0.0, 0.0);
+
+        let projection = LambertCylindricalEqualAreaSphericalProjection::new(&ell, &params);
+        // the antimeridian lies at R·π·cos(30°) from the central meridian
+        let (easting, _) = projection.deg_to_projected(180.0, 0.0);
+        eprintln!("easting: 17334193.94 - {easting}");
+        assert!((easting - 17334193.94).abs() < 0.01);
+
+        for (lon, lat) in [(-100.0, 24.0), (0.0, 0.0), (10.0, 54.0), (170.0, -80.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+            assert_jacobian(&projection, lon, lat);
+        }
+    }
+
+    #[test]
+    fn lambert_cylindrical_equal_area_spherical_matches_ellipsoidal() {
+        let ell = Ellipsoid::from_a_b(6371228.0, 6371228.0);
+        let params = LambertCylindricalEqualAreaParams::new(0.0, 30f64.to_radians(), 0.0, 0.0);
+
+        let spherical = LambertCylindricalEqualAreaSphericalProjection::new(&ell, &params);
+        let ellipsoidal = LambertCylindricalEqualAreaProjection::new(&ell, &params);
+        for (lon, lat) in [(-100.0, 24.0), (0.0, 0.0), (10.0, 54.0), (170.0, -80.0)] {
+            let (easting_s, northing_s) = spherical.deg_to_projected(lon, lat);
+            let (easting_e, northing_e) = ellipsoidal.deg_to_projected(lon, lat);
+            eprintln!("{lon}, {lat}: {easting_s}, {northing_s} - {easting_e}, {northing_e}");
+            assert!((easting_s - easting_e).abs() < 1e-6);
+            assert!((northing_s - northing_e).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::Geographic3DCoordinate,
+    ellipsoid::Ellipsoid,
+    inverse::iterative_geographic_inverse,
+    traits::{GetterContstruct, InvertibleCoordOperation},
+    CoordOperation, DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LongitudeRotationParams {
+    /// longitude offset
+    lon_offset: f64,
+}
+
+impl_param_eq_hash!(LongitudeRotationParams { lon_offset });
+
+impl LongitudeRotationParams {
+    pub const fn new(lon_offset: f64) -> Self {
+        Self { lon_offset }
+    }
+
+    /// Get longitude offset, radians.
+    pub fn lon_offset(&self) -> f64 {
+        self.lon_offset
+    }
+}
+
+/// Longitude rotation transformation (EPSG:9601).
+///
+/// Changes the meridian longitudes are referenced to, e.g. from Paris to Greenwich.
+#[derive(Copy, Clone, Debug)]
+pub struct LongitudeRotationTransformation {
+    pub lon_offset: f64,
+}
+
+impl_param_eq_hash!(LongitudeRotationTransformation { lon_offset });
+
+impl LongitudeRotationTransformation {
+    pub fn new(params: &LongitudeRotationParams) -> Self {
+        Self {
+            lon_offset: params.lon_offset(),
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which subtracts the offset.
+    pub fn reversed(&self) -> Self {
+        Self {
+            lon_offset: -self.lon_offset,
+        }
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for LongitudeRotationTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        Geographic3DCoordinate {
+            lon: from.lon + self.lon_offset,
+            ..from
+        }
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for LongitudeRotationTransformation
+{
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        iterative_geographic_inverse(self, to)
+    }
+}
+
+impl PseudoSerialize for LongitudeRotationTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"LongitudeRotationTransformation{{
+    lon_offset: {}f64,
+}}",
+            self.lon_offset,
+        )
+    }
+}
+
+impl DbContstruct for LongitudeRotationTransformation {
+    fn from_database_params(params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        let params = LongitudeRotationParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8602 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(&params)
+    }
+}
+
+impl GetterContstruct for LongitudeRotationTransformation {
+    fn with_db_getter<G>(mut getter: G, _ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = LongitudeRotationParams::new(getter(8602)?);
+        Some(Self::new(&params))
+    }
+}
+
+pub fn direct_transformation(
+    params: &[(u32, f64)],
+    source: Ellipsoid,
+    _target: Ellipsoid,
+) -> String {
+    LongitudeRotationTransformation::from_database_params(params, &source).to_constructed()
+}
+
+/// Projection of a coordinate reference system whose prime meridian is not the Greenwich meridian.
+///
+/// The wrapped projection works on longitudes relative to the CRS's prime meridian, like the
+/// parameters of its conversion. `PrimeMeridianShift<P>` applies the longitude rotation so that
+/// longitudes are relative to Greenwich, like for every other `Projection`.
+#[derive(Copy, Clone, Debug)]
+pub struct PrimeMeridianShift<P> {
+    pub projection: P,
+    /// Greenwich longitude of the prime meridian, radians
+    pub prime_meridian: f64,
+}
+
+impl<P> PrimeMeridianShift<P> {
+    /// Wrap a projection of a CRS whose prime meridian has the Greenwich longitude `prime_meridian`
+    /// in radians.
+    pub const fn new(projection: P, prime_meridian: f64) -> Self {
+        Self {
+            projection,
+            prime_meridian,
+        }
+    }
+}
+
+impl<P: PartialEq> PartialEq for PrimeMeridianShift<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.projection == other.projection
+            && self.prime_meridian.to_bits() == other.prime_meridian.to_bits()
+    }
+}
+
+impl<P: Eq> Eq for PrimeMeridianShift<P> {}
+
+impl<P: std::hash::Hash> std::hash::Hash for PrimeMeridianShift<P> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.projection.hash(state);
+        self.prime_meridian.to_bits().hash(state);
+    }
+}
+
+impl<P: Projection> Projection for PrimeMeridianShift<P> {
+    fn projected_to_rad(&self, x: f64, y: f64) -> (f64, f64) {
+        let (lon, lat) = self.projection.projected_to_rad(x, y);
+        (lon + self.prime_meridian, lat)
+    }
+
+    fn rad_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.projection
+            .rad_to_projected(lon - self.prime_meridian, lat)
+    }
+
+    fn jacobian(&self, lon: f64, lat: f64) -> [[f64; 2]; 2] {
+        self.projection.jacobian(lon - self.prime_meridian, lat)
+    }
+}
+
+impl<P: PseudoSerialize> PseudoSerialize for PrimeMeridianShift<P> {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"PrimeMeridianShift{{
+    projection: {},
+    prime_meridian: {}f64,
+}}",
+            self.projection.to_constructed(),
+            self.prime_meridian,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::Geographic3DCoordinate;
+    use crate::ellipsoid::Ellipsoid;
+    use crate::lambert_conic_conformal::*;
+    use crate::longitude_rotation::*;
+    use crate::traits::*;
+
+    #[test]
+    fn longitude_rotation_consistency() {
+        // NTF (Paris) to NTF
+        let paris = 2.5969213 * std::f64::consts::PI / 200.0;
+        let transformation =
+            LongitudeRotationTransformation::new(&LongitudeRotationParams::new(paris));
+        let from = Geographic3DCoordinate::from_deg(0.0, 46.8, 10.0);
+        let (lon, lat, height) = transformation.apply(from).to_deg();
+        let lon_goal = 2.5969213 * 0.9;
+
+        eprintln!("lon: {lon_goal} - {lon}");
+
+        assert!((lon - lon_goal).abs() < 1e-9);
+        assert_eq!((lat, height), (46.8, 10.0));
+
+        let back = transformation.reversed().apply(transformation.apply(from));
+        assert!((back.lon - from.lon).abs() < 1e-15);
+    }
+
+    #[test]
+    fn prime_meridian_shift() {
+        // NTF (Paris) / Lambert zone II
+        let grads = std::f64::consts::PI / 200.0;
+        let ell = Ellipsoid::from_a_b(6378249.2, 6356515.0);
+        let params =
+            LambertConic1SPAParams::new(0.0, 52.0 * grads, 0.99987742, 600000.0, 2200000.0);
+        let projection = PrimeMeridianShift::new(
+            LambertConic1SPAProjection::new(&ell, &params),
+            2.5969213 * grads,
+        );
+
+        let lon_goal = 2.5969213 * 0.9;
+        let (lon, lat) = projection.projected_to_deg(600000.0, 2200000.0);
+        eprintln!("lon: {lon_goal} - {lon}");
+        eprintln!("lat: 46.8 - {lat}");
+        assert!((lon - lon_goal).abs() < 1e-9);
+        assert!((lat - 46.8).abs() < 1e-9);
+
+        let (easting, northing) = projection.deg_to_projected(lon_goal, 46.8);
+        assert!((easting - 600000.0).abs() < 1e-6);
+        assert!((northing - 2200000.0).abs() < 1e-6);
+
+        for (lon, lat) in [(0.0, 43.0), (7.5, 49.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+        }
+    }
+}
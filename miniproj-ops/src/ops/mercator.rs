@@ -0,0 +1,488 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use crate::{
+    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MercatorSphericalParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// latitude of natural origin
+    lat_orig: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(MercatorSphericalParams {
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n
+});
+
+impl MercatorSphericalParams {
+    pub const fn new(lon_orig: f64, lat_orig: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            lat_orig,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of natural origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Mercator (Spherical) coordinate operation (EPSG:1026).
+///
+/// On an ellipsoid the radius of the conformal sphere at the latitude of origin is used, which
+/// reduces to the radius for spheres.
+#[derive(Copy, Clone, Debug)]
+pub struct MercatorSphericalProjection {
+    pub false_e: f64,
+    pub false_n: f64,
+    pub lon_orig: f64,
+    pub radius: f64,
+}
+
+impl_param_eq_hash!(MercatorSphericalProjection {
+    false_e,
+    false_n,
+    lon_orig,
+    radius
+});
+
+impl MercatorSphericalProjection {
+    pub fn new(ell: &Ellipsoid, params: &MercatorSphericalParams) -> Self {
+        Self {
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+            lon_orig: params.lon_orig(),
+            radius: ell.rad_conformal(params.lat_orig()),
+        }
+    }
+}
+
+impl Projection for MercatorSphericalProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        (
+            self.false_e + self.radius * (longitude - self.lon_orig),
+            self.false_n + self.radius * (FRAC_PI_4 + latitude / 2.0).tan().ln(),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    #[allow(non_snake_case)]
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let D = (self.false_n - northing) / self.radius;
+        (
+            (easting - self.false_e) / self.radius + self.lon_orig,
+            FRAC_PI_2 - 2.0 * D.exp().atan(),
+        )
+    }
+
+    fn jacobian(&self, _longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        [[self.radius, 0.0], [0.0, self.radius / latitude.cos()]]
+    }
+}
+
+impl PseudoSerialize for MercatorSphericalProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"MercatorSphericalProjection{{
+    false_e: {}f64,
+    false_n: {}f64,
+    lon_orig: {}f64,
+    radius: {}f64,
+}}",
+            self.false_e, self.false_n, self.lon_orig, self.radius,
+        )
+    }
+}
+
+impl DbContstruct for MercatorSphericalProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = MercatorSphericalParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8801 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for MercatorSphericalProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = MercatorSphericalParams::new(
+            getter(8802)?,
+            getter(8801)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection_spherical(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    MercatorSphericalProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MercatorCParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// latitude of false origin
+    lat_orig: f64,
+    /// latitude of 1st standard parallel
+    lat_sp1: f64,
+    /// easting at false origin
+    false_e: f64,
+    /// northing at false origin
+    false_n: f64,
+}
+
+impl_param_eq_hash!(MercatorCParams {
+    lon_orig,
+    lat_orig,
+    lat_sp1,
+    false_e,
+    false_n
+});
+
+impl MercatorCParams {
+    pub const fn new(
+        lon_orig: f64,
+        lat_orig: f64,
+        lat_sp1: f64,
+        false_e: f64,
+        false_n: f64,
+    ) -> Self {
+        Self {
+            lon_orig,
+            lat_orig,
+            lat_sp1,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get latitude of false origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get latitude of 1st standard parallel, radians.
+    pub fn lat_sp1(&self) -> f64 {
+        self.lat_sp1
+    }
+
+    /// Get easting at false origin.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get northing at false origin.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Mercator (variant C) coordinate operation (EPSG:1044).
+#[derive(Copy, Clone, Debug)]
+pub struct MercatorCProjection {
+    pub false_e: f64,
+    pub lon_orig: f64,
+    pub ellipsoid_e: f64,
+    /// `a * k_O`
+    pub a_k_o: f64,
+    /// northing at false origin minus the unshifted northing `M` of the latitude of false origin
+    pub false_n_m: f64,
+
+    pub phi_2_chi_sin_summand_factor: f64,
+    pub phi_4_chi_sin_summand_factor: f64,
+    pub phi_6_chi_sin_summand_factor: f64,
+    pub phi_8_chi_sin_summand_factor: f64,
+}
+
+impl_param_eq_hash!(MercatorCProjection {
+    false_e,
+    lon_orig,
+    ellipsoid_e,
+    a_k_o,
+    false_n_m,
+    phi_2_chi_sin_summand_factor,
+    phi_4_chi_sin_summand_factor,
+    phi_6_chi_sin_summand_factor,
+    phi_8_chi_sin_summand_factor,
+});
+
+impl MercatorCProjection {
+    pub fn new(ell: &Ellipsoid, params: &MercatorCParams) -> Self {
+        let lat_sp1 = params.lat_sp1().abs();
+        let k_o = lat_sp1.cos() / (1.0 - ell.e_squared() * lat_sp1.sin().powi(2)).sqrt();
+        let a_k_o = ell.a() * k_o;
+        let m = a_k_o * Self::isometric_latitude(ell.e(), params.lat_orig());
+        Self {
+            false_e: params.false_e(),
+            lon_orig: params.lon_orig(),
+            ellipsoid_e: ell.e(),
+            a_k_o,
+            false_n_m: params.false_n() - m,
+
+            phi_2_chi_sin_summand_factor: ell.e_squared() / 2.0
+                + 5.0 * ell.e_squared().powi(2) / 24.0
+                + ell.e_squared().powi(3) / 12.0
+                + 13.0 * ell.e_squared().powi(4) / 360.0,
+            phi_4_chi_sin_summand_factor: 7.0 * ell.e_squared().powi(2) / 48.0
+                + 29.0 * ell.e_squared().powi(3) / 240.0
+                + 811.0 * ell.e_squared().powi(4) / 11520.0,
+            phi_6_chi_sin_summand_factor: 7.0 * ell.e_squared().powi(3) / 120.0
+                + 81.0 * ell.e_squared().powi(4) / 1120.0,
+            phi_8_chi_sin_summand_factor: 4279.0 * ell.e_squared().powi(4) / 161280.0,
+        }
+    }
+
+    fn isometric_latitude(e: f64, latitude: f64) -> f64 {
+        ((FRAC_PI_4 + latitude / 2.0).tan()
+            * ((1.0 - e * latitude.sin()) / (1.0 + e * latitude.sin())).powf(e / 2.0))
+        .ln()
+    }
+}
+
+impl Projection for MercatorCProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        (
+            self.false_e + self.a_k_o * (longitude - self.lon_orig),
+            self.false_n_m + self.a_k_o * Self::isometric_latitude(self.ellipsoid_e, latitude),
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let t = ((self.false_n_m - northing) / self.a_k_o).exp();
+        let chi = FRAC_PI_2 - 2.0 * t.atan();
+        (
+            (easting - self.false_e) / self.a_k_o + self.lon_orig,
+            chi + self.phi_2_chi_sin_summand_factor * (2.0 * chi).sin()
+                + self.phi_4_chi_sin_summand_factor * (4.0 * chi).sin()
+                + self.phi_6_chi_sin_summand_factor * (6.0 * chi).sin()
+                + self.phi_8_chi_sin_summand_factor * (8.0 * chi).sin(),
+        )
+    }
+
+    fn jacobian(&self, _longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        let e_squared = self.ellipsoid_e.powi(2);
+        [
+            [self.a_k_o, 0.0],
+            [
+                0.0,
+                self.a_k_o * (1.0 - e_squared)
+                    / ((1.0 - e_squared * latitude.sin().powi(2)) * latitude.cos()),
+            ],
+        ]
+    }
+}
+
+impl PseudoSerialize for MercatorCProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"MercatorCProjection{{
+    false_e: {}f64,
+    lon_orig: {}f64,
+    ellipsoid_e: {}f64,
+    a_k_o: {}f64,
+    false_n_m: {}f64,
+    phi_2_chi_sin_summand_factor: {}f64,
+    phi_4_chi_sin_summand_factor: {}f64,
+    phi_6_chi_sin_summand_factor: {}f64,
+    phi_8_chi_sin_summand_factor: {}f64,
+}}",
+            self.false_e,
+            self.lon_orig,
+            self.ellipsoid_e,
+            self.a_k_o,
+            self.false_n_m,
+            self.phi_2_chi_sin_summand_factor,
+            self.phi_4_chi_sin_summand_factor,
+            self.phi_6_chi_sin_summand_factor,
+            self.phi_8_chi_sin_summand_factor,
+        )
+    }
+}
+
+impl DbContstruct for MercatorCProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = MercatorCParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8802 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8821 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8823 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8826 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8827 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for MercatorCProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = MercatorCParams::new(
+            getter(8802)?,
+            getter(8821)?,
+            getter(8823)?,
+            getter(8826)?,
+            getter(8827)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection_c(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    MercatorCProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::mercator::*;
+    use crate::traits::*;
+
+    #[test]
+    fn mercator_spherical_consistency() {
+        let ell = Ellipsoid::from_a_b(6371007.0, 6371007.0);
+        let params = MercatorSphericalParams::new(0.0, 0.0, 0.0, 0.0);
+
+        let projection = MercatorSphericalProjection::new(&ell, &params);
+        let easting_goal = -11156569.90;
+        let northing_goal = 2796869.94;
+        let (easting, northing) = projection.rad_to_projected(-1.751147016, 0.425542460);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon, lat) = projection.projected_to_rad(easting_goal, northing_goal);
+        eprintln!("lon: -1.751147016 - {lon}");
+        eprintln!("lat: 0.425542460 - {lat}");
+
+        assert!((lon + 1.751147016).abs() < 1e-9);
+        assert!((lat - 0.425542460).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mercator_spherical_jacobian() {
+        let ell = Ellipsoid::from_a_b(6371007.0, 6371007.0);
+        let params = MercatorSphericalParams::new(0.0, 0.0, 0.0, 0.0);
+
+        let projection = MercatorSphericalProjection::new(&ell, &params);
+        for (lon, lat) in [(-100.0, 24.0), (0.0, 0.0), (10.0, 54.0), (170.0, -80.0)] {
+            assert_jacobian(&projection, lon, lat);
+        }
+    }
+
+    #[test]
+    fn mercator_c_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6378245.0, 298.3);
+        let params = MercatorCParams::new(
+            51f64.to_radians(),
+            42f64.to_radians(),
+            42f64.to_radians(),
+            0.0,
+            0.0,
+        );
+
+        let projection = MercatorCProjection::new(&ell, &params);
+        let easting_goal = 165704.29;
+        let northing_goal = 1351950.22;
+        let (easting, northing) = projection.deg_to_projected(53.0, 53.0);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon, lat) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: 53 - {lon}");
+        eprintln!("lat: 53 - {lat}");
+
+        assert!((lon - 53.0).abs() < 1e-6);
+        assert!((lat - 53.0).abs() < 1e-6);
+
+        for (lon, lat) in [(-100.0, 24.0), (0.0, 0.0), (53.0, 53.0), (170.0, -80.0)] {
+            assert_jacobian(&projection, lon, lat);
+        }
+    }
+}
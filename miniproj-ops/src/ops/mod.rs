@@ -2,22 +2,113 @@ use crate::{traits::GetterContstruct, Ellipsoid, Projection};
 
 use self::{
     albers_equal_area::AlbersEqualAreaProjection,
-    lambert_azimuthal_equal_area::LambertAzimuthalEqualAreaProjection,
-    lambert_conic_conformal::{LambertConic1SPAProjection, LambertConic2SPProjection},
+    azimuthal_equidistant::{
+        AzimuthalEquidistantProjection, GuamProjection, ModifiedAzimuthalEquidistantProjection,
+    },
+    bonne::BonneProjection,
+    cassini_soldner::{CassiniSoldnerProjection, HyperbolicCassiniSoldnerProjection},
+    colombia_urban::ColombiaUrbanProjection,
+    equal_earth::EqualEarthProjection,
+    equidistant_cylindrical::{
+        EquidistantCylindricalProjection, EquidistantCylindricalSphericalProjection,
+    },
+    hotine_oblique_mercator::{HotineObliqueMercatorAProjection, HotineObliqueMercatorBProjection},
+    identity_projection::IdentityProjection,
+    krovak::KrovakProjection,
+    lambert_azimuthal_equal_area::{
+        LambertAzimuthalEqualAreaProjection, LambertAzimuthalEqualAreaSphericalProjection,
+    },
+    lambert_conic_conformal::{
+        LambertConic1SPAProjection, LambertConic2SPBelgiumProjection,
+        LambertConic2SPMichiganProjection, LambertConic2SPProjection,
+        LambertConicNearConformalProjection,
+    },
+    lambert_cylindrical_equal_area::{
+        LambertCylindricalEqualAreaProjection, LambertCylindricalEqualAreaSphericalProjection,
+    },
+    mercator::{MercatorCProjection, MercatorSphericalProjection},
     popvis_pseudo_mercator::PopVisPseudoMercatorProjection,
-    stereographic::{ObliqueStereographicProjection, PolarStereographicAProjection},
-    transverse_mercator::TransverseMercatorProjection,
+    stereographic::{
+        ObliqueStereographicProjection, PolarStereographicAProjection,
+        PolarStereographicBProjection,
+    },
+    transverse_mercator::{
+        TransverseMercatorProjection, TransverseMercatorSouthOrientatedProjection,
+    },
+    tunisia_mining_grid::TunisiaMiningGridProjection,
+    vertical_perspective::VerticalPerspectiveProjection,
 };
 
+/// Implements `PartialEq`, `Eq` and `Hash` for a struct of `f64` fields by comparing the bit
+/// patterns of the listed fields, so that equal values always hash equally. Fields listed after a
+/// `;` are compared and hashed through their own `Eq` and `Hash` implementations.
+macro_rules! impl_param_eq_hash {
+    ($name:ident { $($field:ident),* $(,)? $(; $($nested:ident),* $(,)?)? }) => {
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                true $(&& self.$field.to_bits() == other.$field.to_bits())*
+                    $($(&& self.$nested == other.$nested)*)?
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                $(self.$field.to_bits().hash(state);)*
+                $($(self.$nested.hash(state);)*)?
+            }
+        }
+    };
+}
+
 pub mod ellipsoid;
+pub mod ellipsoids;
+pub mod epoch;
+pub mod fast;
+pub mod projection_params;
+pub mod unit;
 
+pub mod abridged_molodensky;
 pub mod albers_equal_area;
+pub mod axis_order;
+pub mod azimuthal_equidistant;
+pub mod bonne;
+pub mod cassini_soldner;
+pub mod colombia_urban;
+pub mod concatenated;
+pub mod coordinate;
+pub mod equal_earth;
+pub mod equidistant_cylindrical;
+pub mod geocentric_conversion;
+pub mod geographic_3d_to_2d;
+pub mod geographic_offsets;
+pub mod grids;
+pub mod helmert;
+pub mod hotine_oblique_mercator;
 pub mod identity_projection;
+pub mod inverse;
+pub mod krovak;
 pub mod lambert_azimuthal_equal_area;
 pub mod lambert_conic_conformal;
+pub mod lambert_cylindrical_equal_area;
+pub mod longitude_rotation;
+pub mod mercator;
+pub mod molodensky_badekas;
+pub mod pipeline;
+pub mod point_motion;
 pub mod popvis_pseudo_mercator;
+pub mod pressure_depth;
+pub mod rotated_pole;
+pub mod sinusoidal;
+#[cfg(feature = "spherical-harmonic-geoid")]
+pub mod spherical_harmonic_geoid;
 pub mod stereographic;
 pub mod transverse_mercator;
+pub mod tunisia_mining_grid;
+pub mod vertical_offset;
+pub mod vertical_perspective;
+pub mod vertical_pipeline;
 
 /// Try to construct a projection for a specific method code with a getter that provides the parameter values.
 ///
@@ -57,6 +148,108 @@ where
         9820 => Some(Box::new(
             LambertAzimuthalEqualAreaProjection::with_db_getter(getter, ellipsoid)?,
         )),
+        1026 => Some(Box::new(MercatorSphericalProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9834 => Some(Box::new(
+            LambertCylindricalEqualAreaSphericalProjection::with_db_getter(getter, ellipsoid)?,
+        )),
+        9825 => Some(Box::new(IdentityProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        1044 => Some(Box::new(MercatorCProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9812 => Some(Box::new(HotineObliqueMercatorAProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9815 => Some(Box::new(HotineObliqueMercatorBProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9806 => Some(Box::new(CassiniSoldnerProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9833 => Some(Box::new(
+            HyperbolicCassiniSoldnerProjection::with_db_getter(getter, ellipsoid)?,
+        )),
+        9819 => Some(Box::new(KrovakProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9803 => Some(Box::new(LambertConic2SPBelgiumProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        1051 => Some(Box::new(LambertConic2SPMichiganProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9817 => Some(Box::new(
+            LambertConicNearConformalProjection::with_db_getter(getter, ellipsoid)?,
+        )),
+        9829 => Some(Box::new(PolarStereographicBProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        1028 => Some(Box::new(EquidistantCylindricalProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        1029 => Some(Box::new(
+            EquidistantCylindricalSphericalProjection::with_db_getter(getter, ellipsoid)?,
+        )),
+        9827 => Some(Box::new(BonneProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9828 => Some(Box::new(BonneProjection::south_orientated_with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9808 => Some(Box::new(
+            TransverseMercatorSouthOrientatedProjection::with_db_getter(getter, ellipsoid)?,
+        )),
+        1027 => Some(Box::new(
+            LambertAzimuthalEqualAreaSphericalProjection::with_db_getter(getter, ellipsoid)?,
+        )),
+        9832 => Some(Box::new(
+            ModifiedAzimuthalEquidistantProjection::with_db_getter(getter, ellipsoid)?,
+        )),
+        9831 => Some(Box::new(GuamProjection::with_db_getter(getter, ellipsoid)?)),
+        1078 => Some(Box::new(EqualEarthProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        1052 => Some(Box::new(ColombiaUrbanProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9835 => Some(Box::new(
+            LambertCylindricalEqualAreaProjection::with_db_getter(getter, ellipsoid)?,
+        )),
+        9838 => Some(Box::new(VerticalPerspectiveProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        1125 => Some(Box::new(AzimuthalEquidistantProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
+        9816 => Some(Box::new(TunisiaMiningGridProjection::with_db_getter(
+            getter, ellipsoid,
+        )?)),
         _ => None,
     }
 }
+
+/// Best-effort comparison of two projections behind trait objects.
+///
+/// As `dyn Projection` does not expose its parameters, this projects a fixed set of positions
+/// spread over the globe with both projections and compares the results bitwise. Projections
+/// with equal parameters always compare equal; different projections compare unequal unless
+/// they produce identical results at every probe position.
+pub fn projections_equal(a: &dyn Projection, b: &dyn Projection) -> bool {
+    const PROBES: [(f64, f64); 7] = [
+        (0.0, 0.0),
+        (0.1, 0.9),
+        (-0.3, -0.6),
+        (0.25, 0.3),
+        (2.7, 1.2),
+        (-1.9, -1.4),
+        (0.05, 0.05),
+    ];
+    PROBES.iter().all(|&(lon, lat)| {
+        let (ax, ay) = a.rad_to_projected(lon, lat);
+        let (bx, by) = b.rad_to_projected(lon, lat);
+        ax.to_bits() == bx.to_bits() && ay.to_bits() == by.to_bits()
+    })
+}
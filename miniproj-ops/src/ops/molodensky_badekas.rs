@@ -0,0 +1,430 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::{GeocentricCoordinate, Geographic3DCoordinate},
+    ellipsoid::Ellipsoid,
+    helmert::{helmert_params, HelmertParams, HelmertTransformation, PARAMETERS},
+    inverse::iterative_geocentric_inverse,
+    traits::{GetterContstruct, InvertibleCoordOperation},
+    CoordOperation, DbContstruct, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MolodenskyBadekasParams {
+    /// translations, rotations and scale difference
+    helmert: HelmertParams,
+    /// ordinate 1 of evaluation point
+    xp: f64,
+    /// ordinate 2 of evaluation point
+    yp: f64,
+    /// ordinate 3 of evaluation point
+    zp: f64,
+}
+
+impl PartialEq for MolodenskyBadekasParams {
+    fn eq(&self, other: &Self) -> bool {
+        self.helmert == other.helmert
+            && self.xp.to_bits() == other.xp.to_bits()
+            && self.yp.to_bits() == other.yp.to_bits()
+            && self.zp.to_bits() == other.zp.to_bits()
+    }
+}
+
+impl Eq for MolodenskyBadekasParams {}
+
+impl std::hash::Hash for MolodenskyBadekasParams {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.helmert.hash(state);
+        self.xp.to_bits().hash(state);
+        self.yp.to_bits().hash(state);
+        self.zp.to_bits().hash(state);
+    }
+}
+
+impl MolodenskyBadekasParams {
+    pub const fn new(helmert: HelmertParams, xp: f64, yp: f64, zp: f64) -> Self {
+        Self {
+            helmert,
+            xp,
+            yp,
+            zp,
+        }
+    }
+
+    /// Get translations, rotations and scale difference.
+    pub fn helmert(&self) -> &HelmertParams {
+        &self.helmert
+    }
+
+    /// Get geocentric X coordinate of the evaluation point, metres.
+    pub fn xp(&self) -> f64 {
+        self.xp
+    }
+
+    /// Get geocentric Y coordinate of the evaluation point, metres.
+    pub fn yp(&self) -> f64 {
+        self.yp
+    }
+
+    /// Get geocentric Z coordinate of the evaluation point, metres.
+    pub fn zp(&self) -> f64 {
+        self.zp
+    }
+}
+
+/// Reads the Helmert parameters and the evaluation point.
+fn molodensky_badekas_params(params: &[(u32, f64)]) -> MolodenskyBadekasParams {
+    let get = |code: u32| params.iter().find_map(|(c, v)| (*c == code).then_some(*v));
+    MolodenskyBadekasParams::new(
+        helmert_params(params, PARAMETERS),
+        get(8617).unwrap(),
+        get(8618).unwrap(),
+        get(8667).unwrap(),
+    )
+}
+
+/// Molodensky-Badekas transformation between geocentric coordinates, in the geocentric domain of
+/// the Position Vector (EPSG:1061) and Coordinate Frame rotation (EPSG:1034) methods.
+///
+/// Like a `HelmertTransformation`, but rotating and scaling about an evaluation point instead of
+/// the origin, which decorrelates the translations from the rotations for a local network. The
+/// reverse transformation keeps the evaluation point.
+#[derive(Copy, Clone, Debug)]
+pub struct MolodenskyBadekasTransformation {
+    /// translations, rotations and scale difference in the position vector convention
+    pub helmert: HelmertTransformation,
+    pub xp: f64,
+    pub yp: f64,
+    pub zp: f64,
+}
+
+impl PartialEq for MolodenskyBadekasTransformation {
+    fn eq(&self, other: &Self) -> bool {
+        self.helmert == other.helmert
+            && self.xp.to_bits() == other.xp.to_bits()
+            && self.yp.to_bits() == other.yp.to_bits()
+            && self.zp.to_bits() == other.zp.to_bits()
+    }
+}
+
+impl Eq for MolodenskyBadekasTransformation {}
+
+impl std::hash::Hash for MolodenskyBadekasTransformation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.helmert.hash(state);
+        self.xp.to_bits().hash(state);
+        self.yp.to_bits().hash(state);
+        self.zp.to_bits().hash(state);
+    }
+}
+
+impl MolodenskyBadekasTransformation {
+    /// Construct a transformation from parameters in the position vector convention.
+    pub fn new_position_vector(params: &MolodenskyBadekasParams) -> Self {
+        Self {
+            helmert: HelmertTransformation::new_position_vector(params.helmert()),
+            xp: params.xp(),
+            yp: params.yp(),
+            zp: params.zp(),
+        }
+    }
+
+    /// Construct a transformation from parameters in the coordinate frame rotation convention.
+    pub fn new_coordinate_frame(params: &MolodenskyBadekasParams) -> Self {
+        Self {
+            helmert: HelmertTransformation::new_coordinate_frame(params.helmert()),
+            ..Self::new_position_vector(params)
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which negates the Helmert parameters and
+    /// keeps the evaluation point as the EPSG dataset does.
+    pub fn reversed(&self) -> Self {
+        Self {
+            helmert: self.helmert.reversed(),
+            ..*self
+        }
+    }
+}
+
+impl CoordOperation<GeocentricCoordinate, GeocentricCoordinate>
+    for MolodenskyBadekasTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// cartesian coordinates in metres
+    fn apply(&self, from: GeocentricCoordinate) -> GeocentricCoordinate {
+        let relative =
+            GeocentricCoordinate::new(from.x - self.xp, from.y - self.yp, from.z - self.zp);
+        let to = self.helmert.apply(relative);
+        GeocentricCoordinate::new(to.x + self.xp, to.y + self.yp, to.z + self.zp)
+    }
+}
+
+impl InvertibleCoordOperation<GeocentricCoordinate, GeocentricCoordinate>
+    for MolodenskyBadekasTransformation
+{
+    fn apply_inverse(&self, to: GeocentricCoordinate) -> GeocentricCoordinate {
+        iterative_geocentric_inverse(self, to)
+    }
+}
+
+impl PseudoSerialize for MolodenskyBadekasTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"MolodenskyBadekasTransformation{{
+    helmert: {},
+    xp: {}f64,
+    yp: {}f64,
+    zp: {}f64,
+}}",
+            self.helmert.to_constructed(),
+            self.xp,
+            self.yp,
+            self.zp,
+        )
+    }
+}
+
+impl DbContstruct for MolodenskyBadekasTransformation {
+    /// Parameters in the position vector convention.
+    fn from_database_params(params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        Self::new_position_vector(&molodensky_badekas_params(params))
+    }
+}
+
+impl GetterContstruct for MolodenskyBadekasTransformation {
+    /// Parameters in the position vector convention.
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let helmert = HelmertTransformation::with_db_getter(&mut getter, ellipsoid)?;
+        Some(Self {
+            helmert,
+            xp: getter(8617)?,
+            yp: getter(8618)?,
+            zp: getter(8667)?,
+        })
+    }
+}
+
+/// Molodensky-Badekas transformation between geographic coordinates, in the geographic domain of
+/// the Position Vector (EPSG:1063, 1062) and Coordinate Frame rotation (EPSG:9636, 1039) methods.
+///
+/// Converts to geocentric coordinates on the source ellipsoid, applies the
+/// `MolodenskyBadekasTransformation` and converts back on the target ellipsoid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GeographicMolodenskyBadekasTransformation {
+    pub molodensky_badekas: MolodenskyBadekasTransformation,
+    pub source_ellipsoid: Ellipsoid,
+    pub target_ellipsoid: Ellipsoid,
+}
+
+impl GeographicMolodenskyBadekasTransformation {
+    pub fn new(
+        molodensky_badekas: MolodenskyBadekasTransformation,
+        source: &Ellipsoid,
+        target: &Ellipsoid,
+    ) -> Self {
+        Self {
+            molodensky_badekas,
+            source_ellipsoid: *source,
+            target_ellipsoid: *target,
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which also swaps the ellipsoids.
+    pub fn reversed(&self) -> Self {
+        Self::new(
+            self.molodensky_badekas.reversed(),
+            &self.target_ellipsoid,
+            &self.source_ellipsoid,
+        )
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for GeographicMolodenskyBadekasTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        let (x, y, z) = self
+            .source_ellipsoid
+            .rad_to_geocentric(from.lon, from.lat, from.height);
+        let to = self
+            .molodensky_badekas
+            .apply(GeocentricCoordinate::new(x, y, z));
+        let (lon, lat, height) = self.target_ellipsoid.geocentric_to_rad(to.x, to.y, to.z);
+        Geographic3DCoordinate { lon, lat, height }
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for GeographicMolodenskyBadekasTransformation
+{
+    /// longitude & latitude in radians
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        let (x, y, z) = self
+            .target_ellipsoid
+            .rad_to_geocentric(to.lon, to.lat, to.height);
+        let from = self
+            .molodensky_badekas
+            .apply_inverse(GeocentricCoordinate::new(x, y, z));
+        let (lon, lat, height) = self
+            .source_ellipsoid
+            .geocentric_to_rad(from.x, from.y, from.z);
+        Geographic3DCoordinate { lon, lat, height }
+    }
+}
+
+impl PseudoSerialize for GeographicMolodenskyBadekasTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"GeographicMolodenskyBadekasTransformation{{
+    molodensky_badekas: {},
+    source_ellipsoid: {},
+    target_ellipsoid: {},
+}}",
+            self.molodensky_badekas.to_constructed(),
+            self.source_ellipsoid.to_constructed(),
+            self.target_ellipsoid.to_constructed(),
+        )
+    }
+}
+
+pub fn direct_transformation_position_vector(
+    params: &[(u32, f64)],
+    source: Ellipsoid,
+    target: Ellipsoid,
+) -> String {
+    let molodensky_badekas = MolodenskyBadekasTransformation::from_database_params(params, &source);
+    GeographicMolodenskyBadekasTransformation::new(molodensky_badekas, &source, &target)
+        .to_constructed()
+}
+
+pub fn direct_transformation_coordinate_frame(
+    params: &[(u32, f64)],
+    source: Ellipsoid,
+    target: Ellipsoid,
+) -> String {
+    let molodensky_badekas =
+        MolodenskyBadekasTransformation::new_coordinate_frame(&molodensky_badekas_params(params));
+    GeographicMolodenskyBadekasTransformation::new(molodensky_badekas, &source, &target)
+        .to_constructed()
+}
+
+pub fn direct_geocentric_position_vector(params: &[(u32, f64)]) -> String {
+    MolodenskyBadekasTransformation::new_position_vector(&molodensky_badekas_params(params))
+        .to_constructed()
+}
+
+pub fn direct_geocentric_coordinate_frame(params: &[(u32, f64)]) -> String {
+    MolodenskyBadekasTransformation::new_coordinate_frame(&molodensky_badekas_params(params))
+        .to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::{GeocentricCoordinate, Geographic3DCoordinate};
+    use crate::ellipsoid::Ellipsoid;
+    use crate::helmert::HelmertParams;
+    use crate::molodensky_badekas::*;
+    use crate::traits::*;
+
+    #[test]
+    fn molodensky_badekas_consistency() {
+        // EPSG example, La Canoa to REGVEN
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let helmert = HelmertParams::new(
+            -270.933,
+            115.599,
+            -360.226,
+            -5.266 * arcsec,
+            -1.238 * arcsec,
+            2.381 * arcsec,
+            -5.109e-6,
+        );
+        let params = MolodenskyBadekasParams::new(helmert, 2464351.59, -5783466.61, 974809.81);
+        let transformation = MolodenskyBadekasTransformation::new_coordinate_frame(&params);
+
+        let from = GeocentricCoordinate::new(2550408.96, -5749912.26, 1054891.11);
+        let to = transformation.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.x - 2550138.45).abs() < 0.01);
+        assert!((to.y - (-5749799.87)).abs() < 0.01);
+        assert!((to.z - 1054530.82).abs() < 0.01);
+
+        // the reverse with negated parameters is approximate at the centimetre level
+        let back = transformation.reversed().apply(to);
+        eprintln!("{back:?}");
+        assert!((back.x - from.x).abs() < 0.05);
+        assert!((back.y - from.y).abs() < 0.05);
+        assert!((back.z - from.z).abs() < 0.05);
+
+        let database = [
+            (8605, -270.933),
+            (8606, 115.599),
+            (8607, -360.226),
+            (8608, 5.266 * arcsec),
+            (8609, 1.238 * arcsec),
+            (8610, -2.381 * arcsec),
+            (8611, -5.109e-6),
+            (8617, 2464351.59),
+            (8618, -5783466.61),
+            (8667, 974809.81),
+        ];
+        let ell = Ellipsoid::from_a_f_inv(6378388.0, 297.0);
+        let from_db = MolodenskyBadekasTransformation::from_database_params(&database, &ell);
+        let getter = |code| {
+            database
+                .iter()
+                .find_map(|(c, v)| (*c == code).then_some(*v))
+        };
+        assert_eq!(
+            MolodenskyBadekasTransformation::with_db_getter(getter, &ell),
+            Some(from_db)
+        );
+        assert!((from_db.apply(from).x - to.x).abs() < 1e-6);
+        assert!(MolodenskyBadekasTransformation::with_db_getter(|_| None, &ell).is_none());
+    }
+
+    #[test]
+    fn geographic_molodensky_badekas() {
+        let intl = Ellipsoid::from_a_f_inv(6378388.0, 297.0);
+        let grs80 = Ellipsoid::from_a_f_inv(6378137.0, 298.257222101);
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let helmert = HelmertParams::new(
+            -270.933,
+            115.599,
+            -360.226,
+            -5.266 * arcsec,
+            -1.238 * arcsec,
+            2.381 * arcsec,
+            -5.109e-6,
+        );
+        let params = MolodenskyBadekasParams::new(helmert, 2464351.59, -5783466.61, 974809.81);
+        let transformation = GeographicMolodenskyBadekasTransformation::new(
+            MolodenskyBadekasTransformation::new_coordinate_frame(&params),
+            &intl,
+            &grs80,
+        );
+        let from = Geographic3DCoordinate::from_deg(-66.0, 9.5, 200.0);
+        let to = transformation.apply(from);
+        let (x, y, z) = intl.rad_to_geocentric(from.lon, from.lat, from.height);
+        let goal = transformation
+            .molodensky_badekas
+            .apply(GeocentricCoordinate::new(x, y, z));
+        let (x_t, y_t, z_t) = grs80.rad_to_geocentric(to.lon, to.lat, to.height);
+        eprintln!("{:?}", to.to_deg());
+        assert!((x_t - goal.x).abs() < 1e-6);
+        assert!((y_t - goal.y).abs() < 1e-6);
+        assert!((z_t - goal.z).abs() < 1e-6);
+
+        let back = transformation.reversed().apply(to);
+        assert!((back.lon - from.lon).abs() < 1e-8);
+        assert!((back.lat - from.lat).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,329 @@
+//This file is licensed under EUPL v1.2
+
+use std::sync::Arc;
+
+use crate::{
+    coordinate::{GeocentricCoordinate, Geographic3DCoordinate},
+    ellipsoid::Ellipsoid,
+    CoordOperation,
+};
+
+/// Coordinate passed between the steps of a `Pipeline`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PipelineCoordinate {
+    Geographic3D(Geographic3DCoordinate),
+    Geocentric(GeocentricCoordinate),
+}
+
+impl PipelineCoordinate {
+    /// Get the geographic coordinate, or `None` for a geocentric coordinate.
+    pub fn geographic(&self) -> Option<Geographic3DCoordinate> {
+        match self {
+            Self::Geographic3D(coordinate) => Some(*coordinate),
+            Self::Geocentric(_) => None,
+        }
+    }
+
+    /// Get the geocentric coordinate, or `None` for a geographic coordinate.
+    pub fn geocentric(&self) -> Option<GeocentricCoordinate> {
+        match self {
+            Self::Geocentric(coordinate) => Some(*coordinate),
+            Self::Geographic3D(_) => None,
+        }
+    }
+}
+
+impl From<Geographic3DCoordinate> for PipelineCoordinate {
+    fn from(coordinate: Geographic3DCoordinate) -> Self {
+        Self::Geographic3D(coordinate)
+    }
+}
+
+impl From<GeocentricCoordinate> for PipelineCoordinate {
+    fn from(coordinate: GeocentricCoordinate) -> Self {
+        Self::Geocentric(coordinate)
+    }
+}
+
+type GeographicOp = Arc<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>;
+type GeocentricOp = Arc<dyn CoordOperation<GeocentricCoordinate, GeocentricCoordinate>>;
+
+#[derive(Clone)]
+enum StepOp {
+    Geographic(GeographicOp),
+    Geocentric(GeocentricOp),
+    ToGeocentric(Ellipsoid),
+    ToGeographic(Ellipsoid),
+}
+
+/// Step of a `Pipeline`, an operation on geographic or geocentric coordinates or a conversion
+/// between the two, together with its inverse if known.
+#[derive(Clone)]
+pub struct PipelineStep {
+    forward: StepOp,
+    inverse: Option<StepOp>,
+}
+
+impl PipelineStep {
+    /// Step applying an operation on geographic coordinates, without a known inverse.
+    pub fn geographic(
+        operation: impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate> + 'static,
+    ) -> Self {
+        Self {
+            forward: StepOp::Geographic(Arc::new(operation)),
+            inverse: None,
+        }
+    }
+
+    /// Step applying an operation on geographic coordinates, which `inverse` reverts.
+    pub fn geographic_with_inverse(
+        operation: impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate> + 'static,
+        inverse: impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate> + 'static,
+    ) -> Self {
+        Self {
+            forward: StepOp::Geographic(Arc::new(operation)),
+            inverse: Some(StepOp::Geographic(Arc::new(inverse))),
+        }
+    }
+
+    /// Step applying an operation on geocentric coordinates, without a known inverse.
+    pub fn geocentric(
+        operation: impl CoordOperation<GeocentricCoordinate, GeocentricCoordinate> + 'static,
+    ) -> Self {
+        Self {
+            forward: StepOp::Geocentric(Arc::new(operation)),
+            inverse: None,
+        }
+    }
+
+    /// Step applying an operation on geocentric coordinates, which `inverse` reverts.
+    pub fn geocentric_with_inverse(
+        operation: impl CoordOperation<GeocentricCoordinate, GeocentricCoordinate> + 'static,
+        inverse: impl CoordOperation<GeocentricCoordinate, GeocentricCoordinate> + 'static,
+    ) -> Self {
+        Self {
+            forward: StepOp::Geocentric(Arc::new(operation)),
+            inverse: Some(StepOp::Geocentric(Arc::new(inverse))),
+        }
+    }
+
+    /// Step converting geographic coordinates to geocentric coordinates on `ellipsoid`.
+    pub fn to_geocentric(ellipsoid: Ellipsoid) -> Self {
+        Self {
+            forward: StepOp::ToGeocentric(ellipsoid),
+            inverse: Some(StepOp::ToGeographic(ellipsoid)),
+        }
+    }
+
+    /// Step converting geocentric coordinates to geographic coordinates on `ellipsoid`.
+    pub fn to_geographic(ellipsoid: Ellipsoid) -> Self {
+        Self {
+            forward: StepOp::ToGeographic(ellipsoid),
+            inverse: Some(StepOp::ToGeocentric(ellipsoid)),
+        }
+    }
+
+    /// Get the step in the reverse direction, or `None` if the inverse is not known.
+    pub fn inverse(&self) -> Option<Self> {
+        Some(Self {
+            forward: self.inverse.clone()?,
+            inverse: Some(self.forward.clone()),
+        })
+    }
+
+    /// Apply the step, returning `None` if the coordinate is not of the kind the step acts on.
+    pub fn try_apply(&self, from: PipelineCoordinate) -> Option<PipelineCoordinate> {
+        Some(match (&self.forward, from) {
+            (StepOp::Geographic(op), PipelineCoordinate::Geographic3D(c)) => op.apply(c).into(),
+            (StepOp::Geocentric(op), PipelineCoordinate::Geocentric(c)) => op.apply(c).into(),
+            (StepOp::ToGeocentric(ell), PipelineCoordinate::Geographic3D(c)) => {
+                let (x, y, z) = ell.rad_to_geocentric(c.lon, c.lat, c.height);
+                GeocentricCoordinate::new(x, y, z).into()
+            }
+            (StepOp::ToGeographic(ell), PipelineCoordinate::Geocentric(c)) => {
+                let (lon, lat, height) = ell.geocentric_to_rad(c.x, c.y, c.z);
+                Geographic3DCoordinate::new(lon, lat, height).into()
+            }
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Debug for PipelineStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match &self.forward {
+            StepOp::Geographic(_) => "Geographic",
+            StepOp::Geocentric(_) => "Geocentric",
+            StepOp::ToGeocentric(_) => "ToGeocentric",
+            StepOp::ToGeographic(_) => "ToGeographic",
+        };
+        f.debug_struct("PipelineStep")
+            .field("kind", &kind)
+            .field("invertible", &self.inverse.is_some())
+            .finish()
+    }
+}
+
+/// Chain of coordinate operations assembled at runtime, like a PROJ pipeline.
+///
+/// Unlike `ConcatenatedCoordOp`, the steps are not checked at compile time: each step acts on
+/// either geographic or geocentric coordinates, and a coordinate reaching a step of the other kind
+/// makes the pipeline fail. Insert `PipelineStep::to_geocentric` and `PipelineStep::to_geographic`
+/// steps to switch between the two.
+#[derive(Clone, Debug, Default)]
+pub struct Pipeline {
+    steps: Vec<PipelineStep>,
+}
+
+impl Pipeline {
+    /// Construct an empty pipeline, which returns coordinates unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a step to the end of the pipeline.
+    pub fn push(&mut self, step: PipelineStep) -> &mut Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Insert a step at position `index`, shifting all steps after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, step: PipelineStep) -> &mut Self {
+        self.steps.insert(index, step);
+        self
+    }
+
+    /// Append the inverse of a step, returning `None` and leaving the pipeline unchanged if the
+    /// inverse is not known.
+    pub fn push_inverse(&mut self, step: &PipelineStep) -> Option<&mut Self> {
+        self.steps.push(step.inverse()?);
+        Some(self)
+    }
+
+    /// Get the steps of the pipeline.
+    pub fn steps(&self) -> &[PipelineStep] {
+        &self.steps
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Get the pipeline in the reverse direction, applying the inverse steps in reverse order.
+    /// Returns `None` if the inverse of a step is not known.
+    pub fn inverse(&self) -> Option<Self> {
+        let steps = self
+            .steps
+            .iter()
+            .rev()
+            .map(PipelineStep::inverse)
+            .collect::<Option<_>>()?;
+        Some(Self { steps })
+    }
+
+    /// Apply all steps, returning `None` if a coordinate reaches a step of the other kind.
+    pub fn try_apply(&self, from: PipelineCoordinate) -> Option<PipelineCoordinate> {
+        self.steps
+            .iter()
+            .try_fold(from, |coordinate, step| step.try_apply(coordinate))
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate> for Pipeline {
+    /// longitude & latitude in radians, NaN if the pipeline does not end in geographic coordinates
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        self.try_apply(from.into())
+            .and_then(|to| to.geographic())
+            .unwrap_or(Geographic3DCoordinate::new(f64::NAN, f64::NAN, f64::NAN))
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, GeocentricCoordinate> for Pipeline {
+    /// longitude & latitude in radians, NaN if the pipeline does not end in geocentric coordinates
+    fn apply(&self, from: Geographic3DCoordinate) -> GeocentricCoordinate {
+        self.try_apply(from.into())
+            .and_then(|to| to.geocentric())
+            .unwrap_or(GeocentricCoordinate::new(f64::NAN, f64::NAN, f64::NAN))
+    }
+}
+
+impl CoordOperation<GeocentricCoordinate, Geographic3DCoordinate> for Pipeline {
+    /// longitude & latitude in radians, NaN if the pipeline does not end in geographic coordinates
+    fn apply(&self, from: GeocentricCoordinate) -> Geographic3DCoordinate {
+        self.try_apply(from.into())
+            .and_then(|to| to.geographic())
+            .unwrap_or(Geographic3DCoordinate::new(f64::NAN, f64::NAN, f64::NAN))
+    }
+}
+
+impl CoordOperation<GeocentricCoordinate, GeocentricCoordinate> for Pipeline {
+    /// NaN if the pipeline does not end in geocentric coordinates
+    fn apply(&self, from: GeocentricCoordinate) -> GeocentricCoordinate {
+        self.try_apply(from.into())
+            .and_then(|to| to.geocentric())
+            .unwrap_or(GeocentricCoordinate::new(f64::NAN, f64::NAN, f64::NAN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::{GeocentricCoordinate, Geographic3DCoordinate};
+    use crate::ellipsoid::Ellipsoid;
+    use crate::helmert::*;
+    use crate::longitude_rotation::*;
+    use crate::pipeline::*;
+    use crate::traits::*;
+
+    #[test]
+    fn pipeline() {
+        // WGS 72 to WGS 84 through geocentric coordinates, as in the Helmert example
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let wgs72 = Ellipsoid::from_a_f_inv(6378135.0, 298.26);
+        let wgs84 = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params = HelmertParams::new(0.0, 0.0, 4.5, 0.0, 0.0, 0.554 * arcsec, 0.219e-6);
+        let helmert = HelmertTransformation::new_position_vector(&params);
+        let mut pipeline = Pipeline::new();
+        pipeline
+            .push(PipelineStep::to_geocentric(wgs72))
+            .push(PipelineStep::to_geographic(wgs84));
+        let step = PipelineStep::geocentric_with_inverse(helmert, helmert.reversed());
+        pipeline.insert(1, step);
+        assert_eq!(pipeline.len(), 3);
+
+        let from = Geographic3DCoordinate::from_deg(4.0, 55.0, 0.0);
+        let to: Geographic3DCoordinate = pipeline.apply(from);
+        let goal = GeographicHelmertTransformation::new(helmert, &wgs72, &wgs84).apply(from);
+        eprintln!("{:?} - {:?}", to.to_deg(), goal.to_deg());
+        assert_eq!(to, goal);
+
+        let back: Geographic3DCoordinate = pipeline.inverse().unwrap().apply(to);
+        assert!((back.lon - from.lon).abs() < 1e-10);
+        assert!((back.lat - from.lat).abs() < 1e-10);
+        assert!((back.height - from.height).abs() < 1e-3);
+
+        // the pipeline does not end in geocentric coordinates
+        let geocentric: GeocentricCoordinate = pipeline.apply(from);
+        assert!(geocentric.x.is_nan());
+        // geocentric coordinates do not reach the first step
+        assert!(pipeline
+            .try_apply(GeocentricCoordinate::new(0.0, 0.0, 0.0).into())
+            .is_none());
+
+        let rotation = PipelineStep::geographic(LongitudeRotationTransformation::new(
+            &LongitudeRotationParams::new(0.1),
+        ));
+        pipeline.push(rotation.clone());
+        assert!(pipeline.inverse().is_none());
+        assert!(pipeline.push_inverse(&rotation).is_none());
+        assert_eq!(pipeline.len(), 4);
+    }
+}
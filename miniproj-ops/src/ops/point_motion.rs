@@ -0,0 +1,303 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::{GeocentricCoordinate, Geographic3DCoordinate},
+    ellipsoid::Ellipsoid,
+    epoch::Epoch,
+    CoordOperation,
+};
+
+/// Get the parameters `codes` from the database, converted from metres per second to metres per
+/// year. Returns `None` if one is missing.
+fn velocities(params: &[(u32, f64)], codes: [u32; 3]) -> Option<[f64; 3]> {
+    let get = |code| {
+        params.iter().find_map(|(c, v)| {
+            if *c == code {
+                Some(*v * Epoch::SECONDS_PER_YEAR)
+            } else {
+                None
+            }
+        })
+    };
+    Some([get(codes[0])?, get(codes[1])?, get(codes[2])?])
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeocentricPointMotionParams {
+    /// velocity along the X axis
+    vx: f64,
+    /// velocity along the Y axis
+    vy: f64,
+    /// velocity along the Z axis
+    vz: f64,
+}
+
+impl_param_eq_hash!(GeocentricPointMotionParams { vx, vy, vz });
+
+impl GeocentricPointMotionParams {
+    pub const fn new(vx: f64, vy: f64, vz: f64) -> Self {
+        Self { vx, vy, vz }
+    }
+
+    /// Get the parameters from database parameters in base units, returning `None` if one is
+    /// missing.
+    pub fn from_database_params(params: &[(u32, f64)]) -> Option<Self> {
+        let [vx, vy, vz] = velocities(params, [1052, 1053, 1054])?;
+        Some(Self::new(vx, vy, vz))
+    }
+
+    /// Get velocity along the X axis, metres per year.
+    pub fn vx(&self) -> f64 {
+        self.vx
+    }
+
+    /// Get velocity along the Y axis, metres per year.
+    pub fn vy(&self) -> f64 {
+        self.vy
+    }
+
+    /// Get velocity along the Z axis, metres per year.
+    pub fn vz(&self) -> f64 {
+        self.vz
+    }
+}
+
+/// Point motion (geocentric Cartesian) operation (EPSG:1064).
+///
+/// Moves geocentric coordinates from one coordinate epoch to another within a dynamic CRS by a
+/// constant velocity, e.g. the plate motion of a station.
+#[derive(Copy, Clone, Debug)]
+pub struct GeocentricPointMotionTransformation {
+    pub vx: f64,
+    pub vy: f64,
+    pub vz: f64,
+    /// time span from the source to the target epoch, years
+    pub dt: f64,
+}
+
+impl_param_eq_hash!(GeocentricPointMotionTransformation { vx, vy, vz, dt });
+
+impl GeocentricPointMotionTransformation {
+    /// Construct the operation moving coordinates from `from_epoch` to `to_epoch`.
+    pub fn new(params: &GeocentricPointMotionParams, from_epoch: Epoch, to_epoch: Epoch) -> Self {
+        Self {
+            vx: params.vx(),
+            vy: params.vy(),
+            vz: params.vz(),
+            dt: to_epoch.years_since(from_epoch),
+        }
+    }
+
+    /// Get the operation in the reverse direction, which moves coordinates back to the source
+    /// epoch.
+    pub fn reversed(&self) -> Self {
+        Self {
+            dt: -self.dt,
+            ..*self
+        }
+    }
+}
+
+impl CoordOperation<GeocentricCoordinate, GeocentricCoordinate>
+    for GeocentricPointMotionTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    fn apply(&self, from: GeocentricCoordinate) -> GeocentricCoordinate {
+        GeocentricCoordinate {
+            x: from.x + self.dt * self.vx,
+            y: from.y + self.dt * self.vy,
+            z: from.z + self.dt * self.vz,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EllipsoidalPointMotionParams {
+    /// velocity towards north
+    v_north: f64,
+    /// velocity towards east
+    v_east: f64,
+    /// velocity upwards
+    v_up: f64,
+}
+
+impl_param_eq_hash!(EllipsoidalPointMotionParams {
+    v_north,
+    v_east,
+    v_up
+});
+
+impl EllipsoidalPointMotionParams {
+    pub const fn new(v_north: f64, v_east: f64, v_up: f64) -> Self {
+        Self {
+            v_north,
+            v_east,
+            v_up,
+        }
+    }
+
+    /// Get the parameters from database parameters in base units, returning `None` if one is
+    /// missing.
+    pub fn from_database_params(params: &[(u32, f64)]) -> Option<Self> {
+        let [v_north, v_east, v_up] = velocities(params, [1055, 1056, 1057])?;
+        Some(Self::new(v_north, v_east, v_up))
+    }
+
+    /// Get velocity towards north, metres per year.
+    pub fn v_north(&self) -> f64 {
+        self.v_north
+    }
+
+    /// Get velocity towards east, metres per year.
+    pub fn v_east(&self) -> f64 {
+        self.v_east
+    }
+
+    /// Get velocity upwards, metres per year.
+    pub fn v_up(&self) -> f64 {
+        self.v_up
+    }
+}
+
+/// Point motion (ellipsoidal) operation (EPSG:1067).
+///
+/// Moves geographic coordinates from one coordinate epoch to another within a dynamic CRS by a
+/// constant velocity given in north, east and up components. The linear velocities are converted
+/// to angular ones with the radii of curvature of the CRS's ellipsoid at the position.
+#[derive(Copy, Clone, Debug)]
+pub struct EllipsoidalPointMotionTransformation {
+    pub v_north: f64,
+    pub v_east: f64,
+    pub v_up: f64,
+    /// time span from the source to the target epoch, years
+    pub dt: f64,
+    pub ellipsoid: Ellipsoid,
+}
+
+impl PartialEq for EllipsoidalPointMotionTransformation {
+    fn eq(&self, other: &Self) -> bool {
+        self.v_north.to_bits() == other.v_north.to_bits()
+            && self.v_east.to_bits() == other.v_east.to_bits()
+            && self.v_up.to_bits() == other.v_up.to_bits()
+            && self.dt.to_bits() == other.dt.to_bits()
+            && self.ellipsoid == other.ellipsoid
+    }
+}
+
+impl Eq for EllipsoidalPointMotionTransformation {}
+
+impl std::hash::Hash for EllipsoidalPointMotionTransformation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.v_north.to_bits().hash(state);
+        self.v_east.to_bits().hash(state);
+        self.v_up.to_bits().hash(state);
+        self.dt.to_bits().hash(state);
+        self.ellipsoid.hash(state);
+    }
+}
+
+impl EllipsoidalPointMotionTransformation {
+    /// Construct the operation moving coordinates on `ell` from `from_epoch` to `to_epoch`.
+    pub fn new(
+        ell: &Ellipsoid,
+        params: &EllipsoidalPointMotionParams,
+        from_epoch: Epoch,
+        to_epoch: Epoch,
+    ) -> Self {
+        Self {
+            v_north: params.v_north(),
+            v_east: params.v_east(),
+            v_up: params.v_up(),
+            dt: to_epoch.years_since(from_epoch),
+            ellipsoid: *ell,
+        }
+    }
+
+    /// Get the operation in the reverse direction, which moves coordinates back to the source
+    /// epoch.
+    pub fn reversed(&self) -> Self {
+        Self {
+            dt: -self.dt,
+            ..*self
+        }
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for EllipsoidalPointMotionTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        let v_lat = self.v_north / (self.ellipsoid.rho(from.lat) + from.height);
+        let v_lon = self.v_east / ((self.ellipsoid.ny(from.lat) + from.height) * from.lat.cos());
+        Geographic3DCoordinate {
+            lon: from.lon + self.dt * v_lon,
+            lat: from.lat + self.dt * v_lat,
+            height: from.height + self.dt * self.v_up,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::{GeocentricCoordinate, Geographic3DCoordinate};
+    use crate::ellipsoid::Ellipsoid;
+    use crate::epoch::Epoch;
+    use crate::point_motion::*;
+    use crate::traits::*;
+
+    #[test]
+    fn geocentric_point_motion() {
+        let params = GeocentricPointMotionParams::new(-0.0212, 0.0124, 0.0072);
+        let transformation = GeocentricPointMotionTransformation::new(
+            &params,
+            Epoch::from_decimal_year(2005.0).unwrap(),
+            Epoch::from_decimal_year(2010.0).unwrap(),
+        );
+        let from = GeocentricCoordinate::new(2845456.0813, 2160954.2453, 5265993.2296);
+        let to = transformation.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.x - 2845455.9753).abs() < 1e-4);
+        assert!((to.y - 2160954.3073).abs() < 1e-4);
+        assert!((to.z - 5265993.2656).abs() < 1e-4);
+
+        let back = transformation.reversed().apply(to);
+        assert!((back.x - from.x).abs() < 1e-9);
+        assert!((back.z - from.z).abs() < 1e-9);
+
+        // velocities are stored in metres per second in the database
+        let per_second = 0.0212 / Epoch::SECONDS_PER_YEAR;
+        let db = [(1052, -per_second), (1053, 0.0), (1054, 0.0)];
+        let params = GeocentricPointMotionParams::from_database_params(&db).unwrap();
+        assert!((params.vx() + 0.0212).abs() < 1e-12);
+        assert!(GeocentricPointMotionParams::from_database_params(&db[..2]).is_none());
+    }
+
+    #[test]
+    fn ellipsoidal_point_motion() {
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257222101);
+        let params = EllipsoidalPointMotionParams::new(0.01512, -0.00286, 0.0011);
+        let transformation = EllipsoidalPointMotionTransformation::new(
+            &ell,
+            &params,
+            Epoch::from_decimal_year(2017.55).unwrap(),
+            Epoch::from_decimal_year(1997.0).unwrap(),
+        );
+        let from = Geographic3DCoordinate::from_deg(-141.0, 51.0, 1000.0);
+        let (lon, lat, height) = transformation.apply(from).to_deg();
+        let lat_goal = 50.0 + 59.0 / 60.0 + 59.990 / 3600.0;
+        let lon_goal = -(140.0 + 59.0 / 60.0 + 59.997 / 3600.0);
+
+        eprintln!("lon: {lon_goal} - {lon}");
+        eprintln!("lat: {lat_goal} - {lat}");
+        eprintln!("height: 999.977 - {height}");
+
+        assert!((lon - lon_goal).abs() < 0.0005 / 3600.0);
+        assert!((lat - lat_goal).abs() < 0.0005 / 3600.0);
+        assert!((height - 999.977).abs() < 0.0005);
+    }
+}
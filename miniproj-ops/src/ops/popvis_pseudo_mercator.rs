@@ -7,6 +7,7 @@ use crate::{
 };
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PopVisPseudoMercatorParams {
     /// longitude of natural origin
     lon_orig: f64,
@@ -18,6 +19,13 @@ pub struct PopVisPseudoMercatorParams {
     false_n: f64,
 }
 
+impl_param_eq_hash!(PopVisPseudoMercatorParams {
+    lon_orig,
+    lat_orig,
+    false_e,
+    false_n
+});
+
 impl PopVisPseudoMercatorParams {
     pub const fn new(lon_orig: f64, lat_orig: f64, false_e: f64, false_n: f64) -> Self {
         Self {
@@ -59,6 +67,13 @@ pub struct PopVisPseudoMercatorProjection {
     pub lon_orig: f64,
 }
 
+impl_param_eq_hash!(PopVisPseudoMercatorProjection {
+    false_e,
+    false_n,
+    ellipsoid_a,
+    lon_orig
+});
+
 impl PopVisPseudoMercatorProjection {
     #[allow(non_snake_case)]
     pub fn new(ell: &Ellipsoid, params: &PopVisPseudoMercatorParams) -> Self {
@@ -92,6 +107,13 @@ impl Projection for PopVisPseudoMercatorProjection {
             FRAC_PI_2 - 2.0 * D.exp().atan(),
         )
     }
+
+    fn jacobian(&self, _longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        [
+            [self.ellipsoid_a, 0.0],
+            [0.0, self.ellipsoid_a / latitude.cos()],
+        ]
+    }
 }
 
 impl PseudoSerialize for PopVisPseudoMercatorProjection {
@@ -178,4 +200,16 @@ mod tests {
 
         assert!((northing - northing_goal).abs() < 0.01);
     }
+
+    #[test]
+    fn popvis_mercator_jacobian() {
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params =
+            PopVisPseudoMercatorParams::new(0.0f64.to_radians(), 0.0f64.to_radians(), 0.0, 0.0);
+
+        let projection = PopVisPseudoMercatorProjection::new(&ell, &params);
+        for (lon, lat) in [(-100.0, 24.0), (0.0, 0.0), (10.0, 54.0), (170.0, -80.0)] {
+            assert_jacobian(&projection, lon, lat);
+        }
+    }
 }
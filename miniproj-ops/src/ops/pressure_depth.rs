@@ -0,0 +1,76 @@
+//This file is licensed under EUPL v1.2
+
+//! Conversions between sea water pressure and depth, for placing CTD profiles in 3D space.
+//!
+//! Implements the UNESCO 1983 formula (Fofonoff & Millard, UNESCO technical papers in marine
+//! science 44) for a standard ocean of salinity 35 and temperature 0 °C. Pressures are sea pressure
+//! in decibar, i.e. absolute pressure minus one standard atmosphere, depths are positive downwards
+//! in meters and latitudes are in degrees.
+
+const MAX_ITERATIONS: usize = 6;
+
+fn gravity(pressure: f64, lat: f64) -> f64 {
+    let x = lat.to_radians().sin().powi(2);
+    9.780318 * (1.0 + (5.2788e-3 + 2.36e-5 * x) * x) + 1.092e-6 * pressure
+}
+
+fn specific_volume_integral(pressure: f64) -> f64 {
+    (((-1.82e-15 * pressure + 2.279e-10) * pressure - 2.2512e-5) * pressure + 9.72659) * pressure
+}
+
+/// Depth in meters for a sea pressure in decibar at the given latitude in degrees.
+pub fn pressure_to_depth(pressure: f64, lat: f64) -> f64 {
+    specific_volume_integral(pressure) / gravity(pressure, lat)
+}
+
+/// Sea pressure in decibar for a depth in meters at the given latitude in degrees.
+///
+/// Starts from the approximation by Saunders (1981) and refines it by Newton's method, so that
+/// `pressure_to_depth` reproduces the depth to well below a millimeter.
+pub fn depth_to_pressure(depth: f64, lat: f64) -> f64 {
+    let c1 = 5.92e-3 + 5.25e-3 * lat.to_radians().sin().powi(2);
+    let mut pressure = ((1.0 - c1) - ((1.0 - c1).powi(2) - 8.84e-6 * depth).sqrt()) / 4.42e-6;
+    if !pressure.is_finite() {
+        pressure = depth;
+    }
+    for _ in 0..MAX_ITERATIONS {
+        let residual = pressure_to_depth(pressure, lat) - depth;
+        // derivative of the depth with respect to pressure
+        let g = gravity(pressure, lat);
+        let dv = ((-4.0 * 1.82e-15 * pressure + 3.0 * 2.279e-10) * pressure - 2.0 * 2.2512e-5)
+            * pressure
+            + 9.72659;
+        let derivative = (dv * g - specific_volume_integral(pressure) * 1.092e-6) / (g * g);
+        let step = residual / derivative;
+        pressure -= step;
+        if step.abs() < 1e-9 {
+            break;
+        }
+    }
+    pressure
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pressure_depth::*;
+
+    #[test]
+    fn unesco_check_value() {
+        // check value from UNESCO technical papers in marine science 44
+        let depth = pressure_to_depth(10000.0, 30.0);
+        eprintln!("depth: {depth}");
+        assert!((depth - 9712.653).abs() < 1e-3);
+    }
+
+    #[test]
+    fn pressure_depth_consistency() {
+        for lat in [-80.0, -30.0, 0.0, 45.0, 89.0] {
+            for depth in [0.0, 1.0, 150.0, 2000.0, 6000.0, 11000.0] {
+                let pressure = depth_to_pressure(depth, lat);
+                let roundtrip = pressure_to_depth(pressure, lat);
+                eprintln!("{lat}, {depth}: {pressure} dbar - {roundtrip}");
+                assert!((roundtrip - depth).abs() < 1e-6);
+            }
+        }
+    }
+}
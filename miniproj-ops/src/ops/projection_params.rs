@@ -0,0 +1,768 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    albers_equal_area::{AlbersEqualAreaParams, AlbersEqualAreaProjection},
+    azimuthal_equidistant::{
+        AzimuthalEquidistantParams, AzimuthalEquidistantProjection, GuamProjection,
+        ModifiedAzimuthalEquidistantProjection,
+    },
+    bonne::{BonneParams, BonneProjection},
+    cassini_soldner::{
+        CassiniSoldnerParams, CassiniSoldnerProjection, HyperbolicCassiniSoldnerParams,
+        HyperbolicCassiniSoldnerProjection,
+    },
+    colombia_urban::{ColombiaUrbanParams, ColombiaUrbanProjection},
+    equal_earth::{EqualEarthParams, EqualEarthProjection},
+    equidistant_cylindrical::{
+        EquidistantCylindricalParams, EquidistantCylindricalProjection,
+        EquidistantCylindricalSphericalProjection,
+    },
+    hotine_oblique_mercator::{
+        HotineObliqueMercatorAProjection, HotineObliqueMercatorBProjection,
+        HotineObliqueMercatorParams,
+    },
+    identity_projection::IdentityProjection,
+    krovak::{KrovakParams, KrovakProjection},
+    lambert_azimuthal_equal_area::{
+        LambertAzimuthalEqualAreaParams, LambertAzimuthalEqualAreaProjection,
+        LambertAzimuthalEqualAreaSphericalProjection,
+    },
+    lambert_conic_conformal::{
+        LambertConic1SPAParams, LambertConic1SPAProjection, LambertConic2SPBelgiumProjection,
+        LambertConic2SPMichiganParams, LambertConic2SPMichiganProjection, LambertConic2SPParams,
+        LambertConic2SPProjection, LambertConicNearConformalProjection,
+    },
+    lambert_cylindrical_equal_area::{
+        LambertCylindricalEqualAreaParams, LambertCylindricalEqualAreaProjection,
+        LambertCylindricalEqualAreaSphericalProjection,
+    },
+    mercator::{
+        MercatorCParams, MercatorCProjection, MercatorSphericalParams, MercatorSphericalProjection,
+    },
+    popvis_pseudo_mercator::{PopVisPseudoMercatorParams, PopVisPseudoMercatorProjection},
+    stereographic::{
+        ObliqueStereographicParams, ObliqueStereographicProjection, PolarStereographicAParams,
+        PolarStereographicAProjection, PolarStereographicBParams, PolarStereographicBProjection,
+    },
+    transverse_mercator::{
+        TransverseMercatorParams, TransverseMercatorProjection,
+        TransverseMercatorSouthOrientatedProjection,
+    },
+    tunisia_mining_grid::{TunisiaMiningGridParams, TunisiaMiningGridProjection},
+    vertical_perspective::{VerticalPerspectiveParams, VerticalPerspectiveProjection},
+    Ellipsoid, Projection,
+};
+
+/// Defining parameters of a projection, independent of the ellipsoid it is applied to.
+///
+/// This is the in-memory definition of a projected CRS's conversion: it can be built from and
+/// turned back into a list of `(EPSG parameter code, value)` pairs, with angles in radians and
+/// lengths in meters, and instantiated for an ellipsoid with `to_projection`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProjectionParams {
+    /// EPSG:9807
+    TransverseMercator(TransverseMercatorParams),
+    /// EPSG:9810
+    PolarStereographicA(PolarStereographicAParams),
+    /// EPSG:9802
+    LambertConic2SP(LambertConic2SPParams),
+    /// EPSG:1024
+    PopVisPseudoMercator(PopVisPseudoMercatorParams),
+    /// EPSG:9801
+    LambertConic1SPA(LambertConic1SPAParams),
+    /// EPSG:9809
+    ObliqueStereographic(ObliqueStereographicParams),
+    /// EPSG:9822
+    AlbersEqualArea(AlbersEqualAreaParams),
+    /// EPSG:9820
+    LambertAzimuthalEqualArea(LambertAzimuthalEqualAreaParams),
+    /// EPSG:1026
+    MercatorSpherical(MercatorSphericalParams),
+    /// EPSG:9834
+    LambertCylindricalEqualAreaSpherical(LambertCylindricalEqualAreaParams),
+    /// EPSG:9825, which has no parameters
+    PseudoPlateCarree,
+    /// EPSG:1044
+    MercatorC(MercatorCParams),
+    /// EPSG:9812
+    HotineObliqueMercatorA(HotineObliqueMercatorParams),
+    /// EPSG:9815
+    HotineObliqueMercatorB(HotineObliqueMercatorParams),
+    /// EPSG:9806
+    CassiniSoldner(CassiniSoldnerParams),
+    /// EPSG:9833
+    HyperbolicCassiniSoldner(HyperbolicCassiniSoldnerParams),
+    /// EPSG:9819
+    Krovak(KrovakParams),
+    /// EPSG:9803
+    LambertConic2SPBelgium(LambertConic2SPParams),
+    /// EPSG:1051
+    LambertConic2SPMichigan(LambertConic2SPMichiganParams),
+    /// EPSG:9817
+    LambertConicNearConformal(LambertConic1SPAParams),
+    /// EPSG:9829
+    PolarStereographicB(PolarStereographicBParams),
+    /// EPSG:1028
+    EquidistantCylindrical(EquidistantCylindricalParams),
+    /// EPSG:1029
+    EquidistantCylindricalSpherical(EquidistantCylindricalParams),
+    /// EPSG:9827 and EPSG:9828
+    Bonne(BonneParams),
+    /// EPSG:9808
+    TransverseMercatorSouthOrientated(TransverseMercatorParams),
+    /// EPSG:1027
+    LambertAzimuthalEqualAreaSpherical(LambertAzimuthalEqualAreaParams),
+    /// EPSG:9832
+    ModifiedAzimuthalEquidistant(AzimuthalEquidistantParams),
+    /// EPSG:9831
+    Guam(AzimuthalEquidistantParams),
+    /// EPSG:1078
+    EqualEarth(EqualEarthParams),
+    /// EPSG:1052
+    ColombiaUrban(ColombiaUrbanParams),
+    /// EPSG:9835
+    LambertCylindricalEqualArea(LambertCylindricalEqualAreaParams),
+    /// EPSG:9838
+    VerticalPerspective(VerticalPerspectiveParams),
+    /// EPSG:1125
+    AzimuthalEquidistant(AzimuthalEquidistantParams),
+    /// EPSG:9816
+    TunisiaMiningGrid(TunisiaMiningGridParams),
+}
+
+impl ProjectionParams {
+    /// Try to construct the parameters for a specific method code with a getter that provides the parameter values.
+    /// Returns `None` if the method is not implemented or a required parameter is missing.
+    pub fn from_getter<G>(method_code: u32, mut getter: G) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        Some(match method_code {
+            9807 => Self::TransverseMercator(TransverseMercatorParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8805)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9810 => Self::PolarStereographicA(PolarStereographicAParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8805)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9802 => Self::LambertConic2SP(LambertConic2SPParams::new(
+                getter(8822)?,
+                getter(8821)?,
+                getter(8823)?,
+                getter(8824)?,
+                getter(8826)?,
+                getter(8827)?,
+            )),
+            1024 => Self::PopVisPseudoMercator(PopVisPseudoMercatorParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9801 => Self::LambertConic1SPA(LambertConic1SPAParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8805)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9809 => Self::ObliqueStereographic(ObliqueStereographicParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8805)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9822 => Self::AlbersEqualArea(AlbersEqualAreaParams::new(
+                getter(8822)?,
+                getter(8821)?,
+                getter(8823)?,
+                getter(8824)?,
+                getter(8826)?,
+                getter(8827)?,
+            )),
+            9820 => Self::LambertAzimuthalEqualArea(LambertAzimuthalEqualAreaParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            1026 => Self::MercatorSpherical(MercatorSphericalParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9834 => {
+                Self::LambertCylindricalEqualAreaSpherical(LambertCylindricalEqualAreaParams::new(
+                    getter(8802)?,
+                    getter(8823)?,
+                    getter(8806)?,
+                    getter(8807)?,
+                ))
+            }
+            9825 => Self::PseudoPlateCarree,
+            1044 => Self::MercatorC(MercatorCParams::new(
+                getter(8802)?,
+                getter(8821)?,
+                getter(8823)?,
+                getter(8826)?,
+                getter(8827)?,
+            )),
+            9812 => Self::HotineObliqueMercatorA(HotineObliqueMercatorParams::new(
+                getter(8811)?,
+                getter(8812)?,
+                getter(8813)?,
+                getter(8814)?,
+                getter(8815)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9815 => Self::HotineObliqueMercatorB(HotineObliqueMercatorParams::new(
+                getter(8811)?,
+                getter(8812)?,
+                getter(8813)?,
+                getter(8814)?,
+                getter(8815)?,
+                getter(8816)?,
+                getter(8817)?,
+            )),
+            9806 => Self::CassiniSoldner(CassiniSoldnerParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9833 => Self::HyperbolicCassiniSoldner(HyperbolicCassiniSoldnerParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9819 => Self::Krovak(KrovakParams::new(
+                getter(8811)?,
+                getter(8833)?,
+                getter(1036)?,
+                getter(8818)?,
+                getter(8819)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9803 => Self::LambertConic2SPBelgium(LambertConic2SPParams::new(
+                getter(8822)?,
+                getter(8821)?,
+                getter(8823)?,
+                getter(8824)?,
+                getter(8826)?,
+                getter(8827)?,
+            )),
+            1051 => Self::LambertConic2SPMichigan(LambertConic2SPMichiganParams::new(
+                getter(8822)?,
+                getter(8821)?,
+                getter(8823)?,
+                getter(8824)?,
+                getter(8826)?,
+                getter(8827)?,
+                getter(1038)?,
+            )),
+            9817 => Self::LambertConicNearConformal(LambertConic1SPAParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8805)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9829 => Self::PolarStereographicB(PolarStereographicBParams::new(
+                getter(8833)?,
+                getter(8832)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            1028 => Self::EquidistantCylindrical(EquidistantCylindricalParams::new(
+                getter(8802)?,
+                getter(8823)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            1029 => Self::EquidistantCylindricalSpherical(EquidistantCylindricalParams::new(
+                getter(8802)?,
+                getter(8823)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9827 | 9828 => Self::Bonne(BonneParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+                method_code == 9828,
+            )),
+            9808 => Self::TransverseMercatorSouthOrientated(TransverseMercatorParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8805)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            1027 => Self::LambertAzimuthalEqualAreaSpherical(LambertAzimuthalEqualAreaParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9832 => Self::ModifiedAzimuthalEquidistant(AzimuthalEquidistantParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9831 => Self::Guam(AzimuthalEquidistantParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            1078 => Self::EqualEarth(EqualEarthParams::new(
+                getter(8802)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            1052 => Self::ColombiaUrban(ColombiaUrbanParams::new(
+                getter(8801)?,
+                getter(8802)?,
+                getter(8806)?,
+                getter(8807)?,
+                getter(1039)?,
+            )),
+            9835 => Self::LambertCylindricalEqualArea(LambertCylindricalEqualAreaParams::new(
+                getter(8802)?,
+                getter(8823)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9838 => Self::VerticalPerspective(VerticalPerspectiveParams::new(
+                getter(8834)?,
+                getter(8835)?,
+                getter(8836)?,
+                getter(8840)?,
+            )),
+            1125 => Self::AzimuthalEquidistant(AzimuthalEquidistantParams::new(
+                getter(8802)?,
+                getter(8801)?,
+                getter(8806)?,
+                getter(8807)?,
+            )),
+            9816 => Self::TunisiaMiningGrid(TunisiaMiningGridParams::new(
+                getter(8821)?,
+                getter(8822)?,
+                getter(8826)?,
+                getter(8827)?,
+            )),
+            _ => return None,
+        })
+    }
+
+    /// Try to construct the parameters for a specific method code from `(parameter code, value)` pairs.
+    pub fn from_params(method_code: u32, params: &[(u32, f64)]) -> Option<Self> {
+        Self::from_getter(method_code, |code| {
+            params.iter().find_map(|(c, v)| (*c == code).then_some(*v))
+        })
+    }
+
+    /// Get the parameters as `(parameter code, value)` pairs.
+    pub fn to_params(&self) -> Vec<(u32, f64)> {
+        match self {
+            Self::TransverseMercator(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8805, p.k_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::PolarStereographicA(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8805, p.k_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::LambertConic2SP(p) => vec![
+                (8822, p.lon_orig()),
+                (8821, p.lat_orig()),
+                (8823, p.lat_p1()),
+                (8824, p.lat_p2()),
+                (8826, p.false_e()),
+                (8827, p.false_n()),
+            ],
+            Self::PopVisPseudoMercator(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::LambertConic1SPA(p) => vec![
+                (8802, p.lon_nat_orig()),
+                (8801, p.lat_nat_orig()),
+                (8805, p.k_nat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::ObliqueStereographic(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8805, p.k_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::AlbersEqualArea(p) => vec![
+                (8822, p.lon_orig()),
+                (8821, p.lat_orig()),
+                (8823, p.lat_sp1()),
+                (8824, p.lat_sp2()),
+                (8826, p.false_e()),
+                (8827, p.false_n()),
+            ],
+            Self::LambertAzimuthalEqualArea(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::MercatorSpherical(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::LambertCylindricalEqualAreaSpherical(p) => vec![
+                (8802, p.lon_orig()),
+                (8823, p.lat_sp1()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::PseudoPlateCarree => Vec::new(),
+            Self::MercatorC(p) => vec![
+                (8802, p.lon_orig()),
+                (8821, p.lat_orig()),
+                (8823, p.lat_sp1()),
+                (8826, p.false_e()),
+                (8827, p.false_n()),
+            ],
+            Self::HotineObliqueMercatorA(p) => vec![
+                (8811, p.lat_c()),
+                (8812, p.lon_c()),
+                (8813, p.azimuth_c()),
+                (8814, p.gamma_c()),
+                (8815, p.k_c()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::HotineObliqueMercatorB(p) => vec![
+                (8811, p.lat_c()),
+                (8812, p.lon_c()),
+                (8813, p.azimuth_c()),
+                (8814, p.gamma_c()),
+                (8815, p.k_c()),
+                (8816, p.false_e()),
+                (8817, p.false_n()),
+            ],
+            Self::CassiniSoldner(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::HyperbolicCassiniSoldner(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::Krovak(p) => vec![
+                (8811, p.lat_c()),
+                (8833, p.lon_orig()),
+                (1036, p.alpha_c()),
+                (8818, p.lat_p()),
+                (8819, p.k_p()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::LambertConic2SPBelgium(p) => vec![
+                (8822, p.lon_orig()),
+                (8821, p.lat_orig()),
+                (8823, p.lat_p1()),
+                (8824, p.lat_p2()),
+                (8826, p.false_e()),
+                (8827, p.false_n()),
+            ],
+            Self::LambertConic2SPMichigan(p) => vec![
+                (8822, p.lon_orig()),
+                (8821, p.lat_orig()),
+                (8823, p.lat_p1()),
+                (8824, p.lat_p2()),
+                (8826, p.false_e()),
+                (8827, p.false_n()),
+                (1038, p.ellipsoid_k()),
+            ],
+            Self::LambertConicNearConformal(p) => vec![
+                (8802, p.lon_nat_orig()),
+                (8801, p.lat_nat_orig()),
+                (8805, p.k_nat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::PolarStereographicB(p) => vec![
+                (8833, p.lon_orig()),
+                (8832, p.lat_sp()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::EquidistantCylindrical(p) => vec![
+                (8802, p.lon_orig()),
+                (8823, p.lat_sp1()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::EquidistantCylindricalSpherical(p) => vec![
+                (8802, p.lon_orig()),
+                (8823, p.lat_sp1()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::Bonne(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::TransverseMercatorSouthOrientated(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8805, p.k_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::LambertAzimuthalEqualAreaSpherical(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::ModifiedAzimuthalEquidistant(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::Guam(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::EqualEarth(p) => vec![
+                (8802, p.lon_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::ColombiaUrban(p) => vec![
+                (8801, p.lat_orig()),
+                (8802, p.lon_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+                (1039, p.height_orig()),
+            ],
+            Self::LambertCylindricalEqualArea(p) => vec![
+                (8802, p.lon_orig()),
+                (8823, p.lat_sp1()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::VerticalPerspective(p) => vec![
+                (8834, p.lat_orig()),
+                (8835, p.lon_orig()),
+                (8836, p.height_orig()),
+                (8840, p.view_height()),
+            ],
+            Self::AzimuthalEquidistant(p) => vec![
+                (8802, p.lon_orig()),
+                (8801, p.lat_orig()),
+                (8806, p.false_e()),
+                (8807, p.false_n()),
+            ],
+            Self::TunisiaMiningGrid(p) => vec![
+                (8821, p.lat_orig()),
+                (8822, p.lon_orig()),
+                (8826, p.false_e()),
+                (8827, p.false_n()),
+            ],
+        }
+    }
+
+    /// Get the EPSG code of the operation method.
+    pub fn method_code(&self) -> u32 {
+        match self {
+            Self::TransverseMercator(_) => 9807,
+            Self::PolarStereographicA(_) => 9810,
+            Self::LambertConic2SP(_) => 9802,
+            Self::PopVisPseudoMercator(_) => 1024,
+            Self::LambertConic1SPA(_) => 9801,
+            Self::ObliqueStereographic(_) => 9809,
+            Self::AlbersEqualArea(_) => 9822,
+            Self::LambertAzimuthalEqualArea(_) => 9820,
+            Self::MercatorSpherical(_) => 1026,
+            Self::LambertCylindricalEqualAreaSpherical(_) => 9834,
+            Self::PseudoPlateCarree => 9825,
+            Self::MercatorC(_) => 1044,
+            Self::HotineObliqueMercatorA(_) => 9812,
+            Self::HotineObliqueMercatorB(_) => 9815,
+            Self::CassiniSoldner(_) => 9806,
+            Self::HyperbolicCassiniSoldner(_) => 9833,
+            Self::Krovak(_) => 9819,
+            Self::LambertConic2SPBelgium(_) => 9803,
+            Self::LambertConic2SPMichigan(_) => 1051,
+            Self::LambertConicNearConformal(_) => 9817,
+            Self::PolarStereographicB(_) => 9829,
+            Self::EquidistantCylindrical(_) => 1028,
+            Self::EquidistantCylindricalSpherical(_) => 1029,
+            Self::Bonne(p) => {
+                if p.south_orientated() {
+                    9828
+                } else {
+                    9827
+                }
+            }
+            Self::TransverseMercatorSouthOrientated(_) => 9808,
+            Self::LambertAzimuthalEqualAreaSpherical(_) => 1027,
+            Self::ModifiedAzimuthalEquidistant(_) => 9832,
+            Self::Guam(_) => 9831,
+            Self::EqualEarth(_) => 1078,
+            Self::ColombiaUrban(_) => 1052,
+            Self::LambertCylindricalEqualArea(_) => 9835,
+            Self::VerticalPerspective(_) => 9838,
+            Self::AzimuthalEquidistant(_) => 1125,
+            Self::TunisiaMiningGrid(_) => 9816,
+        }
+    }
+
+    /// Construct the projection for these parameters on `ellipsoid`.
+    ///
+    /// Note that the resulting projection will not update when the ellipsoid is altered.
+    pub fn to_projection(&self, ellipsoid: &Ellipsoid) -> Box<dyn Projection> {
+        match self {
+            Self::TransverseMercator(p) => {
+                Box::new(TransverseMercatorProjection::new(ellipsoid, p))
+            }
+            Self::PolarStereographicA(p) => {
+                Box::new(PolarStereographicAProjection::new(ellipsoid, p))
+            }
+            Self::LambertConic2SP(p) => Box::new(LambertConic2SPProjection::new(ellipsoid, p)),
+            Self::PopVisPseudoMercator(p) => {
+                Box::new(PopVisPseudoMercatorProjection::new(ellipsoid, p))
+            }
+            Self::LambertConic1SPA(p) => Box::new(LambertConic1SPAProjection::new(ellipsoid, p)),
+            Self::ObliqueStereographic(p) => {
+                Box::new(ObliqueStereographicProjection::new(ellipsoid, p))
+            }
+            Self::AlbersEqualArea(p) => Box::new(AlbersEqualAreaProjection::new(ellipsoid, p)),
+            Self::LambertAzimuthalEqualArea(p) => {
+                Box::new(LambertAzimuthalEqualAreaProjection::new(ellipsoid, p))
+            }
+            Self::MercatorSpherical(p) => Box::new(MercatorSphericalProjection::new(ellipsoid, p)),
+            Self::LambertCylindricalEqualAreaSpherical(p) => Box::new(
+                LambertCylindricalEqualAreaSphericalProjection::new(ellipsoid, p),
+            ),
+            Self::PseudoPlateCarree => Box::new(IdentityProjection),
+            Self::MercatorC(p) => Box::new(MercatorCProjection::new(ellipsoid, p)),
+            Self::HotineObliqueMercatorA(p) => {
+                Box::new(HotineObliqueMercatorAProjection::new(ellipsoid, p))
+            }
+            Self::HotineObliqueMercatorB(p) => {
+                Box::new(HotineObliqueMercatorBProjection::new(ellipsoid, p))
+            }
+            Self::CassiniSoldner(p) => Box::new(CassiniSoldnerProjection::new(ellipsoid, p)),
+            Self::HyperbolicCassiniSoldner(p) => {
+                Box::new(HyperbolicCassiniSoldnerProjection::new(ellipsoid, p))
+            }
+            Self::Krovak(p) => Box::new(KrovakProjection::new(ellipsoid, p)),
+            Self::LambertConic2SPBelgium(p) => {
+                Box::new(LambertConic2SPBelgiumProjection::new(ellipsoid, p))
+            }
+            Self::LambertConic2SPMichigan(p) => {
+                Box::new(LambertConic2SPMichiganProjection::new(ellipsoid, p))
+            }
+            Self::LambertConicNearConformal(p) => {
+                Box::new(LambertConicNearConformalProjection::new(ellipsoid, p))
+            }
+            Self::PolarStereographicB(p) => {
+                Box::new(PolarStereographicBProjection::new(ellipsoid, p))
+            }
+            Self::EquidistantCylindrical(p) => {
+                Box::new(EquidistantCylindricalProjection::new(ellipsoid, p))
+            }
+            Self::EquidistantCylindricalSpherical(p) => {
+                Box::new(EquidistantCylindricalSphericalProjection::new(ellipsoid, p))
+            }
+            Self::Bonne(p) => Box::new(BonneProjection::new(ellipsoid, p)),
+            Self::TransverseMercatorSouthOrientated(p) => Box::new(
+                TransverseMercatorSouthOrientatedProjection::new(ellipsoid, p),
+            ),
+            Self::LambertAzimuthalEqualAreaSpherical(p) => Box::new(
+                LambertAzimuthalEqualAreaSphericalProjection::new(ellipsoid, p),
+            ),
+            Self::ModifiedAzimuthalEquidistant(p) => {
+                Box::new(ModifiedAzimuthalEquidistantProjection::new(ellipsoid, p))
+            }
+            Self::Guam(p) => Box::new(GuamProjection::new(ellipsoid, p)),
+            Self::EqualEarth(p) => Box::new(EqualEarthProjection::new(ellipsoid, p)),
+            Self::ColombiaUrban(p) => Box::new(ColombiaUrbanProjection::new(ellipsoid, p)),
+            Self::LambertCylindricalEqualArea(p) => {
+                Box::new(LambertCylindricalEqualAreaProjection::new(ellipsoid, p))
+            }
+            Self::VerticalPerspective(p) => {
+                Box::new(VerticalPerspectiveProjection::new(ellipsoid, p))
+            }
+            Self::AzimuthalEquidistant(p) => {
+                Box::new(AzimuthalEquidistantProjection::new(ellipsoid, p))
+            }
+            Self::TunisiaMiningGrid(p) => Box::new(TunisiaMiningGridProjection::new(ellipsoid, p)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ellipsoids::WGS84;
+    use crate::projection_params::*;
+
+    #[test]
+    fn projection_params_roundtrip() {
+        let params = [
+            (8802, 9.0f64.to_radians()),
+            (8801, 0.0),
+            (8805, 0.9996),
+            (8806, 500_000.0),
+            (8807, 0.0),
+        ];
+        let pp = ProjectionParams::from_params(9807, &params).expect("Method not implemented.");
+        assert_eq!(pp.method_code(), 9807);
+        assert_eq!(pp.to_params(), params.to_vec());
+        assert_eq!(
+            ProjectionParams::from_params(pp.method_code(), &pp.to_params()),
+            Some(pp)
+        );
+        assert!(ProjectionParams::from_params(9807, &params[1..]).is_none());
+        assert!(ProjectionParams::from_params(1, &params).is_none());
+
+        let (x, y) = pp.to_projection(&WGS84).deg_to_projected(9.0, 0.0);
+        assert!((x - 500_000.0).abs() < 1e-6 && y.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,189 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::{PI, TAU};
+
+use crate::{Projection, PseudoSerialize};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RotatedPoleParams {
+    /// geographic longitude of the rotated north pole
+    pole_lon: f64,
+    /// geographic latitude of the rotated north pole
+    pole_lat: f64,
+    /// rotated longitude of the geographic north pole
+    pole_angle: f64,
+}
+
+impl_param_eq_hash!(RotatedPoleParams {
+    pole_lon,
+    pole_lat,
+    pole_angle
+});
+
+impl RotatedPoleParams {
+    pub const fn new(pole_lon: f64, pole_lat: f64, pole_angle: f64) -> Self {
+        Self {
+            pole_lon,
+            pole_lat,
+            pole_angle,
+        }
+    }
+
+    /// Get geographic longitude of the rotated north pole, radians.
+    /// This is `grid_north_pole_longitude` in CF conventions.
+    pub fn pole_lon(&self) -> f64 {
+        self.pole_lon
+    }
+
+    /// Get geographic latitude of the rotated north pole, radians.
+    /// This is `grid_north_pole_latitude` in CF conventions.
+    pub fn pole_lat(&self) -> f64 {
+        self.pole_lat
+    }
+
+    /// Get rotated longitude of the geographic north pole, radians.
+    /// This is `north_pole_grid_longitude` in CF conventions and usually zero.
+    pub fn pole_angle(&self) -> f64 {
+        self.pole_angle
+    }
+}
+
+/// Rotated pole grid, as described by the CF grid mapping `rotated_latitude_longitude`.
+///
+/// This is used by regional climate models such as COSMO-CLM, ICON-LAM or the CORDEX domains.
+/// The projected coordinates are rotated longitude and latitude in degrees, as stored in the
+/// `rlon` and `rlat` variables of such datasets. There is no EPSG method for this, so it can only
+/// be constructed directly.
+#[derive(Copy, Clone, Debug)]
+pub struct RotatedPoleProjection {
+    pub pole_lon: f64,
+    pub pole_lat: f64,
+    pub pole_angle: f64,
+}
+
+impl_param_eq_hash!(RotatedPoleProjection {
+    pole_lon,
+    pole_lat,
+    pole_angle
+});
+
+impl RotatedPoleProjection {
+    pub fn new(params: &RotatedPoleParams) -> Self {
+        Self {
+            pole_lon: params.pole_lon(),
+            pole_lat: params.pole_lat(),
+            pole_angle: params.pole_angle(),
+        }
+    }
+
+    /// Rotates a position about the axis through the equator at 90° from the pole longitude.
+    /// The rotation is its own inverse, so this converts from geographic to rotated coordinates
+    /// and back, with longitudes relative to `pole_lon` and `pole_angle` respectively.
+    fn rotate(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let (sin_lat, cos_lat) = latitude.sin_cos();
+        let (sin_lon, cos_lon) = longitude.sin_cos();
+        let (sin_pole, cos_pole) = self.pole_lat.sin_cos();
+        let x = cos_pole * sin_lat - sin_pole * cos_lat * cos_lon;
+        let y = -cos_lat * sin_lon;
+        let z = sin_pole * sin_lat + cos_pole * cos_lat * cos_lon;
+        (y.atan2(x), z.atan2(x.hypot(y)))
+    }
+}
+
+impl Projection for RotatedPoleProjection {
+    /// longitude & latitude in radians, rotated longitude & latitude in degrees
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let (rlon, rlat) = self.rotate(longitude - self.pole_lon, latitude);
+        (
+            ((rlon + self.pole_angle + PI).rem_euclid(TAU) - PI).to_degrees(),
+            rlat.to_degrees(),
+        )
+    }
+
+    /// rotated longitude & latitude in degrees, longitude & latitude in radians
+    fn projected_to_rad(&self, rlon: f64, rlat: f64) -> (f64, f64) {
+        let (longitude, latitude) =
+            self.rotate(rlon.to_radians() - self.pole_angle, rlat.to_radians());
+        (
+            (longitude + self.pole_lon + PI).rem_euclid(TAU) - PI,
+            latitude,
+        )
+    }
+}
+
+impl PseudoSerialize for RotatedPoleProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"RotatedPoleProjection{{
+    pole_lon: {}f64,
+    pole_lat: {}f64,
+    pole_angle: {}f64,
+}}",
+            self.pole_lon, self.pole_lat, self.pole_angle,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::rotated_pole::*;
+    use crate::traits::*;
+
+    #[test]
+    fn rotated_pole_consistency() {
+        // EURO-CORDEX
+        let params = RotatedPoleParams::new((-162f64).to_radians(), 39.25f64.to_radians(), 0.0);
+        let projection = RotatedPoleProjection::new(&params);
+
+        // the rotated origin lies opposite the pole longitude
+        let (lon, lat) = projection.projected_to_deg(0.0, 0.0);
+        eprintln!("lon: 18 - {lon}");
+        eprintln!("lat: 50.75 - {lat}");
+        assert!((lon - 18.0).abs() < 1e-9);
+        assert!((lat - 50.75).abs() < 1e-9);
+
+        // the geographic north pole lies on the rotated meridian given by the pole angle
+        let (rlon, rlat) = projection.deg_to_projected(0.0, 90.0);
+        eprintln!("rlon: 0 - {rlon}");
+        eprintln!("rlat: 39.25 - {rlat}");
+        assert!(rlon.abs() < 1e-9);
+        assert!((rlat - 39.25).abs() < 1e-9);
+
+        let (_, rlat) = projection.deg_to_projected(-162.0, 39.25);
+        assert!((rlat - 90.0).abs() < 1e-9);
+
+        for (lon, lat) in [(-10.0, 22.0), (0.0, 0.0), (10.0, 54.0), (170.0, -80.0)] {
+            let (rlon, rlat) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(rlon, rlat);
+            eprintln!("{lon}, {lat}: {rlon}, {rlat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn rotated_pole_angle() {
+        let params = RotatedPoleParams::new(
+            (-170f64).to_radians(),
+            40f64.to_radians(),
+            30f64.to_radians(),
+        );
+        let projection = RotatedPoleProjection::new(&params);
+        let unrotated = RotatedPoleProjection::new(&RotatedPoleParams::new(
+            params.pole_lon(),
+            params.pole_lat(),
+            0.0,
+        ));
+
+        // the pole angle only shifts the rotated longitudes
+        for (lon, lat) in [(-10.0, 22.0), (8.0, 47.0), (170.0, -80.0)] {
+            let (rlon, rlat) = projection.deg_to_projected(lon, lat);
+            let (rlon_0, rlat_0) = unrotated.deg_to_projected(lon, lat);
+            eprintln!("{lon}, {lat}: {rlon}, {rlat} - {rlon_0}, {rlat_0}");
+            assert!(((rlon - rlon_0 - 30.0 + 180.0).rem_euclid(360.0) - 180.0).abs() < 1e-9);
+            assert!((rlat - rlat_0).abs() < 1e-9);
+        }
+    }
+}
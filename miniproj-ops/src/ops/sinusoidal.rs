@@ -0,0 +1,198 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    ellipsoid::{Ellipsoid, MeridianArc},
+    traits::GetterContstruct,
+    Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SinusoidalParams {
+    /// longitude of natural origin
+    lon_orig: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(SinusoidalParams {
+    lon_orig,
+    false_e,
+    false_n
+});
+
+impl SinusoidalParams {
+    pub const fn new(lon_orig: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get longitude of natural origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get false easting.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get false northing.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Sinusoidal projection, as used for the MODIS land product grids.
+///
+/// This method has no EPSG code and can therefore only be constructed directly, e.g. with the
+/// sphere of radius 6371007.181 m for MODIS data.
+#[derive(Copy, Clone, Debug)]
+pub struct SinusoidalProjection {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_e_squared: f64,
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+
+    pub meridian_arc: MeridianArc,
+}
+
+impl_param_eq_hash!(SinusoidalProjection {
+    ellipsoid_a,
+    ellipsoid_e_squared,
+    lon_orig,
+    false_e,
+    false_n;
+    meridian_arc,
+});
+
+impl SinusoidalProjection {
+    pub fn new(ell: &Ellipsoid, params: &SinusoidalParams) -> Self {
+        Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_e_squared: ell.e_squared(),
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+
+            meridian_arc: MeridianArc::new(ell),
+        }
+    }
+}
+
+impl Projection for SinusoidalProjection {
+    /// as per Snyder, Map Projections - A Working Manual, 1987
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        (
+            self.false_e
+                + self.ellipsoid_a * (longitude - self.lon_orig) * latitude.cos()
+                    / (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).sqrt(),
+            self.false_n + self.meridian_arc.distance(latitude),
+        )
+    }
+
+    /// as per Snyder, Map Projections - A Working Manual, 1987
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let latitude = self
+            .meridian_arc
+            .footpoint_latitude(northing - self.false_n);
+        (
+            self.lon_orig
+                + (easting - self.false_e)
+                    * (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).sqrt()
+                    / (self.ellipsoid_a * latitude.cos()),
+            latitude,
+        )
+    }
+}
+
+impl PseudoSerialize for SinusoidalProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"SinusoidalProjection{{
+    ellipsoid_a: {}f64,
+    ellipsoid_e_squared: {}f64,
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+    meridian_arc: {},
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_e_squared,
+            self.lon_orig,
+            self.false_e,
+            self.false_n,
+            self.meridian_arc.to_constructed(),
+        )
+    }
+}
+
+impl GetterContstruct for SinusoidalProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = SinusoidalParams::new(getter(8802)?, getter(8806)?, getter(8807)?);
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::sinusoidal::*;
+    use crate::traits::*;
+
+    #[test]
+    fn sinusoidal_consistency() {
+        // Snyder's ellipsoidal example
+        let ell = Ellipsoid::from_a_b(6378206.4, 6356583.8);
+        let params = SinusoidalParams::new((-90f64).to_radians(), 0.0, 0.0);
+
+        let projection = SinusoidalProjection::new(&ell, &params);
+        let easting_goal = 1075471.5;
+        let northing_goal = -5540628.0;
+        let (easting, northing) = projection.deg_to_projected(-75.0, -50.0);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.1);
+        assert!((northing - northing_goal).abs() < 0.1);
+
+        for (lon, lat) in [(-100.0, 24.0), (0.0, 0.0), (10.0, 54.0), (170.0, -80.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn sinusoidal_modis() {
+        let ell = Ellipsoid::from_a_b(6371007.181, 6371007.181);
+        let params = SinusoidalParams::new(0.0, 0.0, 0.0);
+
+        let projection = SinusoidalProjection::new(&ell, &params);
+        // MODIS tiles span 1111950.5197 m, tile h18v04 has its upper left corner at 0°E 50°N
+        let (easting, northing) = projection.deg_to_projected(0.0, 50.0);
+        eprintln!("{easting}, {northing}");
+        assert!(easting.abs() < 1e-6);
+        assert!((northing - 5559752.598).abs() < 0.01);
+
+        let (lon, lat) = projection.projected_to_deg(1111950.5197, 0.0);
+        eprintln!("{lon}, {lat}");
+        assert!((lon - 10.0).abs() < 1e-9);
+        assert!(lat.abs() < 1e-12);
+    }
+}
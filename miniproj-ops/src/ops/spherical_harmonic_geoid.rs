@@ -0,0 +1,442 @@
+//This file is licensed under EUPL v1.2
+
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read};
+use std::path::Path;
+
+use crate::{
+    coordinate::{Geographic2DCoordinateUserVertical, Geographic3DCoordinate},
+    ellipsoids::WGS84,
+    CoordOperation,
+};
+
+fn invalid_data(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::InvalidData, message.into())
+}
+
+/// Fully normalised coefficients `(n, m, C_nm, S_nm)` of EGM96 up to degree and order 8.
+const EGM96_COEFFICIENTS: [(usize, usize, f64, f64); 42] = [
+    (2, 0, -0.484165371736e-3, 0.0),
+    (2, 1, -0.186987635955e-9, 0.119528012031e-8),
+    (2, 2, 0.243914352398e-5, -0.140016683654e-5),
+    (3, 0, 0.957254173792e-6, 0.0),
+    (3, 1, 0.202998882184e-5, 0.248513158716e-6),
+    (3, 2, 0.904627768605e-6, -0.619025944205e-6),
+    (3, 3, 0.721072657057e-6, 0.141435626958e-5),
+    (4, 0, 0.539873863789e-6, 0.0),
+    (4, 1, -0.536321616971e-6, -0.473440265853e-6),
+    (4, 2, 0.350694105785e-6, 0.662671572540e-6),
+    (4, 3, 0.990771803829e-6, -0.200928369177e-6),
+    (4, 4, -0.188560802735e-6, 0.308853169333e-6),
+    (5, 0, 0.685323475630e-7, 0.0),
+    (5, 1, -0.621012128528e-7, -0.944226127525e-7),
+    (5, 2, 0.652438297612e-6, -0.323349612668e-6),
+    (5, 3, -0.451955406071e-6, -0.214847190624e-6),
+    (5, 4, -0.295301647654e-6, 0.496658876769e-7),
+    (5, 5, 0.174971983203e-6, -0.669384278219e-6),
+    (6, 0, -0.149957994714e-6, 0.0),
+    (6, 1, -0.760879384947e-7, 0.262890545501e-7),
+    (6, 2, 0.481732442832e-7, -0.373728201347e-6),
+    (6, 3, 0.571730990516e-7, 0.902694517163e-8),
+    (6, 4, -0.862142660109e-7, -0.471408154267e-6),
+    (6, 5, -0.267133325490e-6, -0.536488432483e-6),
+    (6, 6, 0.967616121092e-8, -0.237192006935e-6),
+    (7, 0, 0.909789371450e-7, 0.0),
+    (7, 1, 0.279872910488e-6, 0.954336911867e-7),
+    (7, 2, 0.329743816488e-6, 0.930667901103e-7),
+    (7, 3, 0.250398657706e-6, -0.217198608738e-6),
+    (7, 4, -0.275114355257e-6, -0.123800392323e-6),
+    (7, 5, 0.193765507243e-8, 0.177435874086e-7),
+    (7, 6, -0.358856860645e-6, 0.151789817739e-6),
+    (7, 7, 0.109185148045e-8, 0.244415707993e-7),
+    (8, 0, 0.496711667324e-7, 0.0),
+    (8, 1, 0.233422047893e-7, 0.590060493411e-7),
+    (8, 2, 0.802978722615e-7, 0.654175425859e-7),
+    (8, 3, -0.191877757009e-7, -0.863454445021e-7),
+    (8, 4, -0.244600105471e-6, 0.700233016934e-7),
+    (8, 5, -0.255352403037e-7, 0.891462164788e-7),
+    (8, 6, -0.657361610961e-7, 0.309238461807e-6),
+    (8, 7, 0.672811580072e-7, 0.747440473633e-7),
+    (8, 8, -0.124092493016e-6, 0.120533165603e-6),
+];
+
+/// Geoid undulation from a spherical harmonic expansion of the earth's gravity potential, like
+/// the EGM96 and EGM2008 models.
+///
+/// The model is given by fully normalised coefficients `C_nm` and `S_nm` together with the
+/// gravitational constant `GM` and reference radius `a` they refer to. The undulation is computed
+/// relative to the WGS 84 ellipsoid via Bruns' formula from the disturbing potential, i.e. the
+/// model potential minus the normal potential of the WGS 84 ellipsoid, divided by normal gravity.
+/// Truncating the expansion to a low degree trades accuracy for evaluation speed.
+#[derive(Clone, Debug)]
+pub struct SphericalHarmonicGeoid {
+    gm: f64,
+    a: f64,
+    max_degree: usize,
+    c: Vec<f64>,
+    s: Vec<f64>,
+}
+
+impl SphericalHarmonicGeoid {
+    /// WGS 84 geocentric gravitational constant, m³/s²
+    pub const WGS84_GM: f64 = 3.986004418e14;
+    /// WGS 84 normalised second degree zonal harmonic
+    pub const WGS84_C20: f64 = -484.16685e-6;
+    /// WGS 84 normal gravity at the equator, m/s²
+    pub const WGS84_GAMMA_E: f64 = 9.7803253359;
+    /// WGS 84 normal gravity formula constant
+    pub const WGS84_GAMMA_K: f64 = 0.00193185265241;
+    /// Highest degree accepted from `.gfc` files, that of EGM2008. It bounds the memory the header
+    /// of a malformed file can claim.
+    pub const MAX_GFC_DEGREE: usize = 2190;
+
+    /// EGM96 geocentric gravitational constant, m³/s²
+    pub const EGM96_GM: f64 = 3.986004415e14;
+    /// EGM96 reference radius, m
+    pub const EGM96_A: f64 = 6378136.3;
+
+    /// Construct the EGM96 model truncated to degree and order 8 from the embedded coefficients,
+    /// which needs no external data files. It resolves the long-wavelength shape of the geoid, the
+    /// undulation differs from the full EGM96 model by up to about 10 metres.
+    pub fn egm96_truncated() -> Self {
+        Self::from_coefficients(Self::EGM96_GM, Self::EGM96_A, 8, EGM96_COEFFICIENTS)
+    }
+
+    /// Construct a model up to and including `max_degree` with all coefficients set to zero.
+    pub fn new(gm: f64, a: f64, max_degree: usize) -> Self {
+        let len = Self::index(max_degree, max_degree) + 1;
+        Self {
+            gm,
+            a,
+            max_degree,
+            c: vec![0f64; len],
+            s: vec![0f64; len],
+        }
+    }
+
+    /// Construct a model from `(n, m, C_nm, S_nm)`-tuples of fully normalised coefficients.
+    /// Coefficients of a degree higher than `max_degree` are skipped, which truncates the model.
+    pub fn from_coefficients<I>(gm: f64, a: f64, max_degree: usize, coefficients: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, f64, f64)>,
+    {
+        let mut model = Self::new(gm, a, max_degree);
+        for (n, m, c, s) in coefficients {
+            model.set_coefficient(n, m, c, s);
+        }
+        model
+    }
+
+    /// Reads a model from a file in the ICGEM `.gfc` format, in which EGM96 and EGM2008 are
+    /// distributed. The model is truncated to `max_degree` if given.
+    pub fn from_gfc_path(path: impl AsRef<Path>, max_degree: Option<usize>) -> Result<Self, Error> {
+        Self::from_gfc_reader(std::fs::File::open(path)?, max_degree)
+    }
+
+    /// Reads a model in the ICGEM `.gfc` format from `reader` until the end. The model is
+    /// truncated to `max_degree` if given.
+    ///
+    /// The header has to provide `earth_gravity_constant`, `radius` and a `max_degree` of at most
+    /// [`Self::MAX_GFC_DEGREE`], and the coefficients have to be fully normalised. Only the static
+    /// `gfc` coefficients are read.
+    pub fn from_gfc_reader<R: Read>(reader: R, max_degree: Option<usize>) -> Result<Self, Error> {
+        // Fortran formatted files may use D as the exponent marker
+        let number = |s: &str| s.replace(['D', 'd'], "E").parse::<f64>().ok();
+        let mut lines = BufReader::new(reader).lines();
+        let (mut gm, mut a, mut file_degree) = (None, None, None);
+        for line in lines.by_ref() {
+            let line = line?;
+            let mut fields = line.split_whitespace();
+            match (fields.next(), fields.next()) {
+                (Some("end_of_head"), _) => break,
+                (Some("earth_gravity_constant"), Some(v)) => gm = number(v),
+                (Some("radius"), Some(v)) => a = number(v),
+                (Some("max_degree"), Some(v)) => file_degree = v.parse::<usize>().ok(),
+                (Some("norm"), Some(v)) if v != "fully_normalized" => {
+                    return Err(invalid_data(format!("Unsupported normalization {v}")));
+                }
+                _ => {}
+            }
+        }
+        let (Some(gm), Some(a), Some(file_degree)) = (gm, a, file_degree) else {
+            return Err(invalid_data("Incomplete gfc header"));
+        };
+        if file_degree > Self::MAX_GFC_DEGREE {
+            return Err(invalid_data(format!(
+                "Unsupported max_degree {file_degree}"
+            )));
+        }
+        let mut model = Self::new(
+            gm,
+            a,
+            max_degree.map_or(file_degree, |d| d.min(file_degree)),
+        );
+        for line in lines {
+            let line = line?;
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let ["gfc", n, m, c, s, ..] = fields.as_slice() else {
+                continue;
+            };
+            let (Ok(n), Ok(m), Some(c), Some(s)) = (n.parse(), m.parse(), number(c), number(s))
+            else {
+                return Err(invalid_data(format!("Malformed coefficient: {line}")));
+            };
+            model.set_coefficient(n, m, c, s);
+        }
+        Ok(model)
+    }
+
+    /// Set the fully normalised coefficients of degree `n` and order `m`.
+    /// Coefficients of a degree higher than the model's maximum degree are ignored.
+    pub fn set_coefficient(&mut self, n: usize, m: usize, c: f64, s: f64) {
+        if n <= self.max_degree && m <= n {
+            let i = Self::index(n, m);
+            self.c[i] = c;
+            self.s[i] = s;
+        }
+    }
+
+    /// Get the fully normalised coefficients `(C_nm, S_nm)` of degree `n` and order `m`.
+    pub fn coefficient(&self, n: usize, m: usize) -> Option<(f64, f64)> {
+        (n <= self.max_degree && m <= n).then(|| {
+            let i = Self::index(n, m);
+            (self.c[i], self.s[i])
+        })
+    }
+
+    /// Get the maximum degree of the expansion.
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    /// Get a copy of the model truncated to `max_degree`.
+    pub fn truncated(&self, max_degree: usize) -> Self {
+        let max_degree = max_degree.min(self.max_degree);
+        let len = Self::index(max_degree, max_degree) + 1;
+        Self {
+            gm: self.gm,
+            a: self.a,
+            max_degree,
+            c: self.c[..len].to_vec(),
+            s: self.s[..len].to_vec(),
+        }
+    }
+
+    /// Geoid undulation in meters above the WGS 84 ellipsoid, longitude & latitude in degrees.
+    pub fn undulation_deg(&self, lon: f64, lat: f64) -> f64 {
+        self.undulation_rad(lon.to_radians(), lat.to_radians())
+    }
+
+    /// Geoid undulation in meters above the WGS 84 ellipsoid, longitude & latitude in radians.
+    pub fn undulation_rad(&self, lon: f64, lat: f64) -> f64 {
+        let (x, y, z) = WGS84.rad_to_geocentric(lon, lat, 0.0);
+        let p = x.hypot(y);
+        let r = p.hypot(z);
+        let geocentric_lat = z.atan2(p);
+
+        let gamma = Self::WGS84_GAMMA_E * (1.0 + Self::WGS84_GAMMA_K * lat.sin().powi(2))
+            / (1.0 - WGS84.e_squared() * lat.sin().powi(2)).sqrt();
+
+        let legendre = Self::legendre(self.max_degree, geocentric_lat.sin(), geocentric_lat.cos());
+        let normal = self.normal_zonals();
+        let mut sum = 0f64;
+        let mut a_r_n = (self.a / r).powi(2);
+        for n in 2..=self.max_degree {
+            let mut degree_sum = 0f64;
+            for m in 0..=n {
+                let i = Self::index(n, m);
+                let c = if m == 0 && n % 2 == 0 {
+                    self.c[i] - normal.get(n / 2 - 1).copied().unwrap_or(0.0)
+                } else {
+                    self.c[i]
+                };
+                let m_lon = m as f64 * lon;
+                degree_sum += (c * m_lon.cos() + self.s[i] * m_lon.sin()) * legendre[i];
+            }
+            sum += a_r_n * degree_sum;
+            a_r_n *= self.a / r;
+        }
+        self.gm / (r * gamma) * sum
+    }
+
+    /// Fully normalised even zonal coefficients `C_2,0`, `C_4,0`, … of the WGS 84 normal
+    /// potential, rescaled to the model's `GM` and reference radius.
+    fn normal_zonals(&self) -> Vec<f64> {
+        let e_sq = WGS84.e_squared();
+        let j2 = -Self::WGS84_C20 * 5f64.sqrt();
+        (1..=self.max_degree / 2)
+            .map(|n| {
+                let nf = n as f64;
+                let sign = if n % 2 == 1 { 1.0 } else { -1.0 };
+                let j2n = sign * 3.0 * e_sq.powi(n as i32) / ((2.0 * nf + 1.0) * (2.0 * nf + 3.0))
+                    * (1.0 - nf + 5.0 * nf * j2 / e_sq);
+                -j2n / (4.0 * nf + 1.0).sqrt()
+                    * (Self::WGS84_GM / self.gm)
+                    * (WGS84.a() / self.a).powi(2 * n as i32)
+            })
+            .collect()
+    }
+
+    /// Fully normalised associated Legendre functions up to `max_degree`, evaluated for
+    /// `t = sin(φ)` and `u = cos(φ)`, in the same triangular order as the coefficients.
+    fn legendre(max_degree: usize, t: f64, u: f64) -> Vec<f64> {
+        let mut p = vec![0f64; Self::index(max_degree, max_degree) + 1];
+        p[0] = 1.0;
+        for m in 0..=max_degree {
+            let mf = m as f64;
+            if m > 0 {
+                let fac = if m == 1 {
+                    3f64.sqrt()
+                } else {
+                    ((2.0 * mf + 1.0) / (2.0 * mf)).sqrt()
+                };
+                p[Self::index(m, m)] = fac * u * p[Self::index(m - 1, m - 1)];
+            }
+            for n in (m + 1)..=max_degree {
+                let nf = n as f64;
+                let a_nm = ((2.0 * nf - 1.0) * (2.0 * nf + 1.0) / ((nf - mf) * (nf + mf))).sqrt();
+                let mut value = a_nm * t * p[Self::index(n - 1, m)];
+                if n >= m + 2 {
+                    let b_nm = ((2.0 * nf + 1.0) * (nf + mf - 1.0) * (nf - mf - 1.0)
+                        / ((nf - mf) * (nf + mf) * (2.0 * nf - 3.0)))
+                        .sqrt();
+                    value -= b_nm * p[Self::index(n - 2, m)];
+                }
+                p[Self::index(n, m)] = value;
+            }
+        }
+        p
+    }
+
+    fn index(n: usize, m: usize) -> usize {
+        n * (n + 1) / 2 + m
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic2DCoordinateUserVertical>
+    for SphericalHarmonicGeoid
+{
+    /// Gravity-related height from a WGS 84 ellipsoidal height, longitude & latitude in radians
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic2DCoordinateUserVertical {
+        Geographic2DCoordinateUserVertical {
+            lon: from.lon,
+            lat: from.lat,
+            vertical: from.height - self.undulation_rad(from.lon, from.lat),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::Geographic3DCoordinate;
+    use crate::ellipsoids::WGS84;
+    use crate::spherical_harmonic_geoid::*;
+    use crate::traits::*;
+
+    fn normal_model(max_degree: usize) -> SphericalHarmonicGeoid {
+        let mut model =
+            SphericalHarmonicGeoid::new(SphericalHarmonicGeoid::WGS84_GM, WGS84.a(), max_degree);
+        for (n, c) in model.normal_zonals().into_iter().enumerate() {
+            model.set_coefficient(2 * (n + 1), 0, c, 0.0);
+        }
+        model
+    }
+
+    #[test]
+    fn normal_potential_has_no_undulation() {
+        let model = normal_model(12);
+        for lon in [-180.0, -45.0, 0.0, 90.0] {
+            for lat in [-90.0, -30.0, 0.0, 54.3, 89.0] {
+                let n = model.undulation_deg(lon, lat);
+                eprintln!("{lon}, {lat}: {n}");
+                assert!(n.abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn sectorial_undulation() {
+        let mut model = normal_model(4);
+        model.set_coefficient(2, 2, 1e-6, 0.0);
+        // at the equator, r = a and the normalised P_22 is sqrt(15) / 2
+        let expected = SphericalHarmonicGeoid::WGS84_GM
+            / (WGS84.a() * SphericalHarmonicGeoid::WGS84_GAMMA_E)
+            * 1e-6
+            * 15f64.sqrt()
+            / 2.0;
+        let n_0 = model.undulation_deg(0.0, 0.0);
+        let n_90 = model.undulation_deg(90.0, 0.0);
+        eprintln!("expected: {expected} - {n_0}, {n_90}");
+        assert!((n_0 - expected).abs() < 1e-6);
+        assert!((n_90 + expected).abs() < 1e-6);
+        assert!(model.truncated(1).undulation_deg(0.0, 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn egm96_truncated() {
+        let model = SphericalHarmonicGeoid::egm96_truncated();
+        assert_eq!(model.max_degree(), 8);
+        // test points and undulations of the NGA EGM96 interpolation program
+        for (lat, lon, published) in [
+            (38.6281550, 269.7791550, -31.628),
+            (-14.6212170, 305.0211140, -2.969),
+            (46.8743190, 102.4487290, -43.575),
+            (-23.6174460, 133.8747120, 15.871),
+            (38.6254730, 359.9995000, 50.066),
+            (-0.4667440, 0.0023000, 17.329),
+        ] {
+            let n = model.undulation_deg(lon, lat);
+            eprintln!("{lon}, {lat}: {published} - {n}");
+            assert!((n - published).abs() < 10.0);
+        }
+        // the geoid low south of India is resolved at degree 8
+        assert!(model.undulation_deg(78.0, 5.0) < -90.0);
+    }
+
+    #[test]
+    fn gfc_reader() {
+        let normal = normal_model(4);
+        let mut gfc = format!(
+            "product_type gravity_field\n\
+            earth_gravity_constant {}\n\
+            radius {}\n\
+            max_degree 4\n\
+            norm fully_normalized\n\
+            \n\
+            key n m C S sigmaC sigmaS\n\
+            end_of_head ==========\n",
+            SphericalHarmonicGeoid::WGS84_GM,
+            WGS84.a()
+        );
+        for n in 2..=4 {
+            for m in 0..=n {
+                let (c, s) = normal.coefficient(n, m).unwrap();
+                gfc.push_str(&format!("gfc {n} {m} {c:.15E} {s:.15E} 0.0 0.0\n"));
+            }
+        }
+        gfc.push_str("gfc 2 2 1.0D-06 0.0D+00 0.0D+00 0.0D+00\n");
+
+        let model = SphericalHarmonicGeoid::from_gfc_reader(gfc.as_bytes(), None).unwrap();
+        assert_eq!(model.max_degree(), 4);
+        assert_eq!(model.coefficient(2, 2), Some((1e-6, 0.0)));
+        let sectorial = SphericalHarmonicGeoid::from_gfc_reader(gfc.as_bytes(), Some(2)).unwrap();
+        assert_eq!(sectorial.max_degree(), 2);
+
+        // at the equator, the gravity-related height is the ellipsoidal height minus the undulation
+        let from = Geographic3DCoordinate::from_deg(0.0, 0.0, 100.0);
+        let to = model.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.vertical - (100.0 - model.undulation_deg(0.0, 0.0))).abs() < 1e-12);
+        assert!(to.vertical < 99.0);
+
+        let unnormalized = gfc.replace("fully_normalized", "unnormalized");
+        assert!(SphericalHarmonicGeoid::from_gfc_reader(unnormalized.as_bytes(), None).is_err());
+        let headless = gfc.replace("radius", "reference");
+        assert!(SphericalHarmonicGeoid::from_gfc_reader(headless.as_bytes(), None).is_err());
+        // the degree in the header is not trusted to size the model
+        for degree in ["2191", "100000000", "18446744073709551615", "-4"] {
+            let oversized = gfc.replace("max_degree 4", &format!("max_degree {degree}"));
+            let error = SphericalHarmonicGeoid::from_gfc_reader(oversized.as_bytes(), None);
+            assert!(error.is_err());
+        }
+    }
+}
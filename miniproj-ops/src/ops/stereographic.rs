@@ -2,9 +2,12 @@
 
 use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
 
-use crate::{ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, PseudoSerialize};
+use crate::{
+    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+};
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PolarStereographicAParams {
     /// longitude of natural origin
     lon_orig: f64,
@@ -18,6 +21,14 @@ pub struct PolarStereographicAParams {
     false_n: f64,
 }
 
+impl_param_eq_hash!(PolarStereographicAParams {
+    lon_orig,
+    lat_orig,
+    k_orig,
+    false_e,
+    false_n
+});
+
 impl PolarStereographicAParams {
     pub const fn new(
         lon_orig: f64,
@@ -80,6 +91,19 @@ pub struct PolarStereographicAProjection {
     pub ell_e: f64,
 }
 
+impl_param_eq_hash!(PolarStereographicAProjection {
+    t_rho_factor,
+    phi_2_chi_sin_summand_factor,
+    phi_4_chi_sin_summand_factor,
+    phi_6_chi_sin_summand_factor,
+    phi_8_chi_sin_summand_factor,
+    lat_orig,
+    lon_orig,
+    false_e,
+    false_n,
+    ell_e,
+});
+
 impl PolarStereographicAProjection {
     pub fn new(ell: &Ellipsoid, params: &PolarStereographicAParams) -> Self {
         let t_rho_factor =
@@ -91,7 +115,7 @@ impl PolarStereographicAProjection {
             + 13.0 * ell.e_squared().powi(4) / 360.0;
         let phi_4_chi_sin_summand_factor = 7.0 * ell.e_squared().powi(2) / 48.0
             + 29.0 * ell.e_squared().powi(3) / 240.0
-            + ell.e_squared().powi(4) / 11520.0;
+            + 811.0 * ell.e_squared().powi(4) / 11520.0;
         let phi_6_chi_sin_summand_factor =
             7.0 * ell.e_squared().powi(3) / 120.0 + 81.0 * ell.e_squared().powi(4) / 1120.0;
         let phi_8_chi_sin_summand_factor = 4279.0 * ell.e_squared().powi(4) / 161280.0;
@@ -114,7 +138,7 @@ impl PolarStereographicAProjection {
 
 impl crate::traits::Projection for PolarStereographicAProjection {
     fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
-        if self.lat_orig < 0.0 {
+        if self.lat_orig > 0.0 {
             // North Pole Case
             let t = f64::tan(std::f64::consts::FRAC_PI_4 - latitude / 2.0)
                 * ((1.0 + self.ell_e * latitude.sin()) / (1.0 - self.ell_e * latitude.sin()))
@@ -140,7 +164,7 @@ impl crate::traits::Projection for PolarStereographicAProjection {
     fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
         let rho_ = ((easting - self.false_e).powi(2) + (northing - self.false_n).powi(2)).sqrt();
         let t_ = rho_ * self.t_rho_factor;
-        let chi = if self.lat_orig < 0.0 {
+        let chi = if self.lat_orig > 0.0 {
             // North Pole Case
             FRAC_PI_2 - 2.0 * t_.atan()
         } else {
@@ -154,13 +178,30 @@ impl crate::traits::Projection for PolarStereographicAProjection {
             + self.phi_8_chi_sin_summand_factor * (8.0 * chi).sin();
         let lambda = /*if easting == self.false_e { //this appears wrong to me so it's commented out. @ me if you think it's right tho.
             self.lat_orig
-        } else*/ if self.lat_orig < 0.0 { // North Pole Case
+        } else*/ if self.lat_orig > 0.0 { // North Pole Case
             self.lon_orig + (easting - self.false_e).atan2(self.false_n - northing)
         } else { // South Pole Case
             self.lon_orig + (easting - self.false_e).atan2(northing - self.false_n)
         };
         (lambda, phi)
     }
+
+    fn jacobian(&self, longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        let (x, y) = self.rad_to_projected(longitude, latitude);
+        let e_sq = self.ell_e.powi(2);
+        // derivative of the isometric latitude, d(ln t)/dlat up to the sign
+        let w = (1.0 - e_sq) / (latitude.cos() * (1.0 - e_sq * latitude.sin().powi(2)));
+        let rho_sin = x - self.false_e;
+        if self.lat_orig > 0.0 {
+            // North Pole Case
+            let rho_cos = self.false_n - y;
+            [[rho_cos, -w * rho_sin], [rho_sin, w * rho_cos]]
+        } else {
+            // South Pole Case
+            let rho_cos = y - self.false_n;
+            [[rho_cos, w * rho_sin], [-rho_sin, w * rho_cos]]
+        }
+    }
 }
 impl DbContstruct for PolarStereographicAProjection {
     fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
@@ -241,6 +282,156 @@ pub fn direct_projection_a(params: &[(u32, f64)], ell: Ellipsoid) -> String {
 }
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolarStereographicBParams {
+    /// longitude of origin
+    lon_orig: f64,
+    /// latitude of standard parallel
+    lat_sp: f64,
+    /// false easting
+    false_e: f64,
+    /// false northing
+    false_n: f64,
+}
+
+impl_param_eq_hash!(PolarStereographicBParams {
+    lon_orig,
+    lat_sp,
+    false_e,
+    false_n
+});
+
+impl PolarStereographicBParams {
+    pub const fn new(lon_orig: f64, lat_sp: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lon_orig,
+            lat_sp,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// longitude of origin, radians
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// latitude of standard parallel, radians
+    pub fn lat_sp(&self) -> f64 {
+        self.lat_sp
+    }
+
+    /// false easting
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// false northing
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Polar Stereographic (variant B) coordinate operation.
+///
+/// The scale factor at the pole is derived from the standard parallel, after which the
+/// conversion is the same as for variant A.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PolarStereographicBProjection(pub PolarStereographicAProjection);
+
+impl PolarStereographicBProjection {
+    pub fn new(ell: &Ellipsoid, params: &PolarStereographicBParams) -> Self {
+        let lat_sp = params.lat_sp();
+        let e_sin_lat = ell.e() * lat_sp.sin();
+        let t_sp = if lat_sp > 0.0 {
+            // North Pole Case
+            f64::tan(FRAC_PI_4 - lat_sp / 2.0)
+                * ((1.0 + e_sin_lat) / (1.0 - e_sin_lat)).powf(ell.e() / 2.0)
+        } else {
+            // South Pole Case
+            f64::tan(FRAC_PI_4 + lat_sp / 2.0)
+                / ((1.0 + e_sin_lat) / (1.0 - e_sin_lat)).powf(ell.e() / 2.0)
+        };
+        let m_sp = lat_sp.cos() / (1.0 - e_sin_lat.powi(2)).sqrt();
+        let k_orig = m_sp
+            * ((1.0 + ell.e()).powf(1.0 + ell.e()) * (1.0 - ell.e()).powf(1.0 - ell.e())).sqrt()
+            / (2.0 * t_sp);
+        let params_a = PolarStereographicAParams::new(
+            params.lon_orig(),
+            FRAC_PI_2.copysign(lat_sp),
+            k_orig,
+            params.false_e(),
+            params.false_n(),
+        );
+        Self(PolarStereographicAProjection::new(ell, &params_a))
+    }
+}
+
+impl Projection for PolarStereographicBProjection {
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        self.0.rad_to_projected(longitude, latitude)
+    }
+
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        self.0.projected_to_rad(easting, northing)
+    }
+
+    fn jacobian(&self, longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        self.0.jacobian(longitude, latitude)
+    }
+}
+
+impl DbContstruct for PolarStereographicBProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = PolarStereographicBParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8833 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8832 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8806 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8807 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for PolarStereographicBProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = PolarStereographicBParams::new(
+            getter(8833)?,
+            getter(8832)?,
+            getter(8806)?,
+            getter(8807)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+impl PseudoSerialize for PolarStereographicBProjection {
+    fn to_constructed(&self) -> String {
+        format!("PolarStereographicBProjection({})", self.0.to_constructed())
+    }
+}
+
+pub fn direct_projection_b(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    PolarStereographicBProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObliqueStereographicParams {
     // Longitude of natural origin
     lon_orig: f64,
@@ -254,9 +445,16 @@ pub struct ObliqueStereographicParams {
     false_n: f64,
 }
 
+impl_param_eq_hash!(ObliqueStereographicParams {
+    lon_orig,
+    lat_orig,
+    k_orig,
+    false_e,
+    false_n
+});
+
 impl ObliqueStereographicParams {
     pub fn new(lon_orig: f64, lat_orig: f64, k_orig: f64, false_e: f64, false_n: f64) -> Self {
-        assert!(lat_orig > 0f64);
         Self {
             lat_orig,
             lon_orig,
@@ -308,6 +506,20 @@ pub struct ObliqueStereographicProjection {
     pub h: f64,
 }
 
+impl_param_eq_hash!(ObliqueStereographicProjection {
+    false_e,
+    false_n,
+    chi_O,
+    R_k_O_2,
+    c,
+    ellipsoid_e,
+    ellipsoid_e_sq,
+    n,
+    lon_orig,
+    g,
+    h,
+});
+
 impl ObliqueStereographicProjection {
     const MAX_ITERATIONS: usize = 4;
 
@@ -388,6 +600,44 @@ impl crate::traits::Projection for ObliqueStereographicProjection {
                     / B,
         )
     }
+
+    #[allow(non_snake_case)]
+    fn jacobian(&self, lon: f64, lat: f64) -> [[f64; 2]; 2] {
+        let S_a = (1f64 + lat.sin()) / (1f64 - lat.sin());
+        let S_b = (1f64 - self.ellipsoid_e * lat.sin()) / (1f64 + self.ellipsoid_e * lat.sin());
+        let DeltaLambda = self.n * (lon - self.lon_orig);
+        let w = self.c * (S_a * S_b.powf(self.ellipsoid_e)).powf(self.n);
+        let chi = ((w - 1f64) / (w + 1f64)).asin();
+        let B =
+            1f64 + chi.sin() * self.chi_O.sin() + chi.cos() * self.chi_O.cos() * DeltaLambda.cos();
+        let X = chi.cos() * DeltaLambda.sin();
+        let Y = chi.sin() * self.chi_O.cos() - chi.cos() * self.chi_O.sin() * DeltaLambda.cos();
+
+        // partial derivatives with respect to chi and DeltaLambda
+        let dX_dchi = -chi.sin() * DeltaLambda.sin();
+        let dX_dl = chi.cos() * DeltaLambda.cos();
+        let dY_dchi =
+            chi.cos() * self.chi_O.cos() + chi.sin() * self.chi_O.sin() * DeltaLambda.cos();
+        let dY_dl = chi.cos() * self.chi_O.sin() * DeltaLambda.sin();
+        let dB_dchi =
+            chi.cos() * self.chi_O.sin() - chi.sin() * self.chi_O.cos() * DeltaLambda.cos();
+        let dB_dl = -chi.cos() * self.chi_O.cos() * DeltaLambda.sin();
+
+        let dchi_dlat = chi.cos() * self.n * (1.0 - self.ellipsoid_e_sq)
+            / (lat.cos() * (1.0 - self.ellipsoid_e_sq * lat.sin().powi(2)));
+        let dl_dlon = self.n;
+        let f = self.R_k_O_2 / (B * B);
+        [
+            [
+                f * (dX_dl * B - X * dB_dl) * dl_dlon,
+                f * (dX_dchi * B - X * dB_dchi) * dchi_dlat,
+            ],
+            [
+                f * (dY_dl * B - Y * dB_dl) * dl_dlon,
+                f * (dY_dchi * B - Y * dB_dchi) * dchi_dlat,
+            ],
+        ]
+    }
 }
 
 impl DbContstruct for ObliqueStereographicProjection {
@@ -480,17 +730,21 @@ mod tests {
         let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
         let params = PolarStereographicAParams::new(
             0.0f64.to_radians(),
-            -90.0f64.to_radians(),
+            90.0f64.to_radians(),
             0.994,
             2_000_000.0,
             2_000_000.0,
         );
 
         let projection = PolarStereographicAProjection::new(&ell, &params);
-        let easting_goal = 3329416.75;
+        let easting_goal = 3320416.75;
         let northing_goal = 632668.43;
         let (lon, lat) = projection.projected_to_deg(easting_goal, northing_goal);
-        let (easting, northing) = projection.deg_to_projected(lon, lat);
+        eprintln!("lon: 44 - {lon}, lat: 73 - {lat}");
+        assert!((lon - 44.0).abs() < 1e-7);
+        assert!((lat - 73.0).abs() < 1e-7);
+
+        let (easting, northing) = projection.deg_to_projected(44.0, 73.0);
 
         eprintln!("easting: {easting_goal} - {easting}");
         eprintln!("northing: {northing_goal} - {northing}");
@@ -500,6 +754,33 @@ mod tests {
         assert!((northing - northing_goal).abs() < 0.01);
     }
 
+    #[test]
+    fn polar_stereographic_b_consistency() {
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params = PolarStereographicBParams::new(
+            70.0f64.to_radians(),
+            -71.0f64.to_radians(),
+            6_000_000.0,
+            6_000_000.0,
+        );
+
+        let projection = PolarStereographicBProjection::new(&ell, &params);
+        let easting_goal = 7255380.79;
+        let northing_goal = 7053389.56;
+        let (easting, northing) = projection.deg_to_projected(120.0, -75.0);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon, lat) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: 120 - {lon}, lat: -75 - {lat}");
+        assert!((lon - 120.0).abs() < 1e-7);
+        assert!((lat + 75.0).abs() < 1e-7);
+    }
+
     #[test]
     fn oblique_stereographic_consistency() {
         let ell = Ellipsoid::from_a_f_inv(6377397.155, 299.15281);
@@ -525,4 +806,35 @@ mod tests {
 
         assert!((northing - northing_goal).abs() < 0.01);
     }
+
+    #[test]
+    fn stereographic_jacobian() {
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        for lat_orig in [-90.0f64, 90.0] {
+            let params = PolarStereographicAParams::new(
+                0.0f64.to_radians(),
+                lat_orig.to_radians(),
+                0.994,
+                2_000_000.0,
+                2_000_000.0,
+            );
+            let projection = PolarStereographicAProjection::new(&ell, &params);
+            for (lon, lat) in [(44.0, -73.0), (-120.0, -60.0), (10.0, 70.0), (170.0, 85.0)] {
+                assert_jacobian(&projection, lon, lat);
+            }
+        }
+
+        let ell = Ellipsoid::from_a_f_inv(6377397.155, 299.15281);
+        let params = ObliqueStereographicParams::new(
+            0.094032038,
+            0.910296727,
+            0.9999079,
+            155000.0,
+            463000.0,
+        );
+        let projection = ObliqueStereographicProjection::new(&ell, &params);
+        for (lon, lat) in [(5.387, 52.156), (6.0, 53.0), (3.0, 50.0), (12.0, 60.0)] {
+            assert_jacobian(&projection, lon, lat);
+        }
+    }
 }
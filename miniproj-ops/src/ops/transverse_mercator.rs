@@ -1,10 +1,12 @@
 //This file is licensed under EUPL v1.2 as part of the Digital Earth Viewer
 
 use crate::{
-    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+    ellipsoid::Ellipsoid, fast::Fast, traits::GetterContstruct, DbContstruct, Projection,
+    PseudoSerialize,
 };
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransverseMercatorParams {
     /// longitude of natural origin
     lon_orig: f64,
@@ -18,6 +20,14 @@ pub struct TransverseMercatorParams {
     false_n: f64,
 }
 
+impl_param_eq_hash!(TransverseMercatorParams {
+    lon_orig,
+    lat_orig,
+    k_orig,
+    false_e,
+    false_n
+});
+
 impl TransverseMercatorParams {
     pub const fn new(
         lon_orig: f64,
@@ -85,8 +95,27 @@ pub struct TransverseMercatorProjection {
     pub h_4_: f64,
 }
 
+impl_param_eq_hash!(TransverseMercatorProjection {
+    ellipsoid_e,
+    lon_orig,
+    false_e,
+    false_n,
+    k_orig,
+    B,
+    h_1,
+    h_2,
+    h_3,
+    h_4,
+    M_orig,
+    h_1_,
+    h_2_,
+    h_3_,
+    h_4_,
+});
+
 impl TransverseMercatorProjection {
     const MAX_ITERATIONS: usize = 4;
+    const FAST_ITERATIONS: usize = 1;
 
     #[allow(non_snake_case)]
     pub fn new(ell: &Ellipsoid, params: &TransverseMercatorParams) -> Self {
@@ -211,6 +240,128 @@ impl Projection for TransverseMercatorProjection {
             Q__.sinh().atan(),
         )
     }
+
+    /// Analytic derivatives of the forward series above.
+    #[allow(non_snake_case)]
+    fn jacobian(&self, longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        let e_sq = self.ellipsoid_e.powi(2);
+        let Q = latitude.tan().asinh()
+            - (self.ellipsoid_e * f64::atanh(self.ellipsoid_e * latitude.sin()));
+        let beta = Q.sinh().atan();
+        let d_lon = longitude - self.lon_orig;
+        let u = beta.cos() * d_lon.sin();
+        let eta_0 = u.atanh();
+        let v = beta.sin() * eta_0.cosh();
+        let xi_0 = v.asin();
+
+        let dbeta_dlat =
+            beta.cos() * (1.0 - e_sq) / (latitude.cos() * (1.0 - e_sq * latitude.sin().powi(2)));
+        let deta_0_dlon = beta.cos() * d_lon.cos() / (1.0 - u * u);
+        let deta_0_dlat = -beta.sin() * d_lon.sin() / (1.0 - u * u) * dbeta_dlat;
+        let dxi_0_dlon = beta.sin() * eta_0.sinh() * deta_0_dlon / (1.0 - v * v).sqrt();
+        let dxi_0_dlat = (beta.cos() * eta_0.cosh() * dbeta_dlat
+            + beta.sin() * eta_0.sinh() * deta_0_dlat)
+            / (1.0 - v * v).sqrt();
+
+        // the series is holomorphic in xi_0 + i eta_0, with derivative p - i q
+        let mut p = 1.0;
+        let mut q = 0.0;
+        for (k, h) in [self.h_1, self.h_2, self.h_3, self.h_4]
+            .into_iter()
+            .enumerate()
+        {
+            let k2 = 2.0 * (k + 1) as f64;
+            p += k2 * h * f64::cos(k2 * xi_0) * f64::cosh(k2 * eta_0);
+            q += k2 * h * f64::sin(k2 * xi_0) * f64::sinh(k2 * eta_0);
+        }
+        let scale = self.k_orig * self.B;
+        [
+            [
+                scale * (p * deta_0_dlon - q * dxi_0_dlon),
+                scale * (p * deta_0_dlat - q * dxi_0_dlat),
+            ],
+            [
+                scale * (p * dxi_0_dlon + q * deta_0_dlon),
+                scale * (p * dxi_0_dlat + q * deta_0_dlat),
+            ],
+        ]
+    }
+}
+
+impl Projection for Fast<TransverseMercatorProjection> {
+    /// Series truncated after the second order terms, with the sines and cosines of the multiple
+    /// angles from the double angle formulas and the conformal latitude computed from its tangent.
+    /// Within 4° of the central meridian the error is below 1 cm.
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let p = &self.0;
+        let e = p.ellipsoid_e;
+        let (sin_lat, cos_lat) = latitude.sin_cos();
+        let (sin_lon, cos_lon) = (longitude - p.lon_orig).sin_cos();
+        // tangent of the conformal latitude, as the ratio of conformal_sin to cos_lat
+        let sigma = (e * (e * sin_lat).atanh()).sinh();
+        let conformal_sin = sin_lat * (1.0 + sigma * sigma).sqrt() - sigma;
+        let r = (conformal_sin * conformal_sin + (cos_lat * cos_lon).powi(2)).sqrt();
+        // sine and cosine of xi_0, hyperbolic sine and cosine of eta_0
+        let (sin_xi, cos_xi) = (conformal_sin / r, cos_lat * cos_lon / r);
+        let sinh_eta = cos_lat * sin_lon / r;
+        let cosh_eta = (conformal_sin * conformal_sin + cos_lat * cos_lat).sqrt() / r;
+        let xi_0 = conformal_sin.atan2(cos_lat * cos_lon);
+        let eta_0 = sinh_eta.asinh();
+
+        let (sin_2xi, cos_2xi) = (2.0 * sin_xi * cos_xi, 2.0 * cos_xi * cos_xi - 1.0);
+        let (sinh_2eta, cosh_2eta) = (2.0 * sinh_eta * cosh_eta, 2.0 * cosh_eta * cosh_eta - 1.0);
+        let (sin_4xi, cos_4xi) = (2.0 * sin_2xi * cos_2xi, 2.0 * cos_2xi * cos_2xi - 1.0);
+        let (sinh_4eta, cosh_4eta) = (
+            2.0 * sinh_2eta * cosh_2eta,
+            2.0 * cosh_2eta * cosh_2eta - 1.0,
+        );
+        let xi = xi_0 + p.h_1 * sin_2xi * cosh_2eta + p.h_2 * sin_4xi * cosh_4eta;
+        let eta = eta_0 + p.h_1 * cos_2xi * sinh_2eta + p.h_2 * cos_4xi * sinh_4eta;
+
+        (
+            p.false_e + p.k_orig * p.B * eta,
+            p.false_n + p.k_orig * (p.B * xi - p.M_orig),
+        )
+    }
+
+    /// Series truncated after the second order terms like the forward projection, with a single
+    /// Newton iteration for the tangent of the latitude.
+    /// Within 4° of the central meridian the error is below 1e-7 degrees.
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let p = &self.0;
+        let e = p.ellipsoid_e;
+        let eta_ = (easting - p.false_e) / (p.B * p.k_orig);
+        let xi_ = ((northing - p.false_n) + p.k_orig * p.M_orig) / (p.B * p.k_orig);
+
+        let (sin_2xi, cos_2xi) = (2.0 * xi_).sin_cos();
+        let exp_2eta = (2.0 * eta_).exp();
+        let sinh_2eta = (exp_2eta - 1.0 / exp_2eta) / 2.0;
+        let cosh_2eta = (exp_2eta + 1.0 / exp_2eta) / 2.0;
+        let (sin_4xi, cos_4xi) = (2.0 * sin_2xi * cos_2xi, 2.0 * cos_2xi * cos_2xi - 1.0);
+        let (sinh_4eta, cosh_4eta) = (
+            2.0 * sinh_2eta * cosh_2eta,
+            2.0 * cosh_2eta * cosh_2eta - 1.0,
+        );
+        let xi_0 = xi_ - (p.h_1_ * sin_2xi * cosh_2eta + p.h_2_ * sin_4xi * cosh_4eta);
+        let eta_0 = eta_ - (p.h_1_ * cos_2xi * sinh_2eta + p.h_2_ * cos_4xi * sinh_4eta);
+
+        let (sin_xi, cos_xi) = xi_0.sin_cos();
+        let sinh_eta = eta_0.sinh();
+        // tangent of the conformal latitude
+        let conformal_tan = sin_xi / (sinh_eta * sinh_eta + cos_xi * cos_xi).sqrt();
+        // Newton's method on the tangent of the latitude, from its first order approximation
+        let e_sq = e * e;
+        let mut tan = conformal_tan / (1.0 - e_sq);
+        for _ in 0..TransverseMercatorProjection::FAST_ITERATIONS {
+            let sec = (1.0 + tan * tan).sqrt();
+            let sigma = (e * (e * tan / sec).atanh()).sinh();
+            let estimate = tan * (1.0 + sigma * sigma).sqrt() - sigma * sec;
+            tan += (conformal_tan - estimate) * (1.0 + (1.0 - e_sq) * tan * tan)
+                / ((1.0 - e_sq) * sec * (1.0 + estimate * estimate).sqrt());
+        }
+
+        (p.lon_orig + sinh_eta.atan2(cos_xi), tan.atan())
+    }
 }
 
 impl PseudoSerialize for TransverseMercatorProjection {
@@ -310,6 +461,76 @@ impl GetterContstruct for TransverseMercatorProjection {
 pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
     TransverseMercatorProjection::from_database_params(params, &ell).to_constructed()
 }
+
+/// Transverse Mercator (South Orientated) coordinate operation (EPSG:9808).
+///
+/// Returns `(westing, southing)`, increasing from the false origin towards the south-west.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TransverseMercatorSouthOrientatedProjection(pub TransverseMercatorProjection);
+
+impl TransverseMercatorSouthOrientatedProjection {
+    pub fn new(ell: &Ellipsoid, params: &TransverseMercatorParams) -> Self {
+        Self(TransverseMercatorProjection::new(ell, params))
+    }
+}
+
+impl Projection for TransverseMercatorSouthOrientatedProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians, returns `(westing, southing)`
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let (easting, northing) = self.0.rad_to_projected(longitude, latitude);
+        (
+            2.0 * self.0.false_e - easting,
+            2.0 * self.0.false_n - northing,
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// expects `(westing, southing)`, returns longitude & latitude in radians
+    fn projected_to_rad(&self, westing: f64, southing: f64) -> (f64, f64) {
+        self.0.projected_to_rad(
+            2.0 * self.0.false_e - westing,
+            2.0 * self.0.false_n - southing,
+        )
+    }
+
+    fn jacobian(&self, longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        let [[a, b], [c, d]] = self.0.jacobian(longitude, latitude);
+        [[-a, -b], [-c, -d]]
+    }
+}
+
+impl PseudoSerialize for TransverseMercatorSouthOrientatedProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            "TransverseMercatorSouthOrientatedProjection({})",
+            self.0.to_constructed()
+        )
+    }
+}
+
+impl DbContstruct for TransverseMercatorSouthOrientatedProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        Self(TransverseMercatorProjection::from_database_params(
+            params, ellipsoid,
+        ))
+    }
+}
+
+impl GetterContstruct for TransverseMercatorSouthOrientatedProjection {
+    fn with_db_getter<G>(getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        Some(Self(TransverseMercatorProjection::with_db_getter(
+            getter, ellipsoid,
+        )?))
+    }
+}
+
+pub fn direct_projection_south_orientated(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    TransverseMercatorSouthOrientatedProjection::from_database_params(params, &ell).to_constructed()
+}
 #[cfg(test)]
 mod tests {
 
@@ -341,4 +562,101 @@ mod tests {
 
         assert!((northing - northing_goal).abs() < 0.01);
     }
+
+    #[test]
+    fn transverse_mercator_fast() {
+        let wgs_84_ellipsoid = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let utm_32_n = TransverseMercatorParams::new(
+            9.0f64.to_radians(),
+            0.0f64.to_radians(),
+            0.9996,
+            500_000.0,
+            0.0,
+        );
+
+        let projection = TransverseMercatorProjection::new(&wgs_84_ellipsoid, &utm_32_n);
+        let fast = Fast(projection);
+        for lon in [5.0, 7.0, 9.0, 11.0, 13.0] {
+            for lat in [-80.0, -40.0, 0.0, 40.0, 54.3, 84.0] {
+                let (easting, northing) = projection.deg_to_projected(lon, lat);
+                let (fast_easting, fast_northing) = fast.deg_to_projected(lon, lat);
+                eprintln!("easting: {easting} - {fast_easting}");
+                eprintln!("northing: {northing} - {fast_northing}");
+                assert!((easting - fast_easting).abs() < 0.01);
+                assert!((northing - fast_northing).abs() < 0.01);
+
+                let (fast_lon, fast_lat) = fast.projected_to_deg(easting, northing);
+                assert!((fast_lon - lon).abs() < 1e-7);
+                assert!((fast_lat - lat).abs() < 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn transverse_mercator_equality() {
+        use std::collections::HashSet;
+
+        let wgs_84_ellipsoid = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let utm = |zone: f64| {
+            let params = TransverseMercatorParams::new(
+                (zone * 6.0 - 183.0).to_radians(),
+                0.0f64.to_radians(),
+                0.9996,
+                500_000.0,
+                0.0,
+            );
+            TransverseMercatorProjection::new(&wgs_84_ellipsoid, &params)
+        };
+
+        assert_eq!(utm(32.0), utm(32.0));
+        assert_ne!(utm(32.0), utm(33.0));
+        let set: HashSet<_> = [utm(32.0), utm(33.0), utm(32.0)].into_iter().collect();
+        assert_eq!(set.len(), 2);
+
+        assert!(crate::projections_equal(&utm(32.0), &utm(32.0)));
+        assert!(!crate::projections_equal(&utm(32.0), &utm(33.0)));
+        assert!(!crate::projections_equal(&utm(32.0), &Fast(utm(32.0))));
+    }
+
+    #[test]
+    fn transverse_mercator_jacobian() {
+        let wgs_84_ellipsoid = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let utm_32_n = TransverseMercatorParams::new(
+            9.0f64.to_radians(),
+            0.0f64.to_radians(),
+            0.9996,
+            500_000.0,
+            0.0,
+        );
+
+        let projection = TransverseMercatorProjection::new(&wgs_84_ellipsoid, &utm_32_n);
+        for lon in [3.0, 7.0, 9.0, 11.5, 15.0] {
+            for lat in [-80.0, -40.0, 0.0, 40.0, 54.3, 84.0] {
+                assert_jacobian(&projection, lon, lat);
+            }
+        }
+    }
+    #[test]
+    fn transverse_mercator_south_orientated() {
+        // Hartebeesthoek94 / Lo29
+        let wgs_84_ellipsoid = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let lo_29 = TransverseMercatorParams::new(29.0f64.to_radians(), 0.0, 1.0, 0.0, 0.0);
+
+        let projection = TransverseMercatorProjection::new(&wgs_84_ellipsoid, &lo_29);
+        let south = TransverseMercatorSouthOrientatedProjection::new(&wgs_84_ellipsoid, &lo_29);
+        for (lon, lat) in [(28.0, -26.2), (30.5, -29.9), (29.0, -22.0), (27.3, -33.9)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (westing, southing) = south.deg_to_projected(lon, lat);
+            eprintln!("{lon}, {lat}: {westing}, {southing}");
+            assert_eq!(westing, -easting);
+            assert_eq!(southing, -northing);
+            assert!(southing > 0.0);
+            assert_eq!(westing > 0.0, lon < 29.0);
+
+            let (lon_r, lat_r) = south.projected_to_deg(westing, southing);
+            assert!((lon - lon_r).abs() < 1e-9);
+            assert!((lat - lat_r).abs() < 1e-9);
+            assert_jacobian(&south, lon, lat);
+        }
+    }
 }
@@ -0,0 +1,232 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::PI;
+
+use crate::{
+    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TunisiaMiningGridParams {
+    /// latitude of false origin
+    lat_orig: f64,
+    /// longitude of false origin
+    lon_orig: f64,
+    /// easting at false origin
+    false_e: f64,
+    /// northing at false origin
+    false_n: f64,
+}
+
+impl_param_eq_hash!(TunisiaMiningGridParams {
+    lat_orig,
+    lon_orig,
+    false_e,
+    false_n
+});
+
+impl TunisiaMiningGridParams {
+    pub const fn new(lat_orig: f64, lon_orig: f64, false_e: f64, false_n: f64) -> Self {
+        Self {
+            lat_orig,
+            lon_orig,
+            false_e,
+            false_n,
+        }
+    }
+
+    /// Get latitude of false origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get longitude of false origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get easting at false origin.
+    pub fn false_e(&self) -> f64 {
+        self.false_e
+    }
+
+    /// Get northing at false origin.
+    pub fn false_n(&self) -> f64 {
+        self.false_n
+    }
+}
+
+/// Tunisia Mining Grid coordinate operation (EPSG:9816).
+///
+/// A linear grid defined in grads, which does not depend on the ellipsoid. Longitudes are relative
+/// to the prime meridian of the base CRS, i.e. Paris for EPSG:22300.
+#[derive(Copy, Clone, Debug)]
+pub struct TunisiaMiningGridProjection {
+    pub lat_orig: f64,
+    pub lon_orig: f64,
+    pub false_e: f64,
+    pub false_n: f64,
+}
+
+impl_param_eq_hash!(TunisiaMiningGridProjection {
+    lat_orig,
+    lon_orig,
+    false_e,
+    false_n
+});
+
+impl TunisiaMiningGridProjection {
+    /// Radians of longitude per metre of easting, defined as 0.012185 grads per kilometre.
+    const LON_FACTOR: f64 = 0.012185 * PI / 200.0 / 1000.0;
+    /// Radians of latitude per metre of northing north of the false origin, defined as
+    /// 0.010015 grads per kilometre.
+    const LAT_FACTOR_NORTH: f64 = 0.010015 * PI / 200.0 / 1000.0;
+    /// Radians of latitude per metre of northing south of the false origin, defined as
+    /// 0.01002 grads per kilometre.
+    const LAT_FACTOR_SOUTH: f64 = 0.01002 * PI / 200.0 / 1000.0;
+
+    pub fn new(_ell: &Ellipsoid, params: &TunisiaMiningGridParams) -> Self {
+        Self {
+            lat_orig: params.lat_orig(),
+            lon_orig: params.lon_orig(),
+            false_e: params.false_e(),
+            false_n: params.false_n(),
+        }
+    }
+}
+
+impl Projection for TunisiaMiningGridProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let lat_factor = if latitude > self.lat_orig {
+            Self::LAT_FACTOR_NORTH
+        } else {
+            Self::LAT_FACTOR_SOUTH
+        };
+        (
+            self.false_e + (longitude - self.lon_orig) / Self::LON_FACTOR,
+            self.false_n + (latitude - self.lat_orig) / lat_factor,
+        )
+    }
+
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let lat_factor = if northing > self.false_n {
+            Self::LAT_FACTOR_NORTH
+        } else {
+            Self::LAT_FACTOR_SOUTH
+        };
+        (
+            self.lon_orig + (easting - self.false_e) * Self::LON_FACTOR,
+            self.lat_orig + (northing - self.false_n) * lat_factor,
+        )
+    }
+
+    fn jacobian(&self, _longitude: f64, latitude: f64) -> [[f64; 2]; 2] {
+        let lat_factor = if latitude > self.lat_orig {
+            Self::LAT_FACTOR_NORTH
+        } else {
+            Self::LAT_FACTOR_SOUTH
+        };
+        [[1.0 / Self::LON_FACTOR, 0.0], [0.0, 1.0 / lat_factor]]
+    }
+}
+
+impl PseudoSerialize for TunisiaMiningGridProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"TunisiaMiningGridProjection{{
+    lat_orig: {}f64,
+    lon_orig: {}f64,
+    false_e: {}f64,
+    false_n: {}f64,
+}}",
+            self.lat_orig, self.lon_orig, self.false_e, self.false_n,
+        )
+    }
+}
+
+impl DbContstruct for TunisiaMiningGridProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = TunisiaMiningGridParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8821 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8822 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8826 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8827 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for TunisiaMiningGridProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = TunisiaMiningGridParams::new(
+            getter(8821)?,
+            getter(8822)?,
+            getter(8826)?,
+            getter(8827)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    TunisiaMiningGridProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64::consts::PI;
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::traits::*;
+    use crate::tunisia_mining_grid::*;
+
+    #[test]
+    fn tunisia_mining_grid_consistency() {
+        // Carthage (Paris) / Tunisia Mining Grid, grid location 302598
+        let ell = Ellipsoid::from_a_f_inv(6378249.2, 293.4660213);
+        let grads = PI / 200.0;
+        let params =
+            TunisiaMiningGridParams::new(36.5964 * grads, 7.83445 * grads, 270_000.0, 360_000.0);
+
+        let projection = TunisiaMiningGridProjection::new(&ell, &params);
+        let lon_goal = 8.22437;
+        let lat_goal = 38.97997;
+        let (lon, lat) = projection.projected_to_rad(302_000.0, 598_000.0);
+        let (lon, lat) = (lon / grads, lat / grads);
+
+        eprintln!("lon: {lon_goal} - {lon}");
+        eprintln!("lat: {lat_goal} - {lat}");
+
+        assert!((lon - lon_goal).abs() < 1e-9);
+        assert!((lat - lat_goal).abs() < 1e-9);
+
+        for (easting, northing) in [(302_000.0, 598_000.0), (94_000.0, 40_000.0)] {
+            let (lon, lat) = projection.projected_to_rad(easting, northing);
+            let (easting_r, northing_r) = projection.rad_to_projected(lon, lat);
+            eprintln!("{easting}, {northing}: {easting_r}, {northing_r}");
+            assert!((easting - easting_r).abs() < 1e-6);
+            assert!((northing - northing_r).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{Projection, PseudoSerialize};
+
+/// Quantity measured by a `Unit`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    Length,
+    Angle,
+    Scale,
+    Time,
+    /// Units without a type in the registry
+    Other,
+}
+
+impl UnitKind {
+    /// Get the kind for the `unit_of_meas_type` column of the EPSG registry.
+    pub fn from_epsg_type(name: &str) -> Self {
+        match name {
+            "length" => Self::Length,
+            "angle" => Self::Angle,
+            "scale" => Self::Scale,
+            "time" => Self::Time,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Unit of measure as defined in the EPSG registry
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Unit {
+    /// Name of the unit
+    pub name: &'static str,
+    /// Quantity measured by the unit
+    pub kind: UnitKind,
+    /// EPSG code of the unit that `to_base_factor` converts to, e.g. metre for lengths and radian for angles
+    pub base_code: u32,
+    /// Factor to multiply values in this unit with to get values in the base unit.
+    /// `None` for units that cannot be converted by a factor, like sexagesimal degrees.
+    pub to_base_factor: Option<f64>,
+}
+
+impl Unit {
+    /// Convert a value in this unit to the base unit, if the unit can be converted by a factor.
+    pub fn to_base(&self, value: f64) -> Option<f64> {
+        self.to_base_factor.map(|f| value * f)
+    }
+
+    /// Convert a value in the base unit to this unit, if the unit can be converted by a factor.
+    pub fn from_base(&self, value: f64) -> Option<f64> {
+        self.to_base_factor.map(|f| value / f)
+    }
+}
+
+/// Projection taking and returning projected coordinates in a linear unit other than metres, like
+/// the US survey foot.
+///
+/// All `Projection`s take and return projected coordinates in metres, whatever the unit of the
+/// axes of the CRS in the EPSG registry, as the parameters of their conversions are converted to
+/// metres. `LinearUnitProjection<P>` scales the projected coordinates of `P` to the native unit of
+/// the CRS. Geographic coordinates are not affected.
+#[derive(Copy, Clone, Debug)]
+pub struct LinearUnitProjection<P> {
+    pub projection: P,
+    /// length of the unit in metres
+    pub to_metres: f64,
+}
+
+impl<P> LinearUnitProjection<P> {
+    /// Wrap a projection for projected coordinates in a unit of `to_metres` metres.
+    pub const fn new(projection: P, to_metres: f64) -> Self {
+        Self {
+            projection,
+            to_metres,
+        }
+    }
+
+    /// Wrap a projection for projected coordinates in `unit`. Returns `None` if `unit` is not a
+    /// length that can be converted by a factor.
+    pub fn with_unit(projection: P, unit: &Unit) -> Option<Self> {
+        match (unit.kind, unit.to_base_factor) {
+            (UnitKind::Length, Some(to_metres)) => Some(Self::new(projection, to_metres)),
+            _ => None,
+        }
+    }
+
+    /// Converts projected coordinates in the unit to metres.
+    pub fn to_metres(&self, x: f64, y: f64) -> (f64, f64) {
+        (x * self.to_metres, y * self.to_metres)
+    }
+
+    /// Converts projected coordinates in metres to the unit.
+    pub fn from_metres(&self, x: f64, y: f64) -> (f64, f64) {
+        (x / self.to_metres, y / self.to_metres)
+    }
+}
+
+impl<P: PartialEq> PartialEq for LinearUnitProjection<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.projection == other.projection && self.to_metres.to_bits() == other.to_metres.to_bits()
+    }
+}
+
+impl<P: Projection> Projection for LinearUnitProjection<P> {
+    fn projected_to_rad(&self, x: f64, y: f64) -> (f64, f64) {
+        let (x, y) = self.to_metres(x, y);
+        self.projection.projected_to_rad(x, y)
+    }
+
+    fn rad_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (x, y) = self.projection.rad_to_projected(lon, lat);
+        self.from_metres(x, y)
+    }
+
+    fn jacobian(&self, lon: f64, lat: f64) -> [[f64; 2]; 2] {
+        self.projection
+            .jacobian(lon, lat)
+            .map(|row| row.map(|d| d / self.to_metres))
+    }
+}
+
+impl<P: PseudoSerialize> PseudoSerialize for LinearUnitProjection<P> {
+    fn to_constructed(&self) -> String {
+        format!(
+            "LinearUnitProjection{{ projection: {}, to_metres: {:?}f64 }}",
+            self.projection.to_constructed(),
+            self.to_metres
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::lambert_conic_conformal::*;
+    use crate::traits::*;
+    use crate::unit::*;
+    use crate::Ellipsoid;
+
+    #[test]
+    fn linear_unit_projection() {
+        // NAD83 / California zone 3 (ftUS), example coordinates in metres
+        let ft_us = Unit {
+            name: "US survey foot",
+            kind: UnitKind::Length,
+            base_code: 9001,
+            to_base_factor: Some(1200.0 / 3937.0),
+        };
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257222101);
+        let params = LambertConic2SPParams::new(
+            -120.5f64.to_radians(),
+            36.5f64.to_radians(),
+            38.4333333333333f64.to_radians(),
+            37.0666666666667f64.to_radians(),
+            2000000.0,
+            500000.0,
+        );
+        let metres = LambertConic2SPProjection::new(&ell, &params);
+        let feet = LinearUnitProjection::with_unit(metres, &ft_us).unwrap();
+        let (x, y) = metres.deg_to_projected(-122.0, 37.5);
+        let (x_ft, y_ft) = feet.deg_to_projected(-122.0, 37.5);
+        eprintln!("{x_ft}, {y_ft}");
+        assert!((x_ft * 1200.0 / 3937.0 - x).abs() < 1e-6);
+        assert!((y_ft * 1200.0 / 3937.0 - y).abs() < 1e-6);
+        let (lon, lat) = feet.projected_to_deg(x_ft, y_ft);
+        assert!((lon + 122.0).abs() < 1e-9 && (lat - 37.5).abs() < 1e-9);
+        assert_eq!(
+            feet.to_metres(x_ft, y_ft).0,
+            x_ft * ft_us.to_base_factor.unwrap()
+        );
+
+        let degree = Unit {
+            kind: UnitKind::Angle,
+            ..ft_us
+        };
+        assert!(LinearUnitProjection::with_unit(metres, &degree).is_none());
+    }
+}
@@ -0,0 +1,413 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    coordinate::{
+        Geographic2DCoordinateUserVertical, Geographic3DCoordinate, ProjectedCoordinateUserVertical,
+    },
+    ellipsoid::Ellipsoid,
+    inverse::iterative_geographic_inverse,
+    traits::{GetterContstruct, InvertibleCoordOperation},
+    CoordOperation, DbContstruct, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerticalOffsetParams {
+    /// vertical offset
+    vertical_offset: f64,
+}
+
+impl_param_eq_hash!(VerticalOffsetParams { vertical_offset });
+
+impl VerticalOffsetParams {
+    pub const fn new(vertical_offset: f64) -> Self {
+        Self { vertical_offset }
+    }
+
+    /// Get vertical offset, metres.
+    pub fn vertical_offset(&self) -> f64 {
+        self.vertical_offset
+    }
+}
+
+/// Vertical offset transformation (EPSG:9616).
+///
+/// Adds a constant offset to the vertical coordinate, the horizontal position is passed through
+/// unchanged. Source and target vertical CRS need to have the same axis direction, i.e. both
+/// heights or both depths.
+#[derive(Copy, Clone, Debug)]
+pub struct VerticalOffsetTransformation {
+    pub vertical_offset: f64,
+}
+
+impl_param_eq_hash!(VerticalOffsetTransformation { vertical_offset });
+
+impl VerticalOffsetTransformation {
+    pub fn new(params: &VerticalOffsetParams) -> Self {
+        Self {
+            vertical_offset: params.vertical_offset(),
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which subtracts the offset.
+    pub fn reversed(&self) -> Self {
+        Self {
+            vertical_offset: -self.vertical_offset,
+        }
+    }
+}
+
+impl CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>
+    for VerticalOffsetTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    fn apply(
+        &self,
+        from: Geographic2DCoordinateUserVertical,
+    ) -> Geographic2DCoordinateUserVertical {
+        Geographic2DCoordinateUserVertical {
+            vertical: from.vertical + self.vertical_offset,
+            ..from
+        }
+    }
+}
+
+impl CoordOperation<ProjectedCoordinateUserVertical, ProjectedCoordinateUserVertical>
+    for VerticalOffsetTransformation
+{
+    fn apply(&self, from: ProjectedCoordinateUserVertical) -> ProjectedCoordinateUserVertical {
+        ProjectedCoordinateUserVertical {
+            vertical: from.vertical + self.vertical_offset,
+            ..from
+        }
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for VerticalOffsetTransformation
+{
+    /// offsets the ellipsoidal height
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        Geographic3DCoordinate {
+            height: from.height + self.vertical_offset,
+            ..from
+        }
+    }
+}
+
+impl InvertibleCoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>
+    for VerticalOffsetTransformation
+{
+    fn apply_inverse(&self, to: Geographic3DCoordinate) -> Geographic3DCoordinate {
+        iterative_geographic_inverse(self, to)
+    }
+}
+
+impl PseudoSerialize for VerticalOffsetTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"VerticalOffsetTransformation{{
+    vertical_offset: {}f64,
+}}",
+            self.vertical_offset,
+        )
+    }
+}
+
+impl DbContstruct for VerticalOffsetTransformation {
+    fn from_database_params(params: &[(u32, f64)], _ellipsoid: &Ellipsoid) -> Self {
+        let params = VerticalOffsetParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8603 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(&params)
+    }
+}
+
+impl GetterContstruct for VerticalOffsetTransformation {
+    fn with_db_getter<G>(mut getter: G, _ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = VerticalOffsetParams::new(getter(8603)?);
+        Some(Self::new(&params))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerticalOffsetAndSlopeParams {
+    /// vertical offset
+    vertical_offset: f64,
+    /// inclination in latitude
+    inclination_lat: f64,
+    /// inclination in longitude
+    inclination_lon: f64,
+    /// latitude of the evaluation point
+    lat_eval: f64,
+    /// longitude of the evaluation point
+    lon_eval: f64,
+}
+
+impl_param_eq_hash!(VerticalOffsetAndSlopeParams {
+    vertical_offset,
+    inclination_lat,
+    inclination_lon,
+    lat_eval,
+    lon_eval,
+});
+
+impl VerticalOffsetAndSlopeParams {
+    pub const fn new(
+        vertical_offset: f64,
+        inclination_lat: f64,
+        inclination_lon: f64,
+        lat_eval: f64,
+        lon_eval: f64,
+    ) -> Self {
+        Self {
+            vertical_offset,
+            inclination_lat,
+            inclination_lon,
+            lat_eval,
+            lon_eval,
+        }
+    }
+
+    /// Get vertical offset at the evaluation point, metres.
+    pub fn vertical_offset(&self) -> f64 {
+        self.vertical_offset
+    }
+
+    /// Get inclination of the target surface in latitude, radians. Positive towards north.
+    pub fn inclination_lat(&self) -> f64 {
+        self.inclination_lat
+    }
+
+    /// Get inclination of the target surface in longitude, radians. Positive towards east.
+    pub fn inclination_lon(&self) -> f64 {
+        self.inclination_lon
+    }
+
+    /// Get latitude of the evaluation point, radians.
+    pub fn lat_eval(&self) -> f64 {
+        self.lat_eval
+    }
+
+    /// Get longitude of the evaluation point, radians.
+    pub fn lon_eval(&self) -> f64 {
+        self.lon_eval
+    }
+}
+
+/// Vertical offset and slope transformation (EPSG:1046).
+///
+/// Adds an offset that varies linearly with the horizontal position around an evaluation point, as
+/// used between European national height systems and EVRF. The horizontal position is given in the
+/// geographic CRS the parameters refer to, whose ellipsoid is used for the radii of curvature.
+#[derive(Copy, Clone, Debug)]
+pub struct VerticalOffsetAndSlopeTransformation {
+    pub vertical_offset: f64,
+    pub inclination_lat: f64,
+    pub inclination_lon: f64,
+    pub lat_eval: f64,
+    pub lon_eval: f64,
+    /// meridional radius of curvature at the evaluation point
+    pub rho_eval: f64,
+    /// prime vertical radius of curvature at the evaluation point
+    pub ny_eval: f64,
+}
+
+impl_param_eq_hash!(VerticalOffsetAndSlopeTransformation {
+    vertical_offset,
+    inclination_lat,
+    inclination_lon,
+    lat_eval,
+    lon_eval,
+    rho_eval,
+    ny_eval,
+});
+
+impl VerticalOffsetAndSlopeTransformation {
+    pub fn new(ell: &Ellipsoid, params: &VerticalOffsetAndSlopeParams) -> Self {
+        let w_sq = 1.0 - ell.e_squared() * params.lat_eval().sin().powi(2);
+        Self {
+            vertical_offset: params.vertical_offset(),
+            inclination_lat: params.inclination_lat(),
+            inclination_lon: params.inclination_lon(),
+            lat_eval: params.lat_eval(),
+            lon_eval: params.lon_eval(),
+            rho_eval: ell.a() * (1.0 - ell.e_squared()) / w_sq.powf(1.5),
+            ny_eval: ell.a() / w_sq.sqrt(),
+        }
+    }
+
+    /// Get the transformation in the reverse direction, which negates the offset and inclinations.
+    pub fn reversed(&self) -> Self {
+        Self {
+            vertical_offset: -self.vertical_offset,
+            inclination_lat: -self.inclination_lat,
+            inclination_lon: -self.inclination_lon,
+            ..*self
+        }
+    }
+}
+
+impl CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>
+    for VerticalOffsetAndSlopeTransformation
+{
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn apply(
+        &self,
+        from: Geographic2DCoordinateUserVertical,
+    ) -> Geographic2DCoordinateUserVertical {
+        Geographic2DCoordinateUserVertical {
+            vertical: from.vertical
+                + self.vertical_offset
+                + self.inclination_lat * self.rho_eval * (from.lat - self.lat_eval)
+                + self.inclination_lon * self.ny_eval * (from.lon - self.lon_eval) * from.lat.cos(),
+            ..from
+        }
+    }
+}
+
+impl PseudoSerialize for VerticalOffsetAndSlopeTransformation {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"VerticalOffsetAndSlopeTransformation{{
+    vertical_offset: {}f64,
+    inclination_lat: {}f64,
+    inclination_lon: {}f64,
+    lat_eval: {}f64,
+    lon_eval: {}f64,
+    rho_eval: {}f64,
+    ny_eval: {}f64,
+}}",
+            self.vertical_offset,
+            self.inclination_lat,
+            self.inclination_lon,
+            self.lat_eval,
+            self.lon_eval,
+            self.rho_eval,
+            self.ny_eval,
+        )
+    }
+}
+
+impl DbContstruct for VerticalOffsetAndSlopeTransformation {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = VerticalOffsetAndSlopeParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8603 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8730 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8731 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8617 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8618 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for VerticalOffsetAndSlopeTransformation {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = VerticalOffsetAndSlopeParams::new(
+            getter(8603)?,
+            getter(8730)?,
+            getter(8731)?,
+            getter(8617)?,
+            getter(8618)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_transformation(params: &[(u32, f64)], ellipsoid: Ellipsoid) -> String {
+    VerticalOffsetTransformation::from_database_params(params, &ellipsoid).to_constructed()
+}
+
+pub fn direct_transformation_slope(params: &[(u32, f64)], ellipsoid: Ellipsoid) -> String {
+    VerticalOffsetAndSlopeTransformation::from_database_params(params, &ellipsoid).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::coordinate::*;
+    use crate::ellipsoids::GRS80;
+    use crate::traits::*;
+    use crate::vertical_offset::*;
+
+    #[test]
+    fn vertical_offset_consistency() {
+        // EPSG example, Baltic 1977 height to Black Sea height
+        let transformation = VerticalOffsetTransformation::new(&VerticalOffsetParams::new(0.4));
+        let from = Geographic2DCoordinateUserVertical::from_deg(38.0, 45.0, 102.4);
+        let to = transformation.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.vertical - 102.8).abs() < 1e-12);
+        assert_eq!((to.lon, to.lat), (from.lon, from.lat));
+
+        let back = transformation.reversed().apply(to);
+        assert!((back.vertical - from.vertical).abs() < 1e-12);
+
+        let projected = ProjectedCoordinateUserVertical::new(500000.0, 5000000.0, 102.4);
+        let to = transformation.apply(projected);
+        assert!((to.vertical - 102.8).abs() < 1e-12);
+        assert_eq!((to.x, to.y), (projected.x, projected.y));
+
+        let geographic = Geographic3DCoordinate::from_deg(38.0, 45.0, 10.0);
+        assert!((transformation.apply(geographic).height - 10.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vertical_offset_and_slope_consistency() {
+        // parameters of LN02 height to EVRF2000 height
+        let arcsec = 1f64.to_radians() / 3600.0;
+        let params = VerticalOffsetAndSlopeParams::new(
+            -0.245,
+            -0.21 * arcsec,
+            -0.032 * arcsec,
+            (46.0 + 55.0 / 60.0f64).to_radians(),
+            (8.0 + 11.0 / 60.0f64).to_radians(),
+        );
+        let transformation = VerticalOffsetAndSlopeTransformation::new(&GRS80, &params);
+
+        // the offset applies unchanged at the evaluation point
+        let from =
+            Geographic2DCoordinateUserVertical::new(params.lon_eval(), params.lat_eval(), 1.0);
+        assert!((transformation.apply(from).vertical - 0.755).abs() < 1e-12);
+
+        let from = Geographic2DCoordinateUserVertical::from_deg(
+            9.0 + 55.0 / 60.0,
+            47.0 + 20.0 / 60.0,
+            473.0,
+        );
+        let to = transformation.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.vertical - 472.6875).abs() < 1e-4);
+        assert_eq!((to.lon, to.lat), (from.lon, from.lat));
+
+        let back = transformation.reversed().apply(to);
+        assert!((back.vertical - from.vertical).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,283 @@
+//This file is licensed under EUPL v1.2
+
+use crate::{
+    ellipsoid::Ellipsoid, traits::GetterContstruct, DbContstruct, Projection, PseudoSerialize,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerticalPerspectiveParams {
+    /// latitude of topocentric origin
+    lat_orig: f64,
+    /// longitude of topocentric origin
+    lon_orig: f64,
+    /// ellipsoidal height of topocentric origin
+    height_orig: f64,
+    /// viewpoint height
+    view_height: f64,
+}
+
+impl_param_eq_hash!(VerticalPerspectiveParams {
+    lat_orig,
+    lon_orig,
+    height_orig,
+    view_height
+});
+
+impl VerticalPerspectiveParams {
+    pub const fn new(lat_orig: f64, lon_orig: f64, height_orig: f64, view_height: f64) -> Self {
+        Self {
+            lat_orig,
+            lon_orig,
+            height_orig,
+            view_height,
+        }
+    }
+
+    /// Get latitude of topocentric origin, radians.
+    pub fn lat_orig(&self) -> f64 {
+        self.lat_orig
+    }
+
+    /// Get longitude of topocentric origin, radians.
+    pub fn lon_orig(&self) -> f64 {
+        self.lon_orig
+    }
+
+    /// Get ellipsoidal height of topocentric origin, metres.
+    pub fn height_orig(&self) -> f64 {
+        self.height_orig
+    }
+
+    /// Get viewpoint height above the topocentric origin, metres.
+    pub fn view_height(&self) -> f64 {
+        self.view_height
+    }
+}
+
+/// Vertical Perspective coordinate operation (EPSG:9838).
+///
+/// Positions are projected as seen from a viewpoint vertically above the topocentric origin,
+/// and are assumed to lie on the ellipsoid. Positions on the far side of the horizon are
+/// projected nonetheless, while projected positions off the visible disc have no inverse and
+/// result in `NaN`.
+#[derive(Copy, Clone, Debug)]
+pub struct VerticalPerspectiveProjection {
+    pub ellipsoid_a: f64,
+    pub ellipsoid_b: f64,
+    pub ellipsoid_e_squared: f64,
+    pub lat_orig: f64,
+    pub lon_orig: f64,
+    pub height_orig: f64,
+    pub view_height: f64,
+
+    pub ny_orig: f64,
+}
+
+impl_param_eq_hash!(VerticalPerspectiveProjection {
+    ellipsoid_a,
+    ellipsoid_b,
+    ellipsoid_e_squared,
+    lat_orig,
+    lon_orig,
+    height_orig,
+    view_height,
+    ny_orig,
+});
+
+impl VerticalPerspectiveProjection {
+    pub fn new(ell: &Ellipsoid, params: &VerticalPerspectiveParams) -> Self {
+        Self {
+            ellipsoid_a: ell.a(),
+            ellipsoid_b: ell.b(),
+            ellipsoid_e_squared: ell.e_squared(),
+            lat_orig: params.lat_orig(),
+            lon_orig: params.lon_orig(),
+            height_orig: params.height_orig(),
+            view_height: params.view_height(),
+
+            ny_orig: ell.ny(params.lat_orig()),
+        }
+    }
+}
+
+impl Projection for VerticalPerspectiveProjection {
+    /// as per IOGP Publication 373-7-2 – Geomatics Guidance Note number 7, part 2 – March 2020
+    /// longitude & latitude in radians
+    fn rad_to_projected(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let ny =
+            self.ellipsoid_a / (1.0 - self.ellipsoid_e_squared * latitude.sin().powi(2)).sqrt();
+        let d_lon = longitude - self.lon_orig;
+        let e_sq_term =
+            self.ellipsoid_e_squared * (self.ny_orig * self.lat_orig.sin() - ny * latitude.sin());
+        let u = ny * latitude.cos() * d_lon.sin();
+        let v = ny
+            * (latitude.sin() * self.lat_orig.cos()
+                - latitude.cos() * self.lat_orig.sin() * d_lon.cos())
+            + e_sq_term * self.lat_orig.cos();
+        let w = ny
+            * (latitude.sin() * self.lat_orig.sin()
+                + latitude.cos() * self.lat_orig.cos() * d_lon.cos())
+            + e_sq_term * self.lat_orig.sin()
+            - (self.ny_orig + self.height_orig);
+        let scale = self.view_height / (self.view_height - w);
+        (u * scale, v * scale)
+    }
+
+    /// Intersects the line of sight through the projected position with the ellipsoid.
+    /// longitude & latitude in radians
+    fn projected_to_rad(&self, easting: f64, northing: f64) -> (f64, f64) {
+        let (sin_lat, cos_lat) = self.lat_orig.sin_cos();
+        let (sin_lon, cos_lon) = self.lon_orig.sin_cos();
+        // topocentric axes in geocentric coordinates
+        let u_axis = [-sin_lon, cos_lon, 0.0];
+        let v_axis = [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat];
+        let w_axis = [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat];
+        let r = self.ny_orig + self.height_orig;
+        let r_z = (1.0 - self.ellipsoid_e_squared) * self.ny_orig + self.height_orig;
+        let view = [
+            r * cos_lat * cos_lon + self.view_height * w_axis[0],
+            r * cos_lat * sin_lon + self.view_height * w_axis[1],
+            r_z * sin_lat + self.view_height * w_axis[2],
+        ];
+        let dir: [f64; 3] = std::array::from_fn(|i| {
+            easting * u_axis[i] + northing * v_axis[i] - self.view_height * w_axis[i]
+        });
+        // scale z so the ellipsoid becomes a sphere of radius a
+        let z_scale = self.ellipsoid_a / self.ellipsoid_b;
+        let view_s = [view[0], view[1], view[2] * z_scale];
+        let dir_s = [dir[0], dir[1], dir[2] * z_scale];
+        let qa = dir_s.iter().map(|d| d * d).sum::<f64>();
+        let qb = 2.0 * view_s.iter().zip(dir_s).map(|(p, d)| p * d).sum::<f64>();
+        let qc = view_s.iter().map(|p| p * p).sum::<f64>() - self.ellipsoid_a.powi(2);
+        // the nearer intersection is the visible one
+        let t = (-qb - (qb.powi(2) - 4.0 * qa * qc).sqrt()) / (2.0 * qa);
+        let (longitude, latitude, _) = Ellipsoid::from_a_b(self.ellipsoid_a, self.ellipsoid_b)
+            .geocentric_to_rad(
+                view[0] + t * dir[0],
+                view[1] + t * dir[1],
+                view[2] + t * dir[2],
+            );
+        (longitude, latitude)
+    }
+}
+
+impl PseudoSerialize for VerticalPerspectiveProjection {
+    fn to_constructed(&self) -> String {
+        format!(
+            r"VerticalPerspectiveProjection{{
+    ellipsoid_a: {}f64,
+    ellipsoid_b: {}f64,
+    ellipsoid_e_squared: {}f64,
+    lat_orig: {}f64,
+    lon_orig: {}f64,
+    height_orig: {}f64,
+    view_height: {}f64,
+    ny_orig: {}f64,
+}}",
+            self.ellipsoid_a,
+            self.ellipsoid_b,
+            self.ellipsoid_e_squared,
+            self.lat_orig,
+            self.lon_orig,
+            self.height_orig,
+            self.view_height,
+            self.ny_orig,
+        )
+    }
+}
+
+impl DbContstruct for VerticalPerspectiveProjection {
+    fn from_database_params(params: &[(u32, f64)], ellipsoid: &Ellipsoid) -> Self {
+        let params = VerticalPerspectiveParams::new(
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8834 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8835 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8836 { Some(*v) } else { None })
+                .unwrap(),
+            params
+                .iter()
+                .find_map(|(c, v)| if *c == 8840 { Some(*v) } else { None })
+                .unwrap(),
+        );
+        Self::new(ellipsoid, &params)
+    }
+}
+
+impl GetterContstruct for VerticalPerspectiveProjection {
+    fn with_db_getter<G>(mut getter: G, ellipsoid: &Ellipsoid) -> Option<Self>
+    where
+        G: FnMut(u32) -> Option<f64>,
+    {
+        let params = VerticalPerspectiveParams::new(
+            getter(8834)?,
+            getter(8835)?,
+            getter(8836)?,
+            getter(8840)?,
+        );
+        Some(Self::new(ellipsoid, &params))
+    }
+}
+
+pub fn direct_projection(params: &[(u32, f64)], ell: Ellipsoid) -> String {
+    VerticalPerspectiveProjection::from_database_params(params, &ell).to_constructed()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ellipsoid::Ellipsoid;
+    use crate::traits::*;
+    use crate::vertical_perspective::*;
+
+    #[test]
+    fn vertical_perspective_consistency() {
+        // EPSG vertical perspective example
+        let ell = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params =
+            VerticalPerspectiveParams::new(55f64.to_radians(), 5f64.to_radians(), 200.0, 5900000.0);
+
+        let projection = VerticalPerspectiveProjection::new(&ell, &params);
+        // the example point lies 73 m above the ellipsoid, which gives -188878.767, -128550.090;
+        // these are the coordinates of the same position on the ellipsoid
+        let easting_goal = -188874.277;
+        let northing_goal = -128547.030;
+        let lon = 2.0 + 7.0 / 60.0 + 46.38 / 3600.0;
+        let lat = 53.0 + 48.0 / 60.0 + 33.82 / 3600.0;
+        let (easting, northing) = projection.deg_to_projected(lon, lat);
+
+        eprintln!("easting: {easting_goal} - {easting}");
+        eprintln!("northing: {northing_goal} - {northing}");
+
+        assert!((easting - easting_goal).abs() < 0.01);
+        assert!((northing - northing_goal).abs() < 0.01);
+
+        let (lon_r, lat_r) = projection.projected_to_deg(easting_goal, northing_goal);
+        eprintln!("lon: {lon} - {lon_r}");
+        eprintln!("lat: {lat} - {lat_r}");
+
+        assert!((lon - lon_r).abs() < 1e-7);
+        assert!((lat - lat_r).abs() < 1e-7);
+
+        for (lon, lat) in [(5.0, 55.0), (-20.0, 40.0), (30.0, 70.0), (5.0, 90.0)] {
+            let (easting, northing) = projection.deg_to_projected(lon, lat);
+            let (lon_r, lat_r) = projection.projected_to_deg(easting, northing);
+            eprintln!("{lon}, {lat}: {lon_r}, {lat_r}");
+            assert!((lat - lat_r).abs() < 1e-9);
+            if lat < 90.0 {
+                assert!((lon - lon_r).abs() < 1e-9);
+            }
+        }
+
+        // beyond the horizon
+        let (lon, lat) = projection.projected_to_deg(0.0, 1e7);
+        assert!(lon.is_nan() && lat.is_nan());
+    }
+}
@@ -0,0 +1,244 @@
+//This file is licensed under EUPL v1.2
+
+use std::sync::Arc;
+
+use crate::{
+    coordinate::{
+        Geographic2DCoordinateUserVertical, Geographic3DCoordinate, ProjectedCoordinateUserVertical,
+    },
+    grids::gtx::GtxGrid,
+    CoordOperation, Projection,
+};
+
+/// Separation between the reference surfaces of two vertical datums, as used by the steps of a
+/// `VerticalPipeline`.
+///
+/// The separation is the height of the surface of the target datum above the surface of the
+/// source datum, like the geoid height above the ellipsoid or mean sea level above lowest
+/// astronomical tide, so heights in the target datum are those in the source datum minus the
+/// separation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerticalSeparation {
+    /// constant separation, metres
+    Constant(f64),
+    /// separation interpolated from a grid, like a geoid model or a tidal datum model, metres
+    Grid(Arc<GtxGrid>),
+}
+
+impl VerticalSeparation {
+    /// Get the separation at a position in radians, `None` if it is not covered by the grid.
+    pub fn value(&self, lon: f64, lat: f64) -> Option<f64> {
+        match self {
+            Self::Constant(separation) => Some(*separation),
+            Self::Grid(grid) => grid.value(lon, lat),
+        }
+    }
+}
+
+impl From<f64> for VerticalSeparation {
+    fn from(separation: f64) -> Self {
+        Self::Constant(separation)
+    }
+}
+
+impl From<Arc<GtxGrid>> for VerticalSeparation {
+    fn from(grid: Arc<GtxGrid>) -> Self {
+        Self::Grid(grid)
+    }
+}
+
+/// Chain of conversions between vertical datums at a fixed horizontal position, like from
+/// ellipsoidal heights to gravity-related heights with a geoid model, and on to a tidal datum like
+/// lowest astronomical tide with a separation grid or a constant offset.
+///
+/// Each step subtracts its `VerticalSeparation` from the vertical coordinate, or adds it if the
+/// step is applied in reverse. The horizontal position is passed through unchanged, projected
+/// coordinates are converted to longitude & latitude with the projection of their CRS to look up
+/// the separations. Depths are negative heights, so convert them before and after the pipeline.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VerticalPipeline {
+    /// separations, and whether they are added instead of subtracted
+    steps: Vec<(VerticalSeparation, bool)>,
+}
+
+impl VerticalPipeline {
+    /// Construct an empty pipeline, which returns coordinates unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a step from the current datum to a datum whose surface is `separation` above it.
+    pub fn push(&mut self, separation: impl Into<VerticalSeparation>) -> &mut Self {
+        self.steps.push((separation.into(), false));
+        self
+    }
+
+    /// Append a step from the current datum to a datum whose surface is `separation` below it,
+    /// the inverse of `push`.
+    pub fn push_inverse(&mut self, separation: impl Into<VerticalSeparation>) -> &mut Self {
+        self.steps.push((separation.into(), true));
+        self
+    }
+
+    /// Get the separations of the steps, and whether they are applied in reverse.
+    pub fn steps(&self) -> &[(VerticalSeparation, bool)] {
+        &self.steps
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Get the pipeline in the reverse direction, applying the inverse steps in reverse order.
+    pub fn inverse(&self) -> Self {
+        let steps = self
+            .steps
+            .iter()
+            .rev()
+            .map(|(s, reverse)| (s.clone(), !reverse))
+            .collect();
+        Self { steps }
+    }
+
+    /// Convert a vertical coordinate at a position in radians, returning `None` if the position is
+    /// not covered by one of the grids.
+    pub fn try_apply(&self, lon: f64, lat: f64, vertical: f64) -> Option<f64> {
+        self.steps
+            .iter()
+            .try_fold(vertical, |vertical, (separation, reverse)| {
+                let separation = separation.value(lon, lat)?;
+                Some(if *reverse {
+                    vertical + separation
+                } else {
+                    vertical - separation
+                })
+            })
+    }
+
+    /// Convert the vertical coordinate of a position in the projected CRS of `projection`,
+    /// returning `None` if the position is not covered by one of the grids.
+    pub fn try_apply_projected(
+        &self,
+        projection: &dyn Projection,
+        from: ProjectedCoordinateUserVertical,
+    ) -> Option<ProjectedCoordinateUserVertical> {
+        let (lon, lat) = projection.projected_to_rad(from.x, from.y);
+        let vertical = self.try_apply(lon, lat, from.vertical)?;
+        Some(ProjectedCoordinateUserVertical { vertical, ..from })
+    }
+}
+
+impl CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>
+    for VerticalPipeline
+{
+    /// longitude & latitude in radians, the vertical coordinate is NaN if the position is not
+    /// covered by one of the grids
+    fn apply(
+        &self,
+        from: Geographic2DCoordinateUserVertical,
+    ) -> Geographic2DCoordinateUserVertical {
+        let vertical = self
+            .try_apply(from.lon, from.lat, from.vertical)
+            .unwrap_or(f64::NAN);
+        Geographic2DCoordinateUserVertical { vertical, ..from }
+    }
+}
+
+impl CoordOperation<Geographic3DCoordinate, Geographic2DCoordinateUserVertical>
+    for VerticalPipeline
+{
+    /// longitude & latitude in radians, from ellipsoidal heights. The vertical coordinate is NaN
+    /// if the position is not covered by one of the grids.
+    fn apply(&self, from: Geographic3DCoordinate) -> Geographic2DCoordinateUserVertical {
+        self.apply(Geographic2DCoordinateUserVertical::new(
+            from.lon,
+            from.lat,
+            from.height,
+        ))
+    }
+}
+
+impl CoordOperation<Geographic2DCoordinateUserVertical, Geographic3DCoordinate>
+    for VerticalPipeline
+{
+    /// longitude & latitude in radians, to ellipsoidal heights. The height is NaN if the position
+    /// is not covered by one of the grids.
+    fn apply(&self, from: Geographic2DCoordinateUserVertical) -> Geographic3DCoordinate {
+        let height = self
+            .try_apply(from.lon, from.lat, from.vertical)
+            .unwrap_or(f64::NAN);
+        Geographic3DCoordinate::new(from.lon, from.lat, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use crate::coordinate::{
+        Geographic2DCoordinateUserVertical, Geographic3DCoordinate, ProjectedCoordinateUserVertical,
+    };
+    use crate::grids::gtx::GtxGrid;
+    use crate::popvis_pseudo_mercator::*;
+    use crate::traits::*;
+    use crate::vertical_pipeline::*;
+    use crate::Ellipsoid;
+
+    /// Grid from 53°N, 9°E in 0.25° steps with the separation increasing by 1 m per degree east.
+    fn grid(base: f64) -> Arc<GtxGrid> {
+        let mut bytes = Vec::new();
+        for v in [53.0f64, 9.0, 0.25, 0.25] {
+            bytes.extend(v.to_be_bytes());
+        }
+        for v in [4i32, 5] {
+            bytes.extend(v.to_be_bytes());
+        }
+        for _ in 0..4 {
+            for column in 0..5 {
+                bytes.extend(((base + 0.25 * f64::from(column)) as f32).to_be_bytes());
+            }
+        }
+        Arc::new(GtxGrid::from_bytes(&bytes).unwrap())
+    }
+
+    #[test]
+    fn vertical_pipeline() {
+        // ellipsoidal height to a geoid-based height, to mean sea level and to lowest astronomical
+        // tide, 1.8 m to 2.8 m below mean sea level
+        let mut pipeline = VerticalPipeline::new();
+        pipeline.push(grid(39.0)).push(0.2).push_inverse(grid(1.8));
+        assert_eq!(pipeline.len(), 3);
+
+        let from = Geographic3DCoordinate::from_deg(9.5, 53.5, 100.0);
+        let to: Geographic2DCoordinateUserVertical = pipeline.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.vertical - (100.0 - 39.5 - 0.2 + 2.3)).abs() < 1e-6);
+        assert_eq!((to.lon, to.lat), (from.lon, from.lat));
+
+        let back: Geographic3DCoordinate = pipeline.inverse().apply(to);
+        assert!((back.height - from.height).abs() < 1e-9);
+
+        // outside of the grids
+        let outside = Geographic3DCoordinate::from_deg(8.5, 53.5, 100.0);
+        let to: Geographic2DCoordinateUserVertical = pipeline.apply(outside);
+        assert!(to.vertical.is_nan());
+        assert!(VerticalPipeline::new().try_apply(0.0, 0.0, 1.0) == Some(1.0));
+
+        // positions in a projected CRS
+        let wgs84 = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let params = PopVisPseudoMercatorParams::new(0.0, 0.0, 0.0, 0.0);
+        let projection = PopVisPseudoMercatorProjection::new(&wgs84, &params);
+        let (x, y) = projection.deg_to_projected(9.5, 53.5);
+        let projected = ProjectedCoordinateUserVertical::new(x, y, 100.0);
+        let to = pipeline
+            .try_apply_projected(&projection, projected)
+            .unwrap();
+        assert!((to.vertical - (100.0 - 39.5 - 0.2 + 2.3)).abs() < 1e-6);
+        assert_eq!((to.x, to.y), (x, y));
+    }
+}
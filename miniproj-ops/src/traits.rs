@@ -2,6 +2,19 @@
 
 use crate::ellipsoid::Ellipsoid;
 
+/// Direction of a two-dimensional coordinate operation, see `Projection::transform`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Projected coordinates to lon/lat in radians, like `Projection::projected_to_rad`
+    ProjectedToRad,
+    /// Lon/lat in radians to projected coordinates, like `Projection::rad_to_projected`
+    RadToProjected,
+    /// Projected coordinates to lon/lat in degrees, like `Projection::projected_to_deg`
+    ProjectedToDeg,
+    /// Lon/lat in degrees to projected coordinates, like `Projection::deg_to_projected`
+    DegToProjected,
+}
+
 /// Two-dimensional coordinate operation
 pub trait Projection: Send + Sync {
     ///Converts from a coordinate in the target coordinate system to lon/lat in EPSG 4326 in radians
@@ -20,6 +33,143 @@ pub trait Projection: Send + Sync {
     fn deg_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
         self.rad_to_projected(lon.to_radians(), lat.to_radians())
     }
+
+    ///Partial derivatives of the projected coordinates with respect to longitude and latitude in radians,
+    ///as `[[dx/dlon, dx/dlat], [dy/dlon, dy/dlat]]`. Projections without an analytic implementation
+    ///use central differences, see `numerical_jacobian`.
+    fn jacobian(&self, lon: f64, lat: f64) -> [[f64; 2]; 2] {
+        numerical_jacobian(self, lon, lat)
+    }
+
+    ///Converts a coordinate in the given `direction`, dispatching to one of the four methods above.
+    fn transform(&self, direction: Direction, x: f64, y: f64) -> (f64, f64) {
+        match direction {
+            Direction::ProjectedToRad => self.projected_to_rad(x, y),
+            Direction::RadToProjected => self.rad_to_projected(x, y),
+            Direction::ProjectedToDeg => self.projected_to_deg(x, y),
+            Direction::DegToProjected => self.deg_to_projected(x, y),
+        }
+    }
+}
+
+impl<P: Projection + ?Sized> Projection for Box<P> {
+    fn projected_to_rad(&self, x: f64, y: f64) -> (f64, f64) {
+        (**self).projected_to_rad(x, y)
+    }
+
+    fn rad_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
+        (**self).rad_to_projected(lon, lat)
+    }
+
+    fn projected_to_deg(&self, x: f64, y: f64) -> (f64, f64) {
+        (**self).projected_to_deg(x, y)
+    }
+
+    fn deg_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
+        (**self).deg_to_projected(lon, lat)
+    }
+
+    fn jacobian(&self, lon: f64, lat: f64) -> [[f64; 2]; 2] {
+        (**self).jacobian(lon, lat)
+    }
+
+    fn transform(&self, direction: Direction, x: f64, y: f64) -> (f64, f64) {
+        (**self).transform(direction, x, y)
+    }
+}
+
+impl<P: Projection + ?Sized> Projection for &P {
+    fn projected_to_rad(&self, x: f64, y: f64) -> (f64, f64) {
+        (**self).projected_to_rad(x, y)
+    }
+
+    fn rad_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
+        (**self).rad_to_projected(lon, lat)
+    }
+
+    fn projected_to_deg(&self, x: f64, y: f64) -> (f64, f64) {
+        (**self).projected_to_deg(x, y)
+    }
+
+    fn deg_to_projected(&self, lon: f64, lat: f64) -> (f64, f64) {
+        (**self).deg_to_projected(lon, lat)
+    }
+
+    fn jacobian(&self, lon: f64, lat: f64) -> [[f64; 2]; 2] {
+        (**self).jacobian(lon, lat)
+    }
+
+    fn transform(&self, direction: Direction, x: f64, y: f64) -> (f64, f64) {
+        (**self).transform(direction, x, y)
+    }
+}
+
+/// Jacobian of `projection` at a position in radians by central differences, in the layout of `Projection::jacobian`.
+pub fn numerical_jacobian<P: Projection + ?Sized>(
+    projection: &P,
+    lon: f64,
+    lat: f64,
+) -> [[f64; 2]; 2] {
+    const H: f64 = 1e-6;
+    let (x_e, y_e) = projection.rad_to_projected(lon + H, lat);
+    let (x_w, y_w) = projection.rad_to_projected(lon - H, lat);
+    let (x_n, y_n) = projection.rad_to_projected(lon, lat + H);
+    let (x_s, y_s) = projection.rad_to_projected(lon, lat - H);
+    [
+        [(x_e - x_w) / (2.0 * H), (x_n - x_s) / (2.0 * H)],
+        [(y_e - y_w) / (2.0 * H), (y_n - y_s) / (2.0 * H)],
+    ]
+}
+
+/// Asserts that the analytic Jacobian of `projection` at a position in degrees matches the numerical one.
+#[cfg(test)]
+pub(crate) fn assert_jacobian<P: Projection>(projection: &P, lon: f64, lat: f64) {
+    let analytic = projection.jacobian(lon.to_radians(), lat.to_radians());
+    let numerical = numerical_jacobian(projection, lon.to_radians(), lat.to_radians());
+    eprintln!("{lon}, {lat}: {analytic:?} - {numerical:?}");
+    let norm = numerical.iter().flatten().fold(0f64, |m, v| m.max(v.abs()));
+    for (a, n) in analytic.iter().flatten().zip(numerical.iter().flatten()) {
+        assert!((a - n).abs() < 1e-6 * norm);
+    }
+}
+
+/// Coordinate operation from coordinates of type `F` to coordinates of type `T`, like a datum
+/// transformation between two geographic coordinate reference systems.
+pub trait CoordOperation<F, T>: Send + Sync {
+    ///Applies the operation to a coordinate.
+    fn apply(&self, from: F) -> T;
+}
+
+impl<F, T, O: CoordOperation<F, T> + ?Sized> CoordOperation<F, T> for Box<O> {
+    fn apply(&self, from: F) -> T {
+        (**self).apply(from)
+    }
+}
+
+impl<F, T, O: CoordOperation<F, T> + ?Sized> CoordOperation<F, T> for &O {
+    fn apply(&self, from: F) -> T {
+        (**self).apply(from)
+    }
+}
+
+/// Coordinate operation that can also be applied in reverse, from coordinates of type `T` back to
+/// coordinates of type `F`. `Inverse` wraps such an operation into the operation in the reverse
+/// direction.
+pub trait InvertibleCoordOperation<F, T>: CoordOperation<F, T> {
+    ///Applies the operation in reverse to a coordinate.
+    fn apply_inverse(&self, to: T) -> F;
+}
+
+impl<F, T, O: InvertibleCoordOperation<F, T> + ?Sized> InvertibleCoordOperation<F, T> for Box<O> {
+    fn apply_inverse(&self, to: T) -> F {
+        (**self).apply_inverse(to)
+    }
+}
+
+impl<F, T, O: InvertibleCoordOperation<F, T> + ?Sized> InvertibleCoordOperation<F, T> for &O {
+    fn apply_inverse(&self, to: T) -> F {
+        (**self).apply_inverse(to)
+    }
 }
 
 pub trait PseudoSerialize {
@@ -15,7 +15,71 @@ fn main() {
         gen_parameter_constructors(&memdb, IMPL_CONV, &ellipsoids).unwrap(),
     )
     .unwrap();
-    let mut ellipsoid_out = output_dir;
+    let mut transformation_out = output_dir.clone();
+    transformation_out.push("transformation_constructors.rs");
+    std::fs::write(
+        transformation_out,
+        gen_transformation_constructors(&memdb, IMPL_TRANS, &ellipsoids).unwrap(),
+    )
+    .unwrap();
+    let mut vertical_transformation_out = output_dir.clone();
+    vertical_transformation_out.push("vertical_transformation_constructors.rs");
+    std::fs::write(
+        vertical_transformation_out,
+        gen_vertical_transformation_constructors(&memdb, IMPL_VERT_TRANS, &ellipsoids).unwrap(),
+    )
+    .unwrap();
+    let mut helmert_out = output_dir.clone();
+    helmert_out.push("helmert_constructors.rs");
+    std::fs::write(
+        helmert_out,
+        gen_helmert_constructors(
+            &memdb,
+            IMPL_GEOCENTRIC_TRANS,
+            IMPL_TIME_DEPENDENT_TRANS,
+            IMPL_MOLODENSKY_BADEKAS_TRANS,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let mut geocentric_conversion_out = output_dir.clone();
+    geocentric_conversion_out.push("geocentric_conversion_constructors.rs");
+    std::fs::write(
+        geocentric_conversion_out,
+        gen_geocentric_conversion_constructors(&memdb, IMPL_GEOCENTRIC_CONV, &ellipsoids).unwrap(),
+    )
+    .unwrap();
+    let mut ellipsoid_out = output_dir.clone();
     ellipsoid_out.push("ellipsoid_constructors.rs");
     std::fs::write(ellipsoid_out, gen_ellipsoid_constructors(&memdb).unwrap()).unwrap();
+    let mut unit_out = output_dir.clone();
+    unit_out.push("unit_constructors.rs");
+    std::fs::write(unit_out, gen_unit_constructors(&memdb).unwrap()).unwrap();
+    let mut prime_meridian_out = output_dir.clone();
+    prime_meridian_out.push("prime_meridian_constructors.rs");
+    std::fs::write(
+        prime_meridian_out,
+        gen_prime_meridians_source(&memdb).unwrap(),
+    )
+    .unwrap();
+    let mut version_out = output_dir.clone();
+    version_out.push("registry_version.rs");
+    std::fs::write(version_out, gen_registry_version(&memdb).unwrap()).unwrap();
+    let mut compound_crs_out = output_dir.clone();
+    compound_crs_out.push("compound_crs_constructors.rs");
+    std::fs::write(
+        compound_crs_out,
+        gen_compound_crs_constructors(&memdb).unwrap(),
+    )
+    .unwrap();
+    let mut name_out = output_dir.clone();
+    name_out.push("name_constructors.rs");
+    std::fs::write(name_out, gen_name_constructors(&memdb).unwrap()).unwrap();
+    let mut datum_ensemble_out = output_dir;
+    datum_ensemble_out.push("datum_ensemble_constructors.rs");
+    std::fs::write(
+        datum_ensemble_out,
+        gen_datum_ensemble_constructors(&memdb).unwrap(),
+    )
+    .unwrap();
 }
@@ -0,0 +1,141 @@
+//This file is licensed under EUPL v1.2
+
+/// Geographic area a Coordinate Reference System applies to, as a bounding box in degrees.
+///
+/// Areas crossing the antimeridian have their western bound east of their eastern one, e.g.
+/// `west: 167.65, east: -173.3` for New Zealand including the Chatham Islands.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AreaOfUse {
+    /// Name of the region, like `"Germany - onshore"`
+    pub name: &'static str,
+    pub west: f64,
+    pub south: f64,
+    pub east: f64,
+    pub north: f64,
+}
+
+impl AreaOfUse {
+    /// Construct an unnamed area from its bounds in degrees.
+    pub const fn new(west: f64, south: f64, east: f64, north: f64) -> Self {
+        Self {
+            name: "",
+            west,
+            south,
+            east,
+            north,
+        }
+    }
+
+    /// Construct an unnamed area from its bounds in `[east, north, west, south]` order, as returned
+    /// by the deprecated `get_reference_system_areas`.
+    pub const fn from_enws([east, north, west, south]: [f64; 4]) -> Self {
+        Self::new(west, south, east, north)
+    }
+
+    /// Get the bounds in `[east, north, west, south]` order.
+    pub const fn to_enws(&self) -> [f64; 4] {
+        [self.east, self.north, self.west, self.south]
+    }
+
+    /// Whether the area crosses the antimeridian.
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// Ranges of longitudes covered by the area, split at the antimeridian.
+    fn longitude_ranges(&self) -> [(f64, f64); 2] {
+        if self.crosses_antimeridian() {
+            [(self.west, 180.0), (-180.0, self.east)]
+        } else {
+            [(self.west, self.east); 2]
+        }
+    }
+
+    /// Whether the position in degrees lies within the area, including its bounds.
+    pub fn contains(&self, lon: f64, lat: f64) -> bool {
+        let lon = (lon + 180.0).rem_euclid(360.0) - 180.0;
+        (self.south..=self.north).contains(&lat)
+            && self
+                .longitude_ranges()
+                .iter()
+                .any(|(w, e)| (*w..=*e).contains(&lon))
+    }
+
+    /// Whether the area covers all of `other`, including touching bounds.
+    pub fn covers(&self, other: &AreaOfUse) -> bool {
+        // longitudes of areas crossing the antimeridian are continued beyond 180°
+        let span = |a: &AreaOfUse| {
+            (
+                a.west,
+                if a.crosses_antimeridian() {
+                    a.east + 360.0
+                } else {
+                    a.east
+                },
+            )
+        };
+        let ((west, east), (other_west, other_east)) = (span(self), span(other));
+        let lon = east - west >= 360.0
+            || [-360.0, 0.0, 360.0]
+                .iter()
+                .any(|s| west <= other_west + s && other_east + s <= east);
+        lon && self.south <= other.south && other.north <= self.north
+    }
+
+    /// Size of the area in square degrees, for comparing how local areas are.
+    pub fn extent(&self) -> f64 {
+        let (west, east) = self.longitude_ranges()[0];
+        let (other_west, other_east) = self.longitude_ranges()[1];
+        let width = if self.crosses_antimeridian() {
+            (east - west) + (other_east - other_west)
+        } else {
+            east - west
+        };
+        width * (self.north - self.south)
+    }
+
+    /// Whether the area intersects `other`, including touching bounds.
+    pub fn intersects(&self, other: &AreaOfUse) -> bool {
+        let overlap = self.longitude_ranges().iter().any(|(aw, ae)| {
+            other
+                .longitude_ranges()
+                .iter()
+                .any(|(bw, be)| aw <= be && bw <= ae)
+        });
+        overlap && self.south <= other.north && other.south <= self.north
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area_of_use() {
+        let germany = AreaOfUse::new(5.87, 47.27, 15.04, 55.09);
+        assert!(germany.contains(10.0, 54.0));
+        assert!(!germany.contains(10.0, 56.0) && !germany.contains(-10.0, 54.0));
+        assert_eq!(AreaOfUse::from_enws(germany.to_enws()), germany);
+
+        let chatham = AreaOfUse::new(167.65, -55.95, -173.3, -25.88);
+        assert!(chatham.crosses_antimeridian() && !germany.crosses_antimeridian());
+        assert!(chatham.contains(-176.5, -44.0) && chatham.contains(174.0, -41.0));
+        assert!(chatham.contains(183.5, -44.0));
+        assert!(!chatham.contains(0.0, -41.0));
+
+        let fiji = AreaOfUse::new(176.0, -21.0, -178.0, -12.0);
+        let samoa = AreaOfUse::new(-173.0, -15.0, -171.0, -13.0);
+        let kermadec = AreaOfUse::new(178.0, -32.0, -177.0, -28.0);
+        assert!(chatham.intersects(&kermadec) && kermadec.intersects(&chatham));
+        assert!(!chatham.intersects(&fiji));
+        assert!(!fiji.intersects(&samoa) && !germany.intersects(&chatham));
+        let world = AreaOfUse::new(-180.0, -90.0, 180.0, 90.0);
+        assert!(chatham.intersects(&world));
+
+        assert!(world.covers(&chatham) && world.covers(&germany) && chatham.covers(&kermadec));
+        assert!(!kermadec.covers(&chatham) && !chatham.covers(&fiji) && !germany.covers(&world));
+        assert!(chatham.covers(&AreaOfUse::new(-175.0, -45.0, -174.0, -44.0)));
+        assert_eq!(world.extent(), 64800.0);
+        assert!((kermadec.extent() - 20.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,90 @@
+//This file is licensed under EUPL v1.2
+
+use std::collections::BTreeMap;
+
+use crate::get_method_code;
+use crate::projection_constructor::all_codes;
+
+include!(concat!(env!("OUT_DIR"), "/registry_version.rs"));
+
+/// Summary of what this build of miniproj supports, as returned by [`capabilities`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Capabilities {
+    /// Version of the EPSG dataset the registry was generated from
+    pub registry_version: &'static str,
+    /// Version of the miniproj crate
+    pub crate_version: &'static str,
+    /// Number of implemented Coordinate Reference Systems, including geographic ones
+    pub crs_count: usize,
+    /// Implemented projection methods, ordered by method code
+    pub methods: Vec<MethodSupport>,
+    /// Enabled cargo features
+    pub features: Vec<&'static str>,
+}
+
+/// Support for a single projection method, see [`Capabilities`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MethodSupport {
+    /// EPSG code of the operation method
+    pub code: u32,
+    /// Number of projected Coordinate Reference Systems using the method
+    pub crs_count: usize,
+}
+
+/// Returns a listing of the supported projection methods, the number of Coordinate Reference
+/// Systems per method, the registry version and the enabled features.
+pub fn capabilities() -> Capabilities {
+    let mut crs_count = 0;
+    let mut methods = BTreeMap::new();
+    for code in all_codes() {
+        crs_count += 1;
+        if let Some(method) = get_method_code(code) {
+            *methods.entry(method).or_insert(0) += 1;
+        }
+    }
+    let mut features = Vec::new();
+    if cfg!(feature = "serde") {
+        features.push("serde");
+    }
+    if cfg!(feature = "spherical-harmonic-geoid") {
+        features.push("spherical-harmonic-geoid");
+    }
+    if cfg!(feature = "tracing") {
+        features.push("tracing");
+    }
+    Capabilities {
+        registry_version: REGISTRY_VERSION,
+        crate_version: env!("CARGO_PKG_VERSION"),
+        crs_count,
+        methods: methods
+            .into_iter()
+            .map(|(code, crs_count)| MethodSupport { code, crs_count })
+            .collect(),
+        features,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capabilities_listing() {
+        let caps = capabilities();
+        eprintln!("{caps:?}");
+        assert!(!caps.registry_version.is_empty());
+        let tm = caps
+            .methods
+            .iter()
+            .find(|m| m.code == 9807)
+            .expect("TM not listed.");
+        assert!(tm.crs_count > 1000);
+        assert!(caps.methods.iter().map(|m| m.crs_count).sum::<usize>() < caps.crs_count);
+        assert_eq!(
+            caps.features.contains(&"spherical-harmonic-geoid"),
+            cfg!(feature = "spherical-harmonic-geoid")
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//This file is licensed under EUPL v1.2
+
+use miniproj_ops::coordinate::Geographic2DCoordinateUserVertical;
+use miniproj_ops::{CoordOperation, Projection};
+
+use crate::{get_projection, get_vertical_transformation};
+
+/// Vertical coordinate reference system, like a gravity-related height or a depth.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VerticalCrs {
+    pub name: &'static str,
+    /// EPSG code of the vertical datum
+    pub datum: u32,
+    /// EPSG code of the unit of the axis, see `get_unit`
+    pub unit: u32,
+    /// whether the axis points down, like for depths
+    pub depth: bool,
+}
+
+include!(concat!(env!("OUT_DIR"), "/compound_crs_constructors.rs"));
+
+/// Compound coordinate reference system, combining a horizontal and a vertical CRS, like
+/// Amersfoort / RD New + NAP height (EPSG:7415).
+#[derive(Copy, Clone)]
+pub struct CompoundCrs {
+    /// EPSG code of the horizontal CRS
+    pub horizontal: u32,
+    /// Projection of the horizontal CRS
+    pub projection: &'static dyn Projection,
+    /// EPSG code of the vertical CRS
+    pub vertical: u32,
+    pub vertical_crs: &'static VerticalCrs,
+}
+
+impl CompoundCrs {
+    /// Get the transformation of the vertical coordinate from the vertical CRS of this CRS to that
+    /// of `to`, see [`get_vertical_transformation`]. Returns `None` if there is no route between
+    /// the vertical CRSs.
+    pub fn vertical_transformation(
+        &self,
+        to: &CompoundCrs,
+    ) -> Option<
+        Box<
+            dyn CoordOperation<
+                Geographic2DCoordinateUserVertical,
+                Geographic2DCoordinateUserVertical,
+            >,
+        >,
+    > {
+        get_vertical_transformation(self.vertical, to.vertical)
+    }
+}
+
+impl std::fmt::Debug for CompoundCrs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompoundCrs")
+            .field("horizontal", &self.horizontal)
+            .field("vertical", &self.vertical)
+            .field("vertical_crs", &self.vertical_crs)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the vertical Coordinate Reference System corresponding to the EPSG code passed as the
+/// argument. If the code does not refer to a vertical CRS with a single axis, the method returns
+/// `None`.
+pub fn get_vertical_crs(code: u32) -> Option<&'static VerticalCrs> {
+    VERTICAL_CRS.get(&code)
+}
+
+/// Get the height CRS in metres with the lowest code on the vertical datum `datum`.
+pub(crate) fn get_height_crs(datum: u32) -> Option<u32> {
+    VERTICAL_CRS
+        .entries()
+        .filter(|(_, crs)| crs.datum == datum && crs.unit == 9001 && !crs.depth)
+        .map(|(code, _)| *code)
+        .min()
+}
+
+/// Returns the compound Coordinate Reference System corresponding to the EPSG code passed as the
+/// argument, with the projection of its horizontal CRS and its vertical CRS.
+/// If the code does not refer to a compound CRS of a horizontal and a vertical CRS, or the
+/// projection of the horizontal CRS is not implemented, the method returns `None`.
+pub fn get_compound(code: u32) -> Option<CompoundCrs> {
+    let &(horizontal, vertical) = COMPOUND_CRS.get(&code)?;
+    Some(CompoundCrs {
+        horizontal,
+        projection: get_projection(horizontal)?,
+        vertical,
+        vertical_crs: get_vertical_crs(vertical)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compound_crs() {
+        // Amersfoort / RD New + NAP height
+        let compound = get_compound(7415).expect("Compound CRS missing from registry.");
+        eprintln!("{compound:?}");
+        assert_eq!((compound.horizontal, compound.vertical), (28992, 5709));
+        assert_eq!(compound.vertical_crs.name, "NAP height");
+        assert_eq!(compound.vertical_crs.unit, 9001);
+        assert!(!compound.vertical_crs.depth);
+        let (x, y) = compound
+            .projection
+            .deg_to_projected(5.38763889, 52.15616056);
+        assert!((x - 155000.0).abs() < 1.0 && (y - 463000.0).abs() < 1.0);
+
+        // WGS 84 + EGM2008 height
+        let compound = get_compound(9518).unwrap();
+        assert_eq!((compound.horizontal, compound.vertical), (4326, 3855));
+        let identity = compound.vertical_transformation(&compound).unwrap();
+        let height = Geographic2DCoordinateUserVertical::from_deg(10.0, 54.0, 12.5);
+        assert_eq!(identity.apply(height), height);
+        // MSL depth is derived from MSL height
+        let depth = get_vertical_crs(5715).unwrap();
+        assert_eq!(
+            (depth.datum, depth.depth),
+            (get_vertical_crs(5714).unwrap().datum, true)
+        );
+        assert!(get_compound(4326).is_none());
+        assert_eq!(get_height_crs(depth.datum), Some(5714));
+        assert!(get_vertical_crs(4326).is_none());
+    }
+}
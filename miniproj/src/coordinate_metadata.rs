@@ -0,0 +1,140 @@
+//This file is licensed under EUPL v1.2
+
+use miniproj_ops::coordinate::GeocentricCoordinate;
+use miniproj_ops::epoch::Epoch;
+use miniproj_ops::{CoordOperation, Projection};
+
+use crate::{get_projection, get_transformation_at};
+
+/// Coordinate reference system of a set of coordinates together with their coordinate epoch,
+/// following the ISO 19111 `CoordinateMetadata`. The epoch is only required for coordinates in a
+/// dynamic reference frame, where positions of points on the earth's surface change over time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CoordinateMetadata {
+    crs_code: u32,
+    epoch: Option<Epoch>,
+}
+
+impl CoordinateMetadata {
+    /// Metadata for coordinates in the CRS with the EPSG code `crs_code`, without an epoch.
+    pub fn new(crs_code: u32) -> Self {
+        Self {
+            crs_code,
+            epoch: None,
+        }
+    }
+
+    /// Metadata for coordinates in the CRS with the EPSG code `crs_code` at `epoch`.
+    pub fn with_epoch(crs_code: u32, epoch: Epoch) -> Self {
+        Self {
+            crs_code,
+            epoch: Some(epoch),
+        }
+    }
+
+    /// Get the EPSG code of the coordinate reference system.
+    pub fn crs_code(&self) -> u32 {
+        self.crs_code
+    }
+
+    /// Get the coordinate epoch, if any.
+    pub fn epoch(&self) -> Option<Epoch> {
+        self.epoch
+    }
+
+    /// Get the projection of the coordinate reference system, if it is implemented.
+    pub fn projection(&self) -> Option<&'static dyn Projection> {
+        get_projection(self.crs_code)
+    }
+
+    /// Get the transformation of geocentric coordinates described by `self` to the CRS of `target`,
+    /// evaluated at the coordinate epoch as in [`get_transformation_at`]. The epoch of a static
+    /// target CRS may be left out. Returns `None` if neither has an epoch, if the epochs differ, or
+    /// if there is no route between the CRSs.
+    pub fn transformation_to(
+        &self,
+        target: &CoordinateMetadata,
+    ) -> Option<Box<dyn CoordOperation<GeocentricCoordinate, GeocentricCoordinate>>> {
+        let epoch = self.epoch.or(target.epoch)?;
+        if target
+            .epoch
+            .is_some_and(|target_epoch| target_epoch != epoch)
+        {
+            return None;
+        }
+        get_transformation_at(self.crs_code, target.crs_code, epoch)
+    }
+}
+
+/// Coordinate together with the `CoordinateMetadata` describing its CRS and coordinate epoch,
+/// e.g. geocentric coordinates in ITRF2014 (EPSG:7789) at epoch 2015.3.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CoordinateWithMetadata<C> {
+    pub coordinate: C,
+    pub metadata: CoordinateMetadata,
+}
+
+impl<C> CoordinateWithMetadata<C> {
+    pub fn new(coordinate: C, metadata: CoordinateMetadata) -> Self {
+        Self {
+            coordinate,
+            metadata,
+        }
+    }
+}
+
+impl CoordinateWithMetadata<GeocentricCoordinate> {
+    /// Transform the coordinate to the CRS of `target`, see
+    /// [`CoordinateMetadata::transformation_to`]. The result carries the coordinate epoch along.
+    pub fn transform_to(&self, target: &CoordinateMetadata) -> Option<Self> {
+        let coordinate = self
+            .metadata
+            .transformation_to(target)?
+            .apply(self.coordinate);
+        let epoch = self.metadata.epoch.or(target.epoch)?;
+        Some(Self::new(
+            coordinate,
+            CoordinateMetadata::with_epoch(target.crs_code, epoch),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coordinate_metadata() {
+        let epoch = Epoch::from_decimal_year(2024.5).unwrap();
+        let metadata = CoordinateMetadata::with_epoch(32632, epoch);
+        assert_eq!(metadata.crs_code(), 32632);
+        assert_eq!(metadata.epoch(), Some(epoch));
+        assert!(metadata.projection().is_some());
+        assert_eq!(CoordinateMetadata::new(4326).epoch(), None);
+    }
+
+    #[test]
+    fn coordinate_with_metadata() {
+        // ITRF2014 at 2020.0 to GDA2020
+        let epoch = Epoch::from_decimal_year(2020.0).unwrap();
+        let from = CoordinateWithMetadata::new(
+            GeocentricCoordinate::new(-4052052.73, 4212835.99, -2545104.59),
+            CoordinateMetadata::with_epoch(7789, epoch),
+        );
+        let to = from.transform_to(&CoordinateMetadata::new(7842)).unwrap();
+        let goal = get_transformation_at(7789, 7842, epoch)
+            .unwrap()
+            .apply(from.coordinate);
+        eprintln!("{to:?}");
+        assert_eq!(to.coordinate, goal);
+        assert_eq!(to.metadata, CoordinateMetadata::with_epoch(7842, epoch));
+
+        let later = CoordinateMetadata::with_epoch(9988, Epoch::from_decimal_year(2021.0).unwrap());
+        assert!(from.transform_to(&later).is_none());
+        let metadata = CoordinateMetadata::new(7789);
+        let static_from = CoordinateWithMetadata::new(from.coordinate, metadata);
+        assert!(static_from
+            .transform_to(&CoordinateMetadata::new(7842))
+            .is_none());
+    }
+}
@@ -0,0 +1,289 @@
+//This file is licensed under EUPL v1.2
+
+use miniproj_ops::axis_order::{AuthorityAxisOrder, AxisDirection};
+use miniproj_ops::coordinate::Geographic3DCoordinate;
+use miniproj_ops::unit::{LinearUnitProjection, Unit};
+use miniproj_ops::{CoordOperation, Ellipsoid, Projection};
+
+use crate::projection_constructor::crs_name;
+use crate::wkt::{conversion_name, datum_name, ellipsoid_name, prime_meridian_name, write_wkt2};
+use crate::{
+    get_areas_of_use, get_axis_directions, get_base_crs_code, get_ellipsoid, get_ellipsoid_code,
+    get_method_code, get_prime_meridian, get_prime_meridian_code, get_projection_native_unit,
+    get_projection_params, get_transformation, get_unit, get_unit_code, try_get_projection,
+    AreaOfUse, CrsDefinition, MiniprojError,
+};
+
+/// EPSG code of WGS 84, the target of [`Crs::to_wgs84`].
+const WGS84: u32 = 4326;
+
+/// Kind of an implemented Coordinate Reference System.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CrsKind {
+    /// Longitude & latitude on the ellipsoid of the datum
+    Geographic2D,
+    /// Easting & northing of a map projection of a geographic CRS
+    Projected,
+}
+
+/// Coordinate Reference System of the registry, bundling its projection with the metadata that is
+/// otherwise looked up by code with `get_projection`, `get_ellipsoid_code` and friends.
+#[derive(Copy, Clone)]
+pub struct Crs {
+    code: u32,
+    projection: &'static dyn Projection,
+}
+
+impl std::fmt::Debug for Crs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Crs")
+            .field("code", &self.code)
+            .field("name", &self.name())
+            .field("kind", &self.kind())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Crs {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+impl Eq for Crs {}
+
+impl Crs {
+    /// Get the Coordinate Reference System corresponding to the EPSG code passed as the argument.
+    /// Returns `None` if its projection is not implemented.
+    pub fn from_epsg(code: u32) -> Option<Self> {
+        Self::try_from_epsg(code).ok()
+    }
+
+    /// Get the Coordinate Reference System corresponding to the EPSG code passed as the argument,
+    /// with the reason why its projection is not implemented otherwise.
+    pub fn try_from_epsg(code: u32) -> Result<Self, MiniprojError> {
+        let projection = try_get_projection(code)?;
+        Ok(Self { code, projection })
+    }
+
+    /// Get the EPSG code of the CRS.
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    pub fn name(&self) -> &'static str {
+        crs_name(self.code).unwrap_or("Unknown Coordinate Reference System")
+    }
+
+    pub fn kind(&self) -> CrsKind {
+        match get_method_code(self.code) {
+            Some(_) => CrsKind::Projected,
+            None => CrsKind::Geographic2D,
+        }
+    }
+
+    /// Get the projection between the coordinates of the CRS and longitude & latitude on its
+    /// geographic base CRS. Geographic CRSs have the identity as their projection.
+    pub fn projection(&self) -> &'static dyn Projection {
+        self.projection
+    }
+
+    /// Get the projection taking and returning coordinates in the axis order of the EPSG registry,
+    /// e.g. `(latitude, longitude)` for WGS 84, see [`AuthorityAxisOrder`].
+    pub fn authority_projection(&self) -> AuthorityAxisOrder<&'static dyn Projection> {
+        AuthorityAxisOrder::new(self.projection, self.axis_directions())
+    }
+
+    /// Get the directions of the first two axes in authority order. CRSs without axes in the
+    /// registry are assumed to have easting first.
+    pub fn axis_directions(&self) -> [AxisDirection; 2] {
+        get_axis_directions(self.code).unwrap_or([AxisDirection::East, AxisDirection::North])
+    }
+
+    /// Get the EPSG code of the geographic base CRS, the CRS itself if it is a geographic one.
+    pub fn base_code(&self) -> u32 {
+        get_base_crs_code(self.code).unwrap_or(self.code)
+    }
+
+    /// Get the EPSG code of the operation method of a projected CRS.
+    pub fn method_code(&self) -> Option<u32> {
+        get_method_code(self.code)
+    }
+
+    /// Get the EPSG code of the ellipsoid of the datum.
+    pub fn ellipsoid_code(&self) -> Option<u32> {
+        get_ellipsoid_code(self.code)
+    }
+
+    pub fn ellipsoid(&self) -> Option<&'static Ellipsoid> {
+        self.ellipsoid_code().and_then(get_ellipsoid)
+    }
+
+    /// Get the EPSG code of the prime meridian, e.g. 8903 for Paris. Longitudes of the projection
+    /// are relative to Greenwich regardless.
+    pub fn prime_meridian_code(&self) -> Option<u32> {
+        get_prime_meridian_code(self.code)
+    }
+
+    /// Get the EPSG code of the unit of the first axis, e.g. 9001 for metres.
+    pub fn unit_code(&self) -> Option<u32> {
+        get_unit_code(self.code)
+    }
+
+    /// Get the unit of the first axis. The projection always takes metres and returns degrees or
+    /// radians, whatever the unit of the axes, see `native_unit_projection`.
+    pub fn unit(&self) -> Option<&'static Unit> {
+        self.unit_code().and_then(get_unit)
+    }
+
+    /// Get the projection of a projected CRS taking and returning projected coordinates in the unit
+    /// of its axes, like US survey feet, instead of metres.
+    pub fn native_unit_projection(&self) -> Option<LinearUnitProjection<&'static dyn Projection>> {
+        get_projection_native_unit(self.code)
+    }
+
+    /// Get the geographic areas the CRS applies to, empty if it has no area of use.
+    pub fn areas_of_use(&self) -> &'static [AreaOfUse] {
+        get_areas_of_use(self.code).unwrap_or(&[])
+    }
+
+    /// Get the definition of the CRS with the names of its datum, ellipsoid, prime meridian,
+    /// conversion and unit, e.g. for writing it as WKT. Returns `None` if the registry lacks the
+    /// ellipsoid or the conversion parameters of the CRS.
+    pub fn to_definition(&self) -> Option<CrsDefinition> {
+        let base = self.base_code();
+        let (prime_meridian_name, prime_meridian) = match self.prime_meridian_code() {
+            Some(code) => (
+                prime_meridian_name(code).unwrap_or("unknown"),
+                get_prime_meridian(code)?.to_degrees(),
+            ),
+            None => ("Greenwich", 0.0),
+        };
+        let projection = match self.method_code() {
+            Some(_) => Some(get_projection_params(self.code)?),
+            None => None,
+        };
+        let unit = self.unit().filter(|u| u.to_base_factor.is_some());
+        let (unit_name, unit_factor) = match (unit, projection.is_some()) {
+            // "degree (supplier to define representation)"
+            (Some(unit), false) if self.unit_code() == Some(9122) => {
+                ("degree", unit.to_base_factor?)
+            }
+            (Some(unit), _) => (unit.name, unit.to_base_factor?),
+            (None, true) => ("metre", 1.0),
+            (None, false) => ("degree", 1f64.to_radians()),
+        };
+        Some(CrsDefinition {
+            name: self.name().to_owned(),
+            epsg: Some(self.code),
+            base_name: crs_name(base).unwrap_or("unknown").to_owned(),
+            base_epsg: projection.is_some().then_some(base),
+            datum_name: datum_name(base).unwrap_or("unknown").to_owned(),
+            ellipsoid_name: self
+                .ellipsoid_code()
+                .and_then(ellipsoid_name)
+                .unwrap_or("unknown")
+                .to_owned(),
+            ellipsoid: *self.ellipsoid()?,
+            prime_meridian_name: prime_meridian_name.to_owned(),
+            prime_meridian,
+            conversion_name: conversion_name(self.code).unwrap_or_default().to_owned(),
+            projection,
+            unit_name: unit_name.to_owned(),
+            unit_factor,
+            axes: self.axis_directions(),
+        })
+    }
+
+    /// Write the CRS as WKT2 (ISO 19162:2019) with its EPSG `ID`s and the bounding box of its first
+    /// area of use, e.g. for GDAL or QGIS. Returns `None` if `to_definition` does.
+    pub fn to_wkt2(&self) -> Option<String> {
+        Some(write_wkt2(
+            &self.to_definition()?,
+            self.areas_of_use().first(),
+        ))
+    }
+
+    /// Write the CRS as a PROJ string, see [`CrsDefinition::to_proj_string`]. Returns `None` if the
+    /// operation method has no equivalent in PROJ or `to_definition` returns `None`.
+    pub fn to_proj_string(&self) -> Option<String> {
+        self.to_definition()?.to_proj_string()
+    }
+
+    /// Get the transformation from the geographic base CRS to WGS 84 (EPSG:4326), see
+    /// [`get_transformation`]. Returns `None` if there is no route between the CRSs.
+    pub fn wgs84_transformation(
+        &self,
+    ) -> Option<Box<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>> {
+        get_transformation(self.base_code(), WGS84)
+    }
+
+    /// Converts coordinates of the CRS to WGS 84 longitude & latitude in degrees, transforming
+    /// between the datums if necessary. The route is looked up on each call, so use the
+    /// `projection` and `wgs84_transformation` for converting many coordinates.
+    /// Returns `None` if there is no route between the base CRS and WGS 84.
+    pub fn to_wgs84(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let (lon, lat) = self.projection.projected_to_rad(x, y);
+        let wgs84 = self
+            .wgs84_transformation()?
+            .apply(Geographic3DCoordinate::new(lon, lat, 0.0));
+        Some((wgs84.lon.to_degrees(), wgs84.lat.to_degrees()))
+    }
+
+    /// Converts WGS 84 longitude & latitude in degrees to coordinates of the CRS, the inverse of
+    /// `to_wgs84`. Returns `None` if there is no route between WGS 84 and the base CRS.
+    pub fn from_wgs84(&self, lon: f64, lat: f64) -> Option<(f64, f64)> {
+        let transformation = get_transformation(WGS84, self.base_code())?;
+        let base = transformation.apply(Geographic3DCoordinate::from_deg(lon, lat, 0.0));
+        Some(self.projection.rad_to_projected(base.lon, base.lat))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crs_handle() {
+        let utm = Crs::from_epsg(32632).unwrap();
+        eprintln!("{utm:?}");
+        assert_eq!(utm.name(), "WGS 84 / UTM zone 32N");
+        assert_eq!(utm.kind(), CrsKind::Projected);
+        assert_eq!((utm.base_code(), utm.method_code()), (4326, Some(9807)));
+        assert_eq!(utm.ellipsoid_code(), Some(7030));
+        assert_eq!(utm.unit().map(|u| u.name), Some("metre"));
+        let area = utm.areas_of_use()[0];
+        assert!(area.name.contains("6°E to 12°E") && area.contains(9.0, 54.0));
+        let (x, y) = utm.projection().deg_to_projected(9.0, 54.0);
+        let (lon, lat) = utm.to_wgs84(x, y).unwrap();
+        assert!((lon - 9.0).abs() < 1e-9 && (lat - 54.0).abs() < 1e-9);
+        assert_eq!(
+            utm.from_wgs84(lon, lat),
+            Some(utm.projection().deg_to_projected(lon, lat))
+        );
+
+        let wgs84 = Crs::from_epsg(4326).unwrap();
+        assert_eq!(wgs84.kind(), CrsKind::Geographic2D);
+        assert_eq!((wgs84.base_code(), wgs84.method_code()), (4326, None));
+        assert_eq!(wgs84.unit_code(), Some(9122));
+        assert_eq!(
+            wgs84.authority_projection().deg_to_projected(10.0, 54.0),
+            (54.0, 10.0)
+        );
+
+        // ETRS89 / UTM zone 32N is transformed to WGS 84
+        let etrs89 = Crs::from_epsg(25832).unwrap();
+        assert_eq!(etrs89.base_code(), 4258);
+        assert!(etrs89.wgs84_transformation().is_some());
+        let (lon, lat) = etrs89.to_wgs84(x, y).unwrap();
+        eprintln!("{lon}, {lat}");
+        assert!((lon - 9.0).abs() < 1e-4 && (lat - 54.0).abs() < 1e-4);
+
+        assert_eq!(
+            Crs::try_from_epsg(1).err(),
+            Some(MiniprojError::UnknownCode(1))
+        );
+        assert_eq!(Crs::from_epsg(4978), None);
+    }
+}
@@ -0,0 +1,107 @@
+//This file is licensed under EUPL v1.2
+
+use miniproj_ops::axis_order::AxisDirection;
+use miniproj_ops::identity_projection::IdentityProjection;
+use miniproj_ops::longitude_rotation::PrimeMeridianShift;
+use miniproj_ops::projection_params::ProjectionParams;
+use miniproj_ops::{Ellipsoid, Projection};
+
+use crate::proj_string::write_proj_string;
+use crate::wkt::write_wkt2;
+
+/// Definition of a geographic or projected Coordinate Reference System that need not be part of
+/// the registry, like one parsed from WKT.
+///
+/// Angles of the `projection` parameters are in radians and lengths in metres, whatever the units
+/// of the definition, and the projection constructed by `to_projection` takes and returns metres
+/// and Greenwich longitudes like all other `Projection`s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrsDefinition {
+    pub name: String,
+    /// EPSG code given in the definition, if any
+    pub epsg: Option<u32>,
+    /// name of the geographic CRS, the base CRS of a projected CRS
+    pub base_name: String,
+    /// EPSG code of the base CRS of a projected CRS, if any
+    pub base_epsg: Option<u32>,
+    pub datum_name: String,
+    pub ellipsoid_name: String,
+    pub ellipsoid: Ellipsoid,
+    pub prime_meridian_name: String,
+    /// Greenwich longitude of the prime meridian, degrees
+    pub prime_meridian: f64,
+    /// name of the conversion of a projected CRS
+    pub conversion_name: String,
+    /// parameters of the conversion of a projected CRS, `None` for geographic CRSs
+    pub projection: Option<ProjectionParams>,
+    /// name of the unit of the axes
+    pub unit_name: String,
+    /// length of the unit of the axes in metres, or its size in radians for geographic CRSs
+    pub unit_factor: f64,
+    /// directions of the first two axes, in the order of the definition
+    pub axes: [AxisDirection; 2],
+}
+
+impl CrsDefinition {
+    /// Construct the definition of a projected CRS in metres with easting first from the
+    /// parameters of its projection, naming everything but the CRS itself `"unknown"`.
+    pub fn new_projected(name: &str, ellipsoid: Ellipsoid, projection: ProjectionParams) -> Self {
+        let unknown = || "unknown".to_owned();
+        Self {
+            name: name.to_owned(),
+            epsg: None,
+            base_name: unknown(),
+            base_epsg: None,
+            datum_name: unknown(),
+            ellipsoid_name: unknown(),
+            ellipsoid,
+            prime_meridian_name: "Greenwich".to_owned(),
+            prime_meridian: 0.0,
+            conversion_name: unknown(),
+            projection: Some(projection),
+            unit_name: "metre".to_owned(),
+            unit_factor: 1.0,
+            axes: [AxisDirection::East, AxisDirection::North],
+        }
+    }
+
+    /// Whether the definition is of a projected CRS.
+    pub fn is_projected(&self) -> bool {
+        self.projection.is_some()
+    }
+
+    /// Get the EPSG code of the operation method of a projected CRS.
+    pub fn method_code(&self) -> Option<u32> {
+        self.projection.as_ref().map(ProjectionParams::method_code)
+    }
+
+    /// Construct the projection of the CRS, the identity for geographic CRSs.
+    pub fn to_projection(&self) -> Box<dyn Projection> {
+        let projection = match &self.projection {
+            Some(params) => params.to_projection(&self.ellipsoid),
+            None => Box::new(IdentityProjection),
+        };
+        if self.prime_meridian == 0.0 {
+            projection
+        } else {
+            Box::new(PrimeMeridianShift::new(
+                projection,
+                self.prime_meridian.to_radians(),
+            ))
+        }
+    }
+
+    /// Write the definition as WKT2 (ISO 19162:2019), e.g. for GDAL or QGIS, with EPSG `ID`s
+    /// where known. Parameters are written in degrees, metres and unity, whatever their units
+    /// in the definition the CRS came from.
+    pub fn to_wkt2(&self) -> String {
+        write_wkt2(self, None)
+    }
+
+    /// Write the definition as a PROJ string, e.g. for cartopy, rasterio or proj4js. Returns `None`
+    /// if the operation method has no equivalent in PROJ, like the Lambert Conic Near-Conformal.
+    /// Names and EPSG codes are left out, as PROJ strings have no place for them.
+    pub fn to_proj_string(&self) -> Option<String> {
+        write_proj_string(self)
+    }
+}
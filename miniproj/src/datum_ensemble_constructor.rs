@@ -0,0 +1,60 @@
+//This file is licensed under EUPL v1.2
+
+/// Datum ensemble, a collection of datums that are treated as interchangeable at the ensemble's
+/// accuracy, like the realisations of WGS 84 and ETRS89.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DatumEnsemble {
+    /// Accuracy of the ensemble in meters, i.e. the maximum difference between its members
+    pub accuracy: Option<f64>,
+    /// EPSG codes of the member datums, oldest first
+    pub members: &'static [u32],
+}
+
+include!(concat!(env!("OUT_DIR"), "/datum_ensemble_constructors.rs"));
+
+/// Returns the datum ensemble corresponding to the EPSG code passed as the argument.
+/// If the code does not refer to a datum ensemble, the method returns `None`.
+pub fn get_datum_ensemble(code: u32) -> Option<&'static DatumEnsemble> {
+    DATUM_ENSEMBLES.get(&code)
+}
+
+/// Returns the datum ensemble the Coordinate Reference System corresponding to the EPSG code passed
+/// as the argument is based on, like the WGS 84 ensemble for EPSG:4326.
+/// If the CRS is not based on a datum ensemble, the method returns `None`.
+pub fn get_crs_datum_ensemble(code: u32) -> Option<&'static DatumEnsemble> {
+    CRS_DATUM_ENSEMBLES
+        .get(&code)
+        .and_then(|e| DATUM_ENSEMBLES.get(e))
+}
+
+/// Returns the accuracy in meters of the datum ensemble the Coordinate Reference System
+/// corresponding to the EPSG code passed as the argument is based on. Coordinates in such a CRS
+/// can not be related to a specific realisation of the datum more accurately than this.
+/// If the CRS is not based on a datum ensemble or the accuracy is unknown, the method returns
+/// `None`.
+pub fn get_crs_ensemble_accuracy(code: u32) -> Option<f64> {
+    get_crs_datum_ensemble(code)?.accuracy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wgs84_ensemble() {
+        let wgs84 = get_datum_ensemble(6326).expect("Datum ensemble missing from registry.");
+        eprintln!("{wgs84:?}");
+        assert_eq!(wgs84.accuracy, Some(2.0));
+        // WGS 84 (Transit) is the first realisation
+        assert_eq!(wgs84.members.first(), Some(&1166));
+        assert!(wgs84.members.len() > 5);
+        assert!(get_datum_ensemble(6258).is_some());
+        assert!(get_datum_ensemble(6314).is_none());
+
+        assert_eq!(get_crs_datum_ensemble(4326), Some(wgs84));
+        assert_eq!(get_crs_ensemble_accuracy(4326), Some(2.0));
+        assert_eq!(get_crs_ensemble_accuracy(4258), Some(0.1));
+        // ETRF2000 is a realisation of the ETRS89 ensemble
+        assert!(get_crs_datum_ensemble(9067).is_none());
+    }
+}
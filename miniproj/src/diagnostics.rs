@@ -0,0 +1,114 @@
+//This file is licensed under EUPL v1.2
+
+//! Tools for checking the numerical accuracy of the implemented projections.
+
+use crate::{get_areas_of_use, get_projection, AreaOfUse};
+
+/// Mean earth radius used for expressing angular errors in meters.
+const MEAN_RADIUS: f64 = 6371008.8;
+
+/// Round trip error statistics for a coordinate reference system, as returned by [`survey`].
+/// Errors are approximate distances on the earth's surface in meters.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SurveyStatistics {
+    /// Number of sampled positions
+    pub samples: usize,
+    /// Number of sampled positions where the round trip did not produce finite coordinates
+    pub failures: usize,
+    /// Smallest round trip error
+    pub min: f64,
+    /// Largest round trip error
+    pub max: f64,
+    /// Mean round trip error
+    pub mean: f64,
+}
+
+/// Sample the area of use of the CRS with the EPSG code `code` on a regular grid of
+/// `samples` × `samples` positions per area, project each position forward and back again,
+/// and return statistics of the distance between the original and the round-tripped position.
+///
+/// Returns `None` if the projection is not implemented, the CRS has no area of use or
+/// `samples` is zero.
+pub fn survey(code: u32, samples: usize) -> Option<SurveyStatistics> {
+    let projection = get_projection(code)?;
+    let areas = get_areas_of_use(code)?;
+    if samples == 0 {
+        return None;
+    }
+    let mut stats = SurveyStatistics {
+        samples: 0,
+        failures: 0,
+        min: f64::INFINITY,
+        max: 0.0,
+        mean: 0.0,
+    };
+    let mut sum = 0f64;
+    for &AreaOfUse {
+        west,
+        south,
+        east,
+        north,
+        ..
+    } in areas
+    {
+        // areas crossing the antimeridian have their western bound east of the eastern one
+        let east = if east < west { east + 360.0 } else { east };
+        for i in 0..samples {
+            for j in 0..samples {
+                let (fx, fy) = if samples == 1 {
+                    (0.5, 0.5)
+                } else {
+                    (
+                        i as f64 / (samples - 1) as f64,
+                        j as f64 / (samples - 1) as f64,
+                    )
+                };
+                let lon = west + (east - west) * fx;
+                let lon = if lon > 180.0 { lon - 360.0 } else { lon };
+                let lat = south + (north - south) * fy;
+                let (x, y) = projection.deg_to_projected(lon, lat);
+                let (lon_rt, lat_rt) = projection.projected_to_deg(x, y);
+                stats.samples += 1;
+                let mut d_lon = (lon_rt - lon).rem_euclid(360.0);
+                if d_lon > 180.0 {
+                    d_lon -= 360.0;
+                }
+                let error = MEAN_RADIUS
+                    * (d_lon.to_radians() * lat.to_radians().cos())
+                        .hypot((lat_rt - lat).to_radians());
+                if !error.is_finite() {
+                    stats.failures += 1;
+                    continue;
+                }
+                stats.min = stats.min.min(error);
+                stats.max = stats.max.max(error);
+                sum += error;
+            }
+        }
+    }
+    let valid = stats.samples - stats.failures;
+    if valid > 0 {
+        stats.mean = sum / valid as f64;
+    } else {
+        stats.min = f64::NAN;
+        stats.max = f64::NAN;
+        stats.mean = f64::NAN;
+    }
+    Some(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn survey_utm() {
+        let stats = survey(32632, 10).expect("Projection not implemented.");
+        eprintln!("{stats:?}");
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.failures, 0);
+        assert!(stats.max < 1e-3);
+        assert!(stats.min <= stats.mean && stats.mean <= stats.max);
+        assert!(survey(32632, 0).is_none());
+    }
+}
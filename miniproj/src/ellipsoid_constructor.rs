@@ -7,3 +7,29 @@ include!(concat!(env!("OUT_DIR"), "/ellipsoid_constructors.rs"));
 pub fn get_ellipsoid(code: u32) -> Option<&'static Ellipsoid> {
     ELLIPSOIDS.get(&code)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miniproj_ops::ellipsoids;
+
+    #[test]
+    fn ellipsoid_constants_match_registry() {
+        for (code, constant) in [
+            (7030, ellipsoids::WGS84),
+            (7019, ellipsoids::GRS80),
+            (7004, ellipsoids::BESSEL_1841),
+            (7008, ellipsoids::CLARKE_1866),
+            (7022, ellipsoids::INTERNATIONAL_1924),
+            (7048, ellipsoids::SPHERE),
+        ] {
+            let registry = get_ellipsoid(code).expect("Ellipsoid missing from registry.");
+            eprintln!("EPSG:{code}: {registry:?} - {constant:?}");
+            assert_eq!(registry.a(), constant.a());
+            assert_eq!(registry.b(), constant.b());
+            assert_eq!(registry.f(), constant.f());
+            assert_eq!(registry.e(), constant.e());
+            assert_eq!(registry.e_squared(), constant.e_squared());
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//This file is licensed under EUPL v1.2
+
+/// Reason why no projection is available for an EPSG code, as returned by
+/// [`try_get_projection`](crate::try_get_projection).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MiniprojError {
+    /// The code does not refer to a Coordinate Reference System of the registry.
+    UnknownCode(u32),
+    /// The CRS is of a kind that has no projection, like a geocentric, vertical or compound CRS.
+    UnsupportedCrsKind { code: u32, kind: &'static str },
+    /// The operation method of the projected CRS is not implemented.
+    MethodNotImplemented { code: u32, method: u32 },
+    /// The projection conversion of the projected CRS is missing from the registry.
+    ConversionUnresolved { code: u32, conversion: u32 },
+    /// The base CRS of the projected CRS is not a geographic 2D CRS of the registry.
+    BaseCrsUnresolved { code: u32, base: u32 },
+    /// Neither the datum of the CRS nor any member of its datum ensemble resolves to a known
+    /// ellipsoid.
+    DatumUnresolved { code: u32, datum: u32 },
+    /// The CRS is based on a prime meridian other than Greenwich whose longitude is not known.
+    NonGreenwichMeridian { code: u32, prime_meridian: u32 },
+}
+
+impl MiniprojError {
+    /// Get the EPSG code of the Coordinate Reference System the error refers to.
+    pub fn code(&self) -> u32 {
+        match *self {
+            Self::UnknownCode(code)
+            | Self::UnsupportedCrsKind { code, .. }
+            | Self::MethodNotImplemented { code, .. }
+            | Self::ConversionUnresolved { code, .. }
+            | Self::BaseCrsUnresolved { code, .. }
+            | Self::DatumUnresolved { code, .. }
+            | Self::NonGreenwichMeridian { code, .. } => code,
+        }
+    }
+}
+
+impl std::fmt::Display for MiniprojError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownCode(code) => {
+                write!(
+                    f,
+                    "EPSG:{code} is not a coordinate reference system of the registry"
+                )
+            }
+            Self::UnsupportedCrsKind { code, kind } => {
+                write!(f, "EPSG:{code} is a {kind} CRS, which has no projection")
+            }
+            Self::MethodNotImplemented { code, method } => {
+                write!(
+                    f,
+                    "the operation method EPSG:{method} of EPSG:{code} is not implemented"
+                )
+            }
+            Self::ConversionUnresolved { code, conversion } => {
+                write!(
+                    f,
+                    "the conversion EPSG:{conversion} of EPSG:{code} is not in the registry"
+                )
+            }
+            Self::BaseCrsUnresolved { code, base } => {
+                write!(
+                    f,
+                    "the base CRS EPSG:{base} of EPSG:{code} is not a geographic 2D CRS"
+                )
+            }
+            Self::DatumUnresolved { code, datum } => {
+                write!(
+                    f,
+                    "the datum EPSG:{datum} of EPSG:{code} does not resolve to an ellipsoid"
+                )
+            }
+            Self::NonGreenwichMeridian {
+                code,
+                prime_meridian,
+            } => {
+                write!(
+                    f,
+                    "the prime meridian EPSG:{prime_meridian} of EPSG:{code} is not known"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MiniprojError {}
@@ -1,15 +1,102 @@
 //This file is licensed under EUPL v1.2
 #![doc = include_str!("../README.md")]
 
+mod area_of_use;
+mod capabilities;
+mod compound_crs_constructor;
+mod coordinate_metadata;
+mod crs;
+mod crs_definition;
+mod datum_ensemble_constructor;
+pub mod diagnostics;
 mod ellipsoid_constructor;
+mod error;
+mod prime_meridian_constructor;
+mod proj_string;
 mod projection_constructor;
+mod transformation_constructor;
+mod unit_constructor;
+mod wkt;
 
+#[doc(inline)]
+pub use area_of_use::AreaOfUse;
+#[doc(inline)]
+pub use capabilities::{capabilities, Capabilities, MethodSupport};
+#[doc(inline)]
+pub use compound_crs_constructor::{get_compound, get_vertical_crs, CompoundCrs, VerticalCrs};
+#[doc(inline)]
+pub use coordinate_metadata::{CoordinateMetadata, CoordinateWithMetadata};
+#[doc(inline)]
+pub use crs::{Crs, CrsKind};
+#[doc(inline)]
+pub use crs_definition::CrsDefinition;
+#[doc(inline)]
+pub use datum_ensemble_constructor::{
+    get_crs_datum_ensemble, get_crs_ensemble_accuracy, get_datum_ensemble, DatumEnsemble,
+};
 #[doc(inline)]
 pub use ellipsoid_constructor::get_ellipsoid;
 #[doc(inline)]
+pub use error::MiniprojError;
+#[doc(inline)]
+pub use miniproj_ops::axis_order::{AuthorityAxisOrder, AxisDirection};
+#[doc(inline)]
+pub use miniproj_ops::coordinate::{
+    GeocentricCoordinate, Geographic2DCoordinate, Geographic2DCoordinateUserVertical,
+    Geographic3DCoordinate, ProjectedCoordinateUserVertical,
+};
+#[doc(inline)]
 pub use miniproj_ops::custom_projection;
+#[doc(inline)]
+pub use miniproj_ops::ellipsoids;
+#[doc(inline)]
+pub use miniproj_ops::epoch::Epoch;
+#[doc(inline)]
+pub use miniproj_ops::geocentric_conversion::GeocentricConversion;
+#[doc(inline)]
+pub use miniproj_ops::helmert::{HelmertTimeDependentTransformation, HelmertTransformation};
+#[doc(inline)]
+pub use miniproj_ops::pipeline::{Pipeline, PipelineCoordinate, PipelineStep};
+#[doc(inline)]
+pub use miniproj_ops::point_motion::GeocentricPointMotionParams;
+#[doc(inline)]
+pub use miniproj_ops::projection_params::ProjectionParams;
+#[doc(inline)]
+pub use prime_meridian_constructor::get_prime_meridian;
+#[doc(inline)]
+pub use proj_string::{parse_proj_pipeline, parse_proj_string, ProjStringError};
+#[doc(inline)]
+pub use unit_constructor::get_unit;
+#[doc(inline)]
+pub use wkt::{parse_wkt, WktError};
 
 #[doc(inline)]
-pub use miniproj_ops::{Ellipsoid, Projection};
+pub use miniproj_ops::unit::{LinearUnitProjection, Unit, UnitKind};
+#[doc(inline)]
+pub use miniproj_ops::{
+    CoordOperation, Direction, Ellipsoid, InvertibleCoordOperation, Projection,
+};
+#[doc(inline)]
+#[allow(deprecated)]
+pub use projection_constructor::{
+    all_names, create_projection, create_projection_spherical, create_projection_spherical_with,
+    crs_candidates_for, crs_candidates_for_bbox, crs_for_region, get_areas_of_use,
+    get_axis_directions, get_base_crs_code, get_ellipsoid_code, get_method_code,
+    get_prime_meridian_code, get_projection, get_projection_authority_order,
+    get_projection_native_unit, get_projection_params, get_reference_system_areas,
+    get_reference_system_name, get_unit_code, projected_bounds, search_crs, transform,
+    try_get_projection, SphereRadius,
+};
 #[doc(inline)]
-pub use projection_constructor::{get_ellipsoid_code, get_projection, get_reference_system_name, get_reference_system_areas, all_names /*create_projection*/};
+pub use transformation_constructor::{
+    describe_transformation, describe_transformation_path, get_concatenated_coord_operation,
+    get_coord_operation, get_coord_operation_areas, get_coord_operation_crs,
+    get_geocentric_conversion, get_geocentric_coord_operation,
+    get_molodensky_badekas_coord_operation, get_time_dependent_coord_operation, get_transformation,
+    get_transformation_alternatives, get_transformation_at, get_transformation_in_area,
+    get_transformation_or_ballpark, get_transformation_path, get_transformation_path_in_area,
+    get_transformation_path_with_accuracy, get_transformation_with_accuracy,
+    get_transformation_with_velocity, get_vertical_coord_operation, get_vertical_transformation,
+    get_vertical_transformation_path, RouteOptions, RoutedTransformation, TransformationStep,
+    TransformerFactory,
+};
@@ -0,0 +1,27 @@
+//This file is licensed under EUPL v1.2
+
+include!(concat!(env!("OUT_DIR"), "/prime_meridian_constructors.rs"));
+
+/// Returns the longitude of the prime meridian corresponding to the EPSG code passed as the
+/// argument, in radians east of Greenwich.
+/// If the code does not refer to a prime meridian, the method returns `None`.
+pub fn get_prime_meridian(code: u32) -> Option<f64> {
+    PRIME_MERIDIANS.get(&code).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prime_meridians() {
+        assert_eq!(get_prime_meridian(8901), Some(0.0));
+        // Paris is given in grads
+        let paris = get_prime_meridian(8903).expect("Prime meridian missing from registry.");
+        assert!((paris.to_degrees() - 2.5969213 * 0.9).abs() < 1e-12);
+        // Ferro is given in sexagesimal degrees
+        let ferro = get_prime_meridian(8909).expect("Prime meridian missing from registry.");
+        assert!((ferro.to_degrees() + 17.0 + 40.0 / 60.0).abs() < 1e-9);
+        assert!(get_prime_meridian(1).is_none());
+    }
+}
@@ -0,0 +1,996 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::PI;
+
+use miniproj_ops::axis_order::AxisDirection;
+use miniproj_ops::helmert::{HelmertParams, HelmertTransformation};
+use miniproj_ops::inverse::Inverse;
+use miniproj_ops::pipeline::{Pipeline, PipelineStep};
+use miniproj_ops::projection_params::ProjectionParams;
+use miniproj_ops::Ellipsoid;
+
+use crate::wkt::{parameter_name, round_degrees, ParameterKind};
+use crate::{Crs, CrsDefinition};
+
+/// Reason why a PROJ string could not be turned into a `CrsDefinition`, as returned by
+/// [`parse_proj_string`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProjStringError {
+    /// The string has neither a `+proj` nor an `+init` parameter.
+    MissingProj,
+    /// The value of the named parameter is not a number, angle or name PROJ accepts.
+    InvalidValue(String),
+    /// The projection named by `+proj` or the variant selected by its parameters is not
+    /// implemented, or the `+init` code is not in the registry.
+    UnsupportedProjection(String),
+    /// The `+ellps`, `+datum`, `+pm` or `+units` name is not known.
+    UnknownName(String),
+}
+
+impl std::fmt::Display for ProjStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingProj => write!(f, "the PROJ string has no +proj"),
+            Self::InvalidValue(key) => write!(f, "the PROJ string has an invalid +{key}"),
+            Self::UnsupportedProjection(name) => {
+                write!(f, "the projection {name:?} is not implemented")
+            }
+            Self::UnknownName(name) => write!(f, "the name {name:?} is not known"),
+        }
+    }
+}
+
+impl std::error::Error for ProjStringError {}
+
+/// Ellipsoids by their PROJ `+ellps` names, with their semi-major axis in metres and inverse
+/// flattening, 0 for spheres.
+const ELLIPSOIDS: &[(&str, f64, f64)] = &[
+    ("WGS84", 6378137.0, 298.257223563),
+    ("GRS80", 6378137.0, 298.257222101),
+    ("WGS72", 6378135.0, 298.26),
+    ("GRS67", 6378160.0, 298.247167427),
+    ("intl", 6378388.0, 297.0),
+    ("bessel", 6377397.155, 299.1528128),
+    ("bess_nam", 6377483.865, 299.1528128),
+    ("clrk66", 6378206.4, 294.978698213898),
+    ("clrk80", 6378249.145, 293.4663),
+    ("clrk80ign", 6378249.2, 293.466021293627),
+    ("krass", 6378245.0, 298.3),
+    ("airy", 6377563.396, 299.3249646),
+    ("mod_airy", 6377340.189, 299.3249646),
+    ("aust_SA", 6378160.0, 298.25),
+    ("evrst30", 6377276.345, 300.8017),
+    ("helmert", 6378200.0, 298.3),
+    ("hough", 6378270.0, 297.0),
+    ("sphere", 6370997.0, 0.0),
+];
+
+/// Datums by their PROJ `+datum` names, with the `+ellps` name of their ellipsoid.
+const DATUMS: &[(&str, &str)] = &[
+    ("WGS84", "WGS84"),
+    ("NAD83", "GRS80"),
+    ("NAD27", "clrk66"),
+    ("GGRS87", "GRS80"),
+    ("potsdam", "bessel"),
+    ("hermannskogel", "bessel"),
+    ("carthage", "clrk80ign"),
+    ("OSGB36", "airy"),
+    ("ire65", "mod_airy"),
+    ("nzgd49", "intl"),
+];
+
+/// Prime meridians by their PROJ `+pm` names, with their Greenwich longitude in degrees.
+const PRIME_MERIDIANS: &[(&str, f64)] = &[
+    ("greenwich", 0.0),
+    ("lisbon", -9.131906111111),
+    ("paris", 2.337229166667),
+    ("bogota", -74.080916666667),
+    ("madrid", -3.687938888889),
+    ("rome", 12.452333333333),
+    ("bern", 7.439583333333),
+    ("jakarta", 106.807719444444),
+    ("ferro", -17.666666666667),
+    ("brussels", 4.367975),
+    ("stockholm", 18.058277777778),
+    ("athens", 23.7163375),
+    ("oslo", 10.722916666667),
+    ("copenhagen", 12.57788),
+];
+
+/// Length units by their PROJ `+units` names, with their length in metres.
+const UNITS: &[(&str, f64)] = &[
+    ("m", 1.0),
+    ("km", 1000.0),
+    ("dm", 0.1),
+    ("cm", 0.01),
+    ("mm", 0.001),
+    ("kmi", 1852.0),
+    ("ft", 0.3048),
+    ("us-ft", 1200.0 / 3937.0),
+    ("yd", 0.9144),
+    ("us-yd", 3600.0 / 3937.0),
+    ("mi", 1609.344),
+    ("us-mi", 6336000.0 / 3937.0),
+    ("fath", 1.8288),
+    ("ch", 20.1168),
+    ("us-ch", 792000.0 / 3937.0),
+    ("link", 0.201168),
+    ("ind-ft", 0.30479841),
+    ("ind-yd", 0.91439523),
+];
+
+/// EPSG parameters by the PROJ parameters standing for them, like `lon_0` for the longitude of
+/// the natural origin of Transverse Mercator and the longitude of the false origin of Albers.
+/// If several PROJ parameters stand for a parameter of a method, the one listing it earlier is
+/// used.
+const PARAMETERS: &[(&str, &[u32])] = &[
+    ("lat_0", &[8801, 8821, 8811]),
+    ("lon_0", &[8802, 8822, 8833]),
+    ("lonc", &[8812]),
+    ("lat_1", &[8823, 8801]),
+    ("lat_2", &[8824]),
+    ("lat_ts", &[8832, 8823]),
+    ("k", &[8805, 8815, 1038]),
+    ("k_0", &[8805, 8815, 1038]),
+    ("x_0", &[8806, 8826, 8816]),
+    ("y_0", &[8807, 8827, 8817]),
+    ("alpha", &[8813]),
+    ("gamma", &[8814]),
+    ("h_0", &[1039]),
+];
+
+/// PROJ parameters and the EPSG parameters they are written from.
+type Parameters = &'static [(&'static str, u32)];
+
+const ORIGIN: Parameters = &[
+    ("lat_0", 8801),
+    ("lon_0", 8802),
+    ("x_0", 8806),
+    ("y_0", 8807),
+];
+const SCALED_ORIGIN: Parameters = &[
+    ("lat_0", 8801),
+    ("lon_0", 8802),
+    ("k", 8805),
+    ("x_0", 8806),
+    ("y_0", 8807),
+];
+const FALSE_ORIGIN: Parameters = &[
+    ("lat_0", 8821),
+    ("lon_0", 8822),
+    ("lat_1", 8823),
+    ("lat_2", 8824),
+    ("x_0", 8826),
+    ("y_0", 8827),
+];
+const STANDARD_PARALLEL: Parameters = &[
+    ("lat_ts", 8823),
+    ("lon_0", 8802),
+    ("x_0", 8806),
+    ("y_0", 8807),
+];
+const OBLIQUE: Parameters = &[
+    ("lat_0", 8811),
+    ("lonc", 8812),
+    ("alpha", 8813),
+    ("gamma", 8814),
+    ("k", 8815),
+];
+
+/// PROJ projections by the EPSG operation methods they implement, with their parameters. Methods
+/// without an equivalent in PROJ, like the Lambert Conic Near-Conformal, are missing.
+const PROJECTIONS: &[(u32, &str, &[Parameters])] = &[
+    (9807, "tmerc", &[SCALED_ORIGIN]),
+    (9808, "tmerc +axis=wsu", &[SCALED_ORIGIN]),
+    (9801, "lcc", &[&[("lat_1", 8801)], SCALED_ORIGIN]),
+    (9802, "lcc", &[FALSE_ORIGIN]),
+    (1051, "lcc", &[FALSE_ORIGIN, &[("k_0", 1038)]]),
+    (9822, "aea", &[FALSE_ORIGIN]),
+    (9809, "sterea", &[SCALED_ORIGIN]),
+    (9810, "stere", &[SCALED_ORIGIN]),
+    (
+        9829,
+        "stere",
+        &[&[
+            ("lat_ts", 8832),
+            ("lon_0", 8833),
+            ("x_0", 8806),
+            ("y_0", 8807),
+        ]],
+    ),
+    (
+        1024,
+        "merc",
+        &[&[("lon_0", 8802), ("x_0", 8806), ("y_0", 8807)]],
+    ),
+    (
+        1026,
+        "merc",
+        &[&[("lon_0", 8802), ("x_0", 8806), ("y_0", 8807)]],
+    ),
+    (9820, "laea", &[ORIGIN]),
+    (1027, "laea", &[ORIGIN]),
+    (9806, "cass", &[ORIGIN]),
+    (9833, "cass +hyperbolic", &[ORIGIN]),
+    (
+        9812,
+        "omerc +no_uoff",
+        &[OBLIQUE, &[("x_0", 8806), ("y_0", 8807)]],
+    ),
+    (9815, "omerc", &[OBLIQUE, &[("x_0", 8816), ("y_0", 8817)]]),
+    (1028, "eqc", &[STANDARD_PARALLEL]),
+    (1029, "eqc", &[STANDARD_PARALLEL]),
+    (9835, "cea", &[STANDARD_PARALLEL]),
+    (9834, "cea", &[STANDARD_PARALLEL]),
+    (1125, "aeqd", &[ORIGIN]),
+    (9831, "aeqd +guam", &[ORIGIN]),
+    (
+        9827,
+        "bonne",
+        &[&[
+            ("lat_1", 8801),
+            ("lon_0", 8802),
+            ("x_0", 8806),
+            ("y_0", 8807),
+        ]],
+    ),
+    (
+        9828,
+        "bonne +axis=wsu",
+        &[&[
+            ("lat_1", 8801),
+            ("lon_0", 8802),
+            ("x_0", 8806),
+            ("y_0", 8807),
+        ]],
+    ),
+    (
+        1078,
+        "eqearth",
+        &[&[("lon_0", 8802), ("x_0", 8806), ("y_0", 8807)]],
+    ),
+    (1052, "col_urban", &[ORIGIN, &[("h_0", 1039)]]),
+];
+
+/// Parse a PROJ angle in decimal degrees or as degrees, minutes and seconds like `52d30'N`.
+fn parse_angle(value: &str) -> Option<f64> {
+    if let Ok(degrees) = value.parse() {
+        return Some(degrees);
+    }
+    let (value, sign) = match value.strip_suffix(['S', 's', 'W', 'w']) {
+        Some(value) => (value, -1.0),
+        None => (
+            value.strip_suffix(['N', 'n', 'E', 'e']).unwrap_or(value),
+            1.0,
+        ),
+    };
+    let (degrees, rest) = value.split_once(['d', 'D']).unwrap_or((value, ""));
+    let (minutes, rest) = rest.split_once('\'').unwrap_or((rest, ""));
+    let seconds = rest.strip_suffix('"').unwrap_or(rest);
+    let part = |p: &str| {
+        if p.is_empty() {
+            Some(0.0)
+        } else {
+            p.parse::<f64>().ok()
+        }
+    };
+    let degrees: f64 = degrees.parse().ok()?;
+    let magnitude = degrees.abs() + part(minutes)? / 60.0 + part(seconds)? / 3600.0;
+    Some(sign * magnitude.copysign(degrees))
+}
+
+/// Get the axis direction for a letter of the PROJ `+axis` parameter.
+fn axis_direction(letter: char) -> AxisDirection {
+    match letter {
+        'e' => AxisDirection::East,
+        'w' => AxisDirection::West,
+        'n' => AxisDirection::North,
+        's' => AxisDirection::South,
+        'u' => AxisDirection::Up,
+        'd' => AxisDirection::Down,
+        _ => AxisDirection::Other,
+    }
+}
+
+/// Parameters of a PROJ string, as their keys and their values if given.
+type Params<'a> = [(&'a str, Option<&'a str>)];
+
+/// Split a PROJ string into its parameters.
+fn split_params(proj_string: &str) -> Vec<(&str, Option<&str>)> {
+    proj_string
+        .split_whitespace()
+        .map(|token| {
+            let token = token.strip_prefix('+').unwrap_or(token);
+            match token.split_once('=') {
+                Some((key, value)) => (key, Some(value)),
+                None => (token, None),
+            }
+        })
+        .collect()
+}
+
+/// Get the value of the first parameter named `key`, `Some(None)` if it is given without value.
+fn param<'a>(params: &Params<'a>, key: &str) -> Option<Option<&'a str>> {
+    params.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+/// Get the value of the parameter named `key` as a number, `None` if it is not given.
+fn number_param(params: &Params, key: &str) -> Result<Option<f64>, ProjStringError> {
+    match param(params, key) {
+        Some(value) => value
+            .and_then(|v| v.parse().ok())
+            .map(Some)
+            .ok_or_else(|| ProjStringError::InvalidValue(key.to_owned())),
+        None => Ok(None),
+    }
+}
+
+/// Get the ellipsoid given by `+ellps`, `+datum`, `+R` or `+a` with `+b`, `+rf` or `+f`, WGS 84
+/// otherwise, with the names of the datum and the ellipsoid.
+fn parse_ellipsoid<'a>(
+    params: &Params<'a>,
+) -> Result<(&'a str, &'a str, Ellipsoid), ProjStringError> {
+    let number = |key: &str| number_param(params, key);
+    let (datum_name, ellps) = match param(params, "datum").flatten() {
+        Some(datum) => {
+            let (_, ellps) = DATUMS
+                .iter()
+                .find(|(name, _)| *name == datum)
+                .ok_or_else(|| ProjStringError::UnknownName(datum.to_owned()))?;
+            (datum, *ellps)
+        }
+        None => (
+            "unknown",
+            param(params, "ellps").flatten().unwrap_or("WGS84"),
+        ),
+    };
+    let (_, ellps_a, ellps_f_inv) = ELLIPSOIDS
+        .iter()
+        .find(|(name, ..)| *name == ellps)
+        .ok_or_else(|| ProjStringError::UnknownName(ellps.to_owned()))?;
+    let ellipsoid = match (number("R")?, number("a")?) {
+        (Some(r), _) => Ellipsoid::from_a_b(r, r),
+        (None, Some(a)) => match (number("b")?, number("rf")?, number("f")?) {
+            (Some(b), ..) => Ellipsoid::from_a_b(a, b),
+            (None, Some(rf), _) => Ellipsoid::from_a_f_inv(a, rf),
+            (None, None, Some(f)) if f != 0.0 => Ellipsoid::from_a_f_inv(a, 1.0 / f),
+            _ => Ellipsoid::from_a_b(a, a),
+        },
+        // spheres have an inverse flattening of 0
+        (None, None) if *ellps_f_inv == 0.0 => Ellipsoid::from_a_b(*ellps_a, *ellps_a),
+        (None, None) => Ellipsoid::from_a_f_inv(*ellps_a, *ellps_f_inv),
+    };
+    let ellipsoid_name = match (param(params, "R"), param(params, "a")) {
+        (None, None) => ellps,
+        _ => "unknown",
+    };
+    Ok((datum_name, ellipsoid_name, ellipsoid))
+}
+
+/// Parses a PROJ string like `+proj=tmerc +lat_0=0 +lon_0=9 +k=0.9996 +x_0=500000 +ellps=WGS84`
+/// of a geographic or projected CRS into a `CrsDefinition`. The projection is constructed with
+/// [`CrsDefinition::to_projection`].
+///
+/// The projections `tmerc`, `utm`, `lcc`, `stere` at the poles, `sterea`, `merc` on spheres,
+/// `webmerc`, `aea`, `laea`, `cass`, `omerc`, `eqc`, `cea`, `aeqd`, `bonne`, `eqearth` and
+/// `col_urban` are translated to the EPSG operation methods they implement, as well as `longlat`
+/// for geographic CRSs. The ellipsoid is given by `+ellps`, `+datum`, `+R` or `+a` with `+b`,
+/// `+rf` or `+f`, WGS 84 otherwise. Parameters missing from the string default like in PROJ: to
+/// 0, scale factors to 1, `+lat_2` of `lcc` to `+lat_1` and `+gamma` of `omerc` to `+alpha`.
+/// Parameters the EPSG methods cannot represent, like `+lat_ts` of `merc`, are rejected with
+/// [`ProjStringError::UnsupportedProjection`] unless they are at their defaults. Parameters that
+/// do not affect the projection, like `+towgs84` or `+no_defs`, are ignored. `+init=epsg:<code>` returns the definition of the CRS in the
+/// registry. Pipelines are parsed by [`parse_proj_pipeline`].
+pub fn parse_proj_string(proj_string: &str) -> Result<CrsDefinition, ProjStringError> {
+    let params = split_params(proj_string);
+    let get = |key: &str| param(&params, key);
+    let text = |key: &'static str| get(key).flatten();
+    let number = |key: &str| number_param(&params, key);
+    let angle = |key: &str| -> Result<Option<f64>, ProjStringError> {
+        match get(key) {
+            Some(value) => value
+                .and_then(parse_angle)
+                .map(Some)
+                .ok_or_else(|| ProjStringError::InvalidValue(key.to_owned())),
+            None => Ok(None),
+        }
+    };
+
+    if let Some(init) = text("init") {
+        let code = init
+            .strip_prefix("epsg:")
+            .or_else(|| init.strip_prefix("EPSG:"))
+            .and_then(|code| code.parse().ok());
+        return code
+            .and_then(Crs::from_epsg)
+            .and_then(|crs| crs.to_definition())
+            .ok_or_else(|| ProjStringError::UnsupportedProjection(init.to_owned()));
+    }
+    let proj = text("proj").ok_or(ProjStringError::MissingProj)?;
+    if proj == "pipeline" {
+        return Err(ProjStringError::UnsupportedProjection(proj.to_owned()));
+    }
+    let (datum_name, ellipsoid_name, ellipsoid) = parse_ellipsoid(&params)?;
+
+    let (prime_meridian_name, prime_meridian) = match text("pm") {
+        Some(pm) => match PRIME_MERIDIANS.iter().find(|(name, _)| *name == pm) {
+            Some((name, lon)) => (*name, *lon),
+            None => (
+                "unknown",
+                parse_angle(pm).ok_or(ProjStringError::UnknownName(pm.into()))?,
+            ),
+        },
+        None => ("greenwich", 0.0),
+    };
+
+    let axis = text("axis").unwrap_or("enu");
+    let mut letters = axis.chars().map(axis_direction);
+    let axes = match (letters.next(), letters.next()) {
+        (Some(first), Some(second)) => [first, second],
+        _ => return Err(ProjStringError::InvalidValue("axis".to_owned())),
+    };
+
+    if ["longlat", "latlong", "lonlat", "latlon"].contains(&proj) {
+        return Ok(CrsDefinition {
+            name: "unknown".to_owned(),
+            epsg: None,
+            base_name: "unknown".to_owned(),
+            base_epsg: None,
+            datum_name: datum_name.to_owned(),
+            ellipsoid_name: ellipsoid_name.to_owned(),
+            ellipsoid,
+            prime_meridian_name: prime_meridian_name.to_owned(),
+            prime_meridian,
+            conversion_name: String::new(),
+            projection: None,
+            unit_name: "degree".to_owned(),
+            unit_factor: PI / 180.0,
+            axes,
+        });
+    }
+
+    // the parameters of the projection, as angles in degrees and lengths in metres
+    let mut values = Vec::new();
+    for (key, codes) in PARAMETERS {
+        if let Some(value) = angle(key)? {
+            values.push((*codes, value));
+        }
+    }
+    let unsupported = || ProjStringError::UnsupportedProjection(proj.to_owned());
+    let lat_0 = angle("lat_0")?.unwrap_or_default();
+    // the spherical variants of methods whose ellipsoidal formulas fail on spheres
+    let sphere = ellipsoid.f() == 0.0;
+    let method = match proj {
+        "tmerc" if axis.starts_with("ws") => 9808,
+        "tmerc" => 9807,
+        "utm" => {
+            let zone = number("zone")?.filter(|z| (1.0..=60.0).contains(z) && z.fract() == 0.0);
+            let zone = zone.ok_or_else(|| ProjStringError::InvalidValue("zone".to_owned()))?;
+            let false_northing = if get("south").is_some() {
+                10000000.0
+            } else {
+                0.0
+            };
+            values = vec![
+                (&[8802][..], zone * 6.0 - 183.0),
+                (&[8805][..], 0.9996),
+                (&[8806][..], 500000.0),
+                (&[8807][..], false_northing),
+            ];
+            9807
+        }
+        "lcc" => {
+            // without lat_2 the cone touches lat_1, the false origin is still at lat_0
+            let lat_1 = angle("lat_1")?.unwrap_or_default();
+            let lat_2 = angle("lat_2")?;
+            if lat_2.unwrap_or(lat_1) == lat_1 && lat_0 == lat_1 {
+                9801
+            } else {
+                if lat_2.is_none() {
+                    values.push((&[8824][..], lat_1));
+                }
+                // a scale factor on the 2SP variant scales the ellipsoid as in the Michigan variant
+                match number("k_0")?.or(number("k")?) {
+                    Some(k) if k != 1.0 => 1051,
+                    _ => 9802,
+                }
+            }
+        }
+        "stere" if (lat_0.abs() - 90.0).abs() < 1e-9 => match get("lat_ts") {
+            Some(_) => 9829,
+            None => 9810,
+        },
+        "sterea" => 9809,
+        "merc" if sphere => 1026,
+        "webmerc" => 1024,
+        "aea" => 9822,
+        "laea" if sphere => 1027,
+        "laea" => 9820,
+        "cass" if get("hyperbolic").is_some() => 9833,
+        "cass" => 9806,
+        "omerc" if get("no_uoff").is_some() || get("no_off").is_some() => 9812,
+        "omerc" => 9815,
+        "eqc" if sphere => 1029,
+        "eqc" => 1028,
+        "cea" if sphere => 9834,
+        "cea" => 9835,
+        "aeqd" if get("guam").is_some() => 9831,
+        "aeqd" => 1125,
+        "bonne" if axis.starts_with("ws") => 9828,
+        "bonne" => 9827,
+        "eqearth" => 1078,
+        "col_urban" => 1052,
+        _ => return Err(unsupported()),
+    };
+    // PROJ parameters the EPSG method has no equivalent for, unless they are at their defaults
+    let fixed: &[(&str, f64)] = match method {
+        1026 => &[("lat_ts", 0.0), ("k", 1.0), ("k_0", 1.0)],
+        1028 | 1029 => &[("lat_0", 0.0)],
+        _ => &[],
+    };
+    for (key, default) in fixed {
+        if angle(key)?.is_some_and(|value| value != *default) {
+            return Err(ProjStringError::UnsupportedProjection(format!(
+                "{proj} +{key}"
+            )));
+        }
+    }
+    // PROJ rectifies the grid of omerc along the centre line unless told otherwise
+    if matches!(method, 9812 | 9815) && get("gamma").is_none() {
+        if let Some(alpha) = angle("alpha")? {
+            values.push((&[8814][..], alpha));
+        }
+    }
+    let projection = ProjectionParams::from_getter(method, |code| {
+        let kind = ParameterKind::from_name(parameter_name(code).unwrap_or_default());
+        let value = values
+            .iter()
+            .filter_map(|(codes, value)| Some((codes.iter().position(|c| *c == code)?, *value)))
+            .min_by_key(|(position, _)| *position)
+            .map(|(_, value)| value);
+        Some(match kind {
+            ParameterKind::Angle => value.unwrap_or_default().to_radians(),
+            ParameterKind::Length => value.unwrap_or_default(),
+            ParameterKind::Scale => value.unwrap_or(1.0),
+        })
+    })
+    .ok_or_else(unsupported)?;
+
+    let (unit_name, unit_factor) = match (number("to_meter")?, text("units")) {
+        (Some(factor), _) => ("unknown", factor),
+        (None, Some(units)) => UNITS
+            .iter()
+            .find(|(name, _)| *name == units)
+            .copied()
+            .ok_or_else(|| ProjStringError::UnknownName(units.to_owned()))?,
+        (None, None) => ("m", 1.0),
+    };
+    Ok(CrsDefinition {
+        name: "unknown".to_owned(),
+        epsg: None,
+        base_name: "unknown".to_owned(),
+        base_epsg: None,
+        datum_name: datum_name.to_owned(),
+        ellipsoid_name: ellipsoid_name.to_owned(),
+        ellipsoid,
+        prime_meridian_name: prime_meridian_name.to_owned(),
+        prime_meridian,
+        conversion_name: "unknown".to_owned(),
+        projection: Some(projection),
+        unit_name: unit_name.to_owned(),
+        unit_factor,
+        axes,
+    })
+}
+
+/// Parses a PROJ pipeline like `+proj=pipeline +step +proj=cart +ellps=bessel +step +proj=helmert
+/// +x=601.705 +y=84.263 +z=485.227 +convention=coordinate_frame +step +inv +proj=cart +ellps=GRS80`
+/// into a [`Pipeline`] on geographic coordinates in radians and geocentric coordinates. A string
+/// without `+proj=pipeline` is parsed as a pipeline of one step.
+///
+/// The steps `cart` and `helmert` without time-dependent parameters are supported, and inverted
+/// by `+inv`. `unitconvert` steps between degrees and radians and `noop` steps are skipped, as the
+/// pipeline takes and returns angles in radians. Parameters before the first `+step` apply to
+/// all steps, like in PROJ. Other steps are rejected with
+/// [`ProjStringError::UnsupportedProjection`].
+pub fn parse_proj_pipeline(proj_string: &str) -> Result<Pipeline, ProjStringError> {
+    let params = split_params(proj_string);
+    let mut steps = params.split(|(key, _)| *key == "step");
+    let global = steps.next().unwrap_or_default();
+    let mut pipeline = Pipeline::new();
+    if param(global, "proj") != Some(Some("pipeline")) {
+        if let Some(step) = parse_pipeline_step(&params)? {
+            pipeline.push(step);
+        }
+        return Ok(pipeline);
+    }
+    let global = global
+        .iter()
+        .filter(|(key, _)| *key != "proj")
+        .copied()
+        .collect::<Vec<_>>();
+    for step in steps {
+        // parameters of the step take precedence over those of the pipeline
+        let params = [step, &global].concat();
+        if let Some(step) = parse_pipeline_step(&params)? {
+            pipeline.push(step);
+        }
+    }
+    Ok(pipeline)
+}
+
+/// Parse a step of a PROJ pipeline, `None` for steps that leave coordinates in radians unchanged.
+fn parse_pipeline_step(params: &Params) -> Result<Option<PipelineStep>, ProjStringError> {
+    let number = |key: &str| number_param(params, key);
+    let proj = param(params, "proj")
+        .flatten()
+        .ok_or(ProjStringError::MissingProj)?;
+    let unsupported = |key: &str| ProjStringError::UnsupportedProjection(format!("{proj} +{key}"));
+    let step = match proj {
+        "noop" => return Ok(None),
+        "unitconvert" => {
+            let angular = |key: &str| {
+                param(params, key)
+                    .flatten()
+                    .is_none_or(|unit| ["deg", "rad"].contains(&unit))
+            };
+            if let Some(key) = ["xy_in", "xy_out"].into_iter().find(|key| !angular(key)) {
+                return Err(unsupported(key));
+            }
+            if param(params, "z_in") != param(params, "z_out") {
+                return Err(unsupported("z_in"));
+            }
+            return Ok(None);
+        }
+        "cart" => PipelineStep::to_geocentric(parse_ellipsoid(params)?.2),
+        "helmert" => {
+            let rates = [
+                "dx", "dy", "dz", "drx", "dry", "drz", "ds", "t_epoch", "exact",
+            ];
+            if let Some(key) = rates.into_iter().find(|key| param(params, key).is_some()) {
+                return Err(unsupported(key));
+            }
+            let arcsec = 1f64.to_radians() / 3600.0;
+            let value = |key| Ok::<_, ProjStringError>(number(key)?.unwrap_or_default());
+            let (rx, ry, rz) = (value("rx")?, value("ry")?, value("rz")?);
+            let helmert_params = HelmertParams::new(
+                value("x")?,
+                value("y")?,
+                value("z")?,
+                rx * arcsec,
+                ry * arcsec,
+                rz * arcsec,
+                value("s")? * 1e-6,
+            );
+            // PROJ requires the convention as soon as there are rotations
+            let helmert = match param(params, "convention").flatten() {
+                Some("position_vector") => {
+                    HelmertTransformation::new_position_vector(&helmert_params)
+                }
+                Some("coordinate_frame") => {
+                    HelmertTransformation::new_coordinate_frame(&helmert_params)
+                }
+                None if rx == 0.0 && ry == 0.0 && rz == 0.0 => {
+                    HelmertTransformation::new_position_vector(&helmert_params)
+                }
+                _ => return Err(ProjStringError::InvalidValue("convention".to_owned())),
+            };
+            PipelineStep::geocentric_with_inverse(helmert, Inverse(helmert))
+        }
+        _ => return Err(ProjStringError::UnsupportedProjection(proj.to_owned())),
+    };
+    match param(params, "inv") {
+        Some(_) => step.inverse().map(Some).ok_or_else(|| unsupported("inv")),
+        None => Ok(Some(step)),
+    }
+}
+
+/// Write the `+ellps` name of the ellipsoid, or its axis and inverse flattening if it has none.
+fn ellipsoid_parameters(ellipsoid: &Ellipsoid) -> String {
+    let (a, f) = (ellipsoid.a(), ellipsoid.f());
+    if f == 0.0 {
+        return format!("+R={a}");
+    }
+    let name = ELLIPSOIDS
+        .iter()
+        .find(|(_, a_e, f_inv)| *a_e == a && *f_inv != 0.0 && (1.0 / f_inv - f).abs() < 1e-12);
+    match name {
+        Some((name, ..)) => format!("+ellps={name}"),
+        None => format!("+a={a} +rf={}", 1.0 / f),
+    }
+}
+
+/// Write a CRS definition as a PROJ string, `None` if its method has no equivalent in PROJ.
+pub(crate) fn write_proj_string(definition: &CrsDefinition) -> Option<String> {
+    let ellipsoid = &definition.ellipsoid;
+    let mut proj = Vec::new();
+    match &definition.projection {
+        Some(params) => {
+            let method = params.method_code();
+            let (_, name, keys) = PROJECTIONS.iter().find(|(m, ..)| *m == method)?;
+            let values = params.to_params();
+            let value = |code: u32| values.iter().find_map(|(c, v)| (*c == code).then_some(*v));
+            proj.push(format!("+proj={name}"));
+            match method {
+                // the pole follows from the hemisphere of the standard parallel
+                9829 => proj.push(format!("+lat_0={}", 90f64.copysign(value(8832)?))),
+                // spherical formulas on the semi-major axis of the ellipsoid
+                1024 => proj.push(format!("+a={0} +b={0} +lat_ts=0", ellipsoid.a())),
+                _ => {}
+            }
+            for (key, code) in keys.iter().copied().flatten() {
+                let value = match ParameterKind::from_name(parameter_name(*code)?) {
+                    ParameterKind::Angle => round_degrees(value(*code)?.to_degrees()),
+                    _ => value(*code)?,
+                };
+                proj.push(format!("+{key}={value}"));
+            }
+            match method {
+                1024 => proj.push("+k=1 +nadgrids=@null".to_owned()),
+                // the spherical variants are on a sphere of the size of the ellipsoid
+                1026 => proj.push(format!("+R={}", ellipsoid.rad_conformal(value(8801)?))),
+                1027 | 1029 | 9834 => proj.push(format!("+R={}", ellipsoid.rad_auth())),
+                _ => proj.push(ellipsoid_parameters(ellipsoid)),
+            }
+        }
+        None => {
+            proj.push("+proj=longlat".to_owned());
+            proj.push(ellipsoid_parameters(ellipsoid));
+        }
+    }
+    if definition.prime_meridian != 0.0 {
+        let named = PRIME_MERIDIANS
+            .iter()
+            .find(|(_, lon)| (lon - definition.prime_meridian).abs() < 1e-8);
+        match named {
+            Some((name, _)) => proj.push(format!("+pm={name}")),
+            None => proj.push(format!("+pm={}", round_degrees(definition.prime_meridian))),
+        }
+    }
+    if definition.is_projected() {
+        let factor = definition.unit_factor;
+        match UNITS
+            .iter()
+            .find(|(_, f)| (f - factor).abs() <= 1e-12 * factor)
+        {
+            Some((name, _)) => proj.push(format!("+units={name}")),
+            None => proj.push(format!("+to_meter={factor}")),
+        }
+    }
+    proj.push("+no_defs +type=crs".to_owned());
+    Some(proj.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection_constructor::all_codes;
+    use crate::{get_projection, get_projection_params};
+    use miniproj_ops::coordinate::{GeocentricCoordinate, Geographic3DCoordinate};
+    use miniproj_ops::CoordOperation;
+
+    /// Assert that the definition projects like the registry CRS `code` around the point `(x, y)`
+    /// in metres, to a millimetre.
+    fn assert_like_registry(definition: &CrsDefinition, code: u32, x: f64, y: f64) {
+        let projection = definition.to_projection();
+        let registry = get_projection(code).unwrap();
+        for (x, y) in [(x, y), (x + 50000.0, y - 50000.0)] {
+            let (lon, lat) = projection.projected_to_deg(x, y);
+            let (lon_r, lat_r) = registry.projected_to_deg(x, y);
+            assert!(
+                (lon - lon_r).abs() < 1e-8 && (lat - lat_r).abs() < 1e-8,
+                "EPSG:{code}: {lon}, {lat} != {lon_r}, {lat_r}"
+            );
+        }
+    }
+
+    #[test]
+    fn proj_strings() {
+        let utm = parse_proj_string("+proj=utm +zone=32 +datum=WGS84 +units=m +no_defs").unwrap();
+        assert_eq!(
+            (utm.method_code(), utm.datum_name.as_str()),
+            (Some(9807), "WGS84")
+        );
+        assert_like_registry(&utm, 32632, 500000.0, 6000000.0);
+        let dms = "+proj=tmerc +lat_0=0 +lon_0=9d0'0\"E +k=0.9996 +x_0=500000 +ellps=WGS84";
+        assert_like_registry(&parse_proj_string(dms).unwrap(), 32632, 500000.0, 6000000.0);
+
+        let lambert_93 = "+proj=lcc +lat_0=46.5 +lon_0=3 +lat_1=49 +lat_2=44 +x_0=700000 \
+            +y_0=6600000 +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m +no_defs +type=crs";
+        let definition = parse_proj_string(lambert_93).unwrap();
+        assert_eq!(definition.method_code(), Some(9802));
+        assert_like_registry(&definition, 2154, 700000.0, 6600000.0);
+
+        let lambert_ii = "+proj=lcc +lat_1=46.8 +lat_0=46.8 +lon_0=0 +k_0=0.99987742 \
+            +x_0=600000 +y_0=2200000 +ellps=clrk80ign +pm=paris +units=m";
+        let definition = parse_proj_string(lambert_ii).unwrap();
+        assert_eq!(definition.method_code(), Some(9801));
+        assert!((definition.prime_meridian - 2.337229166667).abs() < 1e-12);
+        assert_like_registry(&definition, 27572, 600000.0, 2200000.0);
+
+        // without lat_2, the cone touches lat_1 and the false origin stays at lat_0
+        let tangent = parse_proj_string("+proj=lcc +lat_1=45 +lat_0=30").unwrap();
+        let secant = parse_proj_string("+proj=lcc +lat_1=45 +lat_2=45 +lat_0=30").unwrap();
+        assert_eq!(tangent.method_code(), Some(9802));
+        assert_eq!(tangent.projection, secant.projection);
+        let (x, y) = tangent.to_projection().deg_to_projected(0.0, 30.0);
+        assert!(x.abs() < 1e-6 && y.abs() < 1e-6);
+
+        // the rectified grid angle defaults to the azimuth of the centre line
+        let omerc = "+proj=omerc +lat_0=46.5 +lonc=8 +alpha=90 +k=1 +x_0=0 +y_0=0 +ellps=GRS80";
+        let rectified = parse_proj_string(&format!("{omerc} +gamma=90")).unwrap();
+        assert_eq!(
+            parse_proj_string(omerc).unwrap().projection,
+            rectified.projection
+        );
+
+        let feet = "+proj=lcc +lat_0=36.5 +lon_0=-120.5 +lat_1=38.4333333333333 \
+            +lat_2=37.0666666666667 +x_0=2000000.0001016 +y_0=500000.0001016 +datum=NAD83 \
+            +units=us-ft";
+        let definition = parse_proj_string(feet).unwrap();
+        assert_eq!(definition.unit_name, "us-ft");
+        assert!((definition.unit_factor - 0.3048006096012192).abs() < 1e-15);
+        assert_like_registry(&definition, 2227, 2000000.0, 500000.0);
+
+        let polar = "+proj=stere +lat_0=-90 +lat_ts=-71 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84";
+        let definition = parse_proj_string(polar).unwrap();
+        assert_eq!(definition.method_code(), Some(9829));
+        assert_like_registry(&definition, 3031, 100000.0, 100000.0);
+
+        let web_mercator = "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 \
+            +k=1 +units=m +nadgrids=@null +wktext +no_defs";
+        let definition = parse_proj_string(web_mercator).unwrap();
+        assert_eq!(definition.method_code(), Some(1026));
+        assert_like_registry(&definition, 3857, 1000000.0, 6000000.0);
+
+        let geographic = parse_proj_string("+proj=longlat +datum=WGS84 +axis=neu").unwrap();
+        assert!(!geographic.is_projected());
+        assert_eq!(geographic.axes, [AxisDirection::North, AxisDirection::East]);
+        let registry = parse_proj_string("+init=epsg:25832").unwrap();
+        assert_eq!(
+            (registry.epsg, registry.base_epsg),
+            (Some(25832), Some(4258))
+        );
+
+        assert_eq!(parse_angle("52d30'S"), Some(-52.5));
+        assert_eq!(parse_angle("-9d30'"), Some(-9.5));
+        assert_eq!(
+            parse_proj_string("+ellps=WGS84"),
+            Err(ProjStringError::MissingProj)
+        );
+        let robinson = ProjStringError::UnsupportedProjection("robin".into());
+        assert_eq!(parse_proj_string("+proj=robin +lon_0=0"), Err(robinson));
+        let mercator = ProjStringError::UnsupportedProjection("merc".into());
+        assert_eq!(parse_proj_string("+proj=merc +ellps=WGS84"), Err(mercator));
+        let lat_ts = ProjStringError::UnsupportedProjection("merc +lat_ts".into());
+        assert_eq!(
+            parse_proj_string("+proj=merc +R=6371000 +lat_ts=60"),
+            Err(lat_ts)
+        );
+        let lat_0 = ProjStringError::UnsupportedProjection("eqc +lat_0".into());
+        assert_eq!(parse_proj_string("+proj=eqc +lat_0=10"), Err(lat_0));
+        assert!(parse_proj_string("+proj=eqc +lat_ts=0 +lat_0=0 +datum=WGS84").is_ok());
+        let ellps = ProjStringError::UnknownName("unheard".into());
+        assert_eq!(parse_proj_string("+proj=tmerc +ellps=unheard"), Err(ellps));
+        let zone = ProjStringError::InvalidValue("zone".into());
+        assert_eq!(parse_proj_string("+proj=utm +zone=61"), Err(zone));
+        let error = parse_proj_string("+proj=tmerc +lat_0=north").unwrap_err();
+        eprintln!("{error}");
+        assert_eq!(error, ProjStringError::InvalidValue("lat_0".into()));
+    }
+
+    #[test]
+    fn proj_pipelines() {
+        // WGS 72 to WGS 84 (1), a position vector transformation
+        let proj = "+proj=pipeline +step +proj=unitconvert +xy_in=deg +xy_out=rad \
+            +step +proj=cart +ellps=WGS72 +step +proj=helmert +x=0 +y=0 +z=4.5 +rx=0 +ry=0 \
+            +rz=0.554 +s=0.219 +convention=position_vector +step +inv +proj=cart \
+            +step +proj=unitconvert +xy_in=rad +xy_out=deg";
+        let pipeline = parse_proj_pipeline(proj).unwrap();
+        assert_eq!(pipeline.len(), 3);
+        let from = Geographic3DCoordinate::from_deg(4.0, 55.0, 0.0);
+        let to: Geographic3DCoordinate = pipeline.apply(from);
+        let goal = crate::get_coord_operation(1238).unwrap().apply(from);
+        eprintln!("{:?} - {:?}", to.to_deg(), goal.to_deg());
+        assert!((to.lon - goal.lon).abs() < 1e-12 && (to.lat - goal.lat).abs() < 1e-12);
+        assert!((to.height - goal.height).abs() < 1e-6);
+        let back: Geographic3DCoordinate = pipeline.inverse().unwrap().apply(to);
+        assert!((back.lon - from.lon).abs() < 1e-12 && (back.lat - from.lat).abs() < 1e-12);
+
+        // a single step, and global parameters applying to all steps
+        let cart = parse_proj_pipeline("+proj=cart +ellps=GRS80").unwrap();
+        let geocentric: GeocentricCoordinate = cart.apply(from);
+        let global = parse_proj_pipeline("+proj=pipeline +ellps=GRS80 +step +proj=cart").unwrap();
+        let to: GeocentricCoordinate = global.apply(from);
+        assert_eq!(to, geocentric);
+        let wgs84: GeocentricCoordinate = parse_proj_pipeline("+proj=cart").unwrap().apply(from);
+        assert_ne!(wgs84, geocentric);
+
+        let rates = ProjStringError::UnsupportedProjection("helmert +dx".into());
+        let helmert = "+proj=helmert +x=1 +dx=0.1 +t_epoch=2010";
+        assert_eq!(parse_proj_pipeline(helmert).unwrap_err(), rates);
+        let convention = ProjStringError::InvalidValue("convention".into());
+        let helmert = "+proj=pipeline +step +proj=helmert +x=1 +rz=0.5";
+        assert_eq!(parse_proj_pipeline(helmert).unwrap_err(), convention);
+        let units = ProjStringError::UnsupportedProjection("unitconvert +xy_in".into());
+        let unitconvert = "+proj=unitconvert +xy_in=m +xy_out=km";
+        assert_eq!(parse_proj_pipeline(unitconvert).unwrap_err(), units);
+        let axisswap = ProjStringError::UnsupportedProjection("axisswap".into());
+        let proj = "+proj=pipeline +step +proj=axisswap +order=2,1";
+        assert_eq!(parse_proj_pipeline(proj).unwrap_err(), axisswap);
+        let pipeline = ProjStringError::UnsupportedProjection("pipeline".into());
+        assert_eq!(parse_proj_string(proj).unwrap_err(), pipeline);
+    }
+
+    #[test]
+    fn proj_string_export() {
+        for code in all_codes() {
+            let crs = Crs::from_epsg(code).unwrap();
+            let Some(proj) = crs.to_proj_string() else {
+                let method = crs.method_code().unwrap();
+                assert!(
+                    !PROJECTIONS.iter().any(|(m, ..)| *m == method),
+                    "EPSG:{code}"
+                );
+                continue;
+            };
+            let definition = crs.to_definition().unwrap();
+            let parsed = parse_proj_string(&proj).unwrap_or_else(|e| panic!("EPSG:{code}: {e}"));
+            let method = definition
+                .method_code()
+                .map(|m| if m == 1024 { 1026 } else { m });
+            assert_eq!(parsed.method_code(), method, "EPSG:{code}: {proj}");
+            // geographic PROJ strings are always in degrees
+            let unit = (parsed.unit_factor, definition.unit_factor);
+            assert!(
+                !parsed.is_projected() || (unit.0 - unit.1).abs() < 1e-12,
+                "EPSG:{code}"
+            );
+            assert!((parsed.prime_meridian - definition.prime_meridian).abs() < 1e-8);
+            if method == Some(1026) {
+                continue;
+            }
+            let params = parsed.projection.as_ref().map(ProjectionParams::to_params);
+            let expected = definition
+                .projection
+                .as_ref()
+                .map(ProjectionParams::to_params);
+            for ((c, v), (c_e, v_e)) in params.iter().flatten().zip(expected.iter().flatten()) {
+                assert_eq!(c, c_e);
+                assert!(
+                    (v - v_e).abs() <= 1e-9 * v_e.abs().max(1.0),
+                    "EPSG:{code}: {proj}"
+                );
+            }
+        }
+
+        let utm = Crs::from_epsg(32632).unwrap().to_proj_string().unwrap();
+        eprintln!("{utm}");
+        assert_eq!(
+            utm,
+            "+proj=tmerc +lat_0=0 +lon_0=9 +k=0.9996 +x_0=500000 +y_0=0 +ellps=WGS84 +units=m \
+                +no_defs +type=crs"
+        );
+        let web_mercator = Crs::from_epsg(3857).unwrap().to_proj_string().unwrap();
+        assert_like_registry(&parse_proj_string(&web_mercator).unwrap(), 3857, 1e6, 6e6);
+        let geographic = Crs::from_epsg(4807).unwrap().to_proj_string().unwrap();
+        assert_eq!(
+            geographic,
+            "+proj=longlat +ellps=clrk80ign +pm=paris +no_defs +type=crs"
+        );
+
+        // custom projections
+        let params = get_projection_params(32632).unwrap();
+        let sphere = Ellipsoid::from_a_b(6371000.0, 6371000.0);
+        let custom = CrsDefinition::new_projected("UTM 32N on a sphere", sphere, params);
+        let proj = custom.to_proj_string().unwrap();
+        assert!(proj.contains("+R=6371000 +units=m"));
+        let (x, y) = parse_proj_string(&proj)
+            .unwrap()
+            .to_projection()
+            .deg_to_projected(10.0, 54.0);
+        let (x_c, y_c) = custom.to_projection().deg_to_projected(10.0, 54.0);
+        assert!((x - x_c).abs() < 1e-6 && (y - y_c).abs() < 1e-6);
+    }
+}
@@ -1,31 +1,165 @@
 //This file is licensed under EUPL v1.2
 
 use miniproj_ops::albers_equal_area::AlbersEqualAreaProjection;
+use miniproj_ops::axis_order::{AuthorityAxisOrder, AxisDirection};
+use miniproj_ops::azimuthal_equidistant::{
+    AzimuthalEquidistantProjection, GuamProjection, ModifiedAzimuthalEquidistantProjection,
+};
+use miniproj_ops::bonne::BonneProjection;
+use miniproj_ops::cassini_soldner::{CassiniSoldnerProjection, HyperbolicCassiniSoldnerProjection};
+use miniproj_ops::colombia_urban::ColombiaUrbanProjection;
+use miniproj_ops::ellipsoid::MeridianArc;
+use miniproj_ops::equal_earth::EqualEarthProjection;
+use miniproj_ops::equidistant_cylindrical::{
+    EquidistantCylindricalProjection, EquidistantCylindricalSphericalProjection,
+};
+use miniproj_ops::hotine_oblique_mercator::{
+    HotineObliqueMercatorAProjection, HotineObliqueMercatorBProjection,
+};
 use miniproj_ops::identity_projection::IdentityProjection;
-use miniproj_ops::lambert_azimuthal_equal_area::LambertAzimuthalEqualAreaProjection;
+use miniproj_ops::krovak::KrovakProjection;
+use miniproj_ops::lambert_azimuthal_equal_area::{
+    LambertAzimuthalEqualAreaProjection, LambertAzimuthalEqualAreaSphericalProjection,
+};
 use miniproj_ops::lambert_conic_conformal::{
-    LambertConic1SPAProjection, LambertConic2SPProjection,
+    LambertConic1SPAProjection, LambertConic2SPBelgiumProjection,
+    LambertConic2SPMichiganProjection, LambertConic2SPProjection,
+    LambertConicNearConformalProjection,
 };
+use miniproj_ops::lambert_cylindrical_equal_area::{
+    LambertCylindricalEqualAreaProjection, LambertCylindricalEqualAreaSphericalProjection,
+};
+use miniproj_ops::longitude_rotation::PrimeMeridianShift;
 use miniproj_ops::popvis_pseudo_mercator::PopVisPseudoMercatorProjection;
-use miniproj_ops::stereographic::{ObliqueStereographicProjection, PolarStereographicAProjection};
-use miniproj_ops::transverse_mercator::TransverseMercatorProjection;
-use miniproj_ops::Projection;
+use miniproj_ops::projection_params::ProjectionParams;
+use miniproj_ops::stereographic::{
+    ObliqueStereographicProjection, PolarStereographicAProjection, PolarStereographicBProjection,
+};
+use miniproj_ops::transverse_mercator::{
+    TransverseMercatorProjection, TransverseMercatorSouthOrientatedProjection,
+};
+use miniproj_ops::tunisia_mining_grid::TunisiaMiningGridProjection;
+use miniproj_ops::unit::LinearUnitProjection;
+use miniproj_ops::{custom_projection, Direction, Ellipsoid, Projection};
+
+use crate::{get_ellipsoid, get_prime_meridian, get_unit, AreaOfUse, MiniprojError};
 
 include!(concat!(env!("OUT_DIR"), "/projection_constructors.rs"));
 
 /// Returns the Coordinate Reference System corresponding to the EPSG code passed as the argument.
 /// If the code refers to a projection that is not implemented, the method returns `None`
 pub fn get_projection(code: u32) -> Option<&'static dyn Projection> {
-    PROJECTIONS.get(&code).cloned()
+    let projection = PROJECTIONS.get(&code).cloned();
+    #[cfg(feature = "tracing")]
+    tracing::trace!(
+        code,
+        method = METHODS.get(&code),
+        found = projection.is_some(),
+        "resolved coordinate reference system"
+    );
+    projection
+}
+
+/// Returns the Coordinate Reference System corresponding to the EPSG code passed as the argument
+/// like [`get_projection`], with the reason why there is no projection for the code otherwise.
+pub fn try_get_projection(code: u32) -> Result<&'static dyn Projection, MiniprojError> {
+    get_projection(code).ok_or_else(|| {
+        UNSUPPORTED
+            .get(&code)
+            .copied()
+            .unwrap_or(MiniprojError::UnknownCode(code))
+    })
+}
+
+/// Converts a coordinate in the given `direction` using the Coordinate Reference System corresponding to
+/// the EPSG code passed as the first argument. Returns `None` if the projection is not implemented.
+pub fn transform(code: u32, direction: Direction, x: f64, y: f64) -> Option<(f64, f64)> {
+    get_projection(code).map(|p| p.transform(direction, x, y))
 }
 
 /// Returns the EPSG code of the ellipsoid that is associated with the projection
-/// corresponding to `projection_code`. Returns `None` if the projection is
-/// unknown.
+/// corresponding to `projection_code`, for projected and geographic CRSs. Returns `None` if the
+/// projection is unknown.
 pub fn get_ellipsoid_code(projection_code: u32) -> Option<u32> {
     ELLIPSOIDS.get(&projection_code).copied()
 }
 
+/// Returns the EPSG code of the prime meridian of the Coordinate Reference System corresponding to
+/// `code`, e.g. 8903 for Paris. Longitudes of all projections are relative to Greenwich regardless.
+/// Returns `None` if the projection is unknown.
+pub fn get_prime_meridian_code(code: u32) -> Option<u32> {
+    MERIDIANS.get(&code).copied()
+}
+
+/// Returns the EPSG code of the operation method used by the projected Coordinate Reference System
+/// corresponding to `projected_code`, e.g. 9807 for Transverse Mercator. Returns `None` if the
+/// projection is unknown or the CRS is not a projected one.
+pub fn get_method_code(projected_code: u32) -> Option<u32> {
+    METHODS.get(&projected_code).copied()
+}
+
+/// Returns the parameters of the conversion of the projected Coordinate Reference System
+/// corresponding to `projected_code`, in radians and metres. Returns `None` if the projection is
+/// unknown or the CRS is not a projected one.
+pub fn get_projection_params(projected_code: u32) -> Option<ProjectionParams> {
+    let method = get_method_code(projected_code)?;
+    ProjectionParams::from_params(method, PARAMETERS.get(&projected_code)?)
+}
+
+/// Returns the EPSG code of the geographic base CRS of the projected Coordinate Reference System
+/// corresponding to `projected_code`, e.g. 4326 for WGS 84 / UTM zone 32N. Returns `None` if the
+/// projection is unknown or the CRS is not a projected one.
+pub fn get_base_crs_code(projected_code: u32) -> Option<u32> {
+    BASES.get(&projected_code).copied()
+}
+
+/// Returns the EPSG code of the unit of the first axis of the Coordinate Reference System
+/// corresponding to `code`, e.g. 9001 for metres or 9122 for degrees, see `get_unit`.
+/// Returns `None` if the projection is unknown.
+pub fn get_unit_code(code: u32) -> Option<u32> {
+    UNITS.get(&code).copied()
+}
+
+/// Returns the projection of the projected Coordinate Reference System corresponding to `code` like
+/// [`get_projection`], but taking and returning projected coordinates in the unit of the axes of
+/// the CRS, e.g. US survey feet for NAD83 / California zone 3 (ftUS), instead of metres.
+/// Returns `None` if the projection is not implemented or the CRS is not a projected one.
+pub fn get_projection_native_unit(
+    code: u32,
+) -> Option<LinearUnitProjection<&'static dyn Projection>> {
+    get_method_code(code)?;
+    let unit = get_unit(get_unit_code(code)?)?;
+    LinearUnitProjection::with_unit(get_projection(code)?, unit)
+}
+
+/// Returns the directions of the first two axes of the Coordinate Reference System corresponding to
+/// `code` in authority order, e.g. `[North, East]` for WGS 84. Returns `None` if the projection is
+/// unknown.
+pub fn get_axis_directions(code: u32) -> Option<[AxisDirection; 2]> {
+    AXES.get(&code).copied()
+}
+
+/// Returns the projection of the Coordinate Reference System corresponding to `code` like
+/// [`get_projection`], but taking and returning projected coordinates in the axis order of the
+/// EPSG registry, e.g. `(latitude, longitude)` for WGS 84. Returns `None` if the projection is not
+/// implemented.
+pub fn get_projection_authority_order(
+    code: u32,
+) -> Option<AuthorityAxisOrder<&'static dyn Projection>> {
+    let axes = get_axis_directions(code).unwrap_or([AxisDirection::East, AxisDirection::North]);
+    Some(AuthorityAxisOrder::new(get_projection(code)?, axes))
+}
+
+/// Returns the name of the implemented Coordinate Reference System corresponding to `code`.
+pub(crate) fn crs_name(code: u32) -> Option<&'static str> {
+    NAMES.get(&code).copied()
+}
+
+/// Iterates over the codes of all implemented Coordinate Reference Systems.
+pub(crate) fn all_codes() -> impl Iterator<Item = u32> {
+    PROJECTIONS.keys().copied()
+}
+
 /// Returns the Name of the Coordinate Reference System. This is a temporary method that will be removed.
 #[deprecated]
 pub fn get_reference_system_name(code: u32) -> Option<&'static str> {
@@ -33,24 +167,199 @@ pub fn get_reference_system_name(code: u32) -> Option<&'static str> {
 }
 
 /// Returns one or multiple geographic areas that the reference system applies to.
-/// Values are in `[east, north, west, south]`` order. This is a temporary method that will be removed.
+/// Values are in `[east, north, west, south]`` order. This is a temporary method that will be removed,
+/// use `get_areas_of_use` instead.
 #[deprecated]
 pub fn get_reference_system_areas(code: u32) -> Option<&'static [[f64; 4]]> {
     AREAS.get(&code).filter(|a| !a.is_empty()).copied()
 }
 
+/// Returns the geographic areas that the Coordinate Reference System corresponding to `code`
+/// applies to. Returns `None` if the projection is unknown or the CRS has no area of use.
+pub fn get_areas_of_use(code: u32) -> Option<&'static [AreaOfUse]> {
+    AREAS_OF_USE.get(&code).filter(|a| !a.is_empty()).copied()
+}
+
+/// Returns the EPSG codes of all implemented Coordinate Reference Systems with an area of use whose
+/// name contains `region`, ignoring case, e.g. `"Norway"` or `"Germany - onshore"`. The codes are
+/// returned in ascending order.
+pub fn crs_for_region(region: &str) -> Vec<u32> {
+    let region = region.to_lowercase();
+    let mut codes = AREAS_OF_USE
+        .entries()
+        .filter(|(_, areas)| {
+            areas
+                .iter()
+                .any(|a| a.name.to_lowercase().contains(&region))
+        })
+        .map(|(c, _)| *c)
+        .collect::<Vec<_>>();
+    codes.sort_unstable();
+    codes
+}
+
+/// Returns the EPSG codes of all implemented Coordinate Reference Systems whose area of use
+/// contains the position in degrees, only projected ones if `projected_only` is set. CRSs with the
+/// smallest area of use come first, so the first code is usually a sensible local CRS for the position.
+pub fn crs_candidates_for(lon: f64, lat: f64, projected_only: bool) -> Vec<u32> {
+    crs_candidates_for_bbox(AreaOfUse::new(lon, lat, lon, lat), projected_only)
+}
+
+/// Returns the EPSG codes of all implemented Coordinate Reference Systems whose area of use covers
+/// all of `bbox`, only projected ones if `projected_only` is set, like [`crs_candidates_for`].
+pub fn crs_candidates_for_bbox(bbox: AreaOfUse, projected_only: bool) -> Vec<u32> {
+    let mut candidates = AREAS_OF_USE
+        .entries()
+        .filter(|(c, _)| !projected_only || METHODS.contains_key(c))
+        .filter_map(|(c, areas)| {
+            let extent = areas
+                .iter()
+                .filter(|a| a.covers(&bbox))
+                .map(AreaOfUse::extent)
+                .min_by(f64::total_cmp)?;
+            Some((extent, *c))
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_unstable_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+    candidates.into_iter().map(|(_, c)| c).collect()
+}
+
+/// Returns the EPSG codes and names of all implemented Coordinate Reference Systems matching
+/// `query`, ignoring case, e.g. for a CRS picker. A name matches if it contains every
+/// whitespace-separated token of the query, so `"utm 32n wgs 84"` finds WGS 84 / UTM zone 32N.
+/// A query like `"EPSG:25832"` or `"25832"` matches the code as well.
+///
+/// Exact names come first, followed by names starting with the query, names containing the query
+/// as a whole and names matching the tokens in any order; shorter names first within each group.
+pub fn search_crs(query: &str) -> Vec<(u32, &'static str)> {
+    let query = query.trim().to_lowercase();
+    let tokens = query.split_whitespace().collect::<Vec<_>>();
+    if tokens.is_empty() {
+        return Vec::new();
+    }
+    let code = query
+        .strip_prefix("epsg:")
+        .unwrap_or(&query)
+        .trim()
+        .parse::<u32>()
+        .ok();
+    let mut matches = NAMES
+        .entries()
+        .filter_map(|(c, name)| {
+            let lower = name.to_lowercase();
+            let rank = if Some(*c) == code || lower == query {
+                0
+            } else if lower.starts_with(&query) {
+                1
+            } else if lower.contains(&query) {
+                2
+            } else if tokens.iter().all(|t| lower.contains(t)) {
+                3
+            } else {
+                return None;
+            };
+            Some((rank, name.len(), *c, *name))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_unstable();
+    matches
+        .into_iter()
+        .map(|(_, _, c, name)| (c, name))
+        .collect()
+}
+
+/// Returns the bounding box of the Coordinate Reference System's area of use in projected coordinates.
+/// Values are in `[east, north, west, south]` order, i.e. `[max x, max y, min x, min y]`, matching
+/// the order of the geographic areas. The boxes are computed when building the crate by sampling the
+/// area of use on a grid including its edges, so they are exact for edges that map to straight lines
+/// and a close approximation otherwise.
+/// Returns `None` if the projection is not implemented or the CRS has no area of use.
+pub fn projected_bounds(code: u32) -> Option<[f64; 4]> {
+    PROJECTED_BOUNDS.get(&code).copied()
+}
+
+/// Radius of the sphere replacing the ellipsoid of a Coordinate Reference System in
+/// [`create_projection_spherical_with`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum SphereRadius {
+    /// Radius of the sphere with the surface area of the ellipsoid, preserving areas overall
+    #[default]
+    Authalic,
+    /// Mean radius `(2a + b) / 3` of the ellipsoid
+    Mean,
+    /// Radius of the conformal sphere at the given latitude in degrees, matching the scale of the
+    /// ellipsoid around that latitude
+    Conformal(f64),
+    /// Semi-major axis of the ellipsoid, like the Popular Visualisation Pseudo Mercator
+    SemiMajor,
+}
+
+impl SphereRadius {
+    /// Radius in the units of the ellipsoid's axes.
+    pub fn of(&self, ellipsoid: &Ellipsoid) -> f64 {
+        match self {
+            Self::Authalic => ellipsoid.rad_auth(),
+            Self::Mean => (2.0 * ellipsoid.a() + ellipsoid.b()) / 3.0,
+            Self::Conformal(lat) => ellipsoid.rad_conformal(lat.to_radians()),
+            Self::SemiMajor => ellipsoid.a(),
+        }
+    }
+}
+
+/// Creates the projection corresponding to the EPSG code passed as the argument on a sphere instead
+/// of the Coordinate Reference System's ellipsoid, for visualisation where speed matters more than
+/// accuracy. The sphere has the authalic radius of the ellipsoid, positions may be off by several
+/// kilometers compared to `get_projection`.
+/// Returns `None` if the projection is not implemented or the CRS has no ellipsoid.
+pub fn create_projection_spherical(code: u32) -> Option<Box<dyn Projection>> {
+    create_projection_spherical_with(code, SphereRadius::default())
+}
+
+/// Creates the projection corresponding to the EPSG code passed as the argument on a sphere like
+/// [`create_projection_spherical`], with the `radius` of the sphere derived from the ellipsoid as
+/// chosen.
+/// Returns `None` if the projection is not implemented or the CRS has no ellipsoid.
+pub fn create_projection_spherical_with(
+    code: u32,
+    radius: SphereRadius,
+) -> Option<Box<dyn Projection>> {
+    let radius = radius.of(get_ellipsoid(*ELLIPSOIDS.get(&code)?)?);
+    create_projection(code, &Ellipsoid::from_a_b(radius, radius))
+}
+
+/// Creates the projection corresponding to the EPSG code passed as the argument, using the passed
+/// ellipsoid instead of the one of the Coordinate Reference System's datum, e.g. for model output
+/// that reuses a national grid definition on a spherical earth.
+/// The `&Ellipsoid` is not held by the returned projection, if you want the projection for a
+/// different ellipsoid you need to construct it again.
+/// Returns `None` if the projection is not implemented.
+pub fn create_projection(code: u32, ellipsoid: &Ellipsoid) -> Option<Box<dyn Projection>> {
+    let projection = if let Some(method) = METHODS.get(&code) {
+        let params = PARAMETERS.get(&code)?;
+        custom_projection(
+            *method,
+            |c| params.iter().find_map(|(p, v)| (*p == c).then_some(*v)),
+            ellipsoid,
+        )?
+    } else {
+        // geographic coordinate reference systems
+        PROJECTIONS.get(&code)?;
+        Box::new(IdentityProjection) as Box<dyn Projection>
+    };
+    match MERIDIANS.get(&code) {
+        Some(8901) | None => Some(projection),
+        Some(meridian) => Some(Box::new(PrimeMeridianShift::new(
+            projection,
+            get_prime_meridian(*meridian)?,
+        ))),
+    }
+}
+
 #[deprecated]
 pub fn all_names() -> impl Iterator<Item = (u32, &'static str)> {
     NAMES.entries().map(|(c, n)| (*c, *n))
 }
 
-/// Create the Projection corresponding to the EPSG code passed as the argument, using the passed ellipsoid.
-/// The `&Ellipsoid` is not held by the returned projection, if you want the projection for a different
-/// ellipsoid you need to construct it again.
-//pub fn create_projection(code: u32, ellipsoid: &Ellipsoid) -> Option<Box<dyn Projection>> {
-//    todo!()
-//}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,4 +370,320 @@ mod tests {
 
         is_send_sync(get_projection(4326));
     }
+
+    #[test]
+    fn projection_errors() {
+        assert!(try_get_projection(32632).is_ok());
+        assert_eq!(
+            try_get_projection(1).err(),
+            Some(MiniprojError::UnknownCode(1))
+        );
+        let geocentric = try_get_projection(4978).err().unwrap();
+        eprintln!("{geocentric}");
+        assert_eq!(
+            geocentric,
+            MiniprojError::UnsupportedCrsKind {
+                code: 4978,
+                kind: "geocentric"
+            }
+        );
+        assert_eq!(geocentric.code(), 4978);
+        // Tananarive (Paris) / Laborde Grid uses the Laborde Oblique Mercator method
+        let laborde = MiniprojError::MethodNotImplemented {
+            code: 29701,
+            method: 9813,
+        };
+        assert_eq!(try_get_projection(29701).err(), Some(laborde));
+        for code in all_codes().take(100) {
+            assert!(try_get_projection(code).is_ok());
+        }
+    }
+
+    #[test]
+    fn method_codes() {
+        assert_eq!(get_method_code(32632), Some(9807));
+        assert_eq!(get_method_code(3857), Some(1024));
+        assert_eq!(get_method_code(2056), Some(9815));
+        assert_eq!(get_method_code(4326), None);
+        assert_eq!(get_base_crs_code(32632), Some(4326));
+        assert_eq!(get_base_crs_code(4326), None);
+        assert_eq!(get_unit_code(32632), Some(9001));
+        assert_eq!(get_ellipsoid_code(4326), Some(7030));
+    }
+
+    #[test]
+    fn crs_candidates() {
+        // Kiel
+        let candidates = crs_candidates_for(10.18, 54.33, true);
+        eprintln!("{:?}", &candidates[..candidates.len().min(10)]);
+        assert!(candidates.contains(&32632) && candidates.contains(&25832));
+        assert!(!candidates.contains(&32633) && !candidates.contains(&4326));
+        assert!(crs_candidates_for(10.18, 54.33, false).contains(&4326));
+        let bbox = AreaOfUse::new(9.0, 53.0, 11.0, 55.0);
+        let candidates = crs_candidates_for_bbox(bbox, true);
+        assert!(candidates.contains(&25832) && !candidates.contains(&25833));
+        // UTM zone 32N does not cover a box across the zone boundary
+        let bbox = AreaOfUse::new(11.0, 53.0, 13.0, 55.0);
+        assert!(!crs_candidates_for_bbox(bbox, true).contains(&32632));
+    }
+
+    #[test]
+    fn crs_search() {
+        let results = search_crs("utm 32n WGS");
+        eprintln!("{results:?}");
+        assert!(results.contains(&(32632, "WGS 84 / UTM zone 32N")));
+        assert_eq!(
+            search_crs("utm 32n WGS 84")[0],
+            (32632, "WGS 84 / UTM zone 32N")
+        );
+        assert!(results
+            .iter()
+            .all(|(_, n)| n.contains("32N") && n.contains("WGS")));
+        assert_eq!(search_crs("wgs 84")[0], (4326, "WGS 84"));
+        assert_eq!(search_crs("EPSG:25832")[0].0, 25832);
+        assert!(search_crs("  ").is_empty());
+        assert!(search_crs("no such reference system").is_empty());
+    }
+
+    #[test]
+    fn native_units() {
+        // NAD83 / California zone 3 (ftUS)
+        assert_eq!(get_unit_code(2227), Some(9003));
+        let metres = get_projection(2227).unwrap();
+        let feet = get_projection_native_unit(2227).unwrap();
+        let (x, y) = metres.deg_to_projected(-120.5, 36.5);
+        // false origin at 6561666.667 ftUS, 1640416.667 ftUS
+        assert!((x - 2000000.0).abs() < 1e-3 && (y - 500000.0).abs() < 1e-3);
+        let (x, y) = feet.deg_to_projected(-120.5, 36.5);
+        assert!((x - 6561666.667).abs() < 1e-3 && (y - 1640416.667).abs() < 1e-3);
+        assert_eq!(get_projection_native_unit(32632).unwrap().to_metres, 1.0);
+        assert!(get_projection_native_unit(4326).is_none());
+    }
+
+    #[test]
+    fn axis_order() {
+        use AxisDirection::*;
+        assert_eq!(get_axis_directions(4326), Some([North, East]));
+        assert_eq!(get_axis_directions(32632), Some([East, North]));
+        // SWEREF99 TM has northing first, Hartebeesthoek94 / Lo29 westing and southing
+        assert_eq!(get_axis_directions(3006), Some([North, East]));
+        assert_eq!(get_axis_directions(2053), Some([West, South]));
+
+        let wgs84 = get_projection_authority_order(4326).unwrap();
+        assert_eq!(wgs84.deg_to_projected(10.0, 54.0), (54.0, 10.0));
+        let sweref = get_projection_authority_order(3006).unwrap();
+        let (easting, northing) = get_projection(3006).unwrap().deg_to_projected(15.0, 60.0);
+        assert_eq!(sweref.deg_to_projected(15.0, 60.0), (northing, easting));
+        let lo29 = get_projection_authority_order(2053).unwrap();
+        assert!(!lo29.swaps());
+    }
+
+    #[test]
+    fn region_lookup() {
+        let norway = crs_for_region("norway");
+        eprintln!("{norway:?}");
+        // ETRS89 / NTM zone 5
+        assert!(norway.contains(&5105));
+        assert!(!norway.contains(&32632));
+        // DHDN / 3-degree Gauss-Kruger zone 3
+        assert!(crs_for_region("germany").contains(&31467));
+        assert!(crs_for_region("Atlantis").is_empty());
+    }
+
+    #[test]
+    fn transform_directions() {
+        let projection = get_projection(32632).unwrap();
+        let (x, y) = (576935.86, 6020593.46);
+        assert_eq!(
+            transform(32632, Direction::ProjectedToDeg, x, y),
+            Some(projection.projected_to_deg(x, y))
+        );
+        assert_eq!(
+            transform(32632, Direction::ProjectedToRad, x, y),
+            Some(projection.projected_to_rad(x, y))
+        );
+        let (lon, lat) = projection.projected_to_deg(x, y);
+        assert_eq!(
+            transform(32632, Direction::DegToProjected, lon, lat),
+            Some(projection.deg_to_projected(lon, lat))
+        );
+        assert_eq!(
+            transform(
+                32632,
+                Direction::RadToProjected,
+                lon.to_radians(),
+                lat.to_radians()
+            ),
+            Some(projection.rad_to_projected(lon.to_radians(), lat.to_radians()))
+        );
+        assert_eq!(transform(1, Direction::ProjectedToDeg, x, y), None);
+    }
+
+    #[test]
+    fn spherical_approximation() {
+        for code in [32632, 3035, 2154, 28992, 3857] {
+            let exact = get_projection(code).unwrap();
+            let spherical = create_projection_spherical(code).expect("Projection not implemented.");
+            let (x, y) = exact.deg_to_projected(10.0, 54.0);
+            let (x_s, y_s) = spherical.deg_to_projected(10.0, 54.0);
+            let (lon, lat) = spherical.projected_to_deg(x_s, y_s);
+            eprintln!("EPSG:{code}: {x}, {y} - {x_s}, {y_s}");
+            assert!((x - x_s).hypot(y - y_s) < 50_000.0);
+            assert!((lon - 10.0).abs() < 1e-6 && (lat - 54.0).abs() < 1e-6);
+        }
+        let wgs84 = get_ellipsoid(7030).unwrap();
+        assert_eq!(SphereRadius::SemiMajor.of(wgs84), 6378137.0);
+        assert!((SphereRadius::Authalic.of(wgs84) - 6371007.181).abs() < 1e-3);
+        let grs80 = get_ellipsoid(7019).unwrap();
+        assert!((SphereRadius::Mean.of(grs80) - 6371008.7714).abs() < 1e-3);
+        // the conformal sphere at the equator has the radius of the semi-minor axis
+        assert!((SphereRadius::Conformal(0.0).of(wgs84) - 6356752.314).abs() < 1e-3);
+        // Pseudo Mercator already projects on the sphere with the semi-major axis as radius
+        let (x, y) = get_projection(3857).unwrap().deg_to_projected(10.0, 54.0);
+        let (x_s, y_s) = create_projection_spherical_with(3857, SphereRadius::SemiMajor)
+            .unwrap()
+            .deg_to_projected(10.0, 54.0);
+        assert!((x - x_s).abs() < 1e-6 && (y - y_s).abs() < 1e-6);
+        assert!(create_projection_spherical_with(4326, SphereRadius::SemiMajor).is_some());
+        assert!(create_projection_spherical(1).is_none());
+    }
+
+    #[test]
+    fn mercator_c_from_registry_params() {
+        // no CRS of the registry uses Mercator (variant C), so the EPSG example Pulkovo 1942 /
+        // Caspian Sea Mercator is constructed from its parameters in the registry's units
+        let params = [
+            (8802, 51f64.to_radians()),
+            (8821, 42f64.to_radians()),
+            (8823, 42f64.to_radians()),
+            (8826, 0.0),
+            (8827, 0.0),
+        ];
+        let krassowsky = get_ellipsoid(7024).unwrap();
+        let projection = custom_projection(
+            1044,
+            |c| params.iter().find_map(|(p, v)| (*p == c).then_some(*v)),
+            krassowsky,
+        )
+        .expect("Projection not implemented.");
+        let (x, y) = projection.deg_to_projected(53.0, 53.0);
+        eprintln!("{x}, {y}");
+        assert!((x - 165704.29).abs() < 0.01 && (y - 1351950.22).abs() < 0.01);
+    }
+
+    #[test]
+    fn mercator_spherical_from_registry_params() {
+        // no CRS of the registry uses Mercator (Spherical), so the EPSG example is constructed from
+        // its parameters in the registry's units
+        let params = [(8801, 0.0), (8802, 0.0), (8806, 0.0), (8807, 0.0)];
+        let sphere = Ellipsoid::from_a_b(6371007.0, 6371007.0);
+        let projection = custom_projection(
+            1026,
+            |c| params.iter().find_map(|(p, v)| (*p == c).then_some(*v)),
+            &sphere,
+        )
+        .expect("Projection not implemented.");
+        let (x, y) = projection.rad_to_projected(-1.751147016, 0.425542460);
+        eprintln!("{x}, {y}");
+        assert!((x + 11156569.90).abs() < 0.01 && (y - 2796869.94).abs() < 0.01);
+    }
+
+    #[test]
+    fn projection_on_other_ellipsoid() {
+        // the datum's own ellipsoid reproduces the projection of the registry
+        let (x, y) = get_projection(32632).unwrap().deg_to_projected(10.0, 54.0);
+        let wgs84 = get_ellipsoid(7030).unwrap();
+        let projection = create_projection(32632, wgs84).expect("Projection not implemented.");
+        assert_eq!(projection.deg_to_projected(10.0, 54.0), (x, y));
+
+        // British National Grid on a sphere
+        let sphere = Ellipsoid::from_a_b(6371000.0, 6371000.0);
+        let spherical = create_projection(27700, &sphere).expect("Projection not implemented.");
+        let (x, y) = spherical.deg_to_projected(-2.0, 49.0);
+        eprintln!("{x}, {y}");
+        assert!((x - 400000.0).abs() < 1e-6 && (y + 100000.0).abs() < 1e-6);
+        let (lon, lat) = spherical.projected_to_deg(530000.0, 180000.0);
+        let (x, y) = spherical.deg_to_projected(lon, lat);
+        assert!((x - 530000.0).abs() < 1e-6 && (y - 180000.0).abs() < 1e-6);
+        assert!(create_projection(4326, &sphere).is_some());
+        assert!(create_projection(1, &sphere).is_none());
+    }
+
+    #[test]
+    fn swiss_lv95() {
+        let projection = get_projection(2056).expect("Projection not implemented.");
+        // the old observatory of Bern is the projection centre of CH1903+ / LV95
+        let (x, y) = projection.deg_to_projected(
+            7.0 + 26.0 / 60.0 + 22.5 / 3600.0,
+            46.0 + 57.0 / 60.0 + 8.66 / 3600.0,
+        );
+        eprintln!("{x}, {y}");
+        assert!((x - 2600000.0).abs() < 1.0 && (y - 1200000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn belge_lambert_72() {
+        // the regular 2SP definition of EPSG:31370 replaced the 2SP Belgium one of EPSG:31300
+        let belgium = get_projection(31300).expect("Projection not implemented.");
+        let regular = get_projection(31370).expect("Projection not implemented.");
+        for (lon, lat) in [(4.35, 50.85), (2.6, 51.1), (6.3, 49.6)] {
+            let (x, y) = belgium.deg_to_projected(lon, lat);
+            let (x_r, y_r) = regular.deg_to_projected(lon, lat);
+            eprintln!("{x}, {y} - {x_r}, {y_r}");
+            assert!((x - x_r).hypot(y - y_r) < 0.1);
+        }
+    }
+
+    #[test]
+    fn non_greenwich_prime_meridians() {
+        // 2.5969213 grads
+        let paris = 2.5969213 * 0.9;
+        assert_eq!(get_prime_meridian_code(27572), Some(8903));
+        assert_eq!(get_prime_meridian_code(4326), Some(8901));
+
+        // longitudes of NTF (Paris) are relative to the Paris meridian
+        let ntf = get_projection(4807).expect("Projection not implemented.");
+        let (lon, lat) = ntf.projected_to_deg(0.0, 46.8);
+        eprintln!("{paris} - {lon}, {lat}");
+        assert!((lon - paris).abs() < 1e-9 && (lat - 46.8).abs() < 1e-12);
+
+        // the projection centre of NTF (Paris) / Lambert zone II lies on the Paris meridian
+        let lambert = get_projection(27572).expect("Projection not implemented.");
+        let (lon, lat) = lambert.projected_to_deg(600000.0, 2200000.0);
+        eprintln!("{paris}, 46.8 - {lon}, {lat}");
+        assert!((lon - paris).abs() < 1e-9 && (lat - 46.8).abs() < 1e-9);
+
+        // MGI (Ferro) / Austria West Zone has its central meridian at 28° east of Ferro
+        let austria = get_projection(31281).expect("Projection not implemented.");
+        let (x, _) = austria.deg_to_projected(28.0 - 17.0 - 40.0 / 60.0, 47.0);
+        eprintln!("{x}");
+        assert!(x.abs() < 1e-6);
+
+        // Lisbon (Lisbon) / Portuguese National Grid has its natural origin 1° east of Lisbon
+        let portugal = get_projection(20790).expect("Projection not implemented.");
+        let lon = 1.0 - 9.0 - 7.0 / 60.0 - 54.862 / 3600.0;
+        let (x, y) = portugal.deg_to_projected(lon, 39.0 + 40.0 / 60.0);
+        eprintln!("{x}, {y}");
+        assert!((x - 200000.0).abs() < 1e-6 && (y - 300000.0).abs() < 1e-6);
+
+        for code in [4807, 27572, 31281, 20790, 22300] {
+            let spherical = create_projection_spherical(code).expect("Projection not implemented.");
+            let (x, y) = get_projection(code).unwrap().deg_to_projected(5.0, 45.0);
+            let (lon, lat) = spherical.projected_to_deg(x, y);
+            eprintln!("EPSG:{code}: {lon}, {lat}");
+            assert!((lon - 5.0).abs() < 0.5 && (lat - 45.0).abs() < 0.5);
+        }
+    }
+
+    #[test]
+    fn utm_projected_bounds() {
+        let [east, north, west, south] =
+            projected_bounds(32632).expect("Projection not implemented.");
+        eprintln!("{east}, {north}, {west}, {south}");
+        // UTM zone 32N covers 6°E to 12°E between the equator and 84°N
+        assert!(west < 500000.0 && east > 500000.0);
+        assert!(south.abs() < 1e-6 && north > 9000000.0);
+        assert!((west..=east).contains(&576935.86));
+        assert!((south..=north).contains(&6020593.46));
+    }
 }
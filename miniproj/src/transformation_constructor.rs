@@ -0,0 +1,1274 @@
+//This file is licensed under EUPL v1.2
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use miniproj_ops::concatenated::ConcatenatedCoordOp;
+use miniproj_ops::coordinate::{
+    GeocentricCoordinate, Geographic2DCoordinateUserVertical, Geographic3DCoordinate,
+};
+use miniproj_ops::ellipsoid::Ellipsoid;
+use miniproj_ops::epoch::Epoch;
+use miniproj_ops::geocentric_conversion::GeocentricConversion;
+use miniproj_ops::geographic_3d_to_2d::Geographic3DTo2DConversion;
+use miniproj_ops::geographic_offsets::Geographic2DOffsetsTransformation;
+use miniproj_ops::geographic_offsets::{
+    Geographic3DOffsetsParams, Geographic3DOffsetsTransformation,
+};
+use miniproj_ops::helmert::{
+    GeographicHelmertTransformation, HelmertParams, HelmertTimeDependentTransformation,
+    HelmertTransformation,
+};
+use miniproj_ops::molodensky_badekas::{
+    GeographicMolodenskyBadekasTransformation, MolodenskyBadekasTransformation,
+};
+use miniproj_ops::point_motion::{
+    GeocentricPointMotionParams, GeocentricPointMotionTransformation,
+};
+use miniproj_ops::vertical_offset::{
+    VerticalOffsetAndSlopeTransformation, VerticalOffsetParams, VerticalOffsetTransformation,
+};
+use miniproj_ops::CoordOperation;
+
+use crate::{get_vertical_crs, AreaOfUse};
+
+include!(concat!(env!("OUT_DIR"), "/transformation_constructors.rs"));
+include!(concat!(
+    env!("OUT_DIR"),
+    "/vertical_transformation_constructors.rs"
+));
+include!(concat!(env!("OUT_DIR"), "/helmert_constructors.rs"));
+include!(concat!(
+    env!("OUT_DIR"),
+    "/geocentric_conversion_constructors.rs"
+));
+
+/// Returns the transformation corresponding to the EPSG coordinate operation code passed as the
+/// argument, acting on coordinates in the source CRS of the operation.
+/// If the code refers to an operation whose method is not implemented, the method returns `None`.
+pub fn get_coord_operation(
+    code: u32,
+) -> Option<&'static dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>> {
+    TRANSFORMATIONS.get(&code).copied()
+}
+
+/// Returns the concatenated operation corresponding to the EPSG coordinate operation code passed as
+/// the argument, applying its steps in the direction the registry chains them in.
+/// If the code does not refer to a concatenated operation between geographic CRSs or one of its
+/// steps is not implemented, the method returns `None`.
+pub fn get_concatenated_coord_operation(
+    code: u32,
+) -> Option<Box<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>> {
+    concatenate(CONCATENATED_TRANSFORMATIONS.get(&code)?.to_vec())
+}
+
+/// Returns the transformations between the geographic Coordinate Reference Systems `from` and `to`
+/// with the fewest steps, as the EPSG codes of the operations and whether they are applied in
+/// reverse. Of the routes with the fewest steps, the one with the best summed accuracy is chosen.
+/// Deprecated operations are not used. Returns `None` if there is no route between the CRSs.
+pub fn get_transformation_path(from: u32, to: u32) -> Option<Vec<(u32, bool)>> {
+    find_path(&TRANSFORMATION_GRAPH, from, to)
+}
+
+/// Step of a route between geographic Coordinate Reference Systems, as returned by
+/// [`describe_transformation`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransformationStep {
+    /// EPSG code of the operation.
+    pub code: u32,
+    /// Name of the operation in the registry.
+    pub name: &'static str,
+    /// Whether the operation is applied in reverse.
+    pub reversed: bool,
+    /// EPSG code of the CRS the step starts in.
+    pub from: u32,
+    /// EPSG code of the CRS the step ends in.
+    pub to: u32,
+    /// Accuracy of the operation in meters, if known.
+    pub accuracy: Option<f64>,
+}
+
+impl std::fmt::Display for TransformationStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EPSG:{} {:?} from EPSG:{} to EPSG:{}",
+            self.code, self.name, self.from, self.to
+        )?;
+        if self.reversed {
+            write!(f, ", reversed")?;
+        }
+        match self.accuracy {
+            Some(accuracy) => write!(f, ", accuracy {accuracy} m"),
+            None => write!(f, ", unknown accuracy"),
+        }
+    }
+}
+
+/// Describes the route from the geographic Coordinate Reference System `from` to `to` found by
+/// [`get_transformation_path`], with the names, CRSs and accuracies of its operations.
+/// Returns `None` if there is no route between the CRSs.
+pub fn describe_transformation(from: u32, to: u32) -> Option<Vec<TransformationStep>> {
+    describe_transformation_path(from, &get_transformation_path(from, to)?)
+}
+
+/// Describes a route starting in the geographic Coordinate Reference System `from`, given as the
+/// EPSG codes of the operations and whether they are applied in reverse, like the routes returned by
+/// [`get_transformation_path`] and its variants. Returns `None` if a step does not continue from
+/// the CRS the previous step ended in, or is not part of the routing graph.
+pub fn describe_transformation_path(
+    from: u32,
+    path: &[(u32, bool)],
+) -> Option<Vec<TransformationStep>> {
+    let mut crs = from;
+    path.iter()
+        .map(|&(code, reversed)| {
+            let &(target, _, _, accuracy) = TRANSFORMATION_GRAPH
+                .get(&crs)?
+                .iter()
+                .find(|&&(_, c, r, _)| c == code && r == reversed)?;
+            let step = TransformationStep {
+                code,
+                name: TRANSFORMATION_NAMES.get(&code).copied().unwrap_or_default(),
+                reversed,
+                from: crs,
+                to: target,
+                accuracy: accuracy.is_finite().then_some(accuracy),
+            };
+            crs = target;
+            Some(step)
+        })
+        .collect()
+}
+
+/// Finds the route with the fewest steps and, of those, the best summed accuracy in `graph`.
+fn find_path(
+    graph: &phf::Map<u32, &[(u32, u32, bool, f64)]>,
+    from: u32,
+    to: u32,
+) -> Option<Vec<(u32, bool)>> {
+    find_path_where(graph, from, to, |_| true)
+}
+
+/// Like [`find_path`], only using the operations for which `usable` returns `true`.
+fn find_path_where(
+    graph: &phf::Map<u32, &[(u32, u32, bool, f64)]>,
+    from: u32,
+    to: u32,
+    usable: impl Fn(u32) -> bool,
+) -> Option<Vec<(u32, bool)>> {
+    // best predecessor of every reached CRS
+    let mut reached: HashMap<u32, Option<Step>> = HashMap::from([(from, None)]);
+    let mut frontier = vec![(from, 0.0)];
+    while !reached.contains_key(&to) {
+        // reached in this step, as (accumulated accuracy, predecessor) per CRS
+        let mut next: HashMap<u32, (f64, Step)> = HashMap::new();
+        for &(crs, accuracy) in &frontier {
+            for &(target, code, reversed, edge_accuracy) in graph.get(&crs).copied().unwrap_or(&[])
+            {
+                if reached.contains_key(&target) || !usable(code) {
+                    continue;
+                }
+                let accuracy = accuracy + edge_accuracy;
+                let entry = next
+                    .entry(target)
+                    .or_insert((accuracy, (crs, code, reversed)));
+                if accuracy < entry.0 {
+                    *entry = (accuracy, (crs, code, reversed));
+                }
+            }
+        }
+        if next.is_empty() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(from, to, "no transformation path");
+            return None;
+        }
+        frontier = next
+            .iter()
+            .map(|(&crs, &(accuracy, _))| (crs, accuracy))
+            .collect();
+        // visit in a fixed order, so that ties are always resolved the same way
+        frontier.sort_by_key(|&(crs, _)| crs);
+        reached.extend(next.into_iter().map(|(crs, (_, step))| (crs, Some(step))));
+    }
+    let path = trace_path(&reached, to);
+    #[cfg(feature = "tracing")]
+    tracing::debug!(from, to, ?path, "chose transformation path");
+    Some(path)
+}
+
+/// Returns the areas of use of the coordinate operation corresponding to the EPSG code passed as
+/// the argument. If the operation is not implemented or has no area of use, the method returns
+/// `None`.
+pub fn get_coord_operation_areas(code: u32) -> Option<&'static [AreaOfUse]> {
+    TRANSFORMATION_AREAS.get(&code).copied()
+}
+
+/// Whether the operation corresponding to `code` may be used in `area`. Operations without an area
+/// of use, like the conversions between geographic 3D and 2D CRSs, may be used everywhere.
+fn operation_in_area(code: u32, area: &AreaOfUse) -> bool {
+    get_coord_operation_areas(code).is_none_or(|areas| areas.iter().any(|a| a.intersects(area)))
+}
+
+/// Returns the alternative transformations directly between the geographic Coordinate Reference
+/// Systems `from` and `to` whose area of use intersects `area`, as the EPSG codes of the operations
+/// and whether they are applied in reverse. The best accuracy comes first, operations of unknown
+/// accuracy last. Deprecated operations are not included.
+pub fn get_transformation_alternatives(from: u32, to: u32, area: &AreaOfUse) -> Vec<(u32, bool)> {
+    let mut alternatives = TRANSFORMATION_GRAPH
+        .get(&from)
+        .copied()
+        .unwrap_or(&[])
+        .iter()
+        .filter(|&&(target, code, _, _)| target == to && operation_in_area(code, area))
+        .collect::<Vec<_>>();
+    alternatives.sort_by(|a, b| a.3.total_cmp(&b.3).then(a.1.cmp(&b.1)));
+    alternatives
+        .into_iter()
+        .map(|&(_, code, reversed, _)| (code, reversed))
+        .collect()
+}
+
+/// Returns the transformations between the geographic Coordinate Reference Systems `from` and `to`
+/// like [`get_transformation_path`], only using operations whose area of use intersects `area`.
+/// This selects the operation for the region of interest where several operations with different
+/// areas link the same CRSs. Returns `None` if there is no such route between the CRSs.
+pub fn get_transformation_path_in_area(
+    from: u32,
+    to: u32,
+    area: &AreaOfUse,
+) -> Option<Vec<(u32, bool)>> {
+    find_path_where(&TRANSFORMATION_GRAPH, from, to, |code| {
+        operation_in_area(code, area)
+    })
+}
+
+/// Returns a transformation from the geographic Coordinate Reference System `from` to `to`,
+/// concatenating the implemented transformations of the route found by
+/// [`get_transformation_path_in_area`]. Returns `None` if there is no such route.
+pub fn get_transformation_in_area(
+    from: u32,
+    to: u32,
+    area: &AreaOfUse,
+) -> Option<Box<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>> {
+    concatenate(get_transformation_path_in_area(from, to, area)?)
+}
+
+/// Returns the transformations between the geographic Coordinate Reference Systems `from` and `to`
+/// with the best summed accuracy that is at least as good as `accuracy` in meters, like
+/// [`get_transformation_path`]. Of equally accurate routes, the one with the fewest steps is
+/// chosen.
+///
+/// A route through a CRS based on a datum ensemble is only as accurate as the ensemble, so its
+/// accuracy is added for each intermediate CRS. This prefers routes through specific realisations
+/// over ensembles like WGS 84 when a higher accuracy is requested. Operations of unknown accuracy
+/// are not used, and neither are operations whose area of use does not intersect `area`, so that
+/// operations of a smaller region do not make up a route that is only valid there.
+/// Returns `None` if there is no route of the requested accuracy.
+pub fn get_transformation_path_with_accuracy(
+    from: u32,
+    to: u32,
+    accuracy: f64,
+    area: &AreaOfUse,
+) -> Option<Vec<(u32, bool)>> {
+    // accumulated accuracy and steps, and predecessor of every reached CRS
+    let mut reached: HashMap<u32, ((f64, usize), Option<Step>)> =
+        HashMap::from([(from, ((0.0, 0), None))]);
+    let mut done = HashSet::new();
+    // the bit patterns of non-negative floats are ordered like their values
+    let mut queue = BinaryHeap::from([Reverse((0f64.to_bits(), 0, from))]);
+    while let Some(Reverse((cost, steps, crs))) = queue.pop() {
+        if crs == to {
+            break;
+        }
+        if !done.insert(crs) {
+            continue;
+        }
+        let cost = f64::from_bits(cost);
+        for &(target, code, reversed, edge_accuracy) in
+            TRANSFORMATION_GRAPH.get(&crs).copied().unwrap_or(&[])
+        {
+            let ensemble = match target {
+                target if target == to => 0.0,
+                target => crate::get_crs_ensemble_accuracy(target).unwrap_or(0.0),
+            };
+            let target_cost = cost + edge_accuracy + ensemble;
+            // operations of unknown accuracy are infinitely inaccurate
+            if !target_cost.is_finite()
+                || target_cost > accuracy
+                || done.contains(&target)
+                || !operation_in_area(code, area)
+            {
+                continue;
+            }
+            let key = (target_cost, steps + 1);
+            if reached.get(&target).is_none_or(|(best, _)| key < *best) {
+                reached.insert(target, (key, Some((crs, code, reversed))));
+                queue.push(Reverse((target_cost.to_bits(), steps + 1, target)));
+            }
+        }
+    }
+    let Some(&((_total, _), _)) = reached.get(&to) else {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(from, to, accuracy, "no transformation path of the accuracy");
+        return None;
+    };
+    let reached = reached
+        .into_iter()
+        .map(|(crs, (_, step))| (crs, step))
+        .collect();
+    let path = trace_path(&reached, to);
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        from,
+        to,
+        accuracy = _total,
+        ?path,
+        "chose transformation path"
+    );
+    Some(path)
+}
+
+/// Predecessor of a CRS on a route, as (previous CRS, operation code, reversed).
+type Step = (u32, u32, bool);
+
+/// Follows the predecessors from `to` back to the start of the route.
+fn trace_path(reached: &HashMap<u32, Option<Step>>, to: u32) -> Vec<(u32, bool)> {
+    let mut path = Vec::new();
+    let mut crs = to;
+    while let Some(Some((previous, code, reversed))) = reached.get(&crs) {
+        path.push((*code, *reversed));
+        crs = *previous;
+    }
+    path.reverse();
+    path
+}
+
+/// Returns a transformation from the geographic Coordinate Reference System `from` to `to`,
+/// concatenating the implemented transformations of the route found by
+/// [`get_transformation_path`]. If both codes are equal, the identity is returned.
+/// Returns `None` if there is no route between the CRSs.
+pub fn get_transformation(
+    from: u32,
+    to: u32,
+) -> Option<Box<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>> {
+    concatenate(get_transformation_path(from, to)?)
+}
+
+/// Returns a transformation from the geographic Coordinate Reference System `from` to `to` that is
+/// at least as accurate as `accuracy` in meters, concatenating the implemented transformations of
+/// the route found by [`get_transformation_path_with_accuracy`] for `area`.
+/// Returns `None` if there is no route of the requested accuracy.
+pub fn get_transformation_with_accuracy(
+    from: u32,
+    to: u32,
+    accuracy: f64,
+    area: &AreaOfUse,
+) -> Option<Box<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>> {
+    concatenate(get_transformation_path_with_accuracy(
+        from, to, accuracy, area,
+    )?)
+}
+
+/// Transformation between two geographic Coordinate Reference Systems returned by
+/// [`get_transformation_or_ballpark`].
+pub struct RoutedTransformation {
+    pub operation: Box<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>,
+    /// Whether the operation is a ballpark transformation, which ignores the difference between
+    /// the datums of the CRSs. Its results can be off by hundreds of metres.
+    pub ballpark: bool,
+}
+
+impl std::fmt::Debug for RoutedTransformation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoutedTransformation")
+            .field("ballpark", &self.ballpark)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns a transformation from the geographic Coordinate Reference System `from` to `to` like
+/// [`get_transformation`], falling back to a ballpark transformation if there is no route between
+/// the CRSs. The ballpark transformation treats longitude, latitude and height as equal in both
+/// CRSs, which is good enough for visualisation, but not for positioning.
+/// Returns `None` if one of the CRSs is not a geographic CRS on the Greenwich meridian.
+pub fn get_transformation_or_ballpark(from: u32, to: u32) -> Option<RoutedTransformation> {
+    if let Some(operation) = get_transformation(from, to) {
+        return Some(RoutedTransformation {
+            operation,
+            ballpark: false,
+        });
+    }
+    let geographic = |code| {
+        GEOCENTRIC_CONVERSIONS
+            .get(&code)
+            .is_some_and(|(_, geocentric, _)| !geocentric)
+    };
+    if !(geographic(from) && geographic(to)) {
+        return None;
+    }
+    Some(RoutedTransformation {
+        operation: concatenate(Vec::new())?,
+        ballpark: true,
+    })
+}
+
+/// How a [`TransformerFactory`] selects the route between two geographic Coordinate Reference
+/// Systems.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum RouteOptions {
+    /// The route of [`get_transformation_path`].
+    #[default]
+    FewestSteps,
+    /// The route of [`get_transformation_path_with_accuracy`], at least as accurate as the given
+    /// accuracy in meters and usable in the given area.
+    Accuracy(f64, AreaOfUse),
+    /// The route of [`get_transformation_path_in_area`], usable in the given area.
+    Area(AreaOfUse),
+}
+
+impl RouteOptions {
+    /// Key of the options in the cache of a `TransformerFactory`, ignoring the name of an area.
+    fn key(&self) -> (u8, u64, [u64; 4]) {
+        match self {
+            Self::FewestSteps => (0, 0, [0; 4]),
+            Self::Accuracy(accuracy, area) => {
+                (1, accuracy.to_bits(), area.to_enws().map(f64::to_bits))
+            }
+            Self::Area(area) => (2, 0, area.to_enws().map(f64::to_bits)),
+        }
+    }
+}
+
+type SharedTransformation = Arc<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>;
+
+/// Source and target CRS and the key of the options of a route.
+type RouteKey = (u32, u32, (u8, u64, [u64; 4]));
+
+/// Resolves and keeps the transformations between geographic Coordinate Reference Systems, so
+/// that repeated requests for the same CRSs and options do not search the routing graph again.
+///
+/// The transformations are shared between threads and kept until [`TransformerFactory::clear`]
+/// is called, including the requests without a route.
+#[derive(Default)]
+pub struct TransformerFactory {
+    resolved: Mutex<HashMap<RouteKey, Option<SharedTransformation>>>,
+}
+
+impl TransformerFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the transformation from the geographic Coordinate Reference System `from` to `to` on
+    /// the route selected by `options`, resolving it on the first request. Returns `None` if there
+    /// is no such route.
+    pub fn get(&self, from: u32, to: u32, options: RouteOptions) -> Option<SharedTransformation> {
+        let key = (from, to, options.key());
+        if let Some(resolved) = self
+            .resolved
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+        {
+            return resolved.clone();
+        }
+        let path = match options {
+            RouteOptions::FewestSteps => get_transformation_path(from, to),
+            RouteOptions::Accuracy(accuracy, area) => {
+                get_transformation_path_with_accuracy(from, to, accuracy, &area)
+            }
+            RouteOptions::Area(area) => get_transformation_path_in_area(from, to, &area),
+        };
+        let resolved = path.and_then(concatenate).map(SharedTransformation::from);
+        self.resolved
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, resolved.clone());
+        resolved
+    }
+
+    /// Get the number of resolved requests, including those without a route.
+    pub fn len(&self) -> usize {
+        self.resolved
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forget all resolved transformations.
+    pub fn clear(&self) {
+        self.resolved
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+impl std::fmt::Debug for TransformerFactory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransformerFactory")
+            .field("resolved", &self.len())
+            .finish()
+    }
+}
+
+/// Concatenates the transformations of `path`, the identity if it is empty.
+fn concatenate(
+    path: Vec<(u32, bool)>,
+) -> Option<Box<dyn CoordOperation<Geographic3DCoordinate, Geographic3DCoordinate>>> {
+    let steps = path
+        .into_iter()
+        .map(|(code, reversed)| {
+            let operations = if reversed {
+                &REVERSE_TRANSFORMATIONS
+            } else {
+                &TRANSFORMATIONS
+            };
+            operations.get(&code).copied()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    #[cfg(feature = "tracing")]
+    tracing::trace!(steps = steps.len(), "concatenated transformation pipeline");
+    let Some((first, rest)) = steps.split_first() else {
+        let identity = Geographic3DOffsetsParams::new(0.0, 0.0, 0.0);
+        return Some(Box::new(Geographic3DOffsetsTransformation::new(&identity)));
+    };
+    Some(rest.iter().fold(Box::new(*first), |transformation, step| {
+        Box::new(ConcatenatedCoordOp::<_, _, Geographic3DCoordinate>::new(
+            transformation,
+            *step,
+        ))
+    }))
+}
+
+/// Returns a transformation from the geocentric Coordinate Reference System `from` to `to`,
+/// concatenating the implemented Helmert transformations with the fewest steps. Time-dependent
+/// transformations are evaluated at the coordinate epoch, so that transformations between dynamic
+/// CRSs like the realizations of the ITRF are exact for coordinates at that epoch.
+///
+/// The transformations do not change the coordinate epoch, the point motion operations of
+/// `miniproj_ops::point_motion` move coordinates between epochs, see
+/// [`get_transformation_with_velocity`]. Returns `None` if there is no route between the CRSs.
+pub fn get_transformation_at(
+    from: u32,
+    to: u32,
+    epoch: Epoch,
+) -> Option<Box<dyn CoordOperation<GeocentricCoordinate, GeocentricCoordinate>>> {
+    let mut steps = find_path(&GEOCENTRIC_TRANSFORMATION_GRAPH, from, to)?
+        .into_iter()
+        .map(|(code, reversed)| {
+            let helmert = GEOCENTRIC_TRANSFORMATIONS.get(&code).copied().or_else(|| {
+                TIME_DEPENDENT_TRANSFORMATIONS
+                    .get(&code)
+                    .map(|t| t.at_epoch(epoch))
+            });
+            let step: Box<dyn CoordOperation<GeocentricCoordinate, GeocentricCoordinate>> =
+                match (helmert, MOLODENSKY_BADEKAS_TRANSFORMATIONS.get(&code)) {
+                    (Some(h), _) => Box::new(if reversed { h.reversed() } else { h }),
+                    (_, Some(m)) => Box::new(if reversed { m.reversed() } else { *m }),
+                    _ => return None,
+                };
+            Some(step)
+        })
+        .collect::<Option<Vec<_>>>()?
+        .into_iter();
+    let Some(first) = steps.next() else {
+        let identity = HelmertParams::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        return Some(Box::new(HelmertTransformation::new_position_vector(
+            &identity,
+        )));
+    };
+    Some(steps.fold(first, |transformation, step| {
+        Box::new(ConcatenatedCoordOp::<_, _, GeocentricCoordinate>::new(
+            transformation,
+            step,
+        ))
+    }))
+}
+
+/// Returns a transformation from the geocentric Coordinate Reference System `from` at the coordinate
+/// epoch `from_epoch` to `to` at `to_epoch`, e.g. between realizations of the ITRF or from an ITRF
+/// realization to a regional frame like GDA2020.
+///
+/// The coordinates are first moved to `to_epoch` within `from` by the station `velocity` given in
+/// that CRS, then transformed with the time-dependent transformations evaluated at `to_epoch` like
+/// in [`get_transformation_at`]. Returns `None` if there is no route between the CRSs.
+pub fn get_transformation_with_velocity(
+    from: u32,
+    from_epoch: Epoch,
+    to: u32,
+    to_epoch: Epoch,
+    velocity: &GeocentricPointMotionParams,
+) -> Option<Box<dyn CoordOperation<GeocentricCoordinate, GeocentricCoordinate>>> {
+    let transformation = get_transformation_at(from, to, to_epoch)?;
+    let motion = GeocentricPointMotionTransformation::new(velocity, from_epoch, to_epoch);
+    Some(Box::new(
+        ConcatenatedCoordOp::<_, _, GeocentricCoordinate>::new(motion, transformation),
+    ))
+}
+
+/// Returns the Geographic/geocentric conversion (EPSG:9602) between a geographic and a geocentric
+/// Coordinate Reference System, in either order, on the ellipsoid of their datum. This is what the
+/// generic EPSG conversion 15592 resolves to for the two CRSs.
+/// Returns `None` unless exactly one of the CRSs is geocentric and both share a datum.
+pub fn get_geocentric_conversion(from: u32, to: u32) -> Option<GeocentricConversion> {
+    let (from_datum, from_geocentric, conversion) = GEOCENTRIC_CONVERSIONS.get(&from)?;
+    let (to_datum, to_geocentric, _) = GEOCENTRIC_CONVERSIONS.get(&to)?;
+    (from_datum == to_datum && from_geocentric != to_geocentric).then_some(*conversion)
+}
+
+/// Returns the transformation between two vertical Coordinate Reference Systems corresponding to
+/// the EPSG coordinate operation code passed as the argument, acting on the vertical coordinate.
+/// If the code refers to an operation whose method is not implemented, the method returns `None`.
+pub fn get_vertical_coord_operation(
+    code: u32,
+) -> Option<
+    &'static dyn CoordOperation<
+        Geographic2DCoordinateUserVertical,
+        Geographic2DCoordinateUserVertical,
+    >,
+> {
+    VERTICAL_TRANSFORMATIONS.get(&code).copied()
+}
+
+/// Get the CRS a vertical CRS is routed from, the height CRS in metres of its datum for depth
+/// CRSs, and whether its axis points down.
+fn vertical_routing_crs(code: u32) -> Option<(u32, bool)> {
+    let crs = get_vertical_crs(code)?;
+    if !crs.depth {
+        return Some((code, false));
+    }
+    Some((
+        crate::compound_crs_constructor::get_height_crs(crs.datum)?,
+        true,
+    ))
+}
+
+/// Returns the transformations between the vertical Coordinate Reference Systems `from` and `to`
+/// with the fewest steps, as the EPSG codes of the operations and whether they are applied in
+/// reverse, like [`get_transformation_path`]. Depth CRSs are routed through the height CRS of
+/// their datum. Deprecated operations are not used. Returns `None` if there is no route between
+/// the CRSs.
+pub fn get_vertical_transformation_path(from: u32, to: u32) -> Option<Vec<(u32, bool)>> {
+    let ((from, _), (to, _)) = (vertical_routing_crs(from)?, vertical_routing_crs(to)?);
+    find_path(&VERTICAL_TRANSFORMATION_GRAPH, from, to)
+}
+
+/// Vertical transformation between CRSs whose axes point up or down, applying `operation` to
+/// heights.
+struct VerticalAxes<O> {
+    operation: O,
+    from_depth: bool,
+    to_depth: bool,
+}
+
+impl<O> CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>
+    for VerticalAxes<O>
+where
+    O: CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>,
+{
+    fn apply(
+        &self,
+        from: Geographic2DCoordinateUserVertical,
+    ) -> Geographic2DCoordinateUserVertical {
+        let sign = |depth| if depth { -1.0 } else { 1.0 };
+        let height = Geographic2DCoordinateUserVertical {
+            vertical: sign(self.from_depth) * from.vertical,
+            ..from
+        };
+        let to = self.operation.apply(height);
+        Geographic2DCoordinateUserVertical {
+            vertical: sign(self.to_depth) * to.vertical,
+            ..to
+        }
+    }
+}
+
+/// Returns a transformation from the vertical Coordinate Reference System `from` to `to`,
+/// concatenating the implemented transformations of the route found by
+/// [`get_vertical_transformation_path`], like between national height systems and EVRF or
+/// between mean sea level heights and depths. The horizontal position is given in radians, in the
+/// geographic CRS the offsets of the operations refer to. If both codes are equal, the identity
+/// is returned. Returns `None` if there is no route between the CRSs.
+pub fn get_vertical_transformation(
+    from: u32,
+    to: u32,
+) -> Option<
+    Box<dyn CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>>,
+> {
+    let ((from, from_depth), (to, to_depth)) =
+        (vertical_routing_crs(from)?, vertical_routing_crs(to)?);
+    let steps = find_path(&VERTICAL_TRANSFORMATION_GRAPH, from, to)?
+        .into_iter()
+        .map(|(code, reversed)| {
+            let operations = if reversed {
+                &VERTICAL_REVERSE_TRANSFORMATIONS
+            } else {
+                &VERTICAL_TRANSFORMATIONS
+            };
+            operations.get(&code).copied()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let operation: Box<
+        dyn CoordOperation<Geographic2DCoordinateUserVertical, Geographic2DCoordinateUserVertical>,
+    > = match steps.split_first() {
+        None => Box::new(VerticalOffsetTransformation::new(
+            &VerticalOffsetParams::new(0.0),
+        )),
+        Some((first, rest)) => rest.iter().fold(Box::new(*first), |transformation, step| {
+            Box::new(ConcatenatedCoordOp::<
+                _,
+                _,
+                Geographic2DCoordinateUserVertical,
+            >::new(transformation, *step))
+        }),
+    };
+    Some(Box::new(VerticalAxes {
+        operation,
+        from_depth,
+        to_depth,
+    }))
+}
+
+/// Returns the Helmert transformation between two geocentric Coordinate Reference Systems
+/// corresponding to the EPSG coordinate operation code passed as the argument.
+/// If the code refers to an operation whose method is not implemented or that is time-dependent,
+/// the method returns `None`.
+pub fn get_geocentric_coord_operation(code: u32) -> Option<&'static HelmertTransformation> {
+    GEOCENTRIC_TRANSFORMATIONS.get(&code)
+}
+
+/// Returns the time-dependent Helmert transformation between two geocentric Coordinate Reference
+/// Systems corresponding to the EPSG coordinate operation code passed as the argument. Use
+/// [`HelmertTimeDependentTransformation::at_epoch`] to obtain the transformation at an epoch.
+/// If the code refers to an operation whose method is not implemented, the method returns `None`.
+pub fn get_time_dependent_coord_operation(
+    code: u32,
+) -> Option<&'static HelmertTimeDependentTransformation> {
+    TIME_DEPENDENT_TRANSFORMATIONS.get(&code)
+}
+
+/// Returns the Molodensky-Badekas transformation between two geocentric Coordinate Reference
+/// Systems corresponding to the EPSG coordinate operation code passed as the argument.
+/// If the code refers to an operation whose method is not implemented, the method returns `None`.
+pub fn get_molodensky_badekas_coord_operation(
+    code: u32,
+) -> Option<&'static MolodenskyBadekasTransformation> {
+    MOLODENSKY_BADEKAS_TRANSFORMATIONS.get(&code)
+}
+
+/// Returns the EPSG codes of the source and target Coordinate Reference System of the
+/// transformation corresponding to `code`, which is a transformation between geographic, vertical
+/// or geocentric CRSs, or a concatenated operation between geographic CRSs. Returns `None` if the
+/// transformation is not implemented.
+pub fn get_coord_operation_crs(code: u32) -> Option<(u32, u32)> {
+    TRANSFORMATION_CRS
+        .get(&code)
+        .or_else(|| VERTICAL_TRANSFORMATION_CRS.get(&code))
+        .or_else(|| GEOCENTRIC_TRANSFORMATION_CRS.get(&code))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geographic_2d_offsets() {
+        // Greek to GGRS87
+        assert_eq!(get_coord_operation_crs(1891), Some((4120, 4121)));
+        let operation = get_coord_operation(1891).expect("Transformation not implemented.");
+        let from = Geographic3DCoordinate::from_deg(23.784317, 38.143490, 0.0);
+        let (lon, lat, _) = operation.apply(from).to_deg();
+        eprintln!("{lon}, {lat}");
+        assert!((lon - (23.784317 + 0.28 / 3600.0)).abs() < 1e-9);
+        assert!((lat - (38.143490 - 5.86 / 3600.0)).abs() < 1e-9);
+        assert!(get_coord_operation(1447).is_some());
+        assert!(get_coord_operation(16070).is_none());
+    }
+
+    #[test]
+    fn transformation_routing() {
+        // ETRS89 to WGS 84 uses ETRS89 to WGS 84 (1) directly
+        assert_eq!(
+            get_transformation_path(4258, 4326),
+            Some(vec![(1149, false)])
+        );
+        let operation = get_transformation(4258, 4326).expect("No route found.");
+        let from = Geographic3DCoordinate::from_deg(10.0, 54.0, 10.0);
+        let to = operation.apply(from);
+        assert_eq!(to, get_coord_operation(1149).unwrap().apply(from));
+
+        // the reverse direction reverses the operation
+        assert_eq!(
+            get_transformation_path(4326, 4258),
+            Some(vec![(1149, true)])
+        );
+        let back = get_transformation(4326, 4258).unwrap().apply(to);
+        eprintln!("{:?}", back.to_deg());
+        assert!((back.lon - from.lon).abs() < 1e-12);
+        assert!((back.lat - from.lat).abs() < 1e-12);
+        assert!((back.height - from.height).abs() < 1e-6);
+
+        // Greek to WGS 84 via GGRS87
+        assert_eq!(
+            get_transformation_path(4120, 4326),
+            Some(vec![(1891, false), (1272, false)])
+        );
+        let from = Geographic3DCoordinate::from_deg(23.784317, 38.143490, 0.0);
+        let to = get_transformation(4120, 4326).unwrap().apply(from);
+        let goal = get_coord_operation(1272)
+            .unwrap()
+            .apply(get_coord_operation(1891).unwrap().apply(from));
+        eprintln!("{:?} - {:?}", to.to_deg(), goal.to_deg());
+        assert_eq!(to, goal);
+
+        assert_eq!(get_transformation(4326, 4326).unwrap().apply(from), from);
+        assert!(get_transformation_path(4326, 25832).is_none());
+        assert!(get_transformation(4326, 25832).is_none());
+    }
+
+    #[test]
+    fn ballpark_transformation() {
+        let routed = get_transformation_or_ballpark(4258, 4326).unwrap();
+        assert!(!routed.ballpark);
+
+        // there is no transformation from China Geodetic Coordinate System 2000
+        assert!(get_transformation(4490, 4326).is_none());
+        let routed = get_transformation_or_ballpark(4490, 4326).expect("No ballpark found.");
+        eprintln!("{routed:?}");
+        assert!(routed.ballpark);
+        let from = Geographic3DCoordinate::from_deg(116.4, 39.9, 10.0);
+        assert_eq!(routed.operation.apply(from), from);
+
+        assert!(get_transformation_or_ballpark(4326, 4978).is_none());
+        assert!(get_transformation_or_ballpark(4326, 25832).is_none());
+    }
+
+    #[test]
+    fn geographic_3d_to_2d_routing() {
+        // WGS 84 (3D) to WGS 84 drops the height, which is carried along between geographic CRSs
+        assert_eq!(
+            get_transformation_path(4979, 4326),
+            Some(vec![(15593, false)])
+        );
+        let from = Geographic3DCoordinate::from_deg(10.0, 54.0, 10.0);
+        assert_eq!(get_transformation(4979, 4326).unwrap().apply(from), from);
+
+        // ETRS89 (3D) to WGS 84 (3D) through the geographic 2D CRSs
+        let path = get_transformation_path(4937, 4979);
+        assert_eq!(
+            path,
+            Some(vec![(15593, false), (1149, false), (15593, true)])
+        );
+        let to = get_transformation(4937, 4979).unwrap().apply(from);
+        assert_eq!(to, get_coord_operation(1149).unwrap().apply(from));
+        // the conversions are exact, but WGS 84 becomes an intermediate CRS of the datum ensemble
+        let germany = AreaOfUse::new(6.0, 47.0, 15.0, 55.0);
+        assert!(get_transformation_path_with_accuracy(4937, 4979, 1.0, &germany).is_none());
+        assert!(get_transformation_path_with_accuracy(4937, 4979, 5.0, &germany).is_some());
+        assert!(get_transformation_path(4937, 4326).is_some());
+        assert!(get_transformation_path(4979, 4937).is_some());
+    }
+
+    #[test]
+    fn vertical_offset() {
+        // Baltic 1977 height to Black Sea height
+        assert_eq!(get_coord_operation_crs(5447), Some((5705, 5735)));
+        let operation =
+            get_vertical_coord_operation(5447).expect("Transformation not implemented.");
+        let to = operation.apply(Geographic2DCoordinateUserVertical::from_deg(
+            38.0, 45.0, 102.4,
+        ));
+        eprintln!("{to:?}");
+        assert!((to.vertical - 102.8).abs() < 1e-9);
+        // Baltic height to Caspian depth reverses the axis direction
+        assert!(get_vertical_coord_operation(5400).is_none());
+        assert!(get_vertical_coord_operation(1891).is_none());
+    }
+
+    #[test]
+    fn helmert() {
+        // ETRS89 to WGS 84 (1), geocentric translations of zero
+        assert_eq!(get_coord_operation_crs(1149), Some((4258, 4326)));
+        let operation = get_coord_operation(1149).expect("Transformation not implemented.");
+        let from = Geographic3DCoordinate::from_deg(10.0, 54.0, 10.0);
+        let to = operation.apply(from);
+        eprintln!("{:?}", to.to_deg());
+        // GRS 1980 and WGS 84 differ by 0.1 mm in the semi-minor axis
+        assert!((to.lon - from.lon).abs() < 1e-12);
+        assert!((to.lat - from.lat).abs() < 1e-10);
+        assert!((to.height - from.height).abs() < 1e-3);
+
+        // ED50 to WGS 84 (18), position vector transformation
+        let operation = get_coord_operation(1311).expect("Transformation not implemented.");
+        let intl = crate::get_ellipsoid(7022).unwrap();
+        let from = Geographic3DCoordinate::from_deg(2.0, 56.0, 0.0);
+        let (x, y, z) = intl.rad_to_geocentric(from.lon, from.lat, from.height);
+        let to = operation.apply(from);
+        let (x_t, y_t, z_t) = crate::ellipsoids::WGS84.rad_to_geocentric(to.lon, to.lat, to.height);
+        let rz = -0.156f64.to_radians() / 3600.0;
+        eprintln!("{:?}", to.to_deg());
+        assert!((x_t - ((1.0 + 1.2e-6) * (x - rz * y) - 89.5)).abs() < 1e-3);
+        assert!((y_t - ((1.0 + 1.2e-6) * (rz * x + y) - 93.8)).abs() < 1e-3);
+        assert!((z_t - ((1.0 + 1.2e-6) * z - 123.1)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn abridged_molodensky_from_registry_params() {
+        use miniproj_ops::abridged_molodensky::{
+            direct_transformation, AbridgedMolodenskyTransformation,
+        };
+        use miniproj_ops::PseudoSerialize;
+
+        // no operation of the registry uses the method, so the source generated for the EPSG
+        // example WGS 84 to ED50 is checked against the transformation it has to construct
+        let wgs84 = Ellipsoid::from_a_f_inv(6378137.0, 298.257223563);
+        let intl = Ellipsoid::from_a_f_inv(6378388.0, 297.0);
+        let params = [
+            (8605, 84.87),
+            (8606, 96.49),
+            (8607, 116.95),
+            (8654, 251.0),
+            (8655, intl.f() - wgs84.f()),
+        ];
+        let operation = AbridgedMolodenskyTransformation {
+            ellipsoid_a: 6378137.0,
+            ellipsoid_f: wgs84.f(),
+            ellipsoid_e_squared: wgs84.e_squared(),
+            tx: 84.87,
+            ty: 96.49,
+            tz: 116.95,
+            da: 251.0,
+            df: intl.f() - wgs84.f(),
+        };
+        assert_eq!(
+            direct_transformation(&params, wgs84, intl),
+            operation.to_constructed()
+        );
+        let from = Geographic3DCoordinate::from_deg(
+            2.0 + 7.0 / 60.0 + 46.38 / 3600.0,
+            53.0 + 48.0 / 60.0 + 33.82 / 3600.0,
+            73.0,
+        );
+        let (lon, lat, height) = operation.apply(from).to_deg();
+        assert!((lon - (2.0 + 7.0 / 60.0 + 51.477 / 3600.0)).abs() < 0.001 / 3600.0);
+        assert!((lat - (53.0 + 48.0 / 60.0 + 36.563 / 3600.0)).abs() < 0.001 / 3600.0);
+        assert!((height - 28.091).abs() < 0.01);
+    }
+
+    #[test]
+    fn geocentric_conversion() {
+        // WGS 84 to WGS 84 (geocentric)
+        let conversion = get_geocentric_conversion(4326, 4978).expect("Conversion not found.");
+        assert_eq!(conversion.ellipsoid.a, 6378137.0);
+        assert_eq!(get_geocentric_conversion(4978, 4979), Some(conversion));
+        let from = Geographic3DCoordinate::from_deg(0.0, 0.0, 10.0);
+        let to: GeocentricCoordinate = conversion.apply(from);
+        assert!((to.x - 6378147.0).abs() < 1e-6);
+        assert!(to.y.abs() < 1e-6 && to.z.abs() < 1e-6);
+
+        // ETRS89 (geocentric) uses GRS 1980
+        let grs80 = get_geocentric_conversion(4936, 4937).unwrap();
+        assert!((grs80.ellipsoid.f - 1.0 / 298.257222101).abs() < 1e-15);
+        assert!(get_geocentric_conversion(4258, 4978).is_none());
+        assert!(get_geocentric_conversion(4326, 4979).is_none());
+    }
+
+    #[test]
+    fn molodensky_badekas() {
+        // Amersfoort to ETRS89 (2), coordinate frame rotation about an evaluation point
+        assert_eq!(get_coord_operation_crs(1066), Some((4289, 4258)));
+        let operation = get_coord_operation(1066).expect("Transformation not implemented.");
+        let bessel = crate::get_ellipsoid(7004).unwrap();
+        let from = Geographic3DCoordinate::from_deg(5.4, 52.1, 0.0);
+        let (x, y, z) = bessel.rad_to_geocentric(from.lon, from.lat, from.height);
+        let to = operation.apply(from);
+        let grs80 = crate::get_ellipsoid(7019).unwrap();
+        let (x_t, y_t, z_t) = grs80.rad_to_geocentric(to.lon, to.lat, to.height);
+        // rotations in microradians
+        let (rx, ry, rz) = (-1.9848e-6, 1.7439e-6, -9.0587e-6);
+        let m = 1.0 + 4.0772e-6;
+        let (xp, yp, zp) = (3903453.148, 368135.313, 5012970.306);
+        let (dx, dy, dz) = (x - xp, y - yp, z - zp);
+        eprintln!("{:?}", to.to_deg());
+        assert!((x_t - (m * (dx - rz * dy + ry * dz) + xp + 593.032)).abs() < 1e-3);
+        assert!((y_t - (m * (rz * dx + dy - rx * dz) + yp + 26.0)).abs() < 1e-3);
+        assert!((z_t - (m * (-ry * dx + rx * dy + dz) + zp + 478.741)).abs() < 1e-3);
+
+        // IGS08 to IGRS (1), in the geocentric domain
+        let operation =
+            get_molodensky_badekas_coord_operation(6935).expect("Transformation not implemented.");
+        assert_eq!(operation.xp, 3777505.028);
+        assert_eq!(get_coord_operation_crs(6935), Some((6934, 3887)));
+        let epoch = crate::Epoch::from_decimal_year(2020.0).unwrap();
+        let from = GeocentricCoordinate::new(3777505.028, 3779254.396, 3471111.632);
+        let to = get_transformation_at(6934, 3887, epoch)
+            .unwrap()
+            .apply(from);
+        eprintln!("{to:?}");
+        assert!((to.x - from.x - 0.208).abs() < 1e-9);
+    }
+
+    #[test]
+    fn time_dependent_helmert() {
+        // ITRF2008 to ITRF2014 (1)
+        assert_eq!(get_coord_operation_crs(7790), Some((5332, 7789)));
+        let operation =
+            get_time_dependent_coord_operation(7790).expect("Transformation not implemented.");
+        eprintln!("{operation:?}");
+        assert!((operation.reference_epoch - 2010.0).abs() < 1e-9);
+        let at_2020 = operation.at_epoch(crate::Epoch::from_decimal_year(2020.0).unwrap());
+        assert!((at_2020.tx + 0.0016).abs() < 1e-12);
+        assert!((at_2020.tz - (-0.0024 + 0.001)).abs() < 1e-12);
+        assert!((at_2020.ds - (0.02e-9 - 0.3e-9)).abs() < 1e-18);
+        assert!(get_geocentric_coord_operation(7790).is_none());
+    }
+
+    #[test]
+    fn concatenated_operation() {
+        // Amersfoort to ED50 (1), via WGS 84
+        assert_eq!(get_coord_operation_crs(4837), Some((4289, 4230)));
+        let operation = get_concatenated_coord_operation(4837).expect("Operation not implemented.");
+        let from = Geographic3DCoordinate::from_deg(5.4, 52.1, 0.0);
+        let to = operation.apply(from);
+        let goal = get_transformation(4326, 4230)
+            .unwrap()
+            .apply(get_coord_operation(1672).unwrap().apply(from));
+        eprintln!("{:?} - {:?}", to.to_deg(), goal.to_deg());
+        assert_eq!(to, goal);
+        assert!(get_concatenated_coord_operation(1672).is_none());
+    }
+
+    #[test]
+    fn transformation_accuracy() {
+        // ETRS89 to WGS 84 (1) has an accuracy of 1 m, the route via EST92 of 0.6 m, which is only
+        // valid in Estonia
+        let germany = AreaOfUse::new(6.0, 47.0, 15.0, 55.0);
+        let estonia = AreaOfUse::new(24.0, 58.0, 26.0, 59.0);
+        assert_eq!(
+            get_transformation_path_with_accuracy(4258, 4326, 1.0, &germany),
+            Some(vec![(1149, false)])
+        );
+        assert!(get_transformation_path_with_accuracy(4258, 4326, 0.6, &germany).is_none());
+        assert_eq!(
+            get_transformation_path_with_accuracy(4258, 4326, 1.0, &estonia),
+            Some(vec![(1331, true), (1333, false)])
+        );
+        assert!(get_transformation_path_with_accuracy(4258, 4326, 0.5, &estonia).is_none());
+        assert!(get_transformation_with_accuracy(4258, 4326, 1.0, &germany).is_some());
+
+        // NAD27 to NAD83 goes through WGS 84 (EPSG:4326) with the fewest steps, while the route
+        // by accuracy avoids the 4 m NAD83 to WGS 84 (1) step
+        assert_eq!(
+            get_transformation_path(4267, 4269),
+            Some(vec![(7698, false), (1188, true)])
+        );
+        let steps = describe_transformation(4267, 4269).unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(
+            (steps[0].from, steps[0].to, steps[1].to),
+            (4267, 4326, 4269)
+        );
+        assert_eq!(steps[1].name, "NAD83 to WGS 84 (1)");
+        assert!(steps[1].reversed);
+        assert_eq!(steps[1].accuracy, Some(4.0));
+        let conus = AreaOfUse::new(-125.0, 24.0, -66.0, 50.0);
+        let path =
+            get_transformation_path_with_accuracy(4267, 4269, f64::INFINITY, &conus).unwrap();
+        assert!(!path.contains(&(1188, true)));
+        assert_eq!(
+            get_transformation_path_with_accuracy(4326, 4326, 0.0, &conus),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn transformer_factory() {
+        let factory = TransformerFactory::new();
+        let from = Geographic3DCoordinate::from_deg(-100.0, 40.0, 0.0);
+        let operation = factory.get(4267, 4269, RouteOptions::default()).unwrap();
+        assert_eq!(
+            operation.apply(from),
+            get_transformation(4267, 4269).unwrap().apply(from)
+        );
+        let cached = factory.get(4267, 4269, RouteOptions::FewestSteps).unwrap();
+        assert!(Arc::ptr_eq(&operation, &cached));
+        let conus = AreaOfUse::new(-125.0, 24.0, -66.0, 50.0);
+        let accurate = factory.get(4267, 4269, RouteOptions::Accuracy(f64::INFINITY, conus));
+        assert!(!Arc::ptr_eq(&operation, &accurate.unwrap()));
+        let ontario = AreaOfUse::new(-81.0, 44.0, -80.0, 45.0);
+        let in_area = factory
+            .get(4267, 4326, RouteOptions::Area(ontario))
+            .unwrap();
+        assert_eq!(
+            in_area.apply(from),
+            get_coord_operation(1173).unwrap().apply(from)
+        );
+        // requests without a route are kept as well
+        assert!(factory
+            .get(4267, 4326, RouteOptions::Accuracy(0.0, conus))
+            .is_none());
+        assert_eq!(factory.len(), 4);
+        factory.clear();
+        assert!(factory.is_empty());
+    }
+
+    #[test]
+    fn transformation_at_epoch() {
+        // ITRF2008 to ITRF2014 at 2020.0
+        let epoch = crate::Epoch::from_decimal_year(2020.0).unwrap();
+        let operation = get_transformation_at(5332, 7789, epoch).expect("No route found.");
+        let from = GeocentricCoordinate::new(3657660.66, 255768.55, 5201382.11);
+        let to = operation.apply(from);
+        let goal = get_time_dependent_coord_operation(7790)
+            .unwrap()
+            .at_epoch(epoch)
+            .apply(from);
+        eprintln!("{to:?} - {goal:?}");
+        assert_eq!(to, goal);
+
+        let back = get_transformation_at(7789, 5332, epoch).unwrap().apply(to);
+        assert!((back.x - from.x).abs() < 1e-6);
+        assert!((back.y - from.y).abs() < 1e-6);
+        assert!((back.z - from.z).abs() < 1e-6);
+
+        // ITRF2008 to GDA2020 via ITRF2014
+        let path = find_path(&GEOCENTRIC_TRANSFORMATION_GRAPH, 5332, 7842);
+        assert_eq!(path, Some(vec![(7790, false), (8049, false)]));
+        let to = get_transformation_at(5332, 7842, epoch)
+            .unwrap()
+            .apply(from);
+        let goal = get_time_dependent_coord_operation(8049)
+            .unwrap()
+            .at_epoch(epoch)
+            .apply(goal);
+        eprintln!("{to:?} - {goal:?}");
+        assert_eq!(to, goal);
+    }
+
+    #[test]
+    fn transformation_with_velocity() {
+        // ITRF2014 at 2010.0 to ITRF2020 at 2020.0
+        let (epoch, later) = (
+            Epoch::from_decimal_year(2010.0),
+            Epoch::from_decimal_year(2020.0),
+        );
+        let (epoch, later) = (epoch.unwrap(), later.unwrap());
+        let velocity = GeocentricPointMotionParams::new(-0.0151, 0.0174, 0.0104);
+        let from = GeocentricCoordinate::new(3657660.66, 255768.55, 5201382.11);
+        let operation = get_transformation_with_velocity(7789, epoch, 9988, later, &velocity)
+            .expect("No route found.");
+        let to = operation.apply(from);
+        let moved = GeocentricCoordinate::new(3657660.509, 255768.724, 5201382.214);
+        let goal = get_time_dependent_coord_operation(9991)
+            .unwrap()
+            .at_epoch(later)
+            .apply(moved);
+        eprintln!("{to:?} - {goal:?}");
+        assert!((to.x - goal.x).abs() < 1e-6);
+        assert!((to.y - goal.y).abs() < 1e-6);
+        assert!((to.z - goal.z).abs() < 1e-6);
+
+        // without motion, this is the transformation at the epoch
+        let still = GeocentricPointMotionParams::new(0.0, 0.0, 0.0);
+        let to = get_transformation_with_velocity(5332, later, 7842, later, &still).unwrap();
+        let goal = get_transformation_at(5332, 7842, later).unwrap();
+        assert_eq!(to.apply(from), goal.apply(from));
+
+        // across epochs, the coordinates are moved before the transformation at the target epoch
+        let to = get_transformation_with_velocity(5332, epoch, 7842, later, &velocity).unwrap();
+        let goal = get_transformation_at(5332, 7842, later)
+            .unwrap()
+            .apply(moved);
+        let at_start = get_transformation_at(5332, 7842, epoch)
+            .unwrap()
+            .apply(moved);
+        eprintln!("{:?} - {goal:?}", to.apply(from));
+        assert!((to.apply(from).x - goal.x).abs() < 1e-6);
+        assert!((to.apply(from).y - goal.y).abs() < 1e-6);
+        assert!((to.apply(from).z - goal.z).abs() < 1e-6);
+        assert!((goal.x - at_start.x).abs() > 1e-3);
+        assert!(get_transformation_with_velocity(7789, epoch, 4326, later, &still).is_none());
+    }
+
+    #[test]
+    fn vertical_offset_and_slope() {
+        // Alicante height to EVRF2000 height, the offset applies unchanged at the evaluation point
+        let operation =
+            get_vertical_coord_operation(5429).expect("Transformation not implemented.");
+        let from = Geographic2DCoordinateUserVertical::from_deg(
+            -(3.0 + 39.0 / 60.0 + 35.0 / 3600.0),
+            40.0 + 46.0 / 60.0 + 20.0 / 3600.0,
+            100.0,
+        );
+        let to = operation.apply(from);
+        eprintln!("{to:?}");
+        assert!((to.vertical - 99.514).abs() < 1e-6);
+        // an inclination of 0.006" in longitude adds about 2.5 mm over a degree
+        let east = Geographic2DCoordinateUserVertical {
+            lon: from.lon + 1f64.to_radians(),
+            ..from
+        };
+        assert!((operation.apply(east).vertical - to.vertical - 0.0025).abs() < 1e-4);
+    }
+
+    #[test]
+    fn vertical_routing() {
+        // Alicante height to EVRF2000 height and back
+        let from = Geographic2DCoordinateUserVertical::from_deg(-3.66, 40.77, 100.0);
+        let operation = get_vertical_transformation(5782, 5730).unwrap();
+        let goal = get_vertical_coord_operation(5429).unwrap().apply(from);
+        assert_eq!(operation.apply(from), goal);
+        let back = get_vertical_transformation(5730, 5782).unwrap().apply(goal);
+        assert!((back.vertical - from.vertical).abs() < 1e-9);
+        // there is no direct route to EVRF2007 height, only through another national height system
+        let path = get_vertical_transformation_path(5782, 5621).unwrap();
+        eprintln!("{path:?}");
+        assert_eq!((path.len(), path[0]), (3, (5429, false)));
+        assert!(get_vertical_transformation(5782, 5621).is_some());
+        // depths are routed through the height CRS of their datum
+        let depth = get_vertical_transformation(5714, 5715).unwrap().apply(from);
+        assert_eq!(depth.vertical, -100.0);
+        assert_eq!(get_vertical_transformation_path(5715, 5714), Some(vec![]));
+        assert!(get_vertical_transformation(5782, 5714).is_none());
+        assert!(get_vertical_transformation(4326, 5730).is_none());
+    }
+
+    #[test]
+    fn transformation_in_area() {
+        // NAD27 to WGS 84 has operations for many regions of North America
+        let alaska = AreaOfUse::new(-155.0, 60.0, -150.0, 65.0);
+        let ontario = AreaOfUse::new(-81.0, 44.0, -80.0, 45.0);
+        let alternatives = get_transformation_alternatives(4267, 4326, &alaska);
+        eprintln!("{alternatives:?}");
+        assert_eq!(alternatives[0], (1176, false));
+        assert_eq!(
+            get_transformation_path_in_area(4267, 4326, &alaska),
+            Some(vec![(1176, false)])
+        );
+        let alternatives = get_transformation_alternatives(4267, 4326, &ontario);
+        assert!(alternatives.len() > 1 && !alternatives.contains(&(1176, false)));
+        assert_eq!(
+            get_transformation_path_in_area(4267, 4326, &ontario),
+            Some(vec![(1173, false)])
+        );
+        // an area crossing the antimeridian, with operations for the Aleutians on either side
+        let aleutians = AreaOfUse::new(175.0, 51.0, -175.0, 53.0);
+        let alternatives = get_transformation_alternatives(4267, 4326, &aleutians);
+        assert_eq!(alternatives, vec![(1249, false), (1250, false)]);
+        assert!(get_transformation_in_area(4267, 4326, &alaska).is_some());
+        let germany = AreaOfUse::new(5.0, 50.0, 10.0, 55.0);
+        assert!(get_transformation_in_area(4267, 4326, &germany).is_none());
+        assert!(get_coord_operation_areas(1176)
+            .unwrap()
+            .iter()
+            .any(|a| a.name.contains("Alaska")));
+    }
+}
@@ -0,0 +1,34 @@
+//This file is licensed under EUPL v1.2
+
+use miniproj_ops::unit::{Unit, UnitKind};
+
+include!(concat!(env!("OUT_DIR"), "/unit_constructors.rs"));
+
+/// Returns the unit of measure corresponding to the EPSG code passed as the argument.
+/// If the code does not refer to a unit of measure, the method returns `None`.
+pub fn get_unit(code: u32) -> Option<&'static Unit> {
+    UNITS.get(&code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn units() {
+        let foot = get_unit(9002).expect("Unit missing from registry.");
+        eprintln!("{foot:?}");
+        assert_eq!(foot.name, "foot");
+        assert_eq!(foot.kind, UnitKind::Length);
+        assert_eq!(foot.base_code, 9001);
+        assert!((foot.to_base(1.0).unwrap() - 0.3048).abs() < 1e-12);
+
+        let degree = get_unit(9102).expect("Unit missing from registry.");
+        assert_eq!(degree.kind, UnitKind::Angle);
+        assert!((degree.to_base(180.0).unwrap() - std::f64::consts::PI).abs() < 1e-12);
+
+        let sexagesimal = get_unit(9110).expect("Unit missing from registry.");
+        assert_eq!(sexagesimal.to_base_factor, None);
+        assert!(get_unit(1).is_none());
+    }
+}
@@ -0,0 +1,1232 @@
+//This file is licensed under EUPL v1.2
+
+use std::f64::consts::PI;
+
+use miniproj_ops::axis_order::AxisDirection;
+use miniproj_ops::projection_params::ProjectionParams;
+use miniproj_ops::Ellipsoid;
+
+use crate::{AreaOfUse, CrsDefinition};
+
+include!(concat!(env!("OUT_DIR"), "/name_constructors.rs"));
+
+/// Reason why a WKT string could not be turned into a `CrsDefinition`, as returned by
+/// [`parse_wkt`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WktError {
+    /// The text is not well-formed WKT, `expected` was expected at byte `position`.
+    Syntax {
+        position: usize,
+        expected: &'static str,
+    },
+    /// A required element, like the `ELLIPSOID` of the datum, is missing.
+    Missing(&'static str),
+    /// A value has the wrong type or is out of range, like a non-positive semi-major axis.
+    InvalidValue(&'static str),
+    /// The CRS is of a kind without projection, like a geocentric, vertical or compound CRS.
+    UnsupportedCrs(String),
+    /// The operation method is unknown or not implemented.
+    UnsupportedMethod(String),
+    /// The parameter name does not resolve to a parameter of the registry.
+    UnknownParameter(String),
+    /// The operation method requires a parameter that is not given.
+    MissingParameter { method: u32, parameter: u32 },
+}
+
+impl std::fmt::Display for WktError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax { position, expected } => {
+                write!(f, "malformed WKT, expected {expected} at byte {position}")
+            }
+            Self::Missing(keyword) => write!(f, "the WKT has no {keyword}"),
+            Self::InvalidValue(keyword) => write!(f, "the WKT has an invalid {keyword}"),
+            Self::UnsupportedCrs(keyword) => write!(f, "{keyword} CRSs have no projection"),
+            Self::UnsupportedMethod(name) => {
+                write!(f, "the operation method {name:?} is not implemented")
+            }
+            Self::UnknownParameter(name) => write!(f, "the parameter {name:?} is not known"),
+            Self::MissingParameter { method, parameter } => {
+                write!(
+                    f,
+                    "the operation method EPSG:{method} requires parameter EPSG:{parameter}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for WktError {}
+
+/// Value of a WKT element
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum WktValue {
+    /// quoted text
+    Text(String),
+    Number(f64),
+    /// unquoted enumeration value, like the axis direction `east`
+    Identifier(String),
+    Node(WktNode),
+}
+
+/// WKT element with its keyword in upper case, like `ELLIPSOID["WGS 84",6378137,298.257223563]`
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct WktNode {
+    pub keyword: String,
+    pub values: Vec<WktValue>,
+}
+
+impl WktNode {
+    /// Get the nested elements with one of `keywords`.
+    pub fn children<'a>(&'a self, keywords: &'a [&'a str]) -> impl Iterator<Item = &'a WktNode> {
+        self.values.iter().filter_map(move |v| match v {
+            WktValue::Node(n) if keywords.contains(&n.keyword.as_str()) => Some(n),
+            _ => None,
+        })
+    }
+
+    /// Get the first nested element with one of `keywords`.
+    pub fn child<'a>(&'a self, keywords: &'a [&'a str]) -> Option<&'a WktNode> {
+        self.children(keywords).next()
+    }
+
+    /// Get the nested element with one of `keywords` at any depth, depth first.
+    pub fn descendant(&self, keywords: &[&str]) -> Option<&WktNode> {
+        self.values.iter().find_map(|v| match v {
+            WktValue::Node(n) if keywords.contains(&n.keyword.as_str()) => Some(n),
+            WktValue::Node(n) => n.descendant(keywords),
+            _ => None,
+        })
+    }
+
+    /// Get the value at `index` as text, the name for most elements.
+    pub fn text(&self, index: usize) -> Option<&str> {
+        match self.values.get(index)? {
+            WktValue::Text(t) | WktValue::Identifier(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self, index: usize) -> Option<f64> {
+        match self.values.get(index)? {
+            WktValue::Number(n) => Some(*n),
+            WktValue::Text(t) => t.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the EPSG code of the `ID` or `AUTHORITY` element, if the authority is EPSG.
+    pub fn epsg_code(&self) -> Option<u32> {
+        self.children(&["ID", "AUTHORITY"])
+            .find(|id| id.text(0).is_some_and(|a| a.eq_ignore_ascii_case("EPSG")))
+            .and_then(|id| id.number(1))
+            .filter(|code| code.fract() == 0.0 && *code > 0.0 && *code <= u32::MAX as f64)
+            .map(|code| code as u32)
+    }
+
+    /// Get the name and conversion factor of the unit element with one of `keywords`.
+    pub fn unit(&self, keywords: &[&str]) -> Option<(String, f64)> {
+        let unit = self.child(keywords)?;
+        Some((unit.text(0)?.to_owned(), unit.number(1)?))
+    }
+
+    pub fn new(keyword: &str) -> Self {
+        Self {
+            keyword: keyword.to_owned(),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.values.push(WktValue::Text(text.to_owned()));
+        self
+    }
+
+    pub fn with_number(mut self, number: f64) -> Self {
+        self.values.push(WktValue::Number(number));
+        self
+    }
+
+    pub fn with_identifier(mut self, identifier: &str) -> Self {
+        self.values
+            .push(WktValue::Identifier(identifier.to_owned()));
+        self
+    }
+
+    pub fn with_node(mut self, node: WktNode) -> Self {
+        self.values.push(WktValue::Node(node));
+        self
+    }
+
+    /// Append an `ID["EPSG",code]` element if there is a code.
+    pub fn with_epsg_code(self, code: Option<u32>) -> Self {
+        match code {
+            Some(code) => self.with_node(
+                WktNode::new("ID")
+                    .with_text("EPSG")
+                    .with_number(code.into()),
+            ),
+            None => self,
+        }
+    }
+
+    fn write(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        write!(f, "{}[", self.keyword)?;
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            match value {
+                WktValue::Text(text) => write!(f, "\"{}\"", text.replace('"', "\"\""))?,
+                WktValue::Number(number) => write!(f, "{number}")?,
+                WktValue::Identifier(identifier) => f.write_str(identifier)?,
+                // nested elements go on lines of their own
+                WktValue::Node(node) => {
+                    write!(f, "\n{:indent$}", "", indent = 4 * (depth + 1))?;
+                    node.write(f, depth + 1)?;
+                }
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+impl std::fmt::Display for WktNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+/// Recursive descent parser of the WKT grammar shared by all dialects.
+struct Parser<'a> {
+    input: &'a [u8],
+    position: usize,
+    /// Number of elements the parser is currently nested in
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Deepest nesting of elements accepted, well above that of any CRS. Deeper input is rejected
+    /// as the parser and the resulting tree recurse once per level.
+    const MAX_DEPTH: usize = 64;
+
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    fn error(&self, expected: &'static str) -> WktError {
+        WktError::Syntax {
+            position: self.position,
+            expected,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .input
+            .get(self.position)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.get(self.position).copied()
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.position;
+        while self.input.get(self.position).is_some_and(|b| predicate(*b)) {
+            self.position += 1;
+        }
+        // the predicates only accept ASCII characters
+        std::str::from_utf8(&self.input[start..self.position]).unwrap_or_default()
+    }
+
+    /// Parse a whole WKT string, which has a single element at its root.
+    fn parse(mut self) -> Result<WktNode, WktError> {
+        let keyword = self
+            .take_identifier()
+            .ok_or_else(|| self.error("keyword"))?;
+        let node = self.parse_node(keyword)?;
+        match self.peek() {
+            None => Ok(node),
+            Some(_) => Err(self.error("end of input")),
+        }
+    }
+
+    fn take_identifier(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let identifier = self.take_while(|b| b.is_ascii_alphanumeric() || b == b'_');
+        (!identifier.is_empty()).then_some(identifier)
+    }
+
+    fn parse_node(&mut self, keyword: &str) -> Result<WktNode, WktError> {
+        if !matches!(self.peek(), Some(b'[' | b'(')) {
+            return Err(self.error("'['"));
+        }
+        if self.depth == Self::MAX_DEPTH {
+            return Err(self.error("at most 64 nested elements"));
+        }
+        self.position += 1;
+        self.depth += 1;
+        let mut values = Vec::new();
+        loop {
+            values.push(self.parse_value()?);
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']' | b')') => {
+                    self.position += 1;
+                    break;
+                }
+                _ => return Err(self.error("',' or ']'")),
+            }
+        }
+        self.depth -= 1;
+        Ok(WktNode {
+            keyword: keyword.to_ascii_uppercase(),
+            values,
+        })
+    }
+
+    fn parse_value(&mut self) -> Result<WktValue, WktError> {
+        match self.peek() {
+            Some(b'"') => {
+                self.position += 1;
+                let mut text = Vec::new();
+                loop {
+                    match self.input.get(self.position) {
+                        // quotes are escaped by doubling them
+                        Some(b'"') if self.input.get(self.position + 1) == Some(&b'"') => {
+                            text.push(b'"');
+                            self.position += 2;
+                        }
+                        Some(b'"') => {
+                            self.position += 1;
+                            break;
+                        }
+                        Some(b) => {
+                            text.push(*b);
+                            self.position += 1;
+                        }
+                        None => return Err(self.error("'\"'")),
+                    }
+                }
+                String::from_utf8(text)
+                    .map(WktValue::Text)
+                    .map_err(|_| self.error("UTF-8 text"))
+            }
+            Some(b'0'..=b'9' | b'+' | b'-' | b'.') => {
+                let start = self.position;
+                let number = self.take_while(|b| b.is_ascii_digit() || b"+-.eE".contains(&b));
+                number
+                    .parse()
+                    .map(WktValue::Number)
+                    .map_err(|_| WktError::Syntax {
+                        position: start,
+                        expected: "number",
+                    })
+            }
+            _ => {
+                let identifier = self.take_identifier().ok_or_else(|| self.error("value"))?;
+                if matches!(self.peek(), Some(b'[' | b'(')) {
+                    self.parse_node(identifier).map(WktValue::Node)
+                } else {
+                    Ok(WktValue::Identifier(identifier.to_owned()))
+                }
+            }
+        }
+    }
+}
+
+/// Parse the grammar of a WKT string, without interpreting the elements.
+pub(crate) fn parse_wkt_tree(wkt: &str) -> Result<WktNode, WktError> {
+    Parser::new(wkt).parse()
+}
+
+/// Lower case letters and digits of a name, for comparing names regardless of spelling.
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Get the EPSG code of the entry of `names` called `name`, ignoring case, spaces and punctuation.
+/// The lowest code wins if several entries share the name.
+fn lookup_name(names: &phf::Map<u32, &str>, name: &str) -> Option<u32> {
+    let name = normalize_name(name);
+    names
+        .entries()
+        .filter(|(_, n)| normalize_name(n) == name)
+        .map(|(c, _)| *c)
+        .min()
+}
+
+/// Get the EPSG code of the operation method called `name`, see `lookup_name`.
+pub(crate) fn method_code_by_name(name: &str) -> Option<u32> {
+    lookup_name(&METHOD_NAMES, name)
+}
+
+/// Get the EPSG code of the operation parameter called `name`, see `lookup_name`.
+pub(crate) fn parameter_code_by_name(name: &str) -> Option<u32> {
+    lookup_name(&PARAMETER_NAMES, name)
+}
+
+/// Get the name of the operation method with the EPSG code `code`.
+pub(crate) fn method_name(code: u32) -> Option<&'static str> {
+    METHOD_NAMES.get(&code).copied()
+}
+
+/// Get the name of the operation parameter with the EPSG code `code`.
+pub(crate) fn parameter_name(code: u32) -> Option<&'static str> {
+    PARAMETER_NAMES.get(&code).copied()
+}
+
+/// Get the name of the datum of the geographic CRS with the EPSG code `code`.
+pub(crate) fn datum_name(code: u32) -> Option<&'static str> {
+    DATUM_NAMES.get(&code).copied()
+}
+
+/// Get the name of the conversion of the projected CRS with the EPSG code `code`.
+pub(crate) fn conversion_name(code: u32) -> Option<&'static str> {
+    CONVERSION_NAMES.get(&code).copied()
+}
+
+pub(crate) fn ellipsoid_name(code: u32) -> Option<&'static str> {
+    ELLIPSOID_NAMES.get(&code).copied()
+}
+
+pub(crate) fn prime_meridian_name(code: u32) -> Option<&'static str> {
+    PRIME_MERIDIAN_NAMES.get(&code).copied()
+}
+
+/// Kind of the value of an operation parameter, by its name.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ParameterKind {
+    Angle,
+    Length,
+    Scale,
+}
+
+impl ParameterKind {
+    pub fn from_name(name: &str) -> Self {
+        let name = name.to_lowercase();
+        if ["easting", "northing", "height", "geocentric"]
+            .iter()
+            .any(|n| name.contains(n))
+        {
+            Self::Length
+        // scale factors and the ellipsoid scaling factor
+        } else if name.contains("scal") {
+            Self::Scale
+        } else {
+            Self::Angle
+        }
+    }
+}
+
+/// Construct the parameters of the operation method `method` with a getter of the parameter
+/// values by their codes, naming the first missing parameter if there is one.
+pub(crate) fn projection_params(
+    method: u32,
+    method_name: &str,
+    mut getter: impl FnMut(u32) -> Option<f64>,
+) -> Result<ProjectionParams, WktError> {
+    let mut missing = None;
+    ProjectionParams::from_getter(method, |code| {
+        let value = getter(code);
+        if value.is_none() {
+            missing.get_or_insert(code);
+        }
+        value
+    })
+    .ok_or_else(|| match missing {
+        Some(parameter) => WktError::MissingParameter { method, parameter },
+        None => WktError::UnsupportedMethod(method_name.to_owned()),
+    })
+}
+
+/// Geographic CRS, on its own or as the base of a projected CRS.
+struct Geographic {
+    name: String,
+    datum_name: String,
+    ellipsoid_name: String,
+    ellipsoid: Ellipsoid,
+    prime_meridian_name: String,
+    /// Greenwich longitude, degrees
+    prime_meridian: f64,
+    /// angle unit of the CRS, as its name and size in radians
+    unit: (String, f64),
+}
+
+/// Angle unit of CRSs without one
+fn degree() -> (String, f64) {
+    ("degree".to_owned(), PI / 180.0)
+}
+
+/// Length unit of CRSs without one
+fn metre() -> (String, f64) {
+    ("metre".to_owned(), 1.0)
+}
+
+const GEOGRAPHIC_KEYWORDS: &[&str] = &["GEOGCRS", "GEOGRAPHICCRS", "GEODCRS", "GEODETICCRS"];
+const BASE_KEYWORDS: &[&str] = &["BASEGEOGCRS", "BASEGEODCRS"];
+const DATUM_KEYWORDS: &[&str] = &["DATUM", "GEODETICDATUM", "TRF", "ENSEMBLE", "DATUMENSEMBLE"];
+const UNIT_KEYWORDS: &[&str] = &["ANGLEUNIT", "LENGTHUNIT", "SCALEUNIT", "UNIT"];
+
+fn geographic(node: &WktNode) -> Result<Geographic, WktError> {
+    let datum = node
+        .child(DATUM_KEYWORDS)
+        .ok_or(WktError::Missing("DATUM"))?;
+    let ellipsoid_node = datum
+        .descendant(&["ELLIPSOID", "SPHEROID"])
+        .ok_or(WktError::Missing("ELLIPSOID"))?;
+    let a = ellipsoid_node
+        .number(1)
+        .ok_or(WktError::InvalidValue("ELLIPSOID"))?;
+    let f_inv = ellipsoid_node
+        .number(2)
+        .ok_or(WktError::InvalidValue("ELLIPSOID"))?;
+    let a = a * ellipsoid_node
+        .unit(&["LENGTHUNIT", "UNIT"])
+        .map_or(1.0, |(_, f)| f);
+    if !(a > 0.0 && f_inv >= 0.0) {
+        return Err(WktError::InvalidValue("ELLIPSOID"));
+    }
+    // an inverse flattening of 0 denotes a sphere
+    let ellipsoid = if f_inv == 0.0 {
+        Ellipsoid::from_a_b(a, a)
+    } else {
+        Ellipsoid::from_a_f_inv(a, f_inv)
+    };
+    let unit = node
+        .child(&["AXIS"])
+        .and_then(|axis| axis.unit(&["ANGLEUNIT", "UNIT"]))
+        .or_else(|| node.unit(&["ANGLEUNIT", "UNIT"]))
+        .unwrap_or_else(degree);
+    let (prime_meridian_name, prime_meridian) = match node.child(&["PRIMEM", "PRIMEMERIDIAN"]) {
+        Some(pm) => {
+            let lon = pm.number(1).ok_or(WktError::InvalidValue("PRIMEM"))?;
+            // GDAL and ESRI write WKT1 prime meridians in degrees, whatever the unit of the CRS
+            let default = if node.keyword == "GEOGCS" {
+                PI / 180.0
+            } else {
+                unit.1
+            };
+            let factor = pm.unit(&["ANGLEUNIT", "UNIT"]).map_or(default, |(_, f)| f);
+            (
+                pm.text(0).unwrap_or_default().to_owned(),
+                (lon * factor).to_degrees(),
+            )
+        }
+        None => ("Greenwich".to_owned(), 0.0),
+    };
+    Ok(Geographic {
+        name: node.text(0).unwrap_or_default().to_owned(),
+        datum_name: datum.text(0).unwrap_or_default().to_owned(),
+        ellipsoid_name: ellipsoid_node.text(0).unwrap_or_default().to_owned(),
+        ellipsoid,
+        prime_meridian_name,
+        prime_meridian,
+        unit,
+    })
+}
+
+/// Get the directions of the first two axes of a CRS, east and north if there are none.
+fn axes(node: &WktNode) -> [AxisDirection; 2] {
+    let mut directions = node.children(&["AXIS"]).map(|axis| {
+        AxisDirection::from_epsg_orientation(&axis.text(1).unwrap_or_default().to_lowercase())
+    });
+    match (directions.next(), directions.next()) {
+        (Some(first), Some(second)) => [first, second],
+        _ => [AxisDirection::East, AxisDirection::North],
+    }
+}
+
+/// Construct the projection of the `CONVERSION` of a WKT2 projected CRS, whose parameters without
+/// a unit are in `length_unit` if they are lengths.
+fn wkt2_projection(conversion: &WktNode, length_unit: f64) -> Result<ProjectionParams, WktError> {
+    let method = conversion
+        .child(&["METHOD"])
+        .ok_or(WktError::Missing("METHOD"))?;
+    let method_name = method.text(0).unwrap_or_default();
+    let method_code = method
+        .epsg_code()
+        .or_else(|| method_code_by_name(method_name))
+        .ok_or_else(|| WktError::UnsupportedMethod(method_name.to_owned()))?;
+    let mut params = Vec::new();
+    for parameter in conversion.children(&["PARAMETER"]) {
+        let name = parameter.text(0).unwrap_or_default();
+        let code = parameter
+            .epsg_code()
+            .or_else(|| parameter_code_by_name(name))
+            .ok_or_else(|| WktError::UnknownParameter(name.to_owned()))?;
+        let value = parameter
+            .number(1)
+            .ok_or(WktError::InvalidValue("PARAMETER"))?;
+        let factor = match parameter.unit(UNIT_KEYWORDS) {
+            Some((_, factor)) => factor,
+            None => match ParameterKind::from_name(name) {
+                ParameterKind::Angle => PI / 180.0,
+                ParameterKind::Length => length_unit,
+                ParameterKind::Scale => 1.0,
+            },
+        };
+        params.push((code, value * factor));
+    }
+    projection_params(method_code, method_name, |code| {
+        params.iter().find_map(|(c, v)| (*c == code).then_some(*v))
+    })
+}
+
+/// Operation methods by their WKT1 (OGC 01-009, as written by GDAL) and ESRI projection names,
+/// normalized like `normalize_name`. Where a name stands for several methods, the first one whose
+/// parameters are all given is used.
+const WKT1_METHODS: &[(&str, &[u32])] = &[
+    ("transversemercator", &[9807]),
+    ("gausskruger", &[9807]),
+    ("transversemercatorsouthorientated", &[9808]),
+    ("lambertconformalconic1sp", &[9801]),
+    ("lambertconformalconic2sp", &[9802]),
+    ("lambertconformalconic2spbelgium", &[9803]),
+    // ESRI writes both variants without the number of standard parallels
+    ("lambertconformalconic", &[9802, 9801]),
+    ("mercator1sp", &[9804]),
+    ("mercator2sp", &[9805]),
+    ("mercator", &[9805, 9804]),
+    ("mercatorauxiliarysphere", &[1024]),
+    ("popularvisualisationpseudomercator", &[1024]),
+    ("polarstereographic", &[9810, 9829]),
+    ("stereographicnorthpole", &[9829]),
+    ("stereographicsouthpole", &[9829]),
+    ("obliquestereographic", &[9809]),
+    ("doublestereographic", &[9809]),
+    ("albersconicequalarea", &[9822]),
+    ("albers", &[9822]),
+    ("lambertazimuthalequalarea", &[9820]),
+    ("cassinisoldner", &[9806]),
+    ("cassini", &[9806]),
+    ("hotineobliquemercator", &[9812]),
+    ("hotineobliquemercatorazimuthnaturalorigin", &[9812]),
+    ("obliquemercator", &[9815]),
+    ("hotineobliquemercatorazimuthcenter", &[9815]),
+    ("krovak", &[9819]),
+    ("equirectangular", &[1028]),
+    ("equidistantcylindrical", &[1028]),
+    ("cylindricalequalarea", &[9835]),
+    ("azimuthalequidistant", &[1125]),
+    ("bonne", &[9827]),
+    ("equalearth", &[1078]),
+];
+
+/// EPSG parameters by their WKT1 and ESRI names, normalized like `normalize_name`. The names stand
+/// for different parameters in different methods, e.g. `central_meridian` is the longitude of the
+/// natural origin of Transverse Mercator but the longitude of the false origin of Lambert Conic
+/// Conformal (2SP), so each name lists all parameters it stands for. If several names stand for
+/// a parameter of a method, the one listing it earlier is used.
+const WKT1_PARAMETERS: &[(&str, &[u32])] = &[
+    ("latitudeoforigin", &[8801, 8821, 8811, 8832]),
+    ("centralmeridian", &[8802, 8822, 8812, 8833]),
+    ("longitudeoforigin", &[8802, 8822, 8833]),
+    ("latitudeofcenter", &[8811, 8801, 8821]),
+    ("longitudeofcenter", &[8812, 8802, 8822, 8833]),
+    ("scalefactor", &[8805, 8815, 8819]),
+    ("falseeasting", &[8806, 8826, 8816]),
+    ("falsenorthing", &[8807, 8827, 8817]),
+    ("standardparallel1", &[8823, 8832, 8801]),
+    ("standardparallel2", &[8824]),
+    ("azimuth", &[8813, 1036]),
+    ("rectifiedgridangle", &[8814]),
+    ("xyplanerotation", &[8814]),
+    ("pseudostandardparallel1", &[8818]),
+];
+
+/// Construct the projection of a WKT1 or ESRI projected CRS from its `PROJECTION` and `PARAMETER`s,
+/// which are in `angle_unit` or `length_unit`.
+fn wkt1_projection(
+    root: &WktNode,
+    angle_unit: f64,
+    length_unit: f64,
+) -> Result<ProjectionParams, WktError> {
+    let projection = root
+        .child(&["PROJECTION"])
+        .ok_or(WktError::Missing("PROJECTION"))?;
+    let method_name = projection.text(0).unwrap_or_default();
+    let normalized = normalize_name(method_name);
+    let methods = match projection.epsg_code() {
+        Some(code) => vec![code],
+        None => match WKT1_METHODS.iter().find(|(name, _)| *name == normalized) {
+            Some((_, methods)) => methods.to_vec(),
+            None => method_code_by_name(method_name).into_iter().collect(),
+        },
+    };
+    // parameters not standing for any EPSG parameter, like the ESRI `Auxiliary_Sphere_Type`, are
+    // left out
+    let mut params = Vec::new();
+    for parameter in root.children(&["PARAMETER"]) {
+        let name = parameter.text(0).unwrap_or_default();
+        let value = parameter
+            .number(1)
+            .ok_or(WktError::InvalidValue("PARAMETER"))?;
+        let normalized = normalize_name(name);
+        let codes = match parameter.epsg_code() {
+            Some(code) => vec![code],
+            None => match WKT1_PARAMETERS.iter().find(|(name, _)| *name == normalized) {
+                Some((_, codes)) => codes.to_vec(),
+                None => parameter_code_by_name(name).into_iter().collect(),
+            },
+        };
+        params.push((codes, value));
+    }
+    let value = |code: u32| {
+        let (_, value) = params
+            .iter()
+            .filter_map(|(codes, value)| Some((codes.iter().position(|c| *c == code)?, *value)))
+            .min_by_key(|(position, _)| *position)?;
+        Some(
+            match ParameterKind::from_name(parameter_name(code).unwrap_or_default()) {
+                ParameterKind::Angle => value * angle_unit,
+                ParameterKind::Length => value * length_unit,
+                ParameterKind::Scale => value,
+            },
+        )
+    };
+    let mut error = WktError::UnsupportedMethod(method_name.to_owned());
+    for (i, method) in methods.into_iter().enumerate() {
+        // variant A of the polar stereographic projection has its natural origin at a pole
+        let origin = value(8801).unwrap_or_default();
+        if method == 9810 && (origin.abs() - PI / 2.0).abs() > 1e-9 {
+            continue;
+        }
+        match projection_params(method, method_name, value) {
+            Ok(params) => return Ok(params),
+            // report the error of the preferred method
+            Err(e) if i == 0 => error = e,
+            Err(_) => {}
+        }
+    }
+    Err(error)
+}
+
+/// Parses a WKT string of a geographic or projected CRS into a `CrsDefinition`. The projection is
+/// constructed with [`CrsDefinition::to_projection`].
+///
+/// WKT2 (ISO 19162) as exported by PROJ and GDAL is supported, as well as the older WKT1
+/// (OGC 01-009) and the ESRI dialect of WKT1 found in `.prj` files of shapefiles.
+/// WKT2 methods and parameters are resolved by their EPSG `ID`s, or by their EPSG names otherwise,
+/// ignoring case, spaces and punctuation. Values are converted to radians and metres with their
+/// units, parameters without a unit are taken as degrees, units of the CRS or unity by their
+/// names. WKT1 projection and parameter names like `"Central_Meridian"` are mapped to the EPSG
+/// method and parameters they stand for in the method, and parameters are in the units of the
+/// geographic and projected CRS. Usage and remarks are ignored.
+pub fn parse_wkt(wkt: &str) -> Result<CrsDefinition, WktError> {
+    let root = parse_wkt_tree(wkt)?;
+    let base = match root.keyword.as_str() {
+        "PROJCRS" | "PROJECTEDCRS" => Some(
+            root.child(BASE_KEYWORDS)
+                .ok_or(WktError::Missing("BASEGEOGCRS"))?,
+        ),
+        "PROJCS" => Some(root.child(&["GEOGCS"]).ok_or(WktError::Missing("GEOGCS"))?),
+        keyword if GEOGRAPHIC_KEYWORDS.contains(&keyword) => {
+            let cs = root.child(&["CS"]).and_then(|cs| cs.text(0));
+            if cs.is_some_and(|cs| !cs.eq_ignore_ascii_case("ellipsoidal")) {
+                return Err(WktError::UnsupportedCrs(keyword.to_owned()));
+            }
+            None
+        }
+        "GEOGCS" => None,
+        keyword => return Err(WktError::UnsupportedCrs(keyword.to_owned())),
+    };
+    let geographic = geographic(base.unwrap_or(&root))?;
+    let (unit_name, unit_factor) = match base {
+        Some(_) => root
+            .child(&["AXIS"])
+            .and_then(|axis| axis.unit(&["LENGTHUNIT", "UNIT"]))
+            .or_else(|| root.unit(&["LENGTHUNIT", "UNIT"]))
+            .unwrap_or_else(metre),
+        None => geographic.unit.clone(),
+    };
+    let (conversion_name, projection) = match (base, root.child(&["CONVERSION"])) {
+        (Some(_), Some(conversion)) => (
+            conversion.text(0).unwrap_or_default().to_owned(),
+            Some(wkt2_projection(conversion, unit_factor)?),
+        ),
+        (Some(_), None) if root.keyword == "PROJCS" => (
+            "unknown".to_owned(),
+            Some(wkt1_projection(&root, geographic.unit.1, unit_factor)?),
+        ),
+        (Some(_), None) => return Err(WktError::Missing("CONVERSION")),
+        (None, _) => (String::new(), None),
+    };
+    Ok(CrsDefinition {
+        name: root.text(0).unwrap_or_default().to_owned(),
+        epsg: root.epsg_code(),
+        base_name: geographic.name,
+        base_epsg: base.and_then(WktNode::epsg_code),
+        datum_name: geographic.datum_name,
+        ellipsoid_name: geographic.ellipsoid_name,
+        ellipsoid: geographic.ellipsoid,
+        prime_meridian_name: geographic.prime_meridian_name,
+        prime_meridian: geographic.prime_meridian,
+        conversion_name,
+        projection,
+        unit_name,
+        unit_factor,
+        axes: axes(&root),
+    })
+}
+
+/// WKT element of a unit, like `LENGTHUNIT["metre",1]`
+fn unit_node(keyword: &str, name: &str, factor: f64) -> WktNode {
+    WktNode::new(keyword).with_text(name).with_number(factor)
+}
+
+/// Round off the noise of converting angles to radians and back, like `8.999999999999991`.
+/// 1e-12 degrees are well below a micrometre.
+pub(crate) fn round_degrees(degrees: f64) -> f64 {
+    (degrees * 1e12).round() / 1e12
+}
+
+/// Get the WKT2 axis name for the direction, on geographic or projected CRSs.
+fn axis_name(direction: AxisDirection, geographic: bool) -> &'static str {
+    match (direction, geographic) {
+        (AxisDirection::North, true) => "geodetic latitude (Lat)",
+        (AxisDirection::East, true) => "geodetic longitude (Lon)",
+        (AxisDirection::East, false) => "easting (E)",
+        (AxisDirection::West, false) => "westing (W)",
+        (AxisDirection::North, false) => "northing (N)",
+        (AxisDirection::South, false) => "southing (S)",
+        _ => "unknown",
+    }
+}
+
+/// Get the WKT2 axis direction, the inverse of `AxisDirection::from_epsg_orientation`.
+fn axis_direction(direction: AxisDirection) -> &'static str {
+    match direction {
+        AxisDirection::East => "east",
+        AxisDirection::West => "west",
+        AxisDirection::North => "north",
+        AxisDirection::South => "south",
+        AxisDirection::Up => "up",
+        AxisDirection::Down => "down",
+        AxisDirection::Other => "unspecified",
+    }
+}
+
+/// Write a CRS definition as WKT2, with the bounding box of `area` as its usage.
+pub(crate) fn write_wkt2(definition: &CrsDefinition, area: Option<&AreaOfUse>) -> String {
+    let degree = || unit_node("ANGLEUNIT", "degree", PI / 180.0);
+    let metre = || unit_node("LENGTHUNIT", "metre", 1.0);
+    let f = definition.ellipsoid.f();
+    let datum = WktNode::new("DATUM")
+        .with_text(&definition.datum_name)
+        .with_node(
+            WktNode::new("ELLIPSOID")
+                .with_text(&definition.ellipsoid_name)
+                .with_number(definition.ellipsoid.a())
+                // spheres have an inverse flattening of 0
+                .with_number(if f == 0.0 { 0.0 } else { 1.0 / f })
+                .with_node(metre()),
+        );
+    let prime_meridian = WktNode::new("PRIMEM")
+        .with_text(&definition.prime_meridian_name)
+        .with_number(round_degrees(definition.prime_meridian))
+        .with_node(degree());
+    let mut root = match &definition.projection {
+        Some(params) => {
+            let method = params.method_code();
+            let mut conversion = WktNode::new("CONVERSION")
+                .with_text(&definition.conversion_name)
+                .with_node(
+                    WktNode::new("METHOD")
+                        .with_text(method_name(method).unwrap_or("unknown"))
+                        .with_epsg_code(Some(method)),
+                );
+            for (code, value) in params.to_params() {
+                let name = parameter_name(code).unwrap_or("unknown");
+                let (value, unit) = match ParameterKind::from_name(name) {
+                    ParameterKind::Angle => (round_degrees(value.to_degrees()), degree()),
+                    ParameterKind::Length => (value, metre()),
+                    ParameterKind::Scale => (value, unit_node("SCALEUNIT", "unity", 1.0)),
+                };
+                conversion = conversion.with_node(
+                    WktNode::new("PARAMETER")
+                        .with_text(name)
+                        .with_number(value)
+                        .with_node(unit)
+                        .with_epsg_code(Some(code)),
+                );
+            }
+            let base = WktNode::new("BASEGEOGCRS")
+                .with_text(&definition.base_name)
+                .with_node(datum)
+                .with_node(prime_meridian)
+                .with_epsg_code(definition.base_epsg);
+            WktNode::new("PROJCRS")
+                .with_text(&definition.name)
+                .with_node(base)
+                .with_node(conversion)
+                .with_node(
+                    WktNode::new("CS")
+                        .with_identifier("Cartesian")
+                        .with_number(2.0),
+                )
+        }
+        None => WktNode::new("GEOGCRS")
+            .with_text(&definition.name)
+            .with_node(datum)
+            .with_node(prime_meridian)
+            .with_node(
+                WktNode::new("CS")
+                    .with_identifier("ellipsoidal")
+                    .with_number(2.0),
+            ),
+    };
+    let unit_keyword = if definition.is_projected() {
+        "LENGTHUNIT"
+    } else {
+        "ANGLEUNIT"
+    };
+    for (i, direction) in definition.axes.iter().enumerate() {
+        root = root.with_node(
+            WktNode::new("AXIS")
+                .with_text(axis_name(*direction, !definition.is_projected()))
+                .with_identifier(axis_direction(*direction))
+                .with_node(WktNode::new("ORDER").with_number(i as f64 + 1.0))
+                .with_node(unit_node(
+                    unit_keyword,
+                    &definition.unit_name,
+                    definition.unit_factor,
+                )),
+        );
+    }
+    if let Some(area) = area {
+        let bbox = WktNode::new("BBOX")
+            .with_number(area.south)
+            .with_number(area.west)
+            .with_number(area.north)
+            .with_number(area.east);
+        root = root.with_node(
+            WktNode::new("USAGE")
+                .with_node(WktNode::new("SCOPE").with_text("unknown"))
+                .with_node(WktNode::new("AREA").with_text(area.name))
+                .with_node(bbox),
+        );
+    }
+    root.with_epsg_code(definition.epsg).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection_constructor::all_codes;
+    use crate::{get_projection, get_projection_params, Crs};
+
+    const UTM_32N: &str = r#"PROJCRS["WGS 84 / UTM zone 32N",
+    BASEGEOGCRS["WGS 84",
+        ENSEMBLE["World Geodetic System 1984 ensemble",
+            MEMBER["World Geodetic System 1984 (Transit)"],
+            MEMBER["World Geodetic System 1984 (G2139)"],
+            ELLIPSOID["WGS 84",6378137,298.257223563,
+                LENGTHUNIT["metre",1]],
+            ENSEMBLEACCURACY[2.0]],
+        PRIMEM["Greenwich",0,
+            ANGLEUNIT["degree",0.0174532925199433]],
+        ID["EPSG",4326]],
+    CONVERSION["UTM zone 32N",
+        METHOD["Transverse Mercator",
+            ID["EPSG",9807]],
+        PARAMETER["Latitude of natural origin",0,
+            ANGLEUNIT["degree",0.0174532925199433],
+            ID["EPSG",8801]],
+        PARAMETER["Longitude of natural origin",9,
+            ANGLEUNIT["degree",0.0174532925199433],
+            ID["EPSG",8802]],
+        PARAMETER["Scale factor at natural origin",0.9996,
+            SCALEUNIT["unity",1],
+            ID["EPSG",8805]],
+        PARAMETER["False easting",500000,
+            LENGTHUNIT["metre",1],
+            ID["EPSG",8806]],
+        PARAMETER["False northing",0,
+            LENGTHUNIT["metre",1],
+            ID["EPSG",8807]]],
+    CS[Cartesian,2],
+        AXIS["(E)",east,
+            ORDER[1],
+            LENGTHUNIT["metre",1]],
+        AXIS["(N)",north,
+            ORDER[2],
+            LENGTHUNIT["metre",1]],
+    USAGE[
+        SCOPE["Navigation and medium accuracy spatial referencing."],
+        AREA["Between 6°E and 12°E, northern hemisphere between equator and 84°N."],
+        BBOX[0,6,84,12]],
+    ID["EPSG",32632]]"#;
+
+    /// NTF (Paris) / Lambert zone II without IDs, with grads and a non-Greenwich prime meridian
+    const LAMBERT_II: &str = r#"PROJCRS["NTF (Paris) / Lambert zone II",
+    BASEGEOGCRS["NTF (Paris)",
+        DATUM["Nouvelle Triangulation Francaise (Paris)",
+            ELLIPSOID["Clarke 1880 (IGN)",6378249.2,293.466021293627]],
+        PRIMEM["Paris",2.5969213,ANGLEUNIT["grad",0.0157079632679489]]],
+    CONVERSION["Lambert zone II",
+        METHOD["Lambert Conic Conformal (1SP)"],
+        PARAMETER["Latitude of natural origin",52,ANGLEUNIT["grad",0.0157079632679489]],
+        PARAMETER["Longitude of natural origin",0,ANGLEUNIT["grad",0.0157079632679489]],
+        PARAMETER["Scale factor at natural origin",0.99987742],
+        PARAMETER["False easting",600000],
+        PARAMETER["False northing",2200000]],
+    CS[Cartesian,2],
+        AXIS["easting (X)",east],
+        AXIS["northing (Y)",north],
+        LENGTHUNIT["metre",1]]"#;
+
+    #[test]
+    fn wkt2_projected() {
+        let definition = parse_wkt(UTM_32N).unwrap();
+        eprintln!("{definition:?}");
+        assert_eq!(definition.name, "WGS 84 / UTM zone 32N");
+        assert_eq!(
+            (definition.epsg, definition.method_code()),
+            (Some(32632), Some(9807))
+        );
+        assert_eq!(definition.ellipsoid_name, "WGS 84");
+        assert_eq!(definition.axes, [AxisDirection::East, AxisDirection::North]);
+        let projection = definition.to_projection();
+        let registry = get_projection(32632).unwrap();
+        let (x, y) = projection.deg_to_projected(10.0, 54.0);
+        let (x_r, y_r) = registry.deg_to_projected(10.0, 54.0);
+        assert!((x - x_r).abs() < 1e-6 && (y - y_r).abs() < 1e-6);
+
+        let definition = parse_wkt(LAMBERT_II).unwrap();
+        assert_eq!(
+            (definition.epsg, definition.method_code()),
+            (None, Some(9801))
+        );
+        assert!((definition.prime_meridian - 2.33722917).abs() < 1e-8);
+        let (lon, lat) = definition
+            .to_projection()
+            .projected_to_deg(600000.0, 2200000.0);
+        let (lon_r, lat_r) = get_projection(27572)
+            .unwrap()
+            .projected_to_deg(600000.0, 2200000.0);
+        assert!((lon - lon_r).abs() < 1e-9 && (lat - lat_r).abs() < 1e-9);
+    }
+
+    #[test]
+    fn wkt2_geographic() {
+        let wkt = r#"GEOGCRS["WGS 84",
+            DATUM["World Geodetic System 1984",ELLIPSOID["WGS 84",6378137,298.257223563]],
+            CS[ellipsoidal,2],
+            AXIS["geodetic latitude (Lat)",north,ORDER[1]],
+            AXIS["geodetic longitude (Lon)",east,ORDER[2]],
+            ANGLEUNIT["degree",0.0174532925199433],
+            ID["EPSG",4326]]"#;
+        let definition = parse_wkt(wkt).unwrap();
+        assert!(!definition.is_projected());
+        assert_eq!(definition.epsg, Some(4326));
+        assert_eq!(definition.axes, [AxisDirection::North, AxisDirection::East]);
+        assert_eq!(
+            definition.to_projection().deg_to_projected(10.0, 54.0),
+            (10.0, 54.0)
+        );
+        assert!((definition.ellipsoid.a() - 6378137.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn wkt2_export() {
+        for code in all_codes() {
+            let crs = Crs::from_epsg(code).unwrap();
+            let definition = crs.to_definition().unwrap_or_else(|| panic!("EPSG:{code}"));
+            let wkt = crs.to_wkt2().unwrap();
+            let parsed = parse_wkt(&wkt).unwrap_or_else(|e| panic!("EPSG:{code}: {e}\n{wkt}"));
+            assert_eq!(
+                (parsed.epsg, parsed.method_code()),
+                (Some(code), crs.method_code())
+            );
+            assert_eq!(
+                (&parsed.name, parsed.axes),
+                (&definition.name, definition.axes)
+            );
+            assert_eq!(parsed.unit_factor, definition.unit_factor);
+            assert!((parsed.prime_meridian - definition.prime_meridian).abs() < 1e-11);
+            let params = parsed.projection.as_ref().map(ProjectionParams::to_params);
+            let expected = definition
+                .projection
+                .as_ref()
+                .map(ProjectionParams::to_params);
+            for ((c, v), (c_e, v_e)) in params.iter().flatten().zip(expected.iter().flatten()) {
+                assert_eq!(c, c_e);
+                assert!(
+                    (v - v_e).abs() <= 1e-9 * v_e.abs().max(1.0),
+                    "EPSG:{code}: {v} != {v_e}"
+                );
+            }
+        }
+
+        let wkt = Crs::from_epsg(32632).unwrap().to_wkt2().unwrap();
+        eprintln!("{wkt}");
+        assert!(wkt.starts_with("PROJCRS[\"WGS 84 / UTM zone 32N\",\n    BASEGEOGCRS[\"WGS 84\","));
+        assert!(wkt.contains("METHOD[\"Transverse Mercator\",\n            ID[\"EPSG\",9807]]"));
+        assert!(wkt.contains("BBOX[0,6,84,12]") && wkt.ends_with("ID[\"EPSG\",32632]]"));
+
+        // custom projections
+        let params = get_projection_params(32632).unwrap();
+        let sphere = Ellipsoid::from_a_b(6371000.0, 6371000.0);
+        let custom = CrsDefinition::new_projected("UTM 32N on a sphere", sphere, params);
+        let wkt = custom.to_wkt2();
+        assert!(wkt.contains("ELLIPSOID[\"unknown\",6371000,0,"));
+        let parsed = parse_wkt(&wkt).unwrap();
+        assert_eq!((parsed.epsg, parsed.ellipsoid), (None, sphere));
+        let (x, y) = parsed.to_projection().deg_to_projected(10.0, 54.0);
+        let (x_c, y_c) = custom.to_projection().deg_to_projected(10.0, 54.0);
+        assert!((x - x_c).abs() < 1e-6 && (y - y_c).abs() < 1e-6);
+    }
+
+    /// Assert that the definition projects like the registry CRS `code` around the point `(x, y)`,
+    /// to a millimetre as ESRI rounds the Paris meridian differently.
+    fn assert_like_registry(definition: &CrsDefinition, code: u32, x: f64, y: f64) {
+        let projection = definition.to_projection();
+        let registry = get_projection(code).unwrap();
+        for (x, y) in [(x, y), (x + 50000.0, y - 50000.0)] {
+            let (lon, lat) = projection.projected_to_deg(x, y);
+            let (lon_r, lat_r) = registry.projected_to_deg(x, y);
+            assert!(
+                (lon - lon_r).abs() < 1e-8 && (lat - lat_r).abs() < 1e-8,
+                "EPSG:{code}: {lon}, {lat} != {lon_r}, {lat_r}"
+            );
+        }
+    }
+
+    #[test]
+    fn wkt1_and_esri() {
+        let gdal = r#"PROJCS["WGS 84 / UTM zone 32N",GEOGCS["WGS 84",DATUM["WGS_1984",
+            SPHEROID["WGS 84",6378137,298.257223563,AUTHORITY["EPSG","7030"]],
+            AUTHORITY["EPSG","6326"]],PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],
+            UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],AUTHORITY["EPSG","4326"]],
+            PROJECTION["Transverse_Mercator"],PARAMETER["latitude_of_origin",0],
+            PARAMETER["central_meridian",9],PARAMETER["scale_factor",0.9996],
+            PARAMETER["false_easting",500000],PARAMETER["false_northing",0],
+            UNIT["metre",1,AUTHORITY["EPSG","9001"]],AXIS["Easting",EAST],AXIS["Northing",NORTH],
+            AUTHORITY["EPSG","32632"]]"#;
+        let definition = parse_wkt(gdal).unwrap();
+        assert_eq!(
+            (definition.epsg, definition.base_epsg),
+            (Some(32632), Some(4326))
+        );
+        assert_eq!(definition.method_code(), Some(9807));
+        assert_like_registry(&definition, 32632, 500000.0, 6000000.0);
+
+        // ESRI writes both Lambert Conic Conformal variants alike, and parameters in grads here
+        let esri = r#"PROJCS["NTF_Paris_Lambert_Zone_II",GEOGCS["GCS_NTF_Paris",DATUM["D_NTF",
+            SPHEROID["Clarke_1880_IGN",6378249.2,293.4660212936269]],
+            PRIMEM["Paris",2.337229166666667],UNIT["Grad",0.01570796326794897]],
+            PROJECTION["Lambert_Conformal_Conic"],PARAMETER["False_Easting",600000.0],
+            PARAMETER["False_Northing",2200000.0],PARAMETER["Central_Meridian",0.0],
+            PARAMETER["Standard_Parallel_1",52.0],PARAMETER["Scale_Factor",0.99987742],
+            PARAMETER["Latitude_Of_Origin",52.0],UNIT["Meter",1.0]]"#;
+        let definition = parse_wkt(esri).unwrap();
+        assert_eq!(
+            (definition.epsg, definition.method_code()),
+            (None, Some(9801))
+        );
+        assert_like_registry(&definition, 27572, 600000.0, 2200000.0);
+
+        let esri = r#"PROJCS["NAD_1983_StatePlane_California_III_FIPS_0403_Feet",
+            GEOGCS["GCS_North_American_1983",DATUM["D_North_American_1983",
+            SPHEROID["GRS_1980",6378137.0,298.257222101]],PRIMEM["Greenwich",0.0],
+            UNIT["Degree",0.0174532925199433]],PROJECTION["Lambert_Conformal_Conic"],
+            PARAMETER["False_Easting",6561666.666666666],
+            PARAMETER["False_Northing",1640416.666666667],
+            PARAMETER["Central_Meridian",-120.5],PARAMETER["Standard_Parallel_1",37.06666666666667],
+            PARAMETER["Standard_Parallel_2",38.43333333333333],PARAMETER["Latitude_Of_Origin",36.5],
+            UNIT["Foot_US",0.3048006096012192]]"#;
+        let definition = parse_wkt(esri).unwrap();
+        assert_eq!(definition.method_code(), Some(9802));
+        assert_eq!(definition.unit_name, "Foot_US");
+        assert_like_registry(&definition, 2227, 2000000.0, 500000.0);
+
+        // the polar stereographic variant follows from the latitude of origin
+        let polar = |lat: f64, k: f64, false_easting: f64| {
+            format!(
+                r#"PROJCS["",GEOGCS["WGS 84",DATUM["WGS_1984",
+                SPHEROID["WGS 84",6378137,298.257223563]],PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]],
+                PROJECTION["Polar_Stereographic"],PARAMETER["latitude_of_origin",{lat}],
+                PARAMETER["central_meridian",0],PARAMETER["scale_factor",{k}],
+                PARAMETER["false_easting",{false_easting}],
+                PARAMETER["false_northing",{false_easting}],
+                UNIT["metre",1]]"#
+            )
+        };
+        let definition = parse_wkt(&polar(-71.0, 1.0, 0.0)).unwrap();
+        assert_eq!(definition.method_code(), Some(9829));
+        assert_like_registry(&definition, 3031, 100000.0, 100000.0);
+        let definition = parse_wkt(&polar(90.0, 0.994, 2000000.0)).unwrap();
+        assert_eq!(definition.method_code(), Some(9810));
+        assert_like_registry(&definition, 32661, 2100000.0, 2100000.0);
+
+        let mercator = polar(0.0, 1.0, 0.0).replace("Polar_Stereographic", "Mercator_1SP");
+        assert_eq!(
+            parse_wkt(&mercator),
+            Err(WktError::UnsupportedMethod("Mercator_1SP".into()))
+        );
+        let geographic = r#"GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984",
+            SPHEROID["WGS_1984",6378137.0,298.257223563]],PRIMEM["Greenwich",0.0],
+            UNIT["Degree",0.0174532925199433]]"#;
+        let definition = parse_wkt(geographic).unwrap();
+        assert!(!definition.is_projected() && definition.datum_name == "D_WGS_1984");
+    }
+
+    #[test]
+    fn wkt_errors() {
+        assert!(matches!(
+            parse_wkt("PROJCRS[\"x\""),
+            Err(WktError::Syntax { .. })
+        ));
+        assert!(matches!(
+            parse_wkt("GEOGCRS[\"x\"] trailing"),
+            Err(WktError::Syntax { .. })
+        ));
+        let nested = "PROJCRS[".repeat(100_000) + &"]".repeat(100_000);
+        let error = parse_wkt(&nested).unwrap_err();
+        eprintln!("{error}");
+        assert_eq!(
+            error,
+            WktError::Syntax {
+                position: 65 * 8 - 1,
+                expected: "at most 64 nested elements"
+            }
+        );
+        assert_eq!(parse_wkt("GEOGCRS[\"x\"]"), Err(WktError::Missing("DATUM")));
+        let geocentric = r#"GEODCRS["WGS 84",DATUM["WGS 84",ELLIPSOID["WGS 84",6378137,298.26]],
+            CS[Cartesian,3]]"#;
+        assert_eq!(
+            parse_wkt(geocentric),
+            Err(WktError::UnsupportedCrs("GEODCRS".into()))
+        );
+        let unknown = LAMBERT_II.replace("Lambert Conic Conformal (1SP)", "Unheard Of");
+        assert_eq!(
+            parse_wkt(&unknown),
+            Err(WktError::UnsupportedMethod("Unheard Of".into()))
+        );
+        let missing = LAMBERT_II.replace("PARAMETER[\"False northing\",2200000]", "SCOPE[\"\"]");
+        let error = parse_wkt(&missing).unwrap_err();
+        eprintln!("{error}");
+        assert_eq!(
+            error,
+            WktError::MissingParameter {
+                method: 9801,
+                parameter: 8807
+            }
+        );
+    }
+}